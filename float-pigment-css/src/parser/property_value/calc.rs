@@ -143,7 +143,14 @@ impl Length {
             Length::Vw(v) => *v,
             Length::Vmax(v) => *v,
             Length::Vmin(v) => *v,
-            Length::Expr(_) | Length::Auto | Length::Undefined => panic!("not a literal value"),
+            Length::Expr(_)
+            | Length::Auto
+            | Length::Undefined
+            | Length::MinContent
+            | Length::MaxContent
+            | Length::FitContent
+            | Length::FitContentFunction(_)
+            | Length::Stretch => panic!("not a literal value"),
         }
     }
 }
@@ -285,7 +292,12 @@ impl ComputeCalcExpr<Length> {
             Length::Vmin(v) => (LengthUnit::Vmin, *v),
             Length::Undefined => (LengthUnit::Undefined, f32::NAN),
             Length::Auto => (LengthUnit::Auto, f32::NAN),
-            Length::Expr(_) => (LengthUnit::Expr, f32::NAN),
+            Length::Expr(_)
+            | Length::MinContent
+            | Length::MaxContent
+            | Length::FitContent
+            | Length::FitContentFunction(_)
+            | Length::Stretch => (LengthUnit::Expr, f32::NAN),
         }
     }
 }
@@ -3,7 +3,7 @@ use cssparser::{ParseError, Parser, Token};
 use crate::{
     parser::{property_value::custom_ident_repr, CustomError, ParseState},
     sheet::PropertyMeta,
-    typing::GridAutoFlow,
+    typing::{GridAutoFlow, Length, MasonryAutoFlow, TrackListItem, TrackSize, MAX_REPEAT_COUNT},
 };
 
 #[inline(never)]
@@ -46,6 +46,42 @@ pub(crate) fn fr_repr<'a, 't: 'a, 'i: 't>(
     Err(parser.new_unexpected_token_error(next))
 }
 
+#[inline(never)]
+pub(crate) fn repeat_count_repr<'a, 't: 'a, 'i: 't>(
+    parser: &'a mut Parser<'i, 't>,
+    _properties: &mut [PropertyMeta],
+    _st: &mut ParseState,
+) -> Result<u32, ParseError<'i, CustomError>> {
+    let next = parser.next()?;
+    if let Token::Number {
+        int_value: Some(x), ..
+    } = next
+    {
+        if *x > 0 {
+            return Ok((*x as u32).min(MAX_REPEAT_COUNT));
+        }
+    }
+    let next = next.clone();
+    Err(parser.new_unexpected_token_error(next))
+}
+
+/// Whether the track list of an auto repeat only contains `<fixed-size>` tracks.
+///
+/// CSS Grid §7.2.3.1: `auto-fill` and `auto-fit` repeats cannot contain flexible or intrinsic track sizes,
+/// i.e. one of the min or max sizing functions must be a `<fixed-breadth>`.
+pub(crate) fn is_fixed_track_list(list: &[TrackListItem]) -> bool {
+    let is_fixed_breadth =
+        |x: &TrackSize| matches!(x, TrackSize::Length(x) if !matches!(x, Length::Auto));
+    list.iter().all(|item| match item {
+        TrackListItem::TrackSize(TrackSize::MinMax(min, max)) => {
+            is_fixed_breadth(min) || is_fixed_breadth(max)
+        }
+        TrackListItem::TrackSize(x) => is_fixed_breadth(x),
+        TrackListItem::LineNames(_) => true,
+        TrackListItem::Repeat(..) => false,
+    })
+}
+
 #[inline(never)]
 pub(crate) fn grid_auto_flow_repr<'a, 't: 'a, 'i: 't>(
     parser: &'a mut Parser<'i, 't>,
@@ -95,3 +131,54 @@ pub(crate) fn grid_auto_flow_repr<'a, 't: 'a, 'i: 't>(
         (None, _) => Err(parser.new_custom_error(CustomError::Unmatched)),
     }
 }
+
+#[inline(never)]
+pub(crate) fn masonry_auto_flow_repr<'a, 't: 'a, 'i: 't>(
+    parser: &'a mut Parser<'i, 't>,
+    _properties: &mut [PropertyMeta],
+    _st: &mut ParseState,
+) -> Result<MasonryAutoFlow, ParseError<'i, CustomError>> {
+    let mut next_placement = None;
+    let mut ordered = None;
+    while !parser.is_exhausted() {
+        let next = parser.next()?;
+        match next {
+            Token::Ident(ident) => {
+                let ident: &str = ident;
+                match ident {
+                    "pack" if next_placement.is_none() => {
+                        next_placement.replace(false);
+                    }
+                    "next" if next_placement.is_none() => {
+                        next_placement.replace(true);
+                    }
+                    "definite-first" if ordered.is_none() => {
+                        ordered.replace(false);
+                    }
+                    "ordered" if ordered.is_none() => {
+                        ordered.replace(true);
+                    }
+                    _ => {
+                        let next = next.clone();
+                        return Err(parser.new_unexpected_token_error(next));
+                    }
+                }
+            }
+            _ => {
+                let next = next.clone();
+                return Err(parser.new_unexpected_token_error(next));
+            }
+        }
+    }
+    match (next_placement, ordered) {
+        (None, None) => Err(parser.new_custom_error(CustomError::Unmatched)),
+        (next_placement, ordered) => Ok(
+            match (next_placement.unwrap_or(false), ordered.unwrap_or(false)) {
+                (false, false) => MasonryAutoFlow::Pack,
+                (true, false) => MasonryAutoFlow::Next,
+                (false, true) => MasonryAutoFlow::PackOrdered,
+                (true, true) => MasonryAutoFlow::NextOrdered,
+            },
+        ),
+    }
+}
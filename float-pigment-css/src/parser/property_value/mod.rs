@@ -146,6 +146,36 @@ pub(crate) fn length_percentage_auto<'a, 't: 'a, 'i: 't>(
     parse_length_inner(parser, properties, st, true, true, true)
 }
 
+/// Parse a sizing value: `<length-percentage> | auto | min-content | max-content | fit-content | fit-content(<length-percentage>) | stretch`.
+#[inline(never)]
+pub(crate) fn size_length<'a, 't: 'a, 'i: 't>(
+    parser: &'a mut Parser<'i, 't>,
+    properties: &mut Vec<PropertyMeta>,
+    st: &mut ParseState,
+) -> Result<Length, ParseError<'i, CustomError>> {
+    if let Ok(x) = parser.try_parse(|parser| length_percentage_auto(parser, properties, st)) {
+        return Ok(x);
+    }
+    let next = parser.next()?.clone();
+    match &next {
+        Token::Ident(ident) => match &**ident {
+            "min-content" => return Ok(Length::MinContent),
+            "max-content" => return Ok(Length::MaxContent),
+            "fit-content" => return Ok(Length::FitContent),
+            "stretch" => return Ok(Length::Stretch),
+            _ => {}
+        },
+        Token::Function(name) if &**name == "fit-content" => {
+            let x = parser.parse_nested_block(|parser| {
+                parse_length_inner(parser, properties, st, true, false, false)
+            })?;
+            return Ok(Length::FitContentFunction(Box::new(x)));
+        }
+        _ => {}
+    }
+    Err(parser.new_unexpected_token_error(next))
+}
+
 #[inline(never)]
 pub(crate) fn env_default_value<'a, 't: 'a, 'i: 't>(
     parser: &'a mut Parser<'i, 't>,
@@ -287,7 +317,14 @@ pub(crate) fn non_negative_length_only<'a, 't: 'a, 'i: 't>(
 #[inline(never)]
 pub(crate) fn is_non_negative_length(length: &Length) -> bool {
     match length {
-        Length::Auto | Length::Undefined | Length::Expr(_) => true,
+        Length::Auto
+        | Length::Undefined
+        | Length::Expr(_)
+        | Length::MinContent
+        | Length::MaxContent
+        | Length::FitContent
+        | Length::Stretch => true,
+        Length::FitContentFunction(x) => is_non_negative_length(x),
         Length::Px(v) => *v >= 0.,
         Length::Em(v) => *v >= 0.,
         Length::Rem(v) => *v >= 0.,
@@ -497,6 +534,26 @@ pub(crate) fn non_negative_number<'a, 't: 'a, 'i: 't>(
     Err(parser.new_unexpected_token_error(next))
 }
 
+#[inline(never)]
+pub(crate) fn positive_integer<'a, 't: 'a, 'i: 't>(
+    parser: &'a mut Parser<'i, 't>,
+    _properties: &mut [PropertyMeta],
+    _st: &mut ParseState,
+) -> Result<Number, ParseError<'i, CustomError>> {
+    let next = parser.next()?;
+    if let Token::Number {
+        int_value: Some(value),
+        ..
+    } = next
+    {
+        if *value > 0 {
+            return Ok(Number::I32(*value));
+        }
+    }
+    let next = next.clone();
+    Err(parser.new_unexpected_token_error(next))
+}
+
 #[inline(never)]
 pub(crate) fn line_width<'a, 't: 'a, 'i: 't>(
     parser: &'a mut Parser<'i, 't>,
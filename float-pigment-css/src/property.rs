@@ -153,7 +153,7 @@ property_list! (PropertyValueWithGlobal, {
     0x96 WordBreak: WordBreakType as Inherit default WordBreak::BreakWord;
     0x97 WhiteSpace: WhiteSpaceType as Inherit default WhiteSpace::Normal;
     0x98 TextOverflow: TextOverflowType as Inherit default TextOverflow::Clip;
-    0x99 TextIndent: LengthType as Initial default Length::Undefined, resolver = Length::resolve_em_and_ratio;
+    0x99 TextIndent: LengthType as Initial default Length::Undefined, resolver = Length::resolve_em;
     0x9a VerticalAlign: VerticalAlignType as Initial default VerticalAlign::Baseline;
     0x9b LetterSpacing: LetterSpacingType as Inherit default LetterSpacing::Normal;
     0x9c WordSpacing: WordSpacingType as Inherit default WordSpacing::Normal;
@@ -174,6 +174,30 @@ property_list! (PropertyValueWithGlobal, {
     0xa9 GridAutoRows: GridAutoType as Initial default GridAuto::List(vec![TrackSize::Length(Length::Auto)].into());
     0xaa GridAutoColumns: GridAutoType as Initial default GridAuto::List(vec![TrackSize::Length(Length::Auto)].into());
     0xab TextUnderlineOffset: TextUnderlineOffsetType as Initial default TextUnderlineOffset::Auto;
+    0xac MasonryAutoFlow: MasonryAutoFlowType as Initial default MasonryAutoFlow::Pack;
+    0xad Clear: ClearType as Initial default Clear::None;
+
+    // multi-column
+    0xb0 ColumnCount: ColumnCountType as Initial default ColumnCount::Auto;
+    0xb1 ColumnWidth: LengthType as Initial default Length::Auto, resolver = Length::resolve_em;
+    0xb2 ColumnRuleWidth: LengthType as Initial default Length::Px(3.), resolver = Length::resolve_em;
+    0xb3 ColumnRuleStyle: BorderStyleType as Initial default BorderStyle::None;
+    0xb4 ColumnRuleColor: ColorType as Initial default Color::CurrentColor;
+    0xb5 ColumnSpan: ColumnSpanType as Initial default ColumnSpan::None;
+    0xb6 ColumnFill: ColumnFillType as Initial default ColumnFill::Balance;
+
+    // table
+    0xb8 TableLayout: TableLayoutType as Initial default TableLayout::Auto;
+    0xb9 BorderCollapse: BorderCollapseType as Inherit default BorderCollapse::Separate;
+    0xba BorderSpacingHorizontal: LengthType as Inherit default Length::Px(0.), resolver = Length::resolve_em;
+    0xbb BorderSpacingVertical: LengthType as Inherit default Length::Px(0.), resolver = Length::resolve_em;
+
+    // fragmentation
+    0xbc BreakBefore: BreakType as Initial default Break::Auto;
+    0xbd BreakAfter: BreakType as Initial default Break::Auto;
+    0xbe BreakInside: BreakInsideType as Initial default BreakInside::Auto;
+    0xbf Orphans: NumberType as Inherit default Number::I32(2);
+    0xc0 Widows: NumberType as Inherit default Number::I32(2);
 
     // misc
     0xd0 ListStyleType: ListStyleTypeType as Inherit default ListStyleType::Disc;
@@ -210,6 +234,15 @@ property_value_format! (PropertyValueWithGlobal, {
         | "flow-root" => DisplayType::FlowRoot
         | "inline-flex" => DisplayType::InlineFlex
         | "inline-grid" => DisplayType::InlineGrid
+        | "contents" => DisplayType::Contents
+        | "table" => DisplayType::Table
+        | "table-row" => DisplayType::TableRow
+        | "table-cell" => DisplayType::TableCell
+        | "table-row-group" => DisplayType::TableRowGroup
+        | "table-header-group" => DisplayType::TableHeaderGroup
+        | "table-column" => DisplayType::TableColumn
+        | "table-caption" => DisplayType::TableCaption
+        | "list-item" => DisplayType::ListItem
     }};
     position: {{ Position
         = "static" => PositionType::Static
@@ -225,6 +258,14 @@ property_value_format! (PropertyValueWithGlobal, {
         | "inline-start" => FloatType::InlineStart
         | "inline-end" => FloatType::InlineEnd
     }};
+    clear: {{ Clear
+        = "none" => ClearType::None
+        | "left" => ClearType::Left
+        | "right" => ClearType::Right
+        | "both" => ClearType::Both
+        | "inline-start" => ClearType::InlineStart
+        | "inline-end" => ClearType::InlineEnd
+    }};
     overflow_x: {{ OverflowX
         = "visible" => OverflowType::Visible
         | "hidden" => OverflowType::Hidden
@@ -284,6 +325,8 @@ property_value_format! (PropertyValueWithGlobal, {
         | "flex-start" => AlignItems::FlexStart
         | "flex-end" => AlignItems::FlexEnd
         | "baseline" => AlignItems::Baseline
+        | ["first" "baseline"] => AlignItems::Baseline
+        | ["last" "baseline"] => AlignItems::LastBaseline
         | "normal" => AlignItems::Normal
         | "start" => AlignItems::Start
         | "end" => AlignItems::End
@@ -297,6 +340,8 @@ property_value_format! (PropertyValueWithGlobal, {
         | "flex-start" => AlignSelf::FlexStart
         | "flex-end" => AlignSelf::FlexEnd
         | "baseline" => AlignSelf::Baseline
+        | ["first" "baseline"] => AlignSelf::Baseline
+        | ["last" "baseline"] => AlignSelf::LastBaseline
         | "start" => AlignSelf::Start
         | "end" => AlignSelf::End
         | "self-start" => AlignSelf::SelfStart
@@ -376,12 +421,12 @@ property_value_format! (PropertyValueWithGlobal, {
     }};
     flex_grow: {{ FlexGrow = <number> }};
     flex_shrink: {{ FlexShrink = <number> }};
-    flex_basis: {{ FlexBasis = <length_percentage_auto> }};
+    flex_basis: {{ FlexBasis = <size_length> }};
     flex_flow: <flex_direction> || <flex_wrap>;
     flex: {{ (FlexGrow, FlexShrink, FlexBasis)
         = "auto" -> |_| (Number::F32(1.), Number::F32(1.), Length::Auto);
         | "none" -> |_| (Number::F32(0.), Number::F32(0.), Length::Auto);
-        | [ <number> <number>? || <length_percentage_auto> ] -> |(gs, b): (Option<(Number, Option<Number>)>, Option<Length>)| -> _ {
+        | [ <number> <number>? || <size_length> ] -> |(gs, b): (Option<(Number, Option<Number>)>, Option<Length>)| -> _ {
             let (g, s) = gs.unwrap_or((Number::F32(1.), None));
             let s = s.unwrap_or(Number::F32(1.));
             let b = b.unwrap_or(Length::Ratio(0.));
@@ -458,6 +503,7 @@ property_value_format! (PropertyValueWithGlobal, {
         | "bottom" => VerticalAlignType::Bottom
         | "text-top" => VerticalAlignType::TextTop
         | "text-bottom" => VerticalAlignType::TextBottom
+        | <length_percentage> -> |x: Length| VerticalAlignType::Length(x);
     }};
     letter_spacing: {{ LetterSpacing =
         "normal" => LetterSpacingType::Normal
@@ -778,12 +824,12 @@ property_value_format! (PropertyValueWithGlobal, {
         | "padding-box" => BoxSizingType::PaddingBox
         | "content-box" => BoxSizingType::ContentBox
     }};
-    width: {{ Width = <length_percentage_auto> }};
-    height: {{ Height = <length_percentage_auto> }};
-    min_width: {{ MinWidth = <length_percentage_auto> }};
-    min_height: {{ MinHeight = <length_percentage_auto> }};
-    max_width: {{ MaxWidth = <length_percentage_auto> }};
-    max_height: {{ MaxHeight = <length_percentage_auto> }};
+    width: {{ Width = <size_length> }};
+    height: {{ Height = <size_length> }};
+    min_width: {{ MinWidth = <size_length> }};
+    min_height: {{ MinHeight = <size_length> }};
+    max_width: {{ MaxWidth = <size_length> }};
+    max_height: {{ MaxHeight = <size_length> }};
     left: {{ Left = <length_percentage_auto> }};
     right: {{ Right = <length_percentage_auto> }};
     top: {{ Top = <length_percentage_auto> }};
@@ -1345,6 +1391,7 @@ property_value_format! (PropertyValueWithGlobal, {
         = <color_repr>{1, 2} -> split_hv
     }};
     _wx_line_clamp: {{ WxLineClamp = <number> }};
+    _webkit_line_clamp: {{ WxLineClamp = <number> }};
     _wx_contain: {{ WxContain
         = "none" => ContainType::None
         | "strict" => ContainType::Strict
@@ -1689,27 +1736,88 @@ property_value_format! (PropertyValueWithGlobal, {
         | <fr_repr> -> |x: f32| TrackSize::Fr(x);
     ;
 
+    <inflexible_breadth: TrackSize>:
+        "auto" -> |_| TrackSize::Length(Length::Auto);
+        | <non_negative_length_percentage> -> |x: Length| TrackSize::Length(x);
+        | "min-content" -> |_| TrackSize::MinContent;
+        | "max-content" -> |_| TrackSize::MaxContent;
+    ;
+
     <track_size: TrackSize>:
         <track_breadth> -> |x: TrackSize| x;
+        | minmax(<inflexible_breadth> ',' <track_breadth>)
+            -> |(min, _, max): (TrackSize, _, TrackSize)| {
+                TrackSize::MinMax(Box::new(min), Box::new(max))
+            };
+        | fit_content(<non_negative_length_percentage>) -> |x: Length| TrackSize::FitContent(x);
+    ;
+
+    <track_repeat_count: RepeatCount>:
+        "auto-fill" => RepeatCount::AutoFill
+        | "auto-fit" => RepeatCount::AutoFit
+        | <repeat_count_repr> -> |x: u32| RepeatCount::Count(x);
+    ;
+
+    <track_repeat: TrackListItem>:
+        repeat(<track_repeat_count> ',' [ <line_names>? <track_size> ]+ <line_names>?)
+            -> |x: (RepeatCount, _, Vec<(Option<Vec<String>>, TrackSize)>, Option<Vec<String>>)| {
+                let mut ret = vec![];
+                for item in x.2.into_iter() {
+                    if let Some(line_names) = item.0 {
+                        ret.push(TrackListItem::LineNames(line_names.iter().map(|x| x.into()).collect::<Vec<StrRef>>().into()));
+                    }
+                    ret.push(TrackListItem::TrackSize(item.1));
+                }
+                if let Some(line_names) = x.3 {
+                    ret.push(TrackListItem::LineNames(line_names.iter().map(|x| x.into()).collect::<Vec<StrRef>>().into()));
+                }
+                TrackListItem::Repeat(x.0, ret.into())
+            };
+    ;
+
+    <track_list_entry: TrackListItem>:
+        <track_size> -> |x: TrackSize| TrackListItem::TrackSize(x);
+        | <track_repeat> -> |x: TrackListItem| x;
     ;
 
     <track_list: Vec<TrackListItem>>:
-        [ [ <line_names>? [ <track_size> ] ]+ <line_names>? ] -> |x: (Vec<(Option<Vec<String>>, TrackSize)>, Option<Vec<String>>)| {
+        [ [ <line_names>? [ <track_list_entry> ] ]+ <line_names>? ] -> ResultClosure |x: (Vec<(Option<Vec<String>>, TrackListItem)>, Option<Vec<String>>), parser: &mut Parser<'i, 't>| -> Result<Vec<TrackListItem>, ParseError<'i, CustomError>> {
             let mut ret = vec![];
+            let mut auto_repeat_count = 0;
             for item in x.0.into_iter() {
                 if let Some(line_names) = item.0 {
                     ret.push(TrackListItem::LineNames(line_names.iter().map(|x| x.into()).collect::<Vec<StrRef>>().into()));
                 }
-                ret.push(TrackListItem::TrackSize(item.1));
+                if let TrackListItem::Repeat(RepeatCount::AutoFill | RepeatCount::AutoFit, list) = &item.1 {
+                    // CSS Grid §7.2.3.1: auto repeats only accept fixed track sizes
+                    if !is_fixed_track_list(list.as_ref()) {
+                        return Err(parser.new_custom_error(CustomError::Unmatched));
+                    }
+                    auto_repeat_count += 1;
+                }
+                ret.push(item.1);
             }
             if let Some(line_names) = x.1 {
                 ret.push(TrackListItem::LineNames(line_names.iter().map(|x| x.into()).collect::<Vec<StrRef>>().into()));
             }
-            ret
+            // CSS Grid §7.2.3: at most one auto-repeated track list is allowed
+            if auto_repeat_count > 1 {
+                return Err(parser.new_custom_error(CustomError::Unmatched));
+            }
+            Ok(ret)
         };
     ;
+    <subgrid: GridTemplate>:
+        ["subgrid" <line_names>+] -> |x: ((), Vec<Vec<String>>)| {
+            GridTemplate::Subgrid(x.1.into_iter().map(|line_names| line_names.iter().map(|x| x.into()).collect::<Vec<StrRef>>().into()).collect::<Vec<_>>().into())
+        };
+        | "subgrid" -> |_| GridTemplate::Subgrid(vec![].into());
+    ;
+
     grid_template_rows: {{ GridTemplateRows
         = "none" => GridTemplate::None
+        | "masonry" => GridTemplate::Masonry
+        | <subgrid>
         | <track_list> -> |x: Vec<TrackListItem>| {
             GridTemplate::TrackList(x.into())
         };
@@ -1717,12 +1825,15 @@ property_value_format! (PropertyValueWithGlobal, {
 
     grid_template_columns: {{ GridTemplateColumns
         = "none" => GridTemplate::None
+        | "masonry" => GridTemplate::Masonry
+        | <subgrid>
         | <track_list> -> |x: Vec<TrackListItem>| {
             GridTemplate::TrackList(x.into())
         };
     }};
 
     grid_auto_flow: {{ GridAutoFlow = <grid_auto_flow_repr> }};
+    masonry_auto_flow: {{ MasonryAutoFlow = <masonry_auto_flow_repr> }};
 
     grid_auto_rows: {{ GridAutoRows
         = <track_size>+ -> |x: Vec<TrackSize>| {
@@ -1736,6 +1847,83 @@ property_value_format! (PropertyValueWithGlobal, {
         };
     }};
 
+    column_count: {{ ColumnCount
+        = "auto" => ColumnCountType::Auto
+        | <positive_integer> -> |x: Number| ColumnCountType::Num(x);
+    }};
+    <column_width_repr: Length>:
+        "auto" => Length::Auto
+        | <non_negative_length_only>
+    ;
+    column_width: {{ ColumnWidth = <column_width_repr> }};
+    columns: <column_width> || <column_count>;
+    column_rule_width: {{ ColumnRuleWidth = <line_width> }};
+    column_rule_style: {{ ColumnRuleStyle = <border_style_repr> }};
+    column_rule_color: {{ ColumnRuleColor = <color_repr> }};
+    column_rule: <column_rule_width> || <column_rule_style> || <column_rule_color>;
+    column_span: {{ ColumnSpan
+        = "none" => ColumnSpanType::None
+        | "all" => ColumnSpanType::All
+    }};
+    column_fill: {{ ColumnFill
+        = "balance" => ColumnFillType::Balance
+        | "auto" => ColumnFillType::Auto
+        | "balance-all" => ColumnFillType::BalanceAll
+    }};
+
+    table_layout: {{ TableLayout
+        = "auto" => TableLayoutType::Auto
+        | "fixed" => TableLayoutType::Fixed
+    }};
+    border_collapse: {{ BorderCollapse
+        = "separate" => BorderCollapseType::Separate
+        | "collapse" => BorderCollapseType::Collapse
+    }};
+    border_spacing: {{ (BorderSpacingHorizontal, BorderSpacingVertical)
+        = [ <non_negative_length_only> <non_negative_length_only>? ] -> |(h, v): (Length, Option<Length>)| {
+            if let Some(v) = v {
+                return (h, v);
+            }
+            (h.clone(), h)
+        };
+    }};
+
+    <break_repr: Break>:
+        "auto" => Break::Auto
+        | "avoid" => Break::Avoid
+        | "avoid-page" => Break::AvoidPage
+        | "page" => Break::Page
+        | "left" => Break::Left
+        | "right" => Break::Right
+        | "recto" => Break::Recto
+        | "verso" => Break::Verso
+        | "avoid-column" => Break::AvoidColumn
+        | "column" => Break::Column
+    ;
+    <page_break_repr: Break>:
+        "auto" => Break::Auto
+        | "always" => Break::Page
+        | "avoid" => Break::Avoid
+        | "left" => Break::Left
+        | "right" => Break::Right
+    ;
+    break_before: {{ BreakBefore = <break_repr> }};
+    break_after: {{ BreakAfter = <break_repr> }};
+    break_inside: {{ BreakInside
+        = "auto" => BreakInsideType::Auto
+        | "avoid" => BreakInsideType::Avoid
+        | "avoid-page" => BreakInsideType::AvoidPage
+        | "avoid-column" => BreakInsideType::AvoidColumn
+    }};
+    page_break_before: {{ BreakBefore = <page_break_repr> }};
+    page_break_after: {{ BreakAfter = <page_break_repr> }};
+    page_break_inside: {{ BreakInside
+        = "auto" => BreakInsideType::Auto
+        | "avoid" => BreakInsideType::Avoid
+    }};
+    orphans: {{ Orphans = <number> -> |x: Number| Number::I32(x.to_i32()); }};
+    widows: {{ Widows = <number> -> |x: Number| Number::I32(x.to_i32()); }};
+
     <touch_action_pan_x: u8>:
         "pan-x" -> |_| 3;
         | "pan-left" -> |_| 1;
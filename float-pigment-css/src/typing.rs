@@ -153,6 +153,11 @@ pub enum Length {
     Expr(Box<LengthExpr>),
     Vmin(f32),
     Vmax(f32),
+    MinContent,
+    MaxContent,
+    FitContent,
+    FitContentFunction(Box<Length>),
+    Stretch,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -177,6 +182,8 @@ impl Length {
     pub(crate) fn resolve_em(&mut self, font_size: f32) {
         if let Self::Em(x) = *self {
             *self = Self::Px(x * font_size);
+        } else if let Self::FitContentFunction(x) = self {
+            x.resolve_em(font_size);
         }
     }
 
@@ -219,7 +226,13 @@ impl Length {
         length_as_parent_font_size: bool,
     ) -> Option<f32> {
         let r = match self {
-            Length::Undefined | Length::Auto => None?,
+            Length::Undefined
+            | Length::Auto
+            | Length::MinContent
+            | Length::MaxContent
+            | Length::FitContent
+            | Length::FitContentFunction(_)
+            | Length::Stretch => None?,
             Length::Px(x) => *x,
             Length::Vw(x) => media_query_status.width.to_f32() / 100. * *x,
             Length::Vh(x) => media_query_status.height.to_f32() / 100. * *x,
@@ -461,6 +474,15 @@ pub enum Display {
     FlowRoot,
     InlineFlex,
     InlineGrid,
+    Contents,
+    Table,
+    TableRow,
+    TableCell,
+    TableRowGroup,
+    TableHeaderGroup,
+    TableColumn,
+    TableCaption,
+    ListItem,
 }
 
 #[allow(missing_docs)]
@@ -594,6 +616,7 @@ pub enum AlignItems {
     SelfStart,
     SelfEnd,
     Baseline,
+    LastBaseline,
 }
 
 #[allow(missing_docs)]
@@ -613,6 +636,7 @@ pub enum AlignSelf {
     FlexStart,
     FlexEnd,
     Baseline,
+    LastBaseline,
 }
 
 #[allow(missing_docs)]
@@ -770,6 +794,8 @@ pub enum VerticalAlign {
     Bottom,
     TextTop,
     TextBottom,
+    #[resolve_font_size(Length::resolve_em)]
+    Length(Length),
 }
 
 #[allow(missing_docs)]
@@ -1337,6 +1363,109 @@ pub enum Float {
     InlineEnd,
 }
 
+/// The `clear` property specifies which preceding floats an element must be moved below.
+#[allow(missing_docs)]
+#[repr(C)]
+#[property_value_type(PropertyValueWithGlobal for ClearType)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ResolveFontSize)]
+#[cfg_attr(debug_assertions, derive(CompatibilityEnumCheck))]
+pub enum Clear {
+    None,
+    Left,
+    Right,
+    Both,
+    InlineStart,
+    InlineEnd,
+}
+
+/// The `column-count` property specifies the number of columns of a multi-column container.
+#[allow(missing_docs)]
+#[repr(C)]
+#[property_value_type(PropertyValueWithGlobal for ColumnCountType)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ResolveFontSize)]
+#[cfg_attr(debug_assertions, derive(CompatibilityEnumCheck))]
+pub enum ColumnCount {
+    Auto,
+    Num(Number),
+}
+
+/// The `column-span` property makes an element span across all columns of its multi-column container.
+#[allow(missing_docs)]
+#[repr(C)]
+#[property_value_type(PropertyValueWithGlobal for ColumnSpanType)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ResolveFontSize)]
+#[cfg_attr(debug_assertions, derive(CompatibilityEnumCheck))]
+pub enum ColumnSpan {
+    None,
+    All,
+}
+
+/// The `column-fill` property specifies whether the columns are balanced.
+#[allow(missing_docs)]
+#[repr(C)]
+#[property_value_type(PropertyValueWithGlobal for ColumnFillType)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ResolveFontSize)]
+#[cfg_attr(debug_assertions, derive(CompatibilityEnumCheck))]
+pub enum ColumnFill {
+    Balance,
+    Auto,
+    BalanceAll,
+}
+
+/// The `break-before` and `break-after` properties specify the fragmentation break behavior between boxes.
+#[allow(missing_docs)]
+#[repr(C)]
+#[property_value_type(PropertyValueWithGlobal for BreakType)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ResolveFontSize)]
+#[cfg_attr(debug_assertions, derive(CompatibilityEnumCheck))]
+pub enum Break {
+    Auto,
+    Avoid,
+    AvoidPage,
+    Page,
+    Left,
+    Right,
+    Recto,
+    Verso,
+    AvoidColumn,
+    Column,
+}
+
+/// The `break-inside` property specifies the fragmentation break behavior inside a box.
+#[allow(missing_docs)]
+#[repr(C)]
+#[property_value_type(PropertyValueWithGlobal for BreakInsideType)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ResolveFontSize)]
+#[cfg_attr(debug_assertions, derive(CompatibilityEnumCheck))]
+pub enum BreakInside {
+    Auto,
+    Avoid,
+    AvoidPage,
+    AvoidColumn,
+}
+
+/// The `table-layout` property specifies the algorithm used to lay out table cells, rows, and columns.
+#[allow(missing_docs)]
+#[repr(C)]
+#[property_value_type(PropertyValueWithGlobal for TableLayoutType)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ResolveFontSize)]
+#[cfg_attr(debug_assertions, derive(CompatibilityEnumCheck))]
+pub enum TableLayout {
+    Auto,
+    Fixed,
+}
+
+/// The `border-collapse` property specifies whether the borders of the table cells are shared.
+#[allow(missing_docs)]
+#[repr(C)]
+#[property_value_type(PropertyValueWithGlobal for BorderCollapseType)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ResolveFontSize)]
+#[cfg_attr(debug_assertions, derive(CompatibilityEnumCheck))]
+pub enum BorderCollapse {
+    Separate,
+    Collapse,
+}
+
 #[allow(missing_docs)]
 #[repr(C)]
 #[property_value_type(PropertyValueWithGlobal for ListStyleTypeType)]
@@ -1874,6 +2003,10 @@ pub enum GridTemplate {
     /// A keyword meaning that there is no explicit grid
     None,
     TrackList(Array<TrackListItem>),
+    /// `subgrid`, adopting the tracks of the parent grid, with optional line names.
+    Subgrid(Array<Array<StrRef>>),
+    /// `masonry`, making this axis the masonry axis of a masonry layout.
+    Masonry,
 }
 
 #[allow(missing_docs)]
@@ -1883,6 +2016,24 @@ pub enum GridTemplate {
 pub enum TrackListItem {
     LineNames(Array<StrRef>),
     TrackSize(TrackSize),
+    /// The `repeat()` notation, containing line names and track sizes to be repeated.
+    Repeat(RepeatCount, Array<TrackListItem>),
+}
+
+/// The upper bound of the `repeat()` counts, guarding against huge track lists.
+///
+/// It applies to both the fixed counts and the resolved `auto-fill` / `auto-fit` repetitions.
+pub const MAX_REPEAT_COUNT: u32 = 10000;
+
+/// The repetition count of the `repeat()` notation in a track list.
+#[allow(missing_docs)]
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ResolveFontSize)]
+#[cfg_attr(debug_assertions, derive(CompatibilityEnumCheck))]
+pub enum RepeatCount {
+    Count(u32),
+    AutoFill,
+    AutoFit,
 }
 
 #[allow(missing_docs)]
@@ -1895,6 +2046,11 @@ pub enum TrackSize {
     Fr(f32),
     #[resolve_font_size(Length::resolve_em)]
     Length(Length),
+    /// `minmax(min, max)`: a size range greater than or equal to `min` and less than or equal to `max`.
+    MinMax(Box<TrackSize>, Box<TrackSize>),
+    /// `fit-content(limit)`: `minmax(auto, max-content)` clamped by `limit`.
+    #[resolve_font_size(Length::resolve_em)]
+    FitContent(Length),
 }
 
 #[allow(missing_docs)]
@@ -1909,6 +2065,23 @@ pub enum GridAutoFlow {
     ColumnDense,
 }
 
+/// The `masonry-auto-flow` property controls how items are placed into the tracks of a masonry layout.
+#[allow(missing_docs)]
+#[repr(C)]
+#[property_value_type(PropertyValueWithGlobal for MasonryAutoFlowType)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ResolveFontSize)]
+#[cfg_attr(debug_assertions, derive(CompatibilityEnumCheck))]
+pub enum MasonryAutoFlow {
+    /// `pack`: place each item into the track with the most remaining space.
+    Pack,
+    /// `next`: place the items into the tracks one after another.
+    Next,
+    /// `pack ordered`
+    PackOrdered,
+    /// `next ordered`
+    NextOrdered,
+}
+
 #[allow(missing_docs)]
 #[repr(C)]
 #[property_value_type(PropertyValueWithGlobal for GridAutoType)]
@@ -61,6 +61,15 @@ impl fmt::Display for Display {
                 Self::FlowRoot => "flow-root",
                 Self::InlineFlex => "inline-flex",
                 Self::InlineGrid => "inline-grid",
+                Self::Contents => "contents",
+                Self::Table => "table",
+                Self::TableRow => "table-row",
+                Self::TableCell => "table-cell",
+                Self::TableRowGroup => "table-row-group",
+                Self::TableHeaderGroup => "table-header-group",
+                Self::TableColumn => "table-column",
+                Self::TableCaption => "table-caption",
+                Self::ListItem => "list-item",
             }
         )
     }
@@ -289,6 +298,14 @@ impl fmt::Display for Length {
                     tmp = format!("{x}vmax");
                     &tmp
                 }
+                Length::MinContent => "min-content",
+                Length::MaxContent => "max-content",
+                Length::FitContent => "fit-content",
+                Length::FitContentFunction(x) => {
+                    tmp = format!("fit-content({x})");
+                    &tmp
+                }
+                Length::Stretch => "stretch",
             }
         )
     }
@@ -467,6 +484,7 @@ impl fmt::Display for AlignItems {
                 AlignItems::SelfStart => "self-start",
                 AlignItems::SelfEnd => "self-end",
                 AlignItems::Baseline => "baseline",
+                AlignItems::LastBaseline => "last baseline",
             }
         )
     }
@@ -488,6 +506,7 @@ impl fmt::Display for AlignSelf {
                 AlignSelf::FlexStart => "flex-start",
                 AlignSelf::FlexEnd => "flex-end",
                 AlignSelf::Baseline => "baseline",
+                AlignSelf::LastBaseline => "last baseline",
             }
         )
     }
@@ -661,6 +680,7 @@ impl fmt::Display for TextOverflow {
 }
 impl fmt::Display for VerticalAlign {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let x;
         write!(
             f,
             "{}",
@@ -671,6 +691,10 @@ impl fmt::Display for VerticalAlign {
                 VerticalAlign::Bottom => "bottom",
                 VerticalAlign::TextTop => "text-top",
                 VerticalAlign::TextBottom => "text-bottom",
+                VerticalAlign::Length(a) => {
+                    x = a.to_string();
+                    &x
+                }
             }
         )
     }
@@ -1686,6 +1710,113 @@ impl fmt::Display for Float {
         )
     }
 }
+impl fmt::Display for Clear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Clear::None => "none",
+                Clear::Left => "left",
+                Clear::Right => "right",
+                Clear::Both => "both",
+                Clear::InlineStart => "inline-start",
+                Clear::InlineEnd => "inline-end",
+            }
+        )
+    }
+}
+impl fmt::Display for ColumnCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnCount::Auto => write!(f, "auto"),
+            ColumnCount::Num(x) => write!(f, "{x}"),
+        }
+    }
+}
+impl fmt::Display for ColumnSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ColumnSpan::None => "none",
+                ColumnSpan::All => "all",
+            }
+        )
+    }
+}
+impl fmt::Display for ColumnFill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ColumnFill::Balance => "balance",
+                ColumnFill::Auto => "auto",
+                ColumnFill::BalanceAll => "balance-all",
+            }
+        )
+    }
+}
+impl fmt::Display for Break {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Break::Auto => "auto",
+                Break::Avoid => "avoid",
+                Break::AvoidPage => "avoid-page",
+                Break::Page => "page",
+                Break::Left => "left",
+                Break::Right => "right",
+                Break::Recto => "recto",
+                Break::Verso => "verso",
+                Break::AvoidColumn => "avoid-column",
+                Break::Column => "column",
+            }
+        )
+    }
+}
+impl fmt::Display for BreakInside {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                BreakInside::Auto => "auto",
+                BreakInside::Avoid => "avoid",
+                BreakInside::AvoidPage => "avoid-page",
+                BreakInside::AvoidColumn => "avoid-column",
+            }
+        )
+    }
+}
+impl fmt::Display for TableLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TableLayout::Auto => "auto",
+                TableLayout::Fixed => "fixed",
+            }
+        )
+    }
+}
+impl fmt::Display for BorderCollapse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                BorderCollapse::Separate => "separate",
+                BorderCollapse::Collapse => "collapse",
+            }
+        )
+    }
+}
 impl fmt::Display for ListStyleType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let x;
@@ -2403,28 +2534,54 @@ impl fmt::Display for TrackSize {
             TrackSize::MinContent => write!(f, "min-content"),
             TrackSize::MaxContent => write!(f, "max-content"),
             TrackSize::Fr(x) => write!(f, "{x}fr"),
+            TrackSize::MinMax(min, max) => write!(f, "minmax({min}, {max})"),
+            TrackSize::FitContent(x) => write!(f, "fit-content({x})"),
+        }
+    }
+}
+
+impl fmt::Display for RepeatCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepeatCount::Count(x) => write!(f, "{x}"),
+            RepeatCount::AutoFill => write!(f, "auto-fill"),
+            RepeatCount::AutoFit => write!(f, "auto-fit"),
         }
     }
 }
 
+fn track_list_to_string(list: &Array<TrackListItem>) -> String {
+    let mut ret = vec![];
+    list.iter().for_each(|x| match x {
+        TrackListItem::LineNames(line_names) => ret.push(
+            line_names
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        TrackListItem::TrackSize(track_size) => ret.push(track_size.to_string()),
+        TrackListItem::Repeat(count, list) => {
+            ret.push(format!("repeat({}, {})", count, track_list_to_string(list)))
+        }
+    });
+    ret.join(" ")
+}
+
 impl fmt::Display for GridTemplate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             GridTemplate::None => write!(f, "none"),
-            GridTemplate::TrackList(list) => {
-                let mut ret = vec![];
-                list.iter().for_each(|x| match x {
-                    TrackListItem::LineNames(line_names) => ret.push(
-                        line_names
-                            .iter()
-                            .map(|x| x.to_string())
-                            .collect::<Vec<_>>()
-                            .join(" "),
-                    ),
-                    TrackListItem::TrackSize(track_size) => ret.push(track_size.to_string()),
-                });
-                write!(f, "{}", ret.join(" "))
+            GridTemplate::TrackList(list) => write!(f, "{}", track_list_to_string(list)),
+            GridTemplate::Subgrid(line_name_list) => {
+                write!(f, "subgrid")?;
+                for line_names in line_name_list.iter() {
+                    let line_names = line_names.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+                    write!(f, " [{}]", line_names.join(" "))?;
+                }
+                Ok(())
             }
+            GridTemplate::Masonry => write!(f, "masonry"),
         }
     }
 }
@@ -2440,6 +2597,17 @@ impl fmt::Display for GridAutoFlow {
     }
 }
 
+impl fmt::Display for MasonryAutoFlow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MasonryAutoFlow::Pack => write!(f, "pack"),
+            MasonryAutoFlow::Next => write!(f, "next"),
+            MasonryAutoFlow::PackOrdered => write!(f, "pack ordered"),
+            MasonryAutoFlow::NextOrdered => write!(f, "next ordered"),
+        }
+    }
+}
+
 impl fmt::Display for GridAuto {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -1441,6 +1441,7 @@ mod position {
         test_parse_property!(display, "display", "inline-block", Display::InlineBlock);
         test_parse_property!(display, "display", "grid", Display::Grid);
         test_parse_property!(display, "display", "flow-root", Display::FlowRoot);
+        test_parse_property!(display, "display", "contents", Display::Contents);
     }
 
     // 0x02 Position
@@ -1758,6 +1759,21 @@ mod position {
         assert_eq!(np.wx_line_clamp(), Number::F32(11.));
     }
 
+    #[test]
+    fn webkit_line_clamp() {
+        let mut ssg = StyleSheetGroup::new();
+        let ss = StyleSheet::from_str(
+            r#"
+            .a {
+                -webkit-line-clamp: 3;
+            }
+        "#,
+        );
+        ssg.append(ss);
+        let np = query(&ssg, "", "", ["a"], []);
+        assert_eq!(np.wx_line_clamp(), Number::F32(3.));
+    }
+
     // 0x0b Float
     #[test]
     fn float() {
@@ -1781,6 +1797,35 @@ mod position {
         assert_eq!(np.float(), Float::InlineStart);
     }
 
+    // 0xad Clear
+    #[test]
+    fn clear() {
+        let mut ssg = StyleSheetGroup::new();
+        let ss = StyleSheet::from_str(
+            r#"
+            .a {
+                clear: left;
+            }
+            .b {
+                clear: both;
+            }
+            .c {
+                clear: inline-end;
+            }
+        "#,
+        );
+        ssg.append(ss);
+        let np = query(&ssg, "", "", [""], []);
+        assert_eq!(np.clear(), Clear::None);
+        let np = query(&ssg, "", "", ["a"], []);
+        assert_eq!(np.clear(), Clear::Left);
+        let np = query(&ssg, "", "", ["b"], []);
+        assert_eq!(np.clear(), Clear::Both);
+        let np = query(&ssg, "", "", ["c"], []);
+        assert_eq!(np.clear(), Clear::InlineEnd);
+        assert_eq!(Clear::Both.to_string(), "both");
+    }
+
     // 0x0c OverflowWrap
     #[test]
     fn overflow_wrap() {
@@ -2344,6 +2389,29 @@ mod flex {
         assert_eq!(np.flex_basis(), Length::Px(480.));
     }
 
+    #[test]
+    fn flex_basis_content_sizing() {
+        let mut ssg = StyleSheetGroup::new();
+        let ss = StyleSheet::from_str(
+            r#"
+            .a { flex-basis: max-content }
+            .b { flex-basis: fit-content(50%) }
+            .c { flex: 1 min-content }
+        "#,
+        );
+        ssg.append(ss);
+        let np = query(&ssg, "", "", ["a"], []);
+        assert_eq!(np.flex_basis(), Length::MaxContent);
+        let np = query(&ssg, "", "", ["b"], []);
+        assert_eq!(
+            np.flex_basis(),
+            Length::FitContentFunction(Box::new(Length::Ratio(0.5)))
+        );
+        let np = query(&ssg, "", "", ["c"], []);
+        assert_eq!(np.flex_grow(), Number::F32(1.));
+        assert_eq!(np.flex_basis(), Length::MinContent);
+    }
+
     // 0x29
     #[test]
     fn justify_items() {
@@ -4280,6 +4348,46 @@ mod sizing {
         assert_eq!(np.height(), Length::Vh(10.));
     }
 
+    #[test]
+    fn content_sizing() {
+        let mut ssg = StyleSheetGroup::new();
+        let ss = StyleSheet::from_str(
+            r#"
+            .a { width: min-content; height: max-content }
+            .b { width: fit-content; height: stretch }
+            .c { width: fit-content(200px); min-width: fit-content(2em) }
+            .d { min-height: min-content; max-width: max-content; max-height: fit-content }
+            .e { width: fit-content(-10px) }
+            .f { width: fit-content(auto) }
+            .g { width: content }
+            "#,
+        );
+        ssg.append(ss);
+        let np = query(&ssg, "", "", ["a"], []);
+        assert_eq!(np.width(), Length::MinContent);
+        assert_eq!(np.height(), Length::MaxContent);
+        let np = query(&ssg, "", "", ["b"], []);
+        assert_eq!(np.width(), Length::FitContent);
+        assert_eq!(np.height(), Length::Stretch);
+        let np = query(&ssg, "", "", ["c"], []);
+        assert_eq!(
+            np.width(),
+            Length::FitContentFunction(Box::new(Length::Px(200.)))
+        );
+        assert_eq!(
+            np.min_width(),
+            Length::FitContentFunction(Box::new(Length::Px(32.)))
+        );
+        let np = query(&ssg, "", "", ["d"], []);
+        assert_eq!(np.min_height(), Length::MinContent);
+        assert_eq!(np.max_width(), Length::MaxContent);
+        assert_eq!(np.max_height(), Length::FitContent);
+        for class in ["e", "f", "g"] {
+            let np = query(&ssg, "", "", [class], []);
+            assert_eq!(np.width(), Length::Auto);
+        }
+    }
+
     // 0x42
     #[test]
     fn min_width() {
@@ -6002,6 +6110,9 @@ mod typography {
             .d { vertical-align: bottom }
             .e { vertical-align: text-top }
             .f { vertical-align: text-bottom }
+            .g { vertical-align: -4px }
+            .h { vertical-align: 50% }
+            .i { vertical-align: 0.5em }
         "#,
         );
         ssg.append(ss);
@@ -6017,6 +6128,15 @@ mod typography {
         assert_eq!(np.vertical_align(), VerticalAlign::TextTop);
         let np = query(&ssg, "", "", ["f"], []);
         assert_eq!(np.vertical_align(), VerticalAlign::TextBottom);
+        let np = query(&ssg, "", "", ["g"], []);
+        assert_eq!(np.vertical_align(), VerticalAlign::Length(Length::Px(-4.)));
+        let np = query(&ssg, "", "", ["h"], []);
+        assert_eq!(
+            np.vertical_align(),
+            VerticalAlign::Length(Length::Ratio(0.5))
+        );
+        let np = query(&ssg, "", "", ["i"], []);
+        assert_eq!(np.vertical_align(), VerticalAlign::Length(Length::Px(8.)));
     }
 
     // 0x9b
@@ -6550,6 +6670,283 @@ mod grid {
         );
     }
 
+    // 0xa5 0xa6
+    #[test]
+    fn grid_template_repeat() {
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "repeat(3, 100px)",
+            GridTemplate::TrackList(
+                vec![TrackListItem::Repeat(
+                    RepeatCount::Count(3),
+                    vec![TrackListItem::TrackSize(TrackSize::Length(Length::Px(
+                        100.
+                    )))]
+                    .into()
+                )]
+                .into()
+            )
+        );
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "50px repeat(auto-fill, [a] 100px minmax(50px, 1fr)) [end]",
+            GridTemplate::TrackList(
+                vec![
+                    TrackListItem::TrackSize(TrackSize::Length(Length::Px(50.))),
+                    TrackListItem::Repeat(
+                        RepeatCount::AutoFill,
+                        vec![
+                            TrackListItem::LineNames(vec!["a".to_string().into()].into()),
+                            TrackListItem::TrackSize(TrackSize::Length(Length::Px(100.))),
+                            TrackListItem::TrackSize(TrackSize::MinMax(
+                                Box::new(TrackSize::Length(Length::Px(50.))),
+                                Box::new(TrackSize::Fr(1.)),
+                            )),
+                        ]
+                        .into()
+                    ),
+                    TrackListItem::LineNames(vec!["end".to_string().into()].into()),
+                ]
+                .into()
+            )
+        );
+        test_parse_property!(
+            grid_template_rows,
+            "grid-template-rows",
+            "repeat(auto-fit, 20%)",
+            GridTemplate::TrackList(
+                vec![TrackListItem::Repeat(
+                    RepeatCount::AutoFit,
+                    vec![TrackListItem::TrackSize(TrackSize::Length(Length::Ratio(
+                        0.2
+                    )))]
+                    .into()
+                )]
+                .into()
+            )
+        );
+        test_parse_property!(
+            grid_template_rows,
+            "grid-template-rows",
+            "repeat(0, 100px)",
+            GridTemplate::None
+        );
+        test_parse_property!(
+            grid_template_rows,
+            "grid-template-rows",
+            "repeat(auto-fill, 10px) repeat(auto-fit, 10px)",
+            GridTemplate::None
+        );
+        // flexible and intrinsic track sizes are invalid in auto repeats
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "repeat(auto-fill, 100px 1fr)",
+            GridTemplate::None
+        );
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "repeat(auto-fit, minmax(min-content, max-content))",
+            GridTemplate::None
+        );
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "repeat(auto-fill, auto)",
+            GridTemplate::None
+        );
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "repeat(auto-fill, fit-content(100px))",
+            GridTemplate::None
+        );
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "repeat(auto-fill, minmax(auto, 100px))",
+            GridTemplate::TrackList(
+                vec![TrackListItem::Repeat(
+                    RepeatCount::AutoFill,
+                    vec![TrackListItem::TrackSize(TrackSize::MinMax(
+                        Box::new(TrackSize::Length(Length::Auto)),
+                        Box::new(TrackSize::Length(Length::Px(100.))),
+                    ))]
+                    .into()
+                )]
+                .into()
+            )
+        );
+        // huge counts are capped
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "repeat(100000000, 1px)",
+            GridTemplate::TrackList(
+                vec![TrackListItem::Repeat(
+                    RepeatCount::Count(10000),
+                    vec![TrackListItem::TrackSize(TrackSize::Length(Length::Px(1.)))].into()
+                )]
+                .into()
+            )
+        );
+    }
+
+    // 0xa5 0xa6 0xa9 0xaa
+    #[test]
+    fn grid_template_minmax_fit_content() {
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "minmax(100px, 1fr) minmax(min-content, max-content)",
+            GridTemplate::TrackList(
+                vec![
+                    TrackListItem::TrackSize(TrackSize::MinMax(
+                        Box::new(TrackSize::Length(Length::Px(100.))),
+                        Box::new(TrackSize::Fr(1.)),
+                    )),
+                    TrackListItem::TrackSize(TrackSize::MinMax(
+                        Box::new(TrackSize::MinContent),
+                        Box::new(TrackSize::MaxContent),
+                    )),
+                ]
+                .into()
+            )
+        );
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "repeat(2, minmax(auto, 50%)) fit-content(200px)",
+            GridTemplate::TrackList(
+                vec![
+                    TrackListItem::Repeat(
+                        RepeatCount::Count(2),
+                        vec![TrackListItem::TrackSize(TrackSize::MinMax(
+                            Box::new(TrackSize::Length(Length::Auto)),
+                            Box::new(TrackSize::Length(Length::Ratio(0.5))),
+                        ))]
+                        .into()
+                    ),
+                    TrackListItem::TrackSize(TrackSize::FitContent(Length::Px(200.))),
+                ]
+                .into()
+            )
+        );
+        test_parse_property!(
+            grid_auto_rows,
+            "grid-auto-rows",
+            "minmax(100px, auto) fit-content(10%)",
+            GridAuto::List(
+                vec![
+                    TrackSize::MinMax(
+                        Box::new(TrackSize::Length(Length::Px(100.))),
+                        Box::new(TrackSize::Length(Length::Auto)),
+                    ),
+                    TrackSize::FitContent(Length::Ratio(0.1)),
+                ]
+                .into()
+            )
+        );
+        // a flexible min sizing function is invalid
+        test_parse_property!(
+            grid_template_rows,
+            "grid-template-rows",
+            "minmax(1fr, 100px)",
+            GridTemplate::None
+        );
+        test_parse_property!(
+            grid_template_rows,
+            "grid-template-rows",
+            "fit-content(auto)",
+            GridTemplate::None
+        );
+        assert_eq!(
+            TrackSize::MinMax(
+                Box::new(TrackSize::Length(Length::Px(100.))),
+                Box::new(TrackSize::Fr(1.)),
+            )
+            .to_string(),
+            "minmax(100px, 1fr)"
+        );
+        assert_eq!(
+            TrackSize::FitContent(Length::Px(200.)).to_string(),
+            "fit-content(200px)"
+        );
+    }
+
+    #[test]
+    fn baseline_position() {
+        test_parse_property!(
+            align_items,
+            "align-items",
+            "first baseline",
+            AlignItems::Baseline
+        );
+        test_parse_property!(
+            align_items,
+            "align-items",
+            "last baseline",
+            AlignItems::LastBaseline
+        );
+        test_parse_property!(
+            align_self,
+            "align-self",
+            "first baseline",
+            AlignSelf::Baseline
+        );
+        test_parse_property!(
+            align_self,
+            "align-self",
+            "last baseline",
+            AlignSelf::LastBaseline
+        );
+        assert_eq!(AlignSelf::LastBaseline.to_string(), "last baseline");
+    }
+
+    #[test]
+    fn grid_template_subgrid() {
+        test_parse_property!(
+            grid_template_rows,
+            "grid-template-rows",
+            "subgrid",
+            GridTemplate::Subgrid(vec![].into())
+        );
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "subgrid [a] [b c]",
+            GridTemplate::Subgrid(
+                vec![
+                    vec!["a".to_string().into()].into(),
+                    vec!["b".to_string().into(), "c".to_string().into()].into(),
+                ]
+                .into()
+            )
+        );
+        // track sizes are not allowed after `subgrid`
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "subgrid 100px",
+            GridTemplate::None
+        );
+        assert_eq!(GridTemplate::Subgrid(vec![].into()).to_string(), "subgrid");
+        assert_eq!(
+            GridTemplate::Subgrid(
+                vec![
+                    vec!["a".to_string().into()].into(),
+                    vec!["b".to_string().into(), "c".to_string().into()].into(),
+                ]
+                .into()
+            )
+            .to_string(),
+            "subgrid [a] [b c]"
+        );
+    }
+
     // 0xa7
     #[test]
     fn grid_auto_flow() {
@@ -6692,6 +7089,188 @@ mod grid {
             GridAuto::List(vec![TrackSize::Length(Length::Ratio(0.5))].into())
         );
     }
+
+    #[test]
+    fn grid_template_masonry() {
+        test_parse_property!(
+            grid_template_rows,
+            "grid-template-rows",
+            "masonry",
+            GridTemplate::Masonry
+        );
+        test_parse_property!(
+            grid_template_columns,
+            "grid-template-columns",
+            "masonry",
+            GridTemplate::Masonry
+        );
+        test_parse_property!(
+            grid_template_rows,
+            "grid-template-rows",
+            "masonry 100px",
+            GridTemplate::None
+        );
+        assert_eq!(GridTemplate::Masonry.to_string(), "masonry");
+    }
+
+    // 0xac
+    #[test]
+    fn masonry_auto_flow() {
+        test_parse_property!(
+            masonry_auto_flow,
+            "masonry-auto-flow",
+            "pack",
+            MasonryAutoFlow::Pack
+        );
+        test_parse_property!(
+            masonry_auto_flow,
+            "masonry-auto-flow",
+            "next",
+            MasonryAutoFlow::Next
+        );
+        test_parse_property!(
+            masonry_auto_flow,
+            "masonry-auto-flow",
+            "definite-first",
+            MasonryAutoFlow::Pack
+        );
+        test_parse_property!(
+            masonry_auto_flow,
+            "masonry-auto-flow",
+            "ordered next",
+            MasonryAutoFlow::NextOrdered
+        );
+        test_parse_property!(
+            masonry_auto_flow,
+            "masonry-auto-flow",
+            "pack ordered",
+            MasonryAutoFlow::PackOrdered
+        );
+        test_parse_property!(
+            masonry_auto_flow,
+            "masonry-auto-flow",
+            "next definite-first",
+            MasonryAutoFlow::Next
+        );
+        test_parse_property!(
+            masonry_auto_flow,
+            "masonry-auto-flow",
+            "next pack",
+            MasonryAutoFlow::Pack
+        );
+        assert_eq!(MasonryAutoFlow::NextOrdered.to_string(), "next ordered");
+    }
+}
+
+mod multicol {
+    use super::*;
+
+    // 0xb0
+    #[test]
+    fn column_count() {
+        test_parse_property!(column_count, "column-count", "auto", ColumnCount::Auto);
+        test_parse_property!(
+            column_count,
+            "column-count",
+            "3",
+            ColumnCount::Num(Number::I32(3))
+        );
+        test_parse_property!(column_count, "column-count", "0", ColumnCount::Auto);
+        test_parse_property!(column_count, "column-count", "-1", ColumnCount::Auto);
+        test_parse_property!(column_count, "column-count", "1.5", ColumnCount::Auto);
+        assert_eq!(ColumnCount::Num(Number::I32(2)).to_string(), "2");
+    }
+
+    // 0xb1
+    #[test]
+    fn column_width() {
+        test_parse_property!(column_width, "column-width", "auto", Length::Auto);
+        test_parse_property!(column_width, "column-width", "100px", Length::Px(100.));
+        test_parse_property!(column_width, "column-width", "50%", Length::Auto);
+        test_parse_property!(column_width, "column-width", "-10px", Length::Auto);
+    }
+
+    // 0xb0 0xb1
+    #[test]
+    fn columns() {
+        let mut ssg = StyleSheetGroup::new();
+        let ss = StyleSheet::from_str(
+            r#"
+            .a {
+                columns: 100px 3;
+            }
+            .b {
+                columns: 2;
+            }
+            .c {
+                columns: 120px auto;
+            }
+        "#,
+        );
+        ssg.append(ss);
+        let np = query(&ssg, "", "", ["a"], []);
+        assert_eq!(np.column_width(), Length::Px(100.));
+        assert_eq!(np.column_count(), ColumnCount::Num(Number::I32(3)));
+        let np = query(&ssg, "", "", ["b"], []);
+        assert_eq!(np.column_width(), Length::Auto);
+        assert_eq!(np.column_count(), ColumnCount::Num(Number::I32(2)));
+        let np = query(&ssg, "", "", ["c"], []);
+        assert_eq!(np.column_width(), Length::Px(120.));
+        assert_eq!(np.column_count(), ColumnCount::Auto);
+    }
+
+    // 0xb2 0xb3 0xb4
+    #[test]
+    fn column_rule() {
+        let mut ssg = StyleSheetGroup::new();
+        let ss = StyleSheet::from_str(
+            r#"
+            .a {
+                column-rule: 2px dashed red;
+            }
+            .b {
+                column-rule-width: 4px;
+                column-rule-style: solid;
+                column-rule-color: blue;
+            }
+        "#,
+        );
+        ssg.append(ss);
+        let np = query(&ssg, "", "", [""], []);
+        assert_eq!(np.column_rule_width(), Length::Px(3.));
+        assert_eq!(np.column_rule_style(), BorderStyle::None);
+        assert_eq!(np.column_rule_color(), Color::CurrentColor);
+        let np = query(&ssg, "", "", ["a"], []);
+        assert_eq!(np.column_rule_width(), Length::Px(2.));
+        assert_eq!(np.column_rule_style(), BorderStyle::Dashed);
+        assert_eq!(np.column_rule_color(), Color::Specified(255, 0, 0, 255));
+        let np = query(&ssg, "", "", ["b"], []);
+        assert_eq!(np.column_rule_width(), Length::Px(4.));
+        assert_eq!(np.column_rule_style(), BorderStyle::Solid);
+        assert_eq!(np.column_rule_color(), Color::Specified(0, 0, 255, 255));
+    }
+
+    // 0xb5
+    #[test]
+    fn column_span() {
+        test_parse_property!(column_span, "column-span", "none", ColumnSpan::None);
+        test_parse_property!(column_span, "column-span", "all", ColumnSpan::All);
+        assert_eq!(ColumnSpan::All.to_string(), "all");
+    }
+
+    // 0xb6
+    #[test]
+    fn column_fill() {
+        test_parse_property!(column_fill, "column-fill", "balance", ColumnFill::Balance);
+        test_parse_property!(column_fill, "column-fill", "auto", ColumnFill::Auto);
+        test_parse_property!(
+            column_fill,
+            "column-fill",
+            "balance-all",
+            ColumnFill::BalanceAll
+        );
+        assert_eq!(ColumnFill::BalanceAll.to_string(), "balance-all");
+    }
 }
 
 mod other {
@@ -6816,6 +7395,13 @@ mod other {
         );
     }
 
+    // 0x01
+    #[test]
+    fn display_list_item() {
+        test_parse_property!(display, "display", "list-item", Display::ListItem);
+        assert_eq!(Display::ListItem.to_string(), "list-item");
+    }
+
     // 0xd3
     #[test]
     fn backdrop_filter() {
@@ -8050,8 +8636,8 @@ mod length_type_strictness {
     fn text_indent_rejects_auto() {
         // `auto` is rejected and falls back to the initial value
         test_parse_property!(text_indent, "text-indent", "auto", Length::Undefined);
-        // percentage is accepted and resolved by the length resolver to px
-        test_parse_property!(text_indent, "text-indent", "10%", Length::Px(1.6));
+        // percentage is accepted and kept to be resolved against the containing block
+        test_parse_property!(text_indent, "text-indent", "10%", Length::Ratio(0.1));
         // length is accepted
         test_parse_property!(text_indent, "text-indent", "20px", Length::Px(20.));
     }
@@ -8247,3 +8833,150 @@ mod length_type_strictness {
         );
     }
 }
+
+mod table {
+    use super::*;
+
+    // 0x01
+    #[test]
+    fn display() {
+        test_parse_property!(display, "display", "table", Display::Table);
+        test_parse_property!(display, "display", "table-row", Display::TableRow);
+        test_parse_property!(display, "display", "table-cell", Display::TableCell);
+        test_parse_property!(
+            display,
+            "display",
+            "table-row-group",
+            Display::TableRowGroup
+        );
+        test_parse_property!(
+            display,
+            "display",
+            "table-header-group",
+            Display::TableHeaderGroup
+        );
+        test_parse_property!(display, "display", "table-column", Display::TableColumn);
+        test_parse_property!(display, "display", "table-caption", Display::TableCaption);
+        assert_eq!(Display::TableRowGroup.to_string(), "table-row-group");
+    }
+
+    // 0xb8
+    #[test]
+    fn table_layout() {
+        test_parse_property!(table_layout, "table-layout", "auto", TableLayout::Auto);
+        test_parse_property!(table_layout, "table-layout", "fixed", TableLayout::Fixed);
+        assert_eq!(TableLayout::Fixed.to_string(), "fixed");
+    }
+
+    // 0xb9
+    #[test]
+    fn border_collapse() {
+        test_parse_property!(
+            border_collapse,
+            "border-collapse",
+            "separate",
+            BorderCollapse::Separate
+        );
+        test_parse_property!(
+            border_collapse,
+            "border-collapse",
+            "collapse",
+            BorderCollapse::Collapse
+        );
+        assert_eq!(BorderCollapse::Collapse.to_string(), "collapse");
+    }
+
+    // 0xba 0xbb
+    #[test]
+    fn border_spacing() {
+        let mut ssg = StyleSheetGroup::new();
+        let ss = StyleSheet::from_str(
+            r#"
+            .a {
+                border-spacing: 10px;
+            }
+            .b {
+                border-spacing: 10px 20px;
+            }
+            .c {
+                border-spacing: -10px;
+            }
+        "#,
+        );
+        ssg.append(ss);
+        let np = query(&ssg, "", "", ["a"], []);
+        assert_eq!(np.border_spacing_horizontal(), Length::Px(10.));
+        assert_eq!(np.border_spacing_vertical(), Length::Px(10.));
+        let np = query(&ssg, "", "", ["b"], []);
+        assert_eq!(np.border_spacing_horizontal(), Length::Px(10.));
+        assert_eq!(np.border_spacing_vertical(), Length::Px(20.));
+        let np = query(&ssg, "", "", ["c"], []);
+        assert_eq!(np.border_spacing_horizontal(), Length::Px(0.));
+        assert_eq!(np.border_spacing_vertical(), Length::Px(0.));
+    }
+}
+
+mod fragmentation {
+    use super::*;
+
+    // 0xbc
+    #[test]
+    fn break_before() {
+        test_parse_property!(break_before, "break-before", "auto", Break::Auto);
+        test_parse_property!(break_before, "break-before", "page", Break::Page);
+        test_parse_property!(break_before, "break-before", "avoid-page", Break::AvoidPage);
+        test_parse_property!(break_before, "break-before", "recto", Break::Recto);
+        test_parse_property!(break_before, "page-break-before", "always", Break::Page);
+        test_parse_property!(break_before, "page-break-before", "avoid", Break::Avoid);
+        assert_eq!(Break::AvoidColumn.to_string(), "avoid-column");
+    }
+
+    // 0xbd
+    #[test]
+    fn break_after() {
+        test_parse_property!(break_after, "break-after", "column", Break::Column);
+        test_parse_property!(break_after, "break-after", "left", Break::Left);
+        test_parse_property!(break_after, "page-break-after", "always", Break::Page);
+        test_parse_property!(break_after, "page-break-after", "right", Break::Right);
+        assert_eq!(Break::Page.to_string(), "page");
+    }
+
+    // 0xbe
+    #[test]
+    fn break_inside() {
+        test_parse_property!(break_inside, "break-inside", "auto", BreakInside::Auto);
+        test_parse_property!(break_inside, "break-inside", "avoid", BreakInside::Avoid);
+        test_parse_property!(
+            break_inside,
+            "break-inside",
+            "avoid-page",
+            BreakInside::AvoidPage
+        );
+        test_parse_property!(
+            break_inside,
+            "page-break-inside",
+            "avoid",
+            BreakInside::Avoid
+        );
+        assert_eq!(BreakInside::AvoidPage.to_string(), "avoid-page");
+    }
+
+    // 0xbf 0xc0
+    #[test]
+    fn orphans_widows() {
+        test_parse_property!(orphans, "orphans", "3", Number::I32(3));
+        test_parse_property!(widows, "widows", "4", Number::I32(4));
+        let mut ssg = StyleSheetGroup::new();
+        let ss = StyleSheet::from_str(
+            r#"
+            .a {
+                orphans: 3;
+            }
+        "#,
+        );
+        ssg.append(ss);
+        let np = query(&ssg, "", "", ["a"], []);
+        assert_eq!(np.orphans(), Number::I32(3));
+        assert_eq!(np.widows(), Number::I32(2));
+    }
+}
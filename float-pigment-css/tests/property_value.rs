@@ -334,6 +334,12 @@ fn align_items() {
         "baseline",
         AlignItems::Baseline
     )];
+    &[test_parse_stringify!(
+        align_items,
+        "align-items",
+        "last baseline",
+        AlignItems::LastBaseline
+    )];
 }
 
 #[test]
@@ -404,6 +410,12 @@ fn align_self() {
         "baseline",
         AlignSelf::Baseline
     )];
+    &[test_parse_stringify!(
+        align_self,
+        "align-self",
+        "last baseline",
+        AlignSelf::LastBaseline
+    )];
 }
 
 #[test]
@@ -812,6 +824,12 @@ fn vertical_align() {
         "text-bottom",
         VerticalAlign::TextBottom
     )];
+    &[test_parse_stringify!(
+        vertical_align,
+        "vertical-align",
+        "-4px",
+        VerticalAlign::Length(Length::Px(-4.))
+    )];
 }
 
 #[test]
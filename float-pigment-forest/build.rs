@@ -22,7 +22,9 @@
 // The translator walks the parsed DOM and emits imperative calls in
 // document order. Layout assertions (data-expect-*) are collected during
 // the walk and emitted after `ctx.layout_imperative()` so they read back
-// computed values.
+// computed values. An element with `data-snapshot="true"` adds a
+// `ctx.assert_snapshot("<topic>/<case>")` call, which compares the layout
+// snapshot of the tree against the golden files next to the HTML case.
 use std::{
     collections::BTreeMap,
     fs,
@@ -62,7 +64,7 @@ fn main() {
         let name_ident = name.replace('-', "_");
         let html = fs::read_to_string(&abs).unwrap_or_default();
         let ignore = html.contains("data-ignore=\"true\"");
-        let body = translate_html(&html);
+        let body = translate_html(&html, &rel_str);
         by_topic
             .entry(topic)
             .or_default()
@@ -128,8 +130,9 @@ fn write_cases_tree(
 /// Parse the HTML and emit imperative TestCtx calls.
 ///
 /// Order: create_node/create_text + set_style + append (pre-order DOM walk),
-/// then `ctx.layout_imperative()`, then the collected assert_eq! calls.
-fn translate_html(html: &str) -> String {
+/// then `ctx.layout_imperative()`, then the collected assert_eq! calls, and
+/// the snapshot check if requested.
+fn translate_html(html: &str, case: &str) -> String {
     let mut parse_ctx = Context::create(None);
     parse_ctx.parse(html);
     let mut out = String::from("    let mut ctx = TestCtx::new();\n");
@@ -148,6 +151,12 @@ fn translate_html(html: &str) -> String {
     for a in &counter.asserts {
         out.push_str(a);
     }
+    if counter.snapshot {
+        out.push_str(&format!(
+            "    ctx.assert_snapshot({});\n",
+            rust_str_literal(case)
+        ));
+    }
     out
 }
 
@@ -156,6 +165,7 @@ struct Counter {
     n: usize,
     t: usize,
     asserts: Vec<String>,
+    snapshot: bool,
 }
 
 /// Walk a DOM node, emit create_node/create_text + set_style + append, and
@@ -199,7 +209,25 @@ fn gen_node(out: &mut String, node: &NodeType, parent: Option<&str>, c: &mut Cou
                     parse_value(&font_size.to_string())
                 ));
             }
+            // Table cells and columns: `colspan` / `rowspan` attributes are
+            // supplied to the layout engine by the host, not through styles.
+            if attrs.get("colspan").is_some() || attrs.get("rowspan").is_some() {
+                let span = |name: &str| {
+                    attrs
+                        .get(name)
+                        .and_then(|v| v.trim().parse::<u32>().ok())
+                        .unwrap_or(1)
+                };
+                out.push_str(&format!(
+                    "    ctx.set_table_span({var}, {}, {});\n",
+                    span("colspan"),
+                    span("rowspan")
+                ));
+            }
             collect_asserts(&var, attrs, c);
+            if attrs.get("data-snapshot").as_deref() == Some("true") {
+                c.snapshot = true;
+            }
             if let Some(p) = parent {
                 out.push_str(&format!("    ctx.append({}, {var});\n", p));
             }
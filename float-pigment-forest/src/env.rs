@@ -1,11 +1,13 @@
 use float_pigment_css::num_traits::Zero;
-use float_pigment_layout::ScreenQuery;
+use float_pigment_layout::{LayoutTrace, ScreenQuery};
 
 use crate::Len;
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug)]
 pub struct Env {
     pub screen_width: Len,
     pub screen_height: Len,
+    /// The trace collector, moved in from the caller for the layout pass.
+    pub(crate) trace: Option<LayoutTrace<Len>>,
 }
 
 impl ScreenQuery<Len> for Env {
@@ -22,6 +24,7 @@ impl Default for Env {
         Self {
             screen_width: Len::zero(),
             screen_height: Len::zero(),
+            trace: None,
         }
     }
 }
@@ -1,20 +1,32 @@
+use crate::layout::{LayoutVerticalAlign, MeasuredTextFragment, MeasuredTextLines};
+use crate::LayoutTransformItem;
 use crate::NodeType;
 use crate::{
     node::DumpNode, node::DumpOptions, node::DumpStyleMode, ChildOperation, Len, MeasureMode, Node,
     StyleSetter,
 };
 use float_pigment_css::length_num::*;
+use float_pigment_css::num_traits::Zero;
 use float_pigment_css::property::PropertyValueWithGlobal;
 use float_pigment_css::typing::{
-    AlignContentType, AlignItemsType, AlignSelfType, BoxSizingType, DisplayType, FlexDirectionType,
-    FlexWrapType, JustifyContentType, OverflowType, PositionType, TextAlignType, WritingModeType,
+    AlignContentType, AlignItemsType, AlignSelfType, Angle, BorderCollapseType, BoxSizingType,
+    BreakInsideType, BreakType, ColumnFillType, ColumnSpanType, DisplayType, FlexDirectionType,
+    FlexWrapType, JustifyContentType, Length, ListStylePositionType, OverflowType,
+    PointerEventsType, PositionType, TableLayoutType, TextAlignType, Transform, TransformItem,
+    TransformOrigin, TransformOriginType, VerticalAlign, VerticalAlignType, WhiteSpaceType,
+    WritingModeType,
 };
-use float_pigment_layout::{DefLength, OptionNum};
+use float_pigment_layout::{DefLength, LayoutVirtualization, OptionNum, Point, Vector};
 use std::{ffi::CString, os::raw::c_char};
 
 pub type Width = f32;
 pub type Height = f32;
 pub type Baseline = f32;
+pub type Ordinal = i32;
+pub type TextOffset = usize;
+pub type AvailableWidth = f32;
+pub type AllowEmpty = bool;
+pub type MaxLines = u32;
 pub type MeasureMinWidth = f32;
 pub type MeasureMinHeight = f32;
 pub type MeasureMaxWidth = f32;
@@ -24,6 +36,14 @@ pub type MeasureMaxContentHeight = f32;
 
 pub type BaselineFunc = unsafe extern "C" fn(NodePtr, Width, Height) -> Baseline;
 
+pub type MarkerMeasureFunc = unsafe extern "C" fn(NodePtr, Ordinal) -> Size;
+
+pub type TextFragmentMeasureFunc =
+    unsafe extern "C" fn(NodePtr, TextOffset, AvailableWidth, AllowEmpty) -> TextFragment;
+
+pub type LineClampMeasureFunc =
+    unsafe extern "C" fn(NodePtr, MeasureMaxWidth, MaxLines) -> TextLines;
+
 pub type MeasureFunc = unsafe extern "C" fn(
     NodePtr,
     MeasureMaxWidth,
@@ -42,6 +62,9 @@ pub type ResolveCalc = unsafe extern "C" fn(CalcHandle, f32) -> f32;
 
 pub type DirtyCallback = unsafe extern "C" fn(NodePtr);
 
+pub type LayoutJournalCallback =
+    unsafe extern "C" fn(NodePtr, *const LayoutJournalValues, *const LayoutJournalValues);
+
 #[repr(C)]
 pub struct Size {
     pub width: f32,
@@ -59,6 +82,100 @@ impl From<Size> for float_pigment_layout::Size<Len> {
     }
 }
 
+/// A line fragment of a text node, measured by the text engine of the host.
+#[repr(C)]
+pub struct TextFragment {
+    pub width: f32,
+    pub height: f32,
+    /// The distance from the top edge of the fragment to its baseline.
+    pub baseline: f32,
+    /// Whether the text continues in the next line box.
+    pub has_next: bool,
+    /// The text offset where the next fragment starts, if `has_next` is true.
+    pub next: TextOffset,
+    /// The number of the justification opportunities inside the fragment.
+    pub justification_opportunities: u32,
+}
+
+impl From<TextFragment> for MeasuredTextFragment {
+    fn from(val: TextFragment) -> Self {
+        MeasuredTextFragment {
+            width: Len::from_f32(val.width),
+            height: Len::from_f32(val.height),
+            baseline: Len::from_f32(val.baseline),
+            next: val.has_next.then_some(val.next),
+            justification_opportunities: val.justification_opportunities,
+        }
+    }
+}
+
+/// The shown lines of a multi-line text node in a line-clamped block container.
+#[repr(C)]
+pub struct TextLines {
+    pub width: f32,
+    pub height: f32,
+    /// The distance from the top edge of the shown lines to the baseline of the last shown line.
+    pub last_baseline: f32,
+    /// The number of all the lines of the text.
+    pub line_count: u32,
+}
+
+impl From<TextLines> for MeasuredTextLines {
+    fn from(val: TextLines) -> Self {
+        MeasuredTextLines {
+            width: Len::from_f32(val.width),
+            height: Len::from_f32(val.height),
+            last_baseline: Len::from_f32(val.last_baseline),
+            line_count: val.line_count,
+        }
+    }
+}
+
+#[repr(C)]
+pub struct Bounds {
+    pub left: f32,
+    pub top: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+#[repr(C)]
+pub struct LayoutJournalValues {
+    pub left: f32,
+    pub top: f32,
+    pub width: f32,
+    pub height: f32,
+    pub border_left: f32,
+    pub border_top: f32,
+    pub border_right: f32,
+    pub border_bottom: f32,
+    pub padding_left: f32,
+    pub padding_top: f32,
+    pub padding_right: f32,
+    pub padding_bottom: f32,
+    pub first_baseline_ascent: f32,
+}
+
+impl From<float_pigment_layout::LayoutJournalValues<Len>> for LayoutJournalValues {
+    fn from(val: float_pigment_layout::LayoutJournalValues<Len>) -> Self {
+        Self {
+            left: val.rect.origin.x.to_f32(),
+            top: val.rect.origin.y.to_f32(),
+            width: val.rect.size.width.to_f32(),
+            height: val.rect.size.height.to_f32(),
+            border_left: val.border.left.to_f32(),
+            border_top: val.border.top.to_f32(),
+            border_right: val.border.right.to_f32(),
+            border_bottom: val.border.bottom.to_f32(),
+            padding_left: val.padding.left.to_f32(),
+            padding_top: val.padding.top.to_f32(),
+            padding_right: val.padding.right.to_f32(),
+            padding_bottom: val.padding.bottom.to_f32(),
+            first_baseline_ascent: val.first_baseline_ascent.y.to_f32(),
+        }
+    }
+}
+
 pub type RawMutPtr = *mut ();
 
 pub type NullPtr = *const ();
@@ -432,6 +549,55 @@ pub unsafe extern "C" fn NodeCalculateLayout(
     );
 }
 
+/// # Safety
+///
+/// Calculate the layout of a node instance in fragmentation mode,
+/// breaking the content into fragmentainers (e.g. pages).
+/// The layout positions are continuous, in which the fragmentainer `i` starts at `i * fragmentainer_height`.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `available_width` - Available width
+/// * `available_height` - Available height
+/// * `viewport_width` - Viewport width
+/// * `viewport_height` - Viewport height
+/// * `fragmentainer_height` - Height of each fragmentainer
+///
+/// # Example
+///
+/// ```c
+/// NodeCalculateLayoutWithFragmentation(node, available_width, available_height, viewport_width, viewport_height, fragmentainer_height);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeCalculateLayoutWithFragmentation(
+    node: NodePtr,
+    available_width: f32,
+    available_height: f32,
+    viewport_width: f32,
+    viewport_height: f32,
+    fragmentainer_height: f32,
+) {
+    let node = &*(node as *mut Node);
+    let available_width = if available_width.is_finite() {
+        OptionNum::some(Len::from_f32(available_width))
+    } else {
+        OptionNum::none()
+    };
+    let available_height = if available_height.is_finite() {
+        OptionNum::some(Len::from_f32(available_height))
+    } else {
+        OptionNum::none()
+    };
+    node.layout_with_fragmentation(
+        crate::node::OptionSize::new(available_width, available_height),
+        crate::node::Size::new(
+            Len::from_f32(viewport_width),
+            Len::from_f32(viewport_height),
+        ),
+        Len::from_f32(fragmentainer_height),
+    );
+}
+
 /// # Safety
 ///
 /// Calculate the dry layout of a node instance.
@@ -770,6 +936,217 @@ pub unsafe extern "C" fn NodeSetBaselineFunc(node: NodePtr, baseline_func: Basel
     )));
 }
 
+/// # Safety
+///
+/// Set the marker measure function for a `display: list-item` node instance.
+/// The list item does not generate a marker box if the function is not set.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `marker_measure_func` - Marker measure function, called with the ordinal of the list item
+///
+/// # Example
+///
+/// ```c
+/// NodeSetMarkerMeasureFunc(node, marker_measure_func);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetMarkerMeasureFunc(
+    node: NodePtr,
+    marker_measure_func: MarkerMeasureFunc,
+) {
+    let node = &*(node as *mut Node);
+    node.set_marker_measure_func(Some(Box::new(
+        move |node: *mut Node, ordinal: i32| -> crate::node::Size<Len> {
+            marker_measure_func(node as NodePtr, ordinal).into()
+        },
+    )));
+}
+
+/// # Safety
+///
+/// Clear the marker measure function for a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeClearMarkerMeasureFunc(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeClearMarkerMeasureFunc(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_marker_measure_func(None);
+}
+
+/// # Safety
+///
+/// Check if a node instance has a marker measure function.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Returns
+/// * `bool` - True if the node has a marker measure function, false otherwise
+///
+/// # Example
+///
+/// ```c
+/// NodeHasMarkerMeasureFunc(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeHasMarkerMeasureFunc(node: NodePtr) -> bool {
+    let node = &*(node as *mut Node);
+    node.has_marker_measure_func()
+}
+
+/// # Safety
+///
+/// Set the text fragment measure function for a text node instance, so the text can break across line boxes.
+///
+/// The function is called with the text offset where the fragment starts,
+/// the remaining width of the current line, and whether the fragment can be empty.
+/// It should return the longest fragment fitting the width,
+/// or at least one unbreakable segment of the text if the fragment cannot be empty.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `text_fragment_measure_func` - Text fragment measure function
+///
+/// # Example
+///
+/// ```c
+/// NodeSetTextFragmentMeasureFunc(node, text_fragment_measure_func);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetTextFragmentMeasureFunc(
+    node: NodePtr,
+    text_fragment_measure_func: TextFragmentMeasureFunc,
+) {
+    let node = &*(node as *mut Node);
+    node.set_text_fragment_measure_func(Some(Box::new(
+        move |node: *mut Node, start: usize, available_width: Len, allow_empty: bool| {
+            text_fragment_measure_func(
+                node as NodePtr,
+                start,
+                available_width.to_f32(),
+                allow_empty,
+            )
+            .into()
+        },
+    )));
+}
+
+/// # Safety
+///
+/// Clear the text fragment measure function for a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeClearTextFragmentMeasureFunc(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeClearTextFragmentMeasureFunc(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_text_fragment_measure_func(None);
+}
+
+/// # Safety
+///
+/// Check if a node instance has a text fragment measure function.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Returns
+/// * `bool` - True if the node has a text fragment measure function, false otherwise
+///
+/// # Example
+///
+/// ```c
+/// NodeHasTextFragmentMeasureFunc(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeHasTextFragmentMeasureFunc(node: NodePtr) -> bool {
+    let node = &*(node as *mut Node);
+    node.has_text_fragment_measure_func()
+}
+
+/// # Safety
+///
+/// Set the line clamp measure function for a text node instance,
+/// so the lines inside the text are counted by the line clamp of its block container.
+///
+/// The function is called with the width of the text and the number of the lines that can still be shown.
+/// It should return the size of the shown lines and the number of all the lines of the text.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `line_clamp_measure_func` - Line clamp measure function
+///
+/// # Example
+///
+/// ```c
+/// NodeSetLineClampMeasureFunc(node, line_clamp_measure_func);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetLineClampMeasureFunc(
+    node: NodePtr,
+    line_clamp_measure_func: LineClampMeasureFunc,
+) {
+    let node = &*(node as *mut Node);
+    node.set_line_clamp_measure_func(Some(Box::new(
+        move |node: *mut Node, max_width: Len, max_lines: u32| {
+            line_clamp_measure_func(node as NodePtr, max_width.to_f32(), max_lines).into()
+        },
+    )));
+}
+
+/// # Safety
+///
+/// Clear the line clamp measure function for a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeClearLineClampMeasureFunc(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeClearLineClampMeasureFunc(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_line_clamp_measure_func(None);
+}
+
+/// # Safety
+///
+/// Check if a node instance has a line clamp measure function.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Returns
+/// * `bool` - True if the node has a line clamp measure function, false otherwise
+///
+/// # Example
+///
+/// ```c
+/// NodeHasLineClampMeasureFunc(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeHasLineClampMeasureFunc(node: NodePtr) -> bool {
+    let node = &*(node as *mut Node);
+    node.has_line_clamp_measure_func()
+}
+
 /// # Safety
 ///
 /// Clear the measure cache for a node instance.
@@ -830,7 +1207,9 @@ pub unsafe extern "C" fn NodeClearDirtyCallback(node: NodePtr) {
 
 /// # Safety
 ///
-/// Check if a node instance is dirty.
+/// Check if a node instance is dirty, i.e. it or any of its descendants should be laid out again.
+///
+/// The ancestors of a dirty node are also dirty, even if the dirty propagation stops at a relayout boundary between them.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -846,7 +1225,29 @@ pub unsafe extern "C" fn NodeClearDirtyCallback(node: NodePtr) {
 #[no_mangle]
 pub unsafe extern "C" fn NodeIsDirty(node: NodePtr) -> bool {
     let node = &*(node as *mut Node);
-    node.is_dirty()
+    node.needs_layout()
+}
+
+/// # Safety
+///
+/// Check if a node instance ends the last shown line of a line-clamped block container,
+/// so an ellipsis should be rendered at the end of it.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Returns
+/// * `bool` - True if the node carries the ellipsis, false otherwise
+///
+/// # Example
+///
+/// ```c
+/// NodeHasLineClampEllipsis(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeHasLineClampEllipsis(node: NodePtr) -> bool {
+    let node = &*(node as *mut Node);
+    node.has_line_clamp_ellipsis()
 }
 
 /// # Safety
@@ -1440,29 +1841,26 @@ pub unsafe extern "C" fn NodeStyleSetWidthCalcHandle(node: NodePtr, calc_handle:
     let node = &*(node as *mut Node);
     node.set_width(DefLength::Custom(calc_handle));
 }
-
 /// # Safety
 ///
-/// Set the height of a node instance.
+/// Set the width of a node instance to min-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Height type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetHeight(node, value);
+/// NodeStyleSetWidthMinContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetHeight(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetWidthMinContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_height(DefLength::Points(Len::from_f32(value)));
+    node.set_width(DefLength::MinContent);
 }
-
 /// # Safety
 ///
-/// Set the height of a node instance to undefined.
+/// Set the width of a node instance to max-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -1470,175 +1868,176 @@ pub unsafe extern "C" fn NodeStyleSetHeight(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetHeightNone(node);
+/// NodeStyleSetWidthMaxContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetHeightNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetWidthMaxContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_height(DefLength::Undefined);
+    node.set_width(DefLength::MaxContent);
 }
 /// # Safety
 ///
-/// Set the height of a node instance to a percentage.
+/// Set the width of a node instance to fit-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetHeightPercentage(node, value);
+/// NodeStyleSetWidthFitContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetHeightPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetWidthFitContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_height(DefLength::Percent(value));
+    node.set_width(DefLength::FitContent);
 }
 /// # Safety
 ///
-/// Set the height of a node instance to auto.
+/// Set the width of a node instance to fit-content with a fixed limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `value` - Limit
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetHeightAuto(node);
+/// NodeStyleSetWidthFitContentLength(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetHeightAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetWidthFitContentLength(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_height(DefLength::Auto);
+    node.set_width(DefLength::FitContentPoints(Len::from_f32(value)));
 }
 /// # Safety
 ///
-/// Set the height of a node instance to a calc handle.
+/// Set the width of a node instance to fit-content with a percentage limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
+/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetHeightCalcHandle(node, calc_handle);
+/// NodeStyleSetWidthFitContentPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetHeightCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetWidthFitContentPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_height(DefLength::Custom(calc_handle));
+    node.set_width(DefLength::FitContentPercent(value));
 }
 /// # Safety
 ///
-/// Set the min width of a node instance.
+/// Set the width of a node instance to stretch.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Min width type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMinWidth(node, value);
+/// NodeStyleSetWidthStretch(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMinWidth(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetWidthStretch(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_min_width(DefLength::Points(Len::from_f32(value)));
+    node.set_width(DefLength::Stretch);
 }
+
 /// # Safety
 ///
-/// Set the min width of a node instance to undefined.
+/// Set the height of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `value` - Height type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMinWidthNone(node);
+/// NodeStyleSetHeight(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMinWidthNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetHeight(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_min_width(DefLength::Undefined);
+    node.set_height(DefLength::Points(Len::from_f32(value)));
 }
+
 /// # Safety
 ///
-/// Set the min width of a node instance to a percentage.
+/// Set the height of a node instance to undefined.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMinWidthPercentage(node, value);
+/// NodeStyleSetHeightNone(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMinWidthPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetHeightNone(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_min_width(DefLength::Percent(value));
+    node.set_height(DefLength::Undefined);
 }
 /// # Safety
 ///
-/// Set the min width of a node instance to auto.
+/// Set the height of a node instance to a percentage.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMinWidthAuto(node);
+/// NodeStyleSetHeightPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMinWidthAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetHeightPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_min_width(DefLength::Auto);
+    node.set_height(DefLength::Percent(value));
 }
 /// # Safety
 ///
+/// Set the height of a node instance to auto.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMinWidthCalcHandle(node, calc_handle);
+/// NodeStyleSetHeightAuto(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMinWidthCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetHeightAuto(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_min_width(DefLength::Custom(calc_handle));
+    node.set_height(DefLength::Auto);
 }
-
 /// # Safety
 ///
-/// Set the min height of a node instance.
+/// Set the height of a node instance to a calc handle.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Min height type
+/// * `calc_handle` - Calc handle
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMinHeight(node, value);
+/// NodeStyleSetHeightCalcHandle(node, calc_handle);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMinHeight(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetHeightCalcHandle(node: NodePtr, calc_handle: i32) {
     let node = &*(node as *mut Node);
-    node.set_min_height(DefLength::Points(Len::from_f32(value)));
+    node.set_height(DefLength::Custom(calc_handle));
 }
 /// # Safety
 ///
-/// Set the min height of a node instance to undefined.
+/// Set the height of a node instance to min-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -1646,34 +2045,33 @@ pub unsafe extern "C" fn NodeStyleSetMinHeight(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMinHeightNone(node);
+/// NodeStyleSetHeightMinContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMinHeightNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetHeightMinContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_min_height(DefLength::Undefined);
+    node.set_height(DefLength::MinContent);
 }
 /// # Safety
 ///
-/// Set the min height of a node instance to a percentage.
+/// Set the height of a node instance to max-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMinHeightPercentage(node, value);
+/// NodeStyleSetHeightMaxContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMinHeightPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetHeightMaxContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_min_height(DefLength::Percent(value));
+    node.set_height(DefLength::MaxContent);
 }
 /// # Safety
 ///
-/// Set the min height of a node instance to auto.
+/// Set the height of a node instance to fit-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -1681,53 +2079,52 @@ pub unsafe extern "C" fn NodeStyleSetMinHeightPercentage(node: NodePtr, value: f
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMinHeightAuto(node);
+/// NodeStyleSetHeightFitContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMinHeightAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetHeightFitContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_min_height(DefLength::Auto);
+    node.set_height(DefLength::FitContent);
 }
 /// # Safety
 ///
-/// Set the min height of a node instance to a calc handle.
+/// Set the height of a node instance to fit-content with a fixed limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
+/// * `value` - Limit
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMinHeightCalcHandle(node, calc_handle);
+/// NodeStyleSetHeightFitContentLength(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMinHeightCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetHeightFitContentLength(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_min_height(DefLength::Custom(calc_handle));
+    node.set_height(DefLength::FitContentPoints(Len::from_f32(value)));
 }
-
 /// # Safety
 ///
-/// Set the max width of a node instance.
+/// Set the height of a node instance to fit-content with a percentage limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Max width type
+/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMaxWidth(node, value);
+/// NodeStyleSetHeightFitContentPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMaxWidth(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetHeightFitContentPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_max_width(DefLength::Points(Len::from_f32(value)));
+    node.set_height(DefLength::FitContentPercent(value));
 }
 /// # Safety
 ///
-/// Set the max width of a node instance to undefined.
+/// Set the height of a node instance to stretch.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -1735,34 +2132,34 @@ pub unsafe extern "C" fn NodeStyleSetMaxWidth(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMaxWidthNone(node);
+/// NodeStyleSetHeightStretch(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMaxWidthNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetHeightStretch(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_max_width(DefLength::Undefined);
+    node.set_height(DefLength::Stretch);
 }
 /// # Safety
 ///
-/// Set the max width of a node instance to a percentage.
+/// Set the min width of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
+/// * `value` - Min width type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMaxWidthPercentage(node, value);
+/// NodeStyleSetMinWidth(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMaxWidthPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMinWidth(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_max_width(DefLength::Percent(value));
+    node.set_min_width(DefLength::Points(Len::from_f32(value)));
 }
 /// # Safety
 ///
-/// Set the max width of a node instance to auto.
+/// Set the min width of a node instance to undefined.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -1770,88 +2167,85 @@ pub unsafe extern "C" fn NodeStyleSetMaxWidthPercentage(node: NodePtr, value: f3
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMaxWidthAuto(node);
+/// NodeStyleSetMinWidthNone(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMaxWidthAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMinWidthNone(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_max_width(DefLength::Auto);
+    node.set_min_width(DefLength::Undefined);
 }
 /// # Safety
 ///
-/// Set the max width of a node instance to a calc handle.
+/// Set the min width of a node instance to a percentage.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
+/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMaxWidthCalcHandle(node, calc_handle);
+/// NodeStyleSetMinWidthPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMaxWidthCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMinWidthPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_max_width(DefLength::Custom(calc_handle));
+    node.set_min_width(DefLength::Percent(value));
 }
-
 /// # Safety
 ///
-/// Set the max height of a node instance.
+/// Set the min width of a node instance to auto.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Max height type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMaxHeight(node, value);
+/// NodeStyleSetMinWidthAuto(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMaxHeight(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMinWidthAuto(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_max_height(DefLength::Points(Len::from_f32(value)));
+    node.set_min_width(DefLength::Auto);
 }
 /// # Safety
 ///
-/// Set the max height of a node instance to undefined.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMaxHeightNone(node);
+/// NodeStyleSetMinWidthCalcHandle(node, calc_handle);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMaxHeightNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMinWidthCalcHandle(node: NodePtr, calc_handle: i32) {
     let node = &*(node as *mut Node);
-    node.set_max_height(DefLength::Undefined);
+    node.set_min_width(DefLength::Custom(calc_handle));
 }
 /// # Safety
 ///
-/// Set the max height of a node instance to a percentage.
+/// Set the min width of a node instance to min-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMaxHeightPercentage(node, value);
+/// NodeStyleSetMinWidthMinContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMaxHeightPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMinWidthMinContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_max_height(DefLength::Percent(value));
+    node.set_min_width(DefLength::MinContent);
 }
 /// # Safety
 ///
-/// Set the max height of a node instance to auto.
+/// Set the min width of a node instance to max-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -1859,142 +2253,140 @@ pub unsafe extern "C" fn NodeStyleSetMaxHeightPercentage(node: NodePtr, value: f
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMaxHeightAuto(node);
+/// NodeStyleSetMinWidthMaxContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMaxHeightAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMinWidthMaxContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_max_height(DefLength::Auto);
+    node.set_min_width(DefLength::MaxContent);
 }
 /// # Safety
 ///
-/// Set the max height of a node instance to a calc handle.
+/// Set the min width of a node instance to fit-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMaxHeightCalcHandle(node, calc_handle);
+/// NodeStyleSetMinWidthFitContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMaxHeightCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMinWidthFitContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_max_height(DefLength::Custom(calc_handle));
+    node.set_min_width(DefLength::FitContent);
 }
-
 /// # Safety
 ///
-/// Set the margin left of a node instance.
+/// Set the min width of a node instance to fit-content with a fixed limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Margin left type
+/// * `value` - Limit
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginLeft(node, value);
+/// NodeStyleSetMinWidthFitContentLength(node, value);
+/// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginLeft(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMinWidthFitContentLength(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_margin_left(DefLength::Points(Len::from_f32(value)));
+    node.set_min_width(DefLength::FitContentPoints(Len::from_f32(value)));
 }
-
 /// # Safety
 ///
-/// Set the margin left of a node instance to undefined.
+/// Set the min width of a node instance to fit-content with a percentage limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginLeftNone(node);
+/// NodeStyleSetMinWidthFitContentPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginLeftNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMinWidthFitContentPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_margin_left(DefLength::Undefined);
+    node.set_min_width(DefLength::FitContentPercent(value));
 }
 /// # Safety
 ///
-/// Set the margin left of a node instance to a percentage.
+/// Set the min width of a node instance to stretch.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginLeftPercentage(node, value);
+/// NodeStyleSetMinWidthStretch(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginLeftPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMinWidthStretch(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_margin_left(DefLength::Percent(value));
+    node.set_min_width(DefLength::Stretch);
 }
+
 /// # Safety
 ///
-/// Set the margin left of a node instance to auto.
+/// Set the min height of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `value` - Min height type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginLeftAuto(node);
+/// NodeStyleSetMinHeight(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginLeftAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMinHeight(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_margin_left(DefLength::Auto);
+    node.set_min_height(DefLength::Points(Len::from_f32(value)));
 }
 /// # Safety
 ///
-/// Set the margin left of a node instance to a calc handle.
+/// Set the min height of a node instance to undefined.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginLeftCalcHandle(node, calc_handle);
+/// NodeStyleSetMinHeightNone(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginLeftCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMinHeightNone(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_margin_left(DefLength::Custom(calc_handle));
+    node.set_min_height(DefLength::Undefined);
 }
-
 /// # Safety
 ///
-/// Set the margin right of a node instance.
+/// Set the min height of a node instance to a percentage.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Margin right type
+/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginRight(node, value);
+/// NodeStyleSetMinHeightPercentage(node, value);
+/// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginRight(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMinHeightPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_margin_right(DefLength::Points(Len::from_f32(value)));
+    node.set_min_height(DefLength::Percent(value));
 }
-
 /// # Safety
 ///
-/// Set the margin right of a node instance to undefined.
+/// Set the min height of a node instance to auto.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2002,34 +2394,34 @@ pub unsafe extern "C" fn NodeStyleSetMarginRight(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginRightNone(node);
+/// NodeStyleSetMinHeightAuto(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginRightNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMinHeightAuto(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_margin_right(DefLength::Undefined);
+    node.set_min_height(DefLength::Auto);
 }
-
 /// # Safety
 ///
-/// Set the margin right of a node instance to a percentage.
+/// Set the min height of a node instance to a calc handle.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
+/// * `calc_handle` - Calc handle
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginRightPercentage(node, value);
+/// NodeStyleSetMinHeightCalcHandle(node, calc_handle);
+/// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginRightPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMinHeightCalcHandle(node: NodePtr, calc_handle: i32) {
     let node = &*(node as *mut Node);
-    node.set_margin_right(DefLength::Percent(value));
+    node.set_min_height(DefLength::Custom(calc_handle));
 }
 /// # Safety
 ///
-/// Set the margin right of a node instance to auto.
+/// Set the min height of a node instance to min-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2037,70 +2429,68 @@ pub unsafe extern "C" fn NodeStyleSetMarginRightPercentage(node: NodePtr, value:
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginRightAuto(node);
+/// NodeStyleSetMinHeightMinContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginRightAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMinHeightMinContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_margin_right(DefLength::Auto);
+    node.set_min_height(DefLength::MinContent);
 }
 /// # Safety
 ///
-/// Set the margin right of a node instance to a calc handle.
+/// Set the min height of a node instance to max-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginRightCalcHandle(node, calc_handle);
+/// NodeStyleSetMinHeightMaxContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginRightCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMinHeightMaxContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_margin_right(DefLength::Custom(calc_handle));
+    node.set_min_height(DefLength::MaxContent);
 }
-
 /// # Safety
 ///
-/// Set the margin top of a node instance.
+/// Set the min height of a node instance to fit-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Margin top type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginTop(node, value);
+/// NodeStyleSetMinHeightFitContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginTop(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMinHeightFitContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_margin_top(DefLength::Points(Len::from_f32(value)));
+    node.set_min_height(DefLength::FitContent);
 }
 /// # Safety
 ///
-/// Set the margin top of a node instance to undefined.
+/// Set the min height of a node instance to fit-content with a fixed limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `value` - Limit
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginTopNone(node);
+/// NodeStyleSetMinHeightFitContentLength(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginTopNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMinHeightFitContentLength(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_margin_top(DefLength::Undefined);
+    node.set_min_height(DefLength::FitContentPoints(Len::from_f32(value)));
 }
 /// # Safety
 ///
-/// Set the margin top of a node instance to a percentage.  
+/// Set the min height of a node instance to fit-content with a percentage limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2109,16 +2499,16 @@ pub unsafe extern "C" fn NodeStyleSetMarginTopNone(node: NodePtr) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginTopPercentage(node, value);
+/// NodeStyleSetMinHeightFitContentPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginTopPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMinHeightFitContentPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_margin_top(DefLength::Percent(value));
+    node.set_min_height(DefLength::FitContentPercent(value));
 }
 /// # Safety
 ///
-/// Set the margin top of a node instance to auto.
+/// Set the min height of a node instance to stretch.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2126,141 +2516,139 @@ pub unsafe extern "C" fn NodeStyleSetMarginTopPercentage(node: NodePtr, value: f
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginTopAuto(node);
+/// NodeStyleSetMinHeightStretch(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginTopAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMinHeightStretch(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_margin_top(DefLength::Auto);
+    node.set_min_height(DefLength::Stretch);
 }
+
 /// # Safety
 ///
-/// Set the margin top of a node instance to a calc handle.
+/// Set the max width of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
+/// * `value` - Max width type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginTopCalcHandle(node, calc_handle);
+/// NodeStyleSetMaxWidth(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginTopCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidth(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_margin_top(DefLength::Custom(calc_handle));
+    node.set_max_width(DefLength::Points(Len::from_f32(value)));
 }
-
 /// # Safety
 ///
-/// Set the margin bottom of a node instance.
+/// Set the max width of a node instance to undefined.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Margin bottom type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginBottom(node, value);
+/// NodeStyleSetMaxWidthNone(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginBottom(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidthNone(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_margin_bottom(DefLength::Points(Len::from_f32(value)));
+    node.set_max_width(DefLength::Undefined);
 }
 /// # Safety
 ///
-/// Set the margin bottom of a node instance to undefined.
+/// Set the max width of a node instance to a percentage.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginBottomNone(node);
+/// NodeStyleSetMaxWidthPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginBottomNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidthPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_margin_bottom(DefLength::Undefined);
+    node.set_max_width(DefLength::Percent(value));
 }
 /// # Safety
 ///
-/// Set the margin bottom of a node instance to a percentage.
+/// Set the max width of a node instance to auto.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginBottomPercentage(node, value);
+/// NodeStyleSetMaxWidthAuto(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginBottomPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidthAuto(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_margin_bottom(DefLength::Percent(value));
+    node.set_max_width(DefLength::Auto);
 }
 /// # Safety
 ///
-/// Set the margin bottom of a node instance to auto.
+/// Set the max width of a node instance to a calc handle.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginBottomAuto(node);
+/// NodeStyleSetMaxWidthCalcHandle(node, calc_handle);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginBottomAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidthCalcHandle(node: NodePtr, calc_handle: i32) {
     let node = &*(node as *mut Node);
-    node.set_margin_bottom(DefLength::Auto);
+    node.set_max_width(DefLength::Custom(calc_handle));
 }
 /// # Safety
 ///
+/// Set the max width of a node instance to min-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetMarginBottomCalcHandle(node, calc_handle);
+/// NodeStyleSetMaxWidthMinContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetMarginBottomCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidthMinContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_margin_bottom(DefLength::Custom(calc_handle));
+    node.set_max_width(DefLength::MinContent);
 }
-
 /// # Safety
 ///
-/// Set the padding left of a node instance.    
+/// Set the max width of a node instance to max-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Padding left type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingLeft(node, value);
+/// NodeStyleSetMaxWidthMaxContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingLeft(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidthMaxContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_left(DefLength::Points(Len::from_f32(value)));
+    node.set_max_width(DefLength::MaxContent);
 }
 /// # Safety
 ///
-/// Set the padding left of a node instance to undefined.
+/// Set the max width of a node instance to fit-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2268,88 +2656,88 @@ pub unsafe extern "C" fn NodeStyleSetPaddingLeft(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingLeftNone(node);
+/// NodeStyleSetMaxWidthFitContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingLeftNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidthFitContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_left(DefLength::Undefined);
+    node.set_max_width(DefLength::FitContent);
 }
 /// # Safety
 ///
-/// Set the padding left of a node instance to a percentage.
+/// Set the max width of a node instance to fit-content with a fixed limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
+/// * `value` - Limit
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingLeftPercentage(node, value);
+/// NodeStyleSetMaxWidthFitContentLength(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingLeftPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidthFitContentLength(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_padding_left(DefLength::Percent(value));
+    node.set_max_width(DefLength::FitContentPoints(Len::from_f32(value)));
 }
 /// # Safety
 ///
-/// Set the padding left of a node instance to auto.
+/// Set the max width of a node instance to fit-content with a percentage limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingLeftAuto(node);
+/// NodeStyleSetMaxWidthFitContentPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingLeftAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidthFitContentPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_padding_left(DefLength::Auto);
+    node.set_max_width(DefLength::FitContentPercent(value));
 }
 /// # Safety
 ///
-/// Set the padding left of a node instance to a calc handle.
+/// Set the max width of a node instance to stretch.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingLeftCalcHandle(node, calc_handle);
+/// NodeStyleSetMaxWidthStretch(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingLeftCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMaxWidthStretch(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_left(DefLength::Custom(calc_handle));
+    node.set_max_width(DefLength::Stretch);
 }
 
 /// # Safety
 ///
-/// Set the padding right of a node instance.
+/// Set the max height of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Padding right type
+/// * `value` - Max height type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingRight(node, value);
+/// NodeStyleSetMaxHeight(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingRight(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeight(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_padding_right(DefLength::Points(Len::from_f32(value)));
+    node.set_max_height(DefLength::Points(Len::from_f32(value)));
 }
 /// # Safety
 ///
-/// Set the padding right of a node instance to undefined.
+/// Set the max height of a node instance to undefined.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2357,17 +2745,16 @@ pub unsafe extern "C" fn NodeStyleSetPaddingRight(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingRightNone(node);
+/// NodeStyleSetMaxHeightNone(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingRightNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeightNone(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_right(DefLength::Undefined);
+    node.set_max_height(DefLength::Undefined);
 }
-
 /// # Safety
 ///
-/// Set the padding right of a node instance to a percentage.
+/// Set the max height of a node instance to a percentage.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2376,16 +2763,16 @@ pub unsafe extern "C" fn NodeStyleSetPaddingRightNone(node: NodePtr) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingRightPercentage(node, value);
+/// NodeStyleSetMaxHeightPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingRightPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeightPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_padding_right(DefLength::Percent(value));
+    node.set_max_height(DefLength::Percent(value));
 }
 /// # Safety
 ///
-/// Set the padding right of a node instance to auto.
+/// Set the max height of a node instance to auto.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2393,17 +2780,16 @@ pub unsafe extern "C" fn NodeStyleSetPaddingRightPercentage(node: NodePtr, value
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingRightAuto(node);
+/// NodeStyleSetMaxHeightAuto(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingRightAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeightAuto(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_right(DefLength::Auto);
+    node.set_max_height(DefLength::Auto);
 }
-
 /// # Safety
 ///
-/// Set the padding right of a node instance to a calc handle.
+/// Set the max height of a node instance to a calc handle.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2412,35 +2798,33 @@ pub unsafe extern "C" fn NodeStyleSetPaddingRightAuto(node: NodePtr) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingRightCalcHandle(node, calc_handle);
+/// NodeStyleSetMaxHeightCalcHandle(node, calc_handle);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingRightCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeightCalcHandle(node: NodePtr, calc_handle: i32) {
     let node = &*(node as *mut Node);
-    node.set_padding_right(DefLength::Custom(calc_handle));
+    node.set_max_height(DefLength::Custom(calc_handle));
 }
-
 /// # Safety
 ///
-/// Set the padding top of a node instance.
+/// Set the max height of a node instance to min-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Padding top type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingTop(node, value);
+/// NodeStyleSetMaxHeightMinContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingTop(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeightMinContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_top(DefLength::Points(Len::from_f32(value)));
+    node.set_max_height(DefLength::MinContent);
 }
 /// # Safety
 ///
-/// Set the padding top of a node instance to undefined.
+/// Set the max height of a node instance to max-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2448,105 +2832,122 @@ pub unsafe extern "C" fn NodeStyleSetPaddingTop(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingTopNone(node);
+/// NodeStyleSetMaxHeightMaxContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingTopNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeightMaxContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_top(DefLength::Undefined);
+    node.set_max_height(DefLength::MaxContent);
 }
 /// # Safety
 ///
-/// Set the padding top of a node instance to a percentage.
+/// Set the max height of a node instance to fit-content.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingTopPercentage(node, value);
+/// NodeStyleSetMaxHeightFitContent(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingTopPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeightFitContent(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_top(DefLength::Percent(value));
+    node.set_max_height(DefLength::FitContent);
 }
 /// # Safety
 ///
-/// Set the padding top of a node instance to auto.
+/// Set the max height of a node instance to fit-content with a fixed limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `value` - Limit
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingTopAuto(node);
+/// NodeStyleSetMaxHeightFitContentLength(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingTopAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeightFitContentLength(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_padding_top(DefLength::Auto);
+    node.set_max_height(DefLength::FitContentPoints(Len::from_f32(value)));
 }
 /// # Safety
 ///
-/// Set the padding top of a node instance to a calc handle.
+/// Set the max height of a node instance to fit-content with a percentage limit.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
+/// * `value` - Percentage
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingTopCalcHandle(node, calc_handle);
+/// NodeStyleSetMaxHeightFitContentPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingTopCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeightFitContentPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_padding_top(DefLength::Custom(calc_handle));
+    node.set_max_height(DefLength::FitContentPercent(value));
 }
-
 /// # Safety
 ///
-/// Set the padding bottom of a node instance.
+/// Set the max height of a node instance to stretch.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Padding bottom type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingBottom(node, value);
+/// NodeStyleSetMaxHeightStretch(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingBottom(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMaxHeightStretch(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_bottom(DefLength::Points(Len::from_f32(value)));
+    node.set_max_height(DefLength::Stretch);
 }
+
 /// # Safety
 ///
-/// Set the padding bottom of a node instance to undefined.
+/// Set the margin left of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `value` - Margin left type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingBottomNone(node);
+/// NodeStyleSetMarginLeft(node, value);
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetMarginLeft(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_margin_left(DefLength::Points(Len::from_f32(value)));
+}
+
+/// # Safety
+///
+/// Set the margin left of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetMarginLeftNone(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingBottomNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMarginLeftNone(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_bottom(DefLength::Undefined);
+    node.set_margin_left(DefLength::Undefined);
 }
 /// # Safety
 ///
-/// Set the padding bottom of a node instance to a percentage.
+/// Set the margin left of a node instance to a percentage.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2555,16 +2956,16 @@ pub unsafe extern "C" fn NodeStyleSetPaddingBottomNone(node: NodePtr) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingBottomPercentage(node, value);
+/// NodeStyleSetMarginLeftPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingBottomPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMarginLeftPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_padding_bottom(DefLength::Percent(value));
+    node.set_margin_left(DefLength::Percent(value));
 }
 /// # Safety
 ///
-/// Set the padding bottom of a node instance to auto.
+/// Set the margin left of a node instance to auto.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2572,16 +2973,16 @@ pub unsafe extern "C" fn NodeStyleSetPaddingBottomPercentage(node: NodePtr, valu
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingBottomAuto(node);
+/// NodeStyleSetMarginLeftAuto(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingBottomAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMarginLeftAuto(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_padding_bottom(DefLength::Auto);
+    node.set_margin_left(DefLength::Auto);
 }
 /// # Safety
 ///
-/// Set the padding bottom of a node instance to a calc handle.
+/// Set the margin left of a node instance to a calc handle.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2590,36 +2991,35 @@ pub unsafe extern "C" fn NodeStyleSetPaddingBottomAuto(node: NodePtr) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetPaddingBottomCalcHandle(node, calc_handle);
+/// NodeStyleSetMarginLeftCalcHandle(node, calc_handle);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetPaddingBottomCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMarginLeftCalcHandle(node: NodePtr, calc_handle: i32) {
     let node = &*(node as *mut Node);
-    node.set_padding_bottom(DefLength::Custom(calc_handle));
+    node.set_margin_left(DefLength::Custom(calc_handle));
 }
 
 /// # Safety
 ///
-/// Set the border left of a node instance.
+/// Set the margin right of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Border left type
+/// * `value` - Margin right type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderLeft(node, value);
-/// ```
+/// NodeStyleSetMarginRight(node, value);
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderLeft(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMarginRight(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_border_left(DefLength::Points(Len::from_f32(value)));
+    node.set_margin_right(DefLength::Points(Len::from_f32(value)));
 }
 
 /// # Safety
 ///
-/// Set the border left of a node instance to undefined.
+/// Set the margin right of a node instance to undefined.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2627,16 +3027,17 @@ pub unsafe extern "C" fn NodeStyleSetBorderLeft(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderLeftNone(node);
+/// NodeStyleSetMarginRightNone(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderLeftNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMarginRightNone(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_border_left(DefLength::Undefined);
+    node.set_margin_right(DefLength::Undefined);
 }
+
 /// # Safety
 ///
-/// Set the border left of a node instance to a percentage.
+/// Set the margin right of a node instance to a percentage.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2645,16 +3046,15 @@ pub unsafe extern "C" fn NodeStyleSetBorderLeftNone(node: NodePtr) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderLeftPercentage(node, value);
-/// ```
+/// NodeStyleSetMarginRightPercentage(node, value);
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderLeftPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMarginRightPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_border_left(DefLength::Percent(value));
+    node.set_margin_right(DefLength::Percent(value));
 }
 /// # Safety
 ///
-/// Set the border left of a node instance to auto.
+/// Set the margin right of a node instance to auto.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2662,16 +3062,16 @@ pub unsafe extern "C" fn NodeStyleSetBorderLeftPercentage(node: NodePtr, value:
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderLeftAuto(node);
+/// NodeStyleSetMarginRightAuto(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderLeftAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMarginRightAuto(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_border_left(DefLength::Auto);
+    node.set_margin_right(DefLength::Auto);
 }
 /// # Safety
 ///
-/// Set the border left of a node instance to a calc handle.
+/// Set the margin right of a node instance to a calc handle.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2680,35 +3080,35 @@ pub unsafe extern "C" fn NodeStyleSetBorderLeftAuto(node: NodePtr) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderLeftCalcHandle(node, calc_handle);
+/// NodeStyleSetMarginRightCalcHandle(node, calc_handle);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderLeftCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMarginRightCalcHandle(node: NodePtr, calc_handle: i32) {
     let node = &*(node as *mut Node);
-    node.set_border_left(DefLength::Custom(calc_handle));
+    node.set_margin_right(DefLength::Custom(calc_handle));
 }
 
 /// # Safety
 ///
-/// Set the border right of a node instance.
+/// Set the margin top of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Border right type
+/// * `value` - Margin top type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderRight(node, value);
+/// NodeStyleSetMarginTop(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderRight(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMarginTop(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_border_right(DefLength::Points(Len::from_f32(value)));
+    node.set_margin_top(DefLength::Points(Len::from_f32(value)));
 }
 /// # Safety
 ///
-/// Set the border right of a node instance to undefined.
+/// Set the margin top of a node instance to undefined.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2716,16 +3116,16 @@ pub unsafe extern "C" fn NodeStyleSetBorderRight(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderRightNone(node);
+/// NodeStyleSetMarginTopNone(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderRightNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMarginTopNone(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_border_right(DefLength::Undefined);
+    node.set_margin_top(DefLength::Undefined);
 }
 /// # Safety
 ///
-/// Set the border right of a node instance to a percentage.
+/// Set the margin top of a node instance to a percentage.  
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2734,16 +3134,16 @@ pub unsafe extern "C" fn NodeStyleSetBorderRightNone(node: NodePtr) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderRightPercentage(node, value);
+/// NodeStyleSetMarginTopPercentage(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderRightPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMarginTopPercentage(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_border_right(DefLength::Percent(value));
+    node.set_margin_top(DefLength::Percent(value));
 }
 /// # Safety
 ///
-/// Set the border right of a node instance to auto.
+/// Set the margin top of a node instance to auto.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2751,16 +3151,16 @@ pub unsafe extern "C" fn NodeStyleSetBorderRightPercentage(node: NodePtr, value:
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderRightAuto(node);
+/// NodeStyleSetMarginTopAuto(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderRightAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetMarginTopAuto(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_border_right(DefLength::Auto);
+    node.set_margin_top(DefLength::Auto);
 }
 /// # Safety
 ///
-/// Set the border right of a node instance to a calc handle.
+/// Set the margin top of a node instance to a calc handle.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2769,35 +3169,2060 @@ pub unsafe extern "C" fn NodeStyleSetBorderRightAuto(node: NodePtr) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderRightCalcHandle(node, calc_handle);
+/// NodeStyleSetMarginTopCalcHandle(node, calc_handle);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderRightCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetMarginTopCalcHandle(node: NodePtr, calc_handle: i32) {
     let node = &*(node as *mut Node);
-    node.set_border_right(DefLength::Custom(calc_handle));
+    node.set_margin_top(DefLength::Custom(calc_handle));
 }
 
 /// # Safety
 ///
-/// Set the border top of a node instance.
+/// Set the margin bottom of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Border top type
+/// * `value` - Margin bottom type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderTop(node, value);
+/// NodeStyleSetMarginBottom(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderTop(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetMarginBottom(node: NodePtr, value: f32) {
     let node = &*(node as *mut Node);
-    node.set_border_top(DefLength::Points(Len::from_f32(value)));
+    node.set_margin_bottom(DefLength::Points(Len::from_f32(value)));
+}
+/// # Safety
+///
+/// Set the margin bottom of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetMarginBottomNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetMarginBottomNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_margin_bottom(DefLength::Undefined);
+}
+/// # Safety
+///
+/// Set the margin bottom of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetMarginBottomPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetMarginBottomPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_margin_bottom(DefLength::Percent(value));
+}
+/// # Safety
+///
+/// Set the margin bottom of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetMarginBottomAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetMarginBottomAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_margin_bottom(DefLength::Auto);
+}
+/// # Safety
+///
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetMarginBottomCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetMarginBottomCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_margin_bottom(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the padding left of a node instance.    
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Padding left type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingLeft(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingLeft(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_left(DefLength::Points(Len::from_f32(value)));
+}
+/// # Safety
+///
+/// Set the padding left of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingLeftNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingLeftNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_padding_left(DefLength::Undefined);
+}
+/// # Safety
+///
+/// Set the padding left of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingLeftPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingLeftPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_left(DefLength::Percent(value));
+}
+/// # Safety
+///
+/// Set the padding left of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingLeftAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingLeftAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_padding_left(DefLength::Auto);
+}
+/// # Safety
+///
+/// Set the padding left of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingLeftCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingLeftCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_left(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the padding right of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Padding right type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingRight(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingRight(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_right(DefLength::Points(Len::from_f32(value)));
+}
+/// # Safety
+///
+/// Set the padding right of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingRightNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingRightNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_padding_right(DefLength::Undefined);
+}
+
+/// # Safety
+///
+/// Set the padding right of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingRightPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingRightPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_right(DefLength::Percent(value));
+}
+/// # Safety
+///
+/// Set the padding right of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingRightAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingRightAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_padding_right(DefLength::Auto);
+}
+
+/// # Safety
+///
+/// Set the padding right of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingRightCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingRightCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_right(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the padding top of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Padding top type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingTop(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingTop(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_top(DefLength::Points(Len::from_f32(value)));
+}
+/// # Safety
+///
+/// Set the padding top of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingTopNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingTopNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_padding_top(DefLength::Undefined);
+}
+/// # Safety
+///
+/// Set the padding top of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingTopPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingTopPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_top(DefLength::Percent(value));
+}
+/// # Safety
+///
+/// Set the padding top of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingTopAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingTopAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_padding_top(DefLength::Auto);
+}
+/// # Safety
+///
+/// Set the padding top of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingTopCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingTopCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_top(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the padding bottom of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Padding bottom type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingBottom(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingBottom(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_bottom(DefLength::Points(Len::from_f32(value)));
+}
+/// # Safety
+///
+/// Set the padding bottom of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingBottomNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingBottomNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_padding_bottom(DefLength::Undefined);
+}
+/// # Safety
+///
+/// Set the padding bottom of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingBottomPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingBottomPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_bottom(DefLength::Percent(value));
+}
+/// # Safety
+///
+/// Set the padding bottom of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingBottomAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingBottomAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_padding_bottom(DefLength::Auto);
+}
+/// # Safety
+///
+/// Set the padding bottom of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetPaddingBottomCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetPaddingBottomCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_padding_bottom(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the border left of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Border left type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderLeft(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderLeft(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_border_left(DefLength::Points(Len::from_f32(value)));
+}
+
+/// # Safety
+///
+/// Set the border left of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderLeftNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderLeftNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_border_left(DefLength::Undefined);
+}
+/// # Safety
+///
+/// Set the border left of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderLeftPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderLeftPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_border_left(DefLength::Percent(value));
+}
+/// # Safety
+///
+/// Set the border left of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderLeftAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderLeftAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_border_left(DefLength::Auto);
+}
+/// # Safety
+///
+/// Set the border left of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderLeftCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderLeftCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_border_left(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the border right of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Border right type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderRight(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderRight(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_border_right(DefLength::Points(Len::from_f32(value)));
+}
+/// # Safety
+///
+/// Set the border right of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderRightNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderRightNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_border_right(DefLength::Undefined);
+}
+/// # Safety
+///
+/// Set the border right of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderRightPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderRightPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_border_right(DefLength::Percent(value));
+}
+/// # Safety
+///
+/// Set the border right of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderRightAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderRightAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_border_right(DefLength::Auto);
+}
+/// # Safety
+///
+/// Set the border right of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderRightCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderRightCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_border_right(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the border top of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Border top type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderTop(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderTop(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_border_top(DefLength::Points(Len::from_f32(value)));
+}
+/// # Safety
+///
+/// Set the border top of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderTopNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderTopNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_border_top(DefLength::Undefined);
+}
+/// # Safety
+///
+/// Set the border top of a node instance to a percentage.  
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderTopPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderTopPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_border_top(DefLength::Percent(value));
+}
+/// # Safety
+///
+/// Set the border top of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderTopAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderTopAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_border_top(DefLength::Auto);
+}
+/// # Safety
+///
+/// Set the border top of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderTopCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderTopCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_border_top(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the border bottom of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Border bottom type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderBottom(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderBottom(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_border_bottom(DefLength::Points(Len::from_f32(value)));
+}
+/// # Safety
+///
+/// Set the border bottom of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderBottomNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderBottomNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_border_bottom(DefLength::Undefined);
+}
+/// # Safety
+///
+/// Set the border bottom of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderBottomPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderBottomPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_border_bottom(DefLength::Percent(value));
+}
+/// # Safety
+///
+/// Set the border bottom of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderBottomAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderBottomAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_border_bottom(DefLength::Auto);
+}
+/// # Safety
+///
+/// Set the border bottom of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderBottomCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderBottomCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_border_bottom(DefLength::Custom(calc_handle));
+}
+/// # Safety
+///
+/// Set the flex grow of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Flex grow
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexGrow(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexGrow(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_flex_grow(value);
+}
+
+/// # Safety
+///
+/// Set the flex shrink of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Flex shrink
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexShrink(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexShrink(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_flex_shrink(value);
+}
+
+/// # Safety
+///
+/// Set the flex basis of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Flex basis
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasis(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasis(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::Points(Len::from_f32(value)));
+}
+/// # Safety
+///
+/// Set the flex basis of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasisAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasisAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::Auto);
+}
+/// # Safety
+///
+/// Set the flex basis of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasisNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasisNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::Undefined);
+}
+/// # Safety
+///
+/// Set the flex basis of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasisPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasisPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::Percent(value));
+}
+/// # Safety
+///
+/// Set the flex basis of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasisCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasisCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::Custom(calc_handle));
+}
+/// # Safety
+///
+/// Set the flex basis of a node instance to min-content.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasisMinContent(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasisMinContent(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::MinContent);
+}
+/// # Safety
+///
+/// Set the flex basis of a node instance to max-content.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasisMaxContent(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasisMaxContent(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::MaxContent);
+}
+/// # Safety
+///
+/// Set the flex basis of a node instance to fit-content.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasisFitContent(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasisFitContent(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::FitContent);
+}
+/// # Safety
+///
+/// Set the flex basis of a node instance to fit-content with a fixed limit.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Limit
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasisFitContentLength(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasisFitContentLength(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::FitContentPoints(Len::from_f32(value)));
+}
+/// # Safety
+///
+/// Set the flex basis of a node instance to fit-content with a percentage limit.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasisFitContentPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasisFitContentPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::FitContentPercent(value));
+}
+/// # Safety
+///
+/// Set the flex basis of a node instance to stretch.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexBasisStretch(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexBasisStretch(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_flex_basis(DefLength::Stretch);
+}
+
+/// # Safety
+///
+/// Set the flex direction of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Flex direction
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexDirection(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexDirection(node: NodePtr, value: FlexDirectionType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_flex_direction(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the flex wrap of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Flex wrap
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetFlexWrap(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetFlexWrap(node: NodePtr, value: FlexWrapType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_flex_wrap(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the justify content of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Justify content
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetJustifyContent(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetJustifyContent(node: NodePtr, value: JustifyContentType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_justify_content(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the align content of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Align content
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetAlignContent(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetAlignContent(node: NodePtr, value: AlignContentType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_align_content(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the align items of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Align items
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetAlignItems(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetAlignItems(node: NodePtr, value: AlignItemsType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_align_items(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the align self of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Align self
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetAlignSelf(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetAlignSelf(node: NodePtr, value: AlignSelfType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_align_self(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the order of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Order
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetOrder(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetOrder(node: NodePtr, value: i32) {
+    let node = &*(node as *mut Node);
+    node.set_order(value);
+}
+
+/// # Safety
+///
+/// Set the row gap of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Row gap
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetRowGap(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetRowGap(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_row_gap(DefLength::Points(Len::from_f32(value)));
+}
+
+/// # Safety
+///
+/// Set the row gap of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetRowGapNormal(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetRowGapNormal(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_row_gap(DefLength::Undefined);
+}
+
+/// # Safety
+///
+/// Set the row gap of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Row gap percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetRowGapPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetRowGapPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_row_gap(DefLength::Percent(value));
+}
+
+/// # Safety
+///
+/// Set the row gap of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetRowGapCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetRowGapCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_row_gap(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the column gap of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Column gap
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetColumnGap(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetColumnGap(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_column_gap(DefLength::Points(Len::from_f32(value)));
+}
+
+/// # Safety
+///
+/// Set the column gap of a node instance to undefined.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetColumnGapNormal(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetColumnGapNormal(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_column_gap(DefLength::Undefined);
+}
+
+/// # Safety
+///
+/// Set the column gap of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Column gap percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetColumnGapPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetColumnGapPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_column_gap(DefLength::Percent(value));
+}
+
+/// # Safety
+///
+/// Set the column gap of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetColumnGapCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetColumnGapCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_column_gap(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the column count of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Column count, should be positive
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetColumnCount(node, 2);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetColumnCount(node: NodePtr, value: i32) {
+    let node = &*(node as *mut Node);
+    node.set_column_count(if value > 0 { Some(value as u32) } else { None });
+}
+
+/// # Safety
+///
+/// Set the column count of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetColumnCountAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetColumnCountAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_column_count(None);
+}
+
+/// # Safety
+///
+/// Set the column width of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Column width
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetColumnWidth(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetColumnWidth(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_column_width(DefLength::Points(Len::from_f32(value)));
+}
+
+/// # Safety
+///
+/// Set the column width of a node instance to auto.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetColumnWidthAuto(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetColumnWidthAuto(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_column_width(DefLength::Auto);
+}
+
+/// # Safety
+///
+/// Set the column span of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Column span type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetColumnSpan(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetColumnSpan(node: NodePtr, value: ColumnSpanType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_column_span(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the column fill of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Column fill type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetColumnFill(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetColumnFill(node: NodePtr, value: ColumnFillType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_column_fill(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the table layout of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Table layout type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetTableLayout(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetTableLayout(node: NodePtr, value: TableLayoutType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_table_layout(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the border collapse of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Border collapse type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderCollapse(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderCollapse(node: NodePtr, value: BorderCollapseType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_border_collapse(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the horizontal border spacing of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Horizontal border spacing
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderSpacingHorizontal(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderSpacingHorizontal(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_border_spacing_horizontal(DefLength::Points(Len::from_f32(value)));
+}
+
+/// # Safety
+///
+/// Set the vertical border spacing of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Vertical border spacing
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBorderSpacingVertical(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBorderSpacingVertical(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_border_spacing_vertical(DefLength::Points(Len::from_f32(value)));
+}
+
+/// # Safety
+///
+/// Set the number of columns spanned by a table cell or a table column.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Column span, clamped to `1..=1000` (`0` is treated as `1`)
+///
+/// # Example
+///
+/// ```c
+/// NodeSetColspan(node, 2);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetColspan(node: NodePtr, value: u32) {
+    let node = &*(node as *mut Node);
+    node.set_colspan(value);
+}
+
+/// # Safety
+///
+/// Set the number of rows spanned by a table cell.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Row span, clamped to `0..=65534` (`0` means spanning to the end of the row group)
+///
+/// # Example
+///
+/// ```c
+/// NodeSetRowspan(node, 2);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetRowspan(node: NodePtr, value: u32) {
+    let node = &*(node as *mut Node);
+    node.set_rowspan(value);
+}
+
+/// # Safety
+///
+/// Set the list style position of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - List style position type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetListStylePosition(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetListStylePosition(
+    node: NodePtr,
+    value: ListStylePositionType,
+) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_list_style_position(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the explicit ordinal of a list item, e.g. the `value` attribute of `<li>`.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Ordinal of the list item
+///
+/// # Example
+///
+/// ```c
+/// NodeSetListItemValue(node, 3);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetListItemValue(node: NodePtr, value: i32) {
+    let node = &*(node as *mut Node);
+    node.set_list_item_value(Some(value));
+}
+
+/// # Safety
+///
+/// Clear the explicit ordinal of a list item.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeClearListItemValue(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeClearListItemValue(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_list_item_value(None);
+}
+
+/// # Safety
+///
+/// Set the ordinal of the first list item in a list, e.g. the `start` attribute of `<ol>`.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Ordinal of the first list item
+///
+/// # Example
+///
+/// ```c
+/// NodeSetListStart(node, 5);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetListStart(node: NodePtr, value: i32) {
+    let node = &*(node as *mut Node);
+    node.set_list_start(Some(value));
+}
+
+/// # Safety
+///
+/// Clear the ordinal of the first list item in a list.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeClearListStart(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeClearListStart(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_list_start(None);
+}
+
+/// # Safety
+///
+/// Set whether the list items in a list count down, e.g. the `reversed` attribute of `<ol>`.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Reversed
+///
+/// # Example
+///
+/// ```c
+/// NodeSetListReversed(node, true);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetListReversed(node: NodePtr, value: bool) {
+    let node = &*(node as *mut Node);
+    node.set_list_reversed(value);
+}
+
+/// # Safety
+///
+/// Set the text align of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Text align
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetTextAlign(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetTextAlign(node: NodePtr, value: TextAlignType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_text_align(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the line height of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Line height
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetLineHeight(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetLineHeight(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_line_height(DefLength::Points(Len::from_f32(value)));
+}
+
+/// # Safety
+///
+/// Set the line height of a node instance to `normal`.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetLineHeightNormal(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetLineHeightNormal(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_line_height(DefLength::Undefined);
+}
+
+/// # Safety
+///
+/// Set the vertical align of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Vertical align
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetVerticalAlign(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetVerticalAlign(node: NodePtr, value: VerticalAlignType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        let value = match value {
+            VerticalAlign::Baseline => LayoutVerticalAlign::Baseline,
+            VerticalAlign::Top => LayoutVerticalAlign::Top,
+            VerticalAlign::Middle => LayoutVerticalAlign::Middle,
+            VerticalAlign::Bottom => LayoutVerticalAlign::Bottom,
+            VerticalAlign::TextTop => LayoutVerticalAlign::TextTop,
+            VerticalAlign::TextBottom => LayoutVerticalAlign::TextBottom,
+            VerticalAlign::Length(Length::Px(x)) => LayoutVerticalAlign::Length(Len::from_f32(x)),
+            VerticalAlign::Length(Length::Ratio(x)) => LayoutVerticalAlign::Percent(x),
+            VerticalAlign::Length(_) => return,
+        };
+        node.set_vertical_align(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the vertical align of a node instance to a length.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Vertical align length
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetVerticalAlignLength(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetVerticalAlignLength(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_vertical_align(LayoutVerticalAlign::Length(Len::from_f32(value)));
+}
+
+/// # Safety
+///
+/// Set the vertical align of a node instance to a percentage of the line height.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Vertical align percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetVerticalAlignPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetVerticalAlignPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_vertical_align(LayoutVerticalAlign::Percent(value));
+}
+
+/// # Safety
+///
+/// Set the text indent of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Text indent
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetTextIndent(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetTextIndent(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_text_indent(DefLength::Points(Len::from_f32(value)));
+}
+
+/// # Safety
+///
+/// Set the text indent of a node instance to a percentage.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Text indent percentage
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetTextIndentPercentage(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetTextIndentPercentage(node: NodePtr, value: f32) {
+    let node = &*(node as *mut Node);
+    node.set_text_indent(DefLength::Percent(value));
+}
+
+/// # Safety
+///
+/// Set the text indent of a node instance to a calc handle.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `calc_handle` - Calc handle
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetTextIndentCalcHandle(node, calc_handle);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetTextIndentCalcHandle(node: NodePtr, calc_handle: i32) {
+    let node = &*(node as *mut Node);
+    node.set_text_indent(DefLength::Custom(calc_handle));
+}
+
+/// # Safety
+///
+/// Set the white space of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - White space
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetWhiteSpace(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetWhiteSpace(node: NodePtr, value: WhiteSpaceType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_white_space(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the line clamp of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Max number of lines, should be positive
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetLineClamp(node, 2);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetLineClamp(node: NodePtr, value: i32) {
+    let node = &*(node as *mut Node);
+    node.set_line_clamp(if value > 0 { Some(value as u32) } else { None });
+}
+
+/// # Safety
+///
+/// Set the line clamp of a node instance to none.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetLineClampNone(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetLineClampNone(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_line_clamp(None);
+}
+
+/// # Safety
+///
+/// Set the break-before of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Break type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBreakBefore(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBreakBefore(node: NodePtr, value: BreakType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_break_before(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the break-after of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Break type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBreakAfter(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBreakAfter(node: NodePtr, value: BreakType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_break_after(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the break-inside of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Break type
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetBreakInside(node, value);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetBreakInside(node: NodePtr, value: BreakInsideType) {
+    let node = &*(node as *mut Node);
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_break_inside(value);
+    }
+}
+
+/// # Safety
+///
+/// Set the orphans of a node instance, i.e. the minimum number of lines kept in a fragment.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Number of lines, at least 1
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetOrphans(node, 2);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetOrphans(node: NodePtr, value: i32) {
+    let node = &*(node as *mut Node);
+    node.set_orphans(value.max(1) as u32);
+}
+
+/// # Safety
+///
+/// Set the widows of a node instance, i.e. the minimum number of lines kept in a fragment.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `value` - Number of lines, at least 1
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetWidows(node, 2);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetWidows(node: NodePtr, value: i32) {
+    let node = &*(node as *mut Node);
+    node.set_widows(value.max(1) as u32);
+}
+
+/// # Safety
+///
+/// Set the aspect ratio of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `x` - Aspect ratio x
+/// * `y` - Aspect ratio y
+///
+/// # Example
+///
+/// ```c
+/// NodeStyleSetAspectRatio(node, x, y);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeStyleSetAspectRatio(node: NodePtr, x: f32, y: f32) {
+    let node = &*(node as *mut Node);
+    node.set_aspect_ratio(Some(x / y));
 }
+
 /// # Safety
 ///
-/// Set the border top of a node instance to undefined.
+/// Set the aspect ratio of a node instance to auto.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2805,34 +5230,36 @@ pub unsafe extern "C" fn NodeStyleSetBorderTop(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderTopNone(node);
+/// NodeStyleSetAspectRatioAuto(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderTopNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetAspectRatioAuto(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_border_top(DefLength::Undefined);
+    node.set_aspect_ratio(None);
 }
+
 /// # Safety
 ///
-/// Set the border top of a node instance to a percentage.  
+/// Set the z-index of a node instance, which is used in hit testing.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
+/// * `value` - Z-index
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderTopPercentage(node, value);
+/// NodeStyleSetZIndex(node, 1);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderTopPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetZIndex(node: NodePtr, value: i32) {
     let node = &*(node as *mut Node);
-    node.set_border_top(DefLength::Percent(value));
+    node.set_z_index(Some(value));
 }
+
 /// # Safety
 ///
-/// Set the border top of a node instance to auto.
+/// Set the z-index of a node instance to auto.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2840,53 +5267,126 @@ pub unsafe extern "C" fn NodeStyleSetBorderTopPercentage(node: NodePtr, value: f
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderTopAuto(node);
+/// NodeStyleSetZIndexAuto(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderTopAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetZIndexAuto(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_border_top(DefLength::Auto);
+    node.set_z_index(None);
 }
+
 /// # Safety
 ///
-/// Set the border top of a node instance to a calc handle.
+/// Set the pointer-events of a node instance, which is used in hit testing.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
+/// * `value` - Pointer events type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderTopCalcHandle(node, calc_handle);
+/// NodeStyleSetPointerEvents(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderTopCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeStyleSetPointerEvents(node: NodePtr, value: PointerEventsType) {
     let node = &*(node as *mut Node);
-    node.set_border_top(DefLength::Custom(calc_handle));
+    if let Some(value) = value.to_inner_without_global() {
+        node.set_pointer_events(value);
+    }
+}
+
+fn transform_length(length: Length) -> Option<crate::Length> {
+    match length {
+        Length::Px(x) => Some(DefLength::Points(Len::from_f32(x))),
+        Length::Ratio(x) => Some(DefLength::Percent(x)),
+        _ => None,
+    }
+}
+
+fn transform_px(length: Length) -> Option<Len> {
+    match length {
+        Length::Px(x) => Some(Len::from_f32(x)),
+        _ => None,
+    }
+}
+
+fn transform_angle(angle: &Angle) -> Option<f32> {
+    match angle {
+        Angle::Deg(x) => Some(x.to_radians()),
+        Angle::Grad(x) => Some(x * std::f32::consts::PI / 200.),
+        Angle::Rad(x) => Some(*x),
+        Angle::Turn(x) => Some(x * std::f32::consts::TAU),
+        Angle::Calc(_) => None,
+    }
+}
+
+/// Convert a CSS `transform` value to the transform functions used in the geometry queries.
+///
+/// Returns `None` if any of the lengths is not `px` or percentage, or any of the angles is `calc`.
+pub fn convert_transform(value: &Transform) -> Option<Vec<LayoutTransformItem>> {
+    let Transform::Series(items) = value;
+    let mut ret = Vec::new();
+    for item in items.iter() {
+        let item = match item {
+            TransformItem::None => continue,
+            TransformItem::Matrix(m) => LayoutTransformItem::Matrix(*m),
+            TransformItem::Matrix3D(m) => LayoutTransformItem::Matrix3D(*m),
+            TransformItem::Translate2D(x, y) => LayoutTransformItem::Translate(
+                transform_length(x.clone())?,
+                transform_length(y.clone())?,
+                Len::zero(),
+            ),
+            TransformItem::Translate3D(x, y, z) => LayoutTransformItem::Translate(
+                transform_length(x.clone())?,
+                transform_length(y.clone())?,
+                transform_px(z.clone())?,
+            ),
+            TransformItem::Scale2D(x, y) => LayoutTransformItem::Scale(*x, *y, 1.),
+            TransformItem::Scale3D(x, y, z) => LayoutTransformItem::Scale(*x, *y, *z),
+            TransformItem::Rotate2D(angle) => {
+                LayoutTransformItem::Rotate(0., 0., 1., transform_angle(angle)?)
+            }
+            TransformItem::Rotate3D(x, y, z, angle) => {
+                LayoutTransformItem::Rotate(*x, *y, *z, transform_angle(angle)?)
+            }
+            TransformItem::Skew(x, y) => {
+                LayoutTransformItem::Skew(transform_angle(x)?, transform_angle(y)?)
+            }
+            TransformItem::Perspective(d) => {
+                LayoutTransformItem::Perspective(transform_px(d.clone())?)
+            }
+        };
+        ret.push(item);
+    }
+    Some(ret)
 }
 
 /// # Safety
 ///
-/// Set the border bottom of a node instance.
+/// Set the transform of a node instance to a single `matrix3d`, which is used in the geometry queries.
+/// The `transform-origin` is still applied.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Border bottom type
+/// * `matrix` - Raw pointer to 16 floats in column-major order
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderBottom(node, value);
+/// float matrix[16] = { 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 10, 20, 0, 1 };
+/// NodeStyleSetTransformMatrix3D(node, matrix);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderBottom(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetTransformMatrix3D(node: NodePtr, matrix: *const f32) {
     let node = &*(node as *mut Node);
-    node.set_border_bottom(DefLength::Points(Len::from_f32(value)));
+    let matrix = *(matrix as *const [f32; 16]);
+    node.set_transform(vec![LayoutTransformItem::Matrix3D(matrix)]);
 }
+
 /// # Safety
 ///
-/// Set the border bottom of a node instance to undefined.
+/// Set the transform of a node instance to none.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
@@ -2894,675 +5394,855 @@ pub unsafe extern "C" fn NodeStyleSetBorderBottom(node: NodePtr, value: f32) {
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderBottomNone(node);
+/// NodeStyleSetTransformNone(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderBottomNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeStyleSetTransformNone(node: NodePtr) {
     let node = &*(node as *mut Node);
-    node.set_border_bottom(DefLength::Undefined);
+    node.set_transform(Vec::new());
 }
+
 /// # Safety
 ///
-/// Set the border bottom of a node instance to a percentage.
+/// Set the transform origin of a node instance.
+/// Only `px` and percentage lengths are supported; otherwise the value is ignored.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
+/// * `value` - Transform origin type
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderBottomPercentage(node, value);
+/// NodeStyleSetTransformOrigin(node, value);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderBottomPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeStyleSetTransformOrigin(node: NodePtr, value: TransformOriginType) {
     let node = &*(node as *mut Node);
-    node.set_border_bottom(DefLength::Percent(value));
+    let center = DefLength::Percent(0.5);
+    let value = match value.to_inner_without_global() {
+        Some(TransformOrigin::LengthTuple(x, y, z)) => {
+            let (Some(x), Some(y), Some(z)) =
+                (transform_length(x), transform_length(y), transform_px(z))
+            else {
+                return;
+            };
+            (x, y, z)
+        }
+        Some(TransformOrigin::Left) => (DefLength::Percent(0.), center, Len::zero()),
+        Some(TransformOrigin::Right) => (DefLength::Percent(1.), center, Len::zero()),
+        Some(TransformOrigin::Center) => (center, center, Len::zero()),
+        Some(TransformOrigin::Top) => (center, DefLength::Percent(0.), Len::zero()),
+        Some(TransformOrigin::Bottom) => (center, DefLength::Percent(1.), Len::zero()),
+        Some(TransformOrigin::Length(x)) => {
+            let Some(x) = transform_length(x) else {
+                return;
+            };
+            (x, center, Len::zero())
+        }
+        None => return,
+    };
+    node.set_transform_origin(value);
 }
+
+// layout getter
+
 /// # Safety
 ///
-/// Set the border bottom of a node instance to auto.
+/// Get the left position of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
 ///
+/// # Returns
+/// * `f32` - Left position
+///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderBottomAuto(node);
+/// NodeLayoutGetLeft(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderBottomAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeLayoutGetLeft(node: NodePtr) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_border_bottom(DefLength::Auto);
+    node.layout_position().left.to_f32()
 }
+
 /// # Safety
 ///
-/// Set the border bottom of a node instance to a calc handle.
+/// Get the right position of a node instance.  
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
+///
+/// # Returns
+/// * `f32` - Right position
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetBorderBottomCalcHandle(node, calc_handle);
+/// NodeLayoutGetRight(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetBorderBottomCalcHandle(node: NodePtr, calc_handle: i32) {
-    let node = &*(node as *mut Node);
-    node.set_border_bottom(DefLength::Custom(calc_handle));
+pub unsafe extern "C" fn NodeLayoutGetRight(node: NodePtr) -> f32 {
+    let _node = &*(node as *mut Node);
+    // TODO: return real right
+    0.
 }
+
 /// # Safety
 ///
-/// Set the flex grow of a node instance.
+/// Get the top position of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Flex grow
+///
+/// # Returns
+/// * `f32` - Top position
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetFlexGrow(node, value);
+/// NodeLayoutGetTop(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetFlexGrow(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeLayoutGetTop(node: NodePtr) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_flex_grow(value);
+    node.layout_position().top.to_f32()
 }
 
 /// # Safety
 ///
-/// Set the flex shrink of a node instance.
+/// Get the bottom position of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Flex shrink
+///
+/// # Returns
+/// * `f32` - Bottom position
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetFlexShrink(node, value);
+/// NodeLayoutGetBottom(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetFlexShrink(node: NodePtr, value: f32) {
-    let node = &*(node as *mut Node);
-    node.set_flex_shrink(value);
+pub unsafe extern "C" fn NodeLayoutGetBottom(node: NodePtr) -> f32 {
+    let _node = &*(node as *mut Node);
+    // TODO: return real bottom
+    0.
 }
 
 /// # Safety
 ///
-/// Set the flex basis of a node instance.
+/// Get the width of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Flex basis
+///
+/// # Returns
+/// * `f32` - Width
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetFlexBasis(node, value);
+/// NodeLayoutGetWidth(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetFlexBasis(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeLayoutGetWidth(node: NodePtr) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_flex_basis(DefLength::Points(Len::from_f32(value)));
+    node.layout_position().width.to_f32()
 }
+
 /// # Safety
 ///
-/// Set the flex basis of a node instance to auto.
+/// Get the height of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
 ///
+/// # Returns
+/// * `f32` - Height
+///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetFlexBasisAuto(node);
+/// NodeLayoutGetHeight(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetFlexBasisAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeLayoutGetHeight(node: NodePtr) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_flex_basis(DefLength::Auto);
+    node.layout_position().height.to_f32()
 }
+
 /// # Safety
 ///
-/// Set the flex basis of a node instance to undefined.
+/// Get the scroll width of a node instance, i.e. the width of its scrollable overflow rect.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
 ///
+/// # Returns
+/// * `f32` - Scroll width
+///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetFlexBasisNone(node);
+/// NodeLayoutGetScrollWidth(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetFlexBasisNone(node: NodePtr) {
+pub unsafe extern "C" fn NodeLayoutGetScrollWidth(node: NodePtr) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_flex_basis(DefLength::Undefined);
+    node.scrollable_overflow().width.to_f32()
 }
+
 /// # Safety
 ///
-/// Set the flex basis of a node instance to a percentage.
+/// Get the scroll height of a node instance, i.e. the height of its scrollable overflow rect.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Percentage
+///
+/// # Returns
+/// * `f32` - Scroll height
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetFlexBasisPercentage(node, value);
+/// NodeLayoutGetScrollHeight(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetFlexBasisPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeLayoutGetScrollHeight(node: NodePtr) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_flex_basis(DefLength::Percent(value));
+    node.scrollable_overflow().height.to_f32()
 }
+
 /// # Safety
 ///
-/// Set the flex basis of a node instance to a calc handle.
+/// Get the horizontal sticky offset of a `position: sticky` node instance,
+/// when its nearest scroll container is scrolled to the specified position.
+/// The offset is not included in the layout position.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
+/// * `scroll_left` - Horizontal scroll offset of the scroll container
+/// * `scroll_top` - Vertical scroll offset of the scroll container
+///
+/// # Returns
+/// * `f32` - Horizontal sticky offset
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetFlexBasisCalcHandle(node, calc_handle);
+/// NodeLayoutGetStickyOffsetLeft(node, 0.0, 100.0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetFlexBasisCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeLayoutGetStickyOffsetLeft(
+    node: NodePtr,
+    scroll_left: f32,
+    scroll_top: f32,
+) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_flex_basis(DefLength::Custom(calc_handle));
+    node.sticky_offset(Len::from_f32(scroll_left), Len::from_f32(scroll_top))
+        .0
+        .to_f32()
 }
 
 /// # Safety
 ///
-/// Set the flex direction of a node instance.
+/// Get the vertical sticky offset of a `position: sticky` node instance,
+/// when its nearest scroll container is scrolled to the specified position.
+/// The offset is not included in the layout position.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Flex direction
+/// * `scroll_left` - Horizontal scroll offset of the scroll container
+/// * `scroll_top` - Vertical scroll offset of the scroll container
+///
+/// # Returns
+/// * `f32` - Vertical sticky offset
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetFlexDirection(node, value);
+/// NodeLayoutGetStickyOffsetTop(node, 0.0, 100.0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetFlexDirection(node: NodePtr, value: FlexDirectionType) {
+pub unsafe extern "C" fn NodeLayoutGetStickyOffsetTop(
+    node: NodePtr,
+    scroll_left: f32,
+    scroll_top: f32,
+) -> f32 {
     let node = &*(node as *mut Node);
-    if let Some(value) = value.to_inner_without_global() {
-        node.set_flex_direction(value);
-    }
+    node.sticky_offset(Len::from_f32(scroll_left), Len::from_f32(scroll_top))
+        .1
+        .to_f32()
 }
 
 /// # Safety
 ///
-/// Set the flex wrap of a node instance.
+/// Get the number of column boxes of a multi-column container node instance.
+/// The host can paint column rules between adjacent column boxes.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Flex wrap
+///
+/// # Returns
+/// * `usize` - Number of column boxes, or 0 if the node is not a multi-column container
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetFlexWrap(node, value);
+/// NodeLayoutGetColumnRectCount(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetFlexWrap(node: NodePtr, value: FlexWrapType) {
+pub unsafe extern "C" fn NodeLayoutGetColumnRectCount(node: NodePtr) -> usize {
     let node = &*(node as *mut Node);
-    if let Some(value) = value.to_inner_without_global() {
-        node.set_flex_wrap(value);
-    }
+    node.column_rects().len()
 }
 
 /// # Safety
 ///
-/// Set the justify content of a node instance.
+/// Get the left of a column box of a multi-column container node instance,
+/// relative to the border box of the node.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Justify content
+/// * `index` - Index of the column box
+///
+/// # Returns
+/// * `f32` - Left of the column box, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetJustifyContent(node, value);
+/// NodeLayoutGetColumnRectLeft(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetJustifyContent(node: NodePtr, value: JustifyContentType) {
+pub unsafe extern "C" fn NodeLayoutGetColumnRectLeft(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    if let Some(value) = value.to_inner_without_global() {
-        node.set_justify_content(value);
-    }
+    node.column_rects()
+        .get(index)
+        .map(|rect| rect.left.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the align content of a node instance.
+/// Get the top of a column box of a multi-column container node instance,
+/// relative to the border box of the node.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Align content
+/// * `index` - Index of the column box
+///
+/// # Returns
+/// * `f32` - Top of the column box, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetAlignContent(node, value);
+/// NodeLayoutGetColumnRectTop(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetAlignContent(node: NodePtr, value: AlignContentType) {
+pub unsafe extern "C" fn NodeLayoutGetColumnRectTop(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    if let Some(value) = value.to_inner_without_global() {
-        node.set_align_content(value);
-    }
+    node.column_rects()
+        .get(index)
+        .map(|rect| rect.top.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the align items of a node instance.
+/// Get the width of a column box of a multi-column container node instance,
+/// relative to the border box of the node.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Align items
+/// * `index` - Index of the column box
+///
+/// # Returns
+/// * `f32` - Width of the column box, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetAlignItems(node, value);
+/// NodeLayoutGetColumnRectWidth(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetAlignItems(node: NodePtr, value: AlignItemsType) {
+pub unsafe extern "C" fn NodeLayoutGetColumnRectWidth(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    if let Some(value) = value.to_inner_without_global() {
-        node.set_align_items(value);
-    }
+    node.column_rects()
+        .get(index)
+        .map(|rect| rect.width.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the align self of a node instance.
+/// Get the height of a column box of a multi-column container node instance,
+/// relative to the border box of the node.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Align self
+/// * `index` - Index of the column box
+///
+/// # Returns
+/// * `f32` - Height of the column box, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetAlignSelf(node, value);
+/// NodeLayoutGetColumnRectHeight(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetAlignSelf(node: NodePtr, value: AlignSelfType) {
+pub unsafe extern "C" fn NodeLayoutGetColumnRectHeight(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    if let Some(value) = value.to_inner_without_global() {
-        node.set_align_self(value);
-    }
+    node.column_rects()
+        .get(index)
+        .map(|rect| rect.height.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the order of a node instance.
+/// Get the number of fragments of a node instance after the fragmentation layout.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Order
+///
+/// # Returns
+/// * `usize` - Number of fragments, or 0 if the tree is not laid out in fragmentation mode
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetOrder(node, value);
+/// NodeLayoutGetFragmentCount(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetOrder(node: NodePtr, value: i32) {
+pub unsafe extern "C" fn NodeLayoutGetFragmentCount(node: NodePtr) -> usize {
     let node = &*(node as *mut Node);
-    node.set_order(value);
+    node.fragments().len()
 }
 
 /// # Safety
 ///
-/// Set the row gap of a node instance.
+/// Get the fragmentainer index of a fragment of a node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Row gap
+/// * `index` - Index of the fragment
+///
+/// # Returns
+/// * `usize` - Index of the fragmentainer, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetRowGap(node, value);
+/// NodeLayoutGetFragmentIndex(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetRowGap(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeLayoutGetFragmentIndex(node: NodePtr, index: usize) -> usize {
     let node = &*(node as *mut Node);
-    node.set_row_gap(DefLength::Points(Len::from_f32(value)));
+    node.fragments()
+        .get(index)
+        .map(|(fragmentainer, _)| *fragmentainer)
+        .unwrap_or(0)
 }
 
 /// # Safety
 ///
-/// Set the row gap of a node instance to undefined.
+/// Get the left of a fragment of a node instance,
+/// relative to the fragmentainer.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `index` - Index of the fragment
+///
+/// # Returns
+/// * `f32` - Left of the fragment, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetRowGapNormal(node);
+/// NodeLayoutGetFragmentLeft(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetRowGapNormal(node: NodePtr) {
+pub unsafe extern "C" fn NodeLayoutGetFragmentLeft(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_row_gap(DefLength::Undefined);
+    node.fragments()
+        .get(index)
+        .map(|(_, rect)| rect.left.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the row gap of a node instance to a percentage.
+/// Get the top of a fragment of a node instance,
+/// relative to the fragmentainer.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Row gap percentage
+/// * `index` - Index of the fragment
+///
+/// # Returns
+/// * `f32` - Top of the fragment, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetRowGapPercentage(node, value);
+/// NodeLayoutGetFragmentTop(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetRowGapPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeLayoutGetFragmentTop(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_row_gap(DefLength::Percent(value));
+    node.fragments()
+        .get(index)
+        .map(|(_, rect)| rect.top.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the row gap of a node instance to a calc handle.
+/// Get the width of a fragment of a node instance,
+/// relative to the fragmentainer.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
+/// * `index` - Index of the fragment
+///
+/// # Returns
+/// * `f32` - Width of the fragment, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetRowGapCalcHandle(node, calc_handle);
+/// NodeLayoutGetFragmentWidth(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetRowGapCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeLayoutGetFragmentWidth(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_row_gap(DefLength::Custom(calc_handle));
+    node.fragments()
+        .get(index)
+        .map(|(_, rect)| rect.width.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the column gap of a node instance.
+/// Get the height of a fragment of a node instance,
+/// relative to the fragmentainer.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Column gap
+/// * `index` - Index of the fragment
+///
+/// # Returns
+/// * `f32` - Height of the fragment, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetColumnGap(node, value);
+/// NodeLayoutGetFragmentHeight(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetColumnGap(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeLayoutGetFragmentHeight(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_column_gap(DefLength::Points(Len::from_f32(value)));
+    node.fragments()
+        .get(index)
+        .map(|(_, rect)| rect.height.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the column gap of a node instance to undefined.
+/// Get the number of the line fragments of a text node instance with the text fragment measure function.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
 ///
+/// # Returns
+/// * `usize` - Number of the line fragments
+///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetColumnGapNormal(node);
+/// NodeLayoutGetTextFragmentCount(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetColumnGapNormal(node: NodePtr) {
+pub unsafe extern "C" fn NodeLayoutGetTextFragmentCount(node: NodePtr) -> usize {
     let node = &*(node as *mut Node);
-    node.set_column_gap(DefLength::Undefined);
+    node.text_fragments().len()
 }
 
 /// # Safety
 ///
-/// Set the column gap of a node instance to a percentage.
+/// Get the text offset where a line fragment of a text node instance starts.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Column gap percentage
+/// * `index` - Index of the line fragment
+///
+/// # Returns
+/// * `usize` - Text offset of the fragment, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetColumnGapPercentage(node, value);
+/// NodeLayoutGetTextFragmentStart(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetColumnGapPercentage(node: NodePtr, value: f32) {
+pub unsafe extern "C" fn NodeLayoutGetTextFragmentStart(node: NodePtr, index: usize) -> usize {
     let node = &*(node as *mut Node);
-    node.set_column_gap(DefLength::Percent(value));
+    node.text_fragments()
+        .get(index)
+        .map(|x| x.start)
+        .unwrap_or(0)
 }
 
 /// # Safety
 ///
-/// Set the column gap of a node instance to a calc handle.
+/// Get the left of a line fragment of a text node instance,
+/// relative to the border box of the node.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `calc_handle` - Calc handle
+/// * `index` - Index of the line fragment
+///
+/// # Returns
+/// * `f32` - Left of the fragment, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetColumnGapCalcHandle(node, calc_handle);
+/// NodeLayoutGetTextFragmentLeft(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetColumnGapCalcHandle(node: NodePtr, calc_handle: i32) {
+pub unsafe extern "C" fn NodeLayoutGetTextFragmentLeft(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_column_gap(DefLength::Custom(calc_handle));
+    node.text_fragments()
+        .get(index)
+        .map(|x| x.position.left.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the text align of a node instance.
+/// Get the top of a line fragment of a text node instance,
+/// relative to the border box of the node.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `value` - Text align
+/// * `index` - Index of the line fragment
+///
+/// # Returns
+/// * `f32` - Top of the fragment, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetTextAlign(node, value);
+/// NodeLayoutGetTextFragmentTop(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetTextAlign(node: NodePtr, value: TextAlignType) {
+pub unsafe extern "C" fn NodeLayoutGetTextFragmentTop(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    if let Some(value) = value.to_inner_without_global() {
-        node.set_text_align(value);
-    }
+    node.text_fragments()
+        .get(index)
+        .map(|x| x.position.top.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the aspect ratio of a node instance.
+/// Get the width of a line fragment of a text node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
-/// * `x` - Aspect ratio x
-/// * `y` - Aspect ratio y
+/// * `index` - Index of the line fragment
+///
+/// # Returns
+/// * `f32` - Width of the fragment, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetAspectRatio(node, x, y);
+/// NodeLayoutGetTextFragmentWidth(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetAspectRatio(node: NodePtr, x: f32, y: f32) {
+pub unsafe extern "C" fn NodeLayoutGetTextFragmentWidth(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_aspect_ratio(Some(x / y));
+    node.text_fragments()
+        .get(index)
+        .map(|x| x.position.width.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Set the aspect ratio of a node instance to auto.
+/// Get the height of a line fragment of a text node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `index` - Index of the line fragment
+///
+/// # Returns
+/// * `f32` - Height of the fragment, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeStyleSetAspectRatioAuto(node);
+/// NodeLayoutGetTextFragmentHeight(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeStyleSetAspectRatioAuto(node: NodePtr) {
+pub unsafe extern "C" fn NodeLayoutGetTextFragmentHeight(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    node.set_aspect_ratio(None);
+    node.text_fragments()
+        .get(index)
+        .map(|x| x.position.height.to_f32())
+        .unwrap_or(0.)
 }
 
-// layout getter
-
 /// # Safety
 ///
-/// Get the left position of a node instance.
+/// Get the extra space added to each justification opportunity of a line fragment of a text node instance.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
+/// * `index` - Index of the line fragment
 ///
 /// # Returns
-/// * `f32` - Left position
+/// * `f32` - Justification gap of the fragment, or 0 if the index is out of range
 ///
 /// # Example
 ///
 /// ```c
-/// NodeLayoutGetLeft(node);
+/// NodeLayoutGetTextFragmentJustifyGap(node, 0);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeLayoutGetLeft(node: NodePtr) -> f32 {
+pub unsafe extern "C" fn NodeLayoutGetTextFragmentJustifyGap(node: NodePtr, index: usize) -> f32 {
     let node = &*(node as *mut Node);
-    node.layout_position().left.to_f32()
+    node.text_fragments()
+        .get(index)
+        .map(|x| x.justify_gap.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Get the right position of a node instance.  
+/// Check if a list item node instance has a marker box.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
 ///
 /// # Returns
-/// * `f32` - Right position
+/// * `bool` - True if the node has a marker box, false otherwise
 ///
 /// # Example
 ///
 /// ```c
-/// NodeLayoutGetRight(node);
+/// NodeLayoutHasMarker(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeLayoutGetRight(node: NodePtr) -> f32 {
-    let _node = &*(node as *mut Node);
-    // TODO: return real right
-    0.
+pub unsafe extern "C" fn NodeLayoutHasMarker(node: NodePtr) -> bool {
+    let node = &*(node as *mut Node);
+    node.marker_rect().is_some()
 }
 
 /// # Safety
 ///
-/// Get the top position of a node instance.
+/// Get the left of the marker box of a list item node instance,
+/// relative to the border box of the node.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
 ///
 /// # Returns
-/// * `f32` - Top position
+/// * `f32` - Left of the marker box, or 0 if the node has no marker box
 ///
 /// # Example
 ///
 /// ```c
-/// NodeLayoutGetTop(node);
+/// NodeLayoutGetMarkerLeft(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeLayoutGetTop(node: NodePtr) -> f32 {
+pub unsafe extern "C" fn NodeLayoutGetMarkerLeft(node: NodePtr) -> f32 {
     let node = &*(node as *mut Node);
-    node.layout_position().top.to_f32()
+    node.marker_rect()
+        .map(|rect| rect.left.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Get the bottom position of a node instance.
+/// Get the top of the marker box of a list item node instance,
+/// relative to the border box of the node.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
 ///
 /// # Returns
-/// * `f32` - Bottom position
+/// * `f32` - Top of the marker box, or 0 if the node has no marker box
 ///
 /// # Example
 ///
 /// ```c
-/// NodeLayoutGetBottom(node);
+/// NodeLayoutGetMarkerTop(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeLayoutGetBottom(node: NodePtr) -> f32 {
-    let _node = &*(node as *mut Node);
-    // TODO: return real bottom
-    0.
+pub unsafe extern "C" fn NodeLayoutGetMarkerTop(node: NodePtr) -> f32 {
+    let node = &*(node as *mut Node);
+    node.marker_rect()
+        .map(|rect| rect.top.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Get the width of a node instance.
+/// Get the width of the marker box of a list item node instance,
+/// relative to the border box of the node.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
 ///
 /// # Returns
-/// * `f32` - Width
+/// * `f32` - Width of the marker box, or 0 if the node has no marker box
 ///
 /// # Example
 ///
 /// ```c
-/// NodeLayoutGetWidth(node);
+/// NodeLayoutGetMarkerWidth(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeLayoutGetWidth(node: NodePtr) -> f32 {
+pub unsafe extern "C" fn NodeLayoutGetMarkerWidth(node: NodePtr) -> f32 {
     let node = &*(node as *mut Node);
-    node.layout_position().width.to_f32()
+    node.marker_rect()
+        .map(|rect| rect.width.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
 ///
-/// Get the height of a node instance.
+/// Get the height of the marker box of a list item node instance,
+/// relative to the border box of the node.
 ///
 /// # Arguments
 /// * `node` - Raw pointer to the Node instance
 ///
 /// # Returns
-/// * `f32` - Height
+/// * `f32` - Height of the marker box, or 0 if the node has no marker box
 ///
 /// # Example
 ///
 /// ```c
-/// NodeLayoutGetHeight(node);
+/// NodeLayoutGetMarkerHeight(node);
 /// ```
 #[no_mangle]
-pub unsafe extern "C" fn NodeLayoutGetHeight(node: NodePtr) -> f32 {
+pub unsafe extern "C" fn NodeLayoutGetMarkerHeight(node: NodePtr) -> f32 {
     let node = &*(node as *mut Node);
-    node.layout_position().height.to_f32()
+    node.marker_rect()
+        .map(|rect| rect.height.to_f32())
+        .unwrap_or(0.)
 }
 
 /// # Safety
@@ -3816,3 +6496,285 @@ pub unsafe extern "C" fn NodeLayoutGetPaddingBottom(node: NodePtr) -> f32 {
     let node = &*(node as *mut Node);
     node.computed_style().padding.bottom.to_f32()
 }
+
+/// # Safety
+///
+/// Set the scroll position of a node instance, which is used in hit testing.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `x` - Horizontal scroll offset
+/// * `y` - Vertical scroll offset
+///
+/// # Example
+///
+/// ```c
+/// NodeSetScrollOffset(node, 0, 100);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetScrollOffset(node: NodePtr, x: f32, y: f32) {
+    let node = &*(node as *mut Node);
+    node.set_scroll_offset(Vector::new(Len::from_f32(x), Len::from_f32(y)));
+}
+
+/// # Safety
+///
+/// Find the deepest node under a point, after the layout of the tree.
+/// The path to it can be found with `NodeGetParent`.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the root Node instance
+/// * `x` - X of the point, relative to the parent of the root
+/// * `y` - Y of the point, relative to the parent of the root
+///
+/// # Returns
+/// * `NodePtr` - Raw pointer to the deepest node under the point, or null if nothing is hit
+///
+/// # Example
+///
+/// ```c
+/// NodePtr target = NodeHitTest(root, 10, 20);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeHitTest(node: NodePtr, x: f32, y: f32) -> NodePtr {
+    let node = &*(node as *mut Node);
+    match node
+        .hit_test(Point::new(Len::from_f32(x), Len::from_f32(y)))
+        .last()
+    {
+        Some(target) => *target as *const Node as NodePtr,
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// # Safety
+///
+/// Get the bounding box of the transformed border box of a node instance, after the layout of the tree.
+/// The transforms of all ancestors are included, and the bounds are relative to the parent of the root.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `bounds` - Raw pointer to write the bounds to
+///
+/// # Returns
+/// * `bool` - Whether the bounds are written, i.e. the node is not transformed behind the viewer
+///
+/// # Example
+///
+/// ```c
+/// Bounds bounds;
+/// bool ok = NodeLayoutGetTransformedBounds(node, &bounds);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeLayoutGetTransformedBounds(
+    node: NodePtr,
+    bounds: *mut Bounds,
+) -> bool {
+    let node = &*(node as *mut Node);
+    let Some(rect) = node.transformed_bounds() else {
+        return false;
+    };
+    *bounds = Bounds {
+        left: rect.origin.x,
+        top: rect.origin.y,
+        width: rect.size.width,
+        height: rect.size.height,
+    };
+    true
+}
+
+/// # Safety
+///
+/// Lay out a block container or a `flex-direction: column` flex container as a virtualized list,
+/// in which only the children intersecting the viewport range are laid out.
+/// The node is marked dirty if the settings changed.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+/// * `viewport_start` - Start of the viewport range in the block axis, relative to the content box
+/// * `viewport_end` - End of the viewport range in the block axis, relative to the content box
+/// * `estimated_child_size` - Estimated block size of the children which have never been laid out
+///
+/// # Example
+///
+/// ```c
+/// NodeSetVirtualization(node, scroll_top, scroll_top + 750, 80);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetVirtualization(
+    node: NodePtr,
+    viewport_start: f32,
+    viewport_end: f32,
+    estimated_child_size: f32,
+) {
+    let node = &*(node as *mut Node);
+    node.set_virtualization(Some(LayoutVirtualization {
+        viewport_start: Len::from_f32(viewport_start),
+        viewport_end: Len::from_f32(viewport_end),
+        estimated_child_size: Len::from_f32(estimated_child_size),
+    }));
+}
+
+/// # Safety
+///
+/// Disable the virtualized list layout of a node instance.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Example
+///
+/// ```c
+/// NodeClearVirtualization(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeClearVirtualization(node: NodePtr) {
+    let node = &*(node as *mut Node);
+    node.set_virtualization(None);
+}
+
+/// # Safety
+///
+/// Get the total block size of the children of a virtualized list, in which the placeholders are estimated.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Returns
+/// * `f32` - The total extent, or 0 if the node is not laid out as a virtualized list
+///
+/// # Example
+///
+/// ```c
+/// float extent = NodeLayoutGetVirtualizedExtent(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeLayoutGetVirtualizedExtent(node: NodePtr) -> f32 {
+    let node = &*(node as *mut Node);
+    node.virtualized_result()
+        .map(|x| x.total_extent.to_f32())
+        .unwrap_or(0.)
+}
+
+/// # Safety
+///
+/// Get the first child index of a virtualized list which has real layout results.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Returns
+/// * `usize` - The child index, or 0 if the node is not laid out as a virtualized list
+///
+/// # Example
+///
+/// ```c
+/// size_t start = NodeLayoutGetVirtualizedRangeStart(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeLayoutGetVirtualizedRangeStart(node: NodePtr) -> usize {
+    let node = &*(node as *mut Node);
+    node.virtualized_result()
+        .map(|x| x.laid_out.start)
+        .unwrap_or(0)
+}
+
+/// # Safety
+///
+/// Get the end (exclusive) of the child indices of a virtualized list which have real layout results.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Returns
+/// * `usize` - The child index, or 0 if the node is not laid out as a virtualized list
+///
+/// # Example
+///
+/// ```c
+/// size_t end = NodeLayoutGetVirtualizedRangeEnd(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeLayoutGetVirtualizedRangeEnd(node: NodePtr) -> usize {
+    let node = &*(node as *mut Node);
+    node.virtualized_result()
+        .map(|x| x.laid_out.end)
+        .unwrap_or(0)
+}
+
+/// # Safety
+///
+/// Check whether a child of a virtualized list has only an estimated layout result.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the Node instance
+///
+/// # Returns
+/// * `bool` - Whether the node is a placeholder
+///
+/// # Example
+///
+/// ```c
+/// bool placeholder = NodeLayoutIsVirtualizedPlaceholder(node);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeLayoutIsVirtualizedPlaceholder(node: NodePtr) -> bool {
+    let node = &*(node as *mut Node);
+    node.is_virtualized_placeholder()
+}
+
+/// # Safety
+///
+/// Enable or disable the layout change journal of a tree.
+/// If enabled, each layout records the nodes whose position, size, border, padding, or first baseline changed.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the root Node instance
+/// * `enabled` - Whether the journal is enabled
+///
+/// # Example
+///
+/// ```c
+/// NodeSetLayoutJournalEnabled(root, true);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeSetLayoutJournalEnabled(node: NodePtr, enabled: bool) {
+    let node = &*(node as *mut Node);
+    node.set_layout_journal_enabled(enabled);
+}
+
+/// # Safety
+///
+/// Take the layout changes in the last layout of a tree, and call the `callback` for each changed node
+/// with the old values (null if the node is not recorded before) and the new values.
+///
+/// # Arguments
+/// * `node` - Raw pointer to the root Node instance
+/// * `callback` - Called for each changed node
+///
+/// # Returns
+/// * `usize` - The number of changed nodes
+///
+/// # Example
+///
+/// ```c
+/// size_t count = NodeTakeLayoutJournal(root, on_layout_changed);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn NodeTakeLayoutJournal(
+    node: NodePtr,
+    callback: LayoutJournalCallback,
+) -> usize {
+    let node = &*(node as *mut Node);
+    let journal = node.take_layout_journal();
+    for entry in journal.iter() {
+        let old = entry.old.map(LayoutJournalValues::from);
+        let new = LayoutJournalValues::from(entry.new);
+        callback(
+            entry.node as NodePtr,
+            old.as_ref().map_or(std::ptr::null(), |x| x as *const _),
+            &new,
+        );
+    }
+    journal.len()
+}
@@ -0,0 +1,928 @@
+//! The inline formatting context of the forest.
+//!
+//! CSS Inline Layout Module Level 3 <https://www.w3.org/TR/css-inline-3/>
+//! CSS Text Module Level 3 <https://www.w3.org/TR/css-text-3/>
+//!
+//! The inline series of a block container is broken into line boxes,
+//! then each line box is constructed by the `vertical-align` of its units,
+//! and the units are placed in the line box by the `text-align` of the block container.
+//!
+//! Some simplifications are made:
+//!
+//! * a measured (text) node is an unbreakable unit, so the text wrapping inside it is done by its measure function,
+//!   unless it has a text fragment measure function, which measures the text in the remaining space of each line
+//!   and breaks it across the line boxes;
+//! * the line breaking opportunities are between the units (and inside the fragmented texts),
+//!   unless both sides of the opportunity disallow wrapping (`white-space: nowrap` or `pre`);
+//! * `text-align: justify` expands the justification opportunities reported by the fragmented texts,
+//!   and the gaps between the other units;
+//! * the font metrics are unknown, so the line box has no strut,
+//!   `middle` aligns the midpoint of the unit with the baseline (the x-height is treated as zero),
+//!   and `text-top`/`text-bottom` are the content area edges of the baseline-aligned texts in the line;
+//! * the `line-height` of a text unit is applied to its content area as half-leading,
+//!   and the line spacing inside a multi-line text unit is left to its measure function;
+//! * the inline boxes (`display: inline` nodes which are not measured) are always sliced across lines;
+//! * a multi-line text unit (without fragments) is counted as one line box by the line clamp,
+//!   unless it has a line clamp measure function, which measures the text in the remaining lines;
+//! * the ellipsis is carried by the text unit cut by the line clamp, or the last unit of the last shown line box.
+
+use float_pigment_css::length_num::*;
+use float_pigment_css::num_traits::Zero;
+use float_pigment_css::typing::{Display, Float, Position, TextAlign, WhiteSpace};
+use float_pigment_layout::{
+    DefLength, EdgeOption, FloatSide, InlineExclusion, InlineLineClamp, InlineMeasure, InlineUnit,
+    InlineUnitMetadata, LayoutStyle, LayoutTreeNode, MeasureResult, OptionNum, OptionSize, Point,
+    Rect, Size, SizingMode, Vector,
+};
+
+use crate::{
+    convert_node_ref_to_ptr,
+    env::Env,
+    layout::{LayoutPosition, LayoutTextFragment, LayoutVerticalAlign},
+    node::Node,
+    Len, Length,
+};
+
+#[derive(Debug, Clone)]
+pub struct LayoutInlineUnit {
+    node: *const Node,
+    offset: Point<Len>,
+    size: Size<Len>,
+    first_baseline_ascent: Vector<Len>,
+    last_baseline_ascent: Vector<Len>,
+}
+
+impl LayoutInlineUnit {
+    fn node<'a>(&self) -> &'a Node {
+        // the units are only used during the layout of their nodes
+        unsafe { &*self.node }
+    }
+
+    fn to_tuple(&self) -> (Point<Len>, MeasureResult<Len>) {
+        (
+            self.offset,
+            MeasureResult {
+                size: self.size,
+                first_baseline_ascent: self.first_baseline_ascent,
+                last_baseline_ascent: self.last_baseline_ascent,
+            },
+        )
+    }
+}
+
+impl InlineUnit<Node> for LayoutInlineUnit {
+    type Env = Env;
+    fn new(_env: &mut Env, node: &Node, res: MeasureResult<Len>) -> Self {
+        Self {
+            node,
+            offset: Point::zero(),
+            size: res.size,
+            first_baseline_ascent: res.first_baseline_ascent,
+            last_baseline_ascent: res.last_baseline_ascent,
+        }
+    }
+}
+
+// a line fragment of a text unit, measured by its text fragment measure function
+#[derive(Debug, Clone, Copy)]
+struct InlineTextFragment {
+    start: usize,
+    justification_opportunities: u32,
+    justify_gap: Len,
+}
+
+// an inline unit with the properties resolved for the current inline formatting context
+#[derive(Debug, Clone)]
+struct InlineItem {
+    unit: LayoutInlineUnit,
+    margin: EdgeOption<Len>,
+    // the margins, borders and paddings of the inline boxes starting before or ending after the unit
+    inline_start_edge: Len,
+    inline_end_edge: Len,
+    // whether the line can be broken before the unit
+    break_before: bool,
+    // whether the line can be broken inside the unit
+    wraps: bool,
+    is_text: bool,
+    line_height: Option<Len>,
+    vertical_align: LayoutVerticalAlign,
+    fragment: Option<InlineTextFragment>,
+}
+
+impl InlineItem {
+    fn inline_size(&self) -> Len {
+        self.inline_start_edge
+            + self.margin.left.or_zero()
+            + self.unit.size.width
+            + self.margin.right.or_zero()
+            + self.inline_end_edge
+    }
+
+    fn block_size(&self) -> Len {
+        self.unit.size.height + self.margin.vertical()
+    }
+
+    // the layout bounds (ascent and descent) of the unit, relative to its baseline
+    fn layout_bounds(&self) -> (Len, Len) {
+        let ascent = self.unit.first_baseline_ascent.y;
+        let descent = self.unit.size.height - ascent;
+        if !self.is_text {
+            return (
+                ascent + self.margin.top.or_zero(),
+                descent + self.margin.bottom.or_zero(),
+            );
+        }
+        match self.line_height {
+            Some(line_height) => {
+                let half_leading = (line_height - self.content_line_height()).div_f32(2.);
+                (ascent + half_leading, descent + half_leading)
+            }
+            None => (ascent, descent),
+        }
+    }
+
+    // the content height of a single line in the unit
+    fn content_line_height(&self) -> Len {
+        let baseline_diff = self.unit.last_baseline_ascent.y - self.unit.first_baseline_ascent.y;
+        self.unit.size.height - baseline_diff.max(Len::zero())
+    }
+
+    // how far the baseline of the unit is raised above the baseline of the line
+    fn baseline_shift(&self) -> Len {
+        match self.vertical_align {
+            LayoutVerticalAlign::Length(x) => x,
+            LayoutVerticalAlign::Percent(x) => self
+                .line_height
+                .unwrap_or_else(|| self.content_line_height())
+                .mul_f32(x),
+            _ => Len::zero(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Line {
+    items: Vec<InlineItem>,
+    block_start: Len,
+    // the space taken by the exclusions and the text indent at the line-left side
+    inline_start: Len,
+    available_inline_size: Option<Len>,
+    total_inline_size: Len,
+    total_block_size: Len,
+}
+
+impl Line {
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn collect_inline_item(&mut self, item: InlineItem) {
+        self.total_inline_size += item.inline_size();
+        self.total_block_size = self.total_block_size.max(item.block_size());
+        self.items.push(item);
+    }
+
+    // CSS Inline 3 §4: place the units in the block axis and compute the height of the line box
+    fn construct_line_box(&mut self) {
+        // the content area of the baseline-aligned texts, for `text-top` and `text-bottom`
+        let mut text_top = Len::zero();
+        let mut text_bottom = Len::zero();
+        for item in self.items.iter() {
+            if item.is_text && item.vertical_align == LayoutVerticalAlign::Baseline {
+                text_top = text_top.max(item.unit.first_baseline_ascent.y);
+                text_bottom =
+                    text_bottom.max(item.unit.size.height - item.unit.first_baseline_ascent.y);
+            }
+        }
+
+        // the layout box top of each unit, relative to the baseline of the line
+        let mut tops: Vec<Option<Len>> = Vec::with_capacity(self.items.len());
+        let mut line_top: Option<Len> = None;
+        let mut line_bottom: Option<Len> = None;
+        for item in self.items.iter() {
+            let (ascent, descent) = item.layout_bounds();
+            let top = match item.vertical_align {
+                LayoutVerticalAlign::Top | LayoutVerticalAlign::Bottom => None,
+                LayoutVerticalAlign::Middle => Some(-(ascent + descent).div_f32(2.)),
+                LayoutVerticalAlign::TextTop => Some(-text_top),
+                LayoutVerticalAlign::TextBottom => Some(text_bottom - ascent - descent),
+                LayoutVerticalAlign::Baseline
+                | LayoutVerticalAlign::Length(_)
+                | LayoutVerticalAlign::Percent(_) => Some(-item.baseline_shift() - ascent),
+            };
+            if let Some(top) = top {
+                let bottom = top + ascent + descent;
+                line_top = Some(line_top.map_or(top, |x| x.min(top)));
+                line_bottom = Some(line_bottom.map_or(bottom, |x| x.max(bottom)));
+            }
+            tops.push(top);
+        }
+        let mut line_top = line_top.unwrap_or(Len::zero());
+        let mut line_bottom = line_bottom.unwrap_or(Len::zero());
+
+        // the units aligned to the line box edges may enlarge the line box
+        for item in self.items.iter() {
+            let (ascent, descent) = item.layout_bounds();
+            let height = ascent + descent;
+            match item.vertical_align {
+                LayoutVerticalAlign::Top => line_bottom = line_bottom.max(line_top + height),
+                LayoutVerticalAlign::Bottom => line_top = line_top.min(line_bottom - height),
+                _ => {}
+            }
+        }
+
+        for (item, top) in self.items.iter_mut().zip(tops) {
+            let (ascent, descent) = item.layout_bounds();
+            let top = top.unwrap_or(match item.vertical_align {
+                LayoutVerticalAlign::Bottom => line_bottom - ascent - descent,
+                _ => line_top,
+            });
+            item.unit.offset.y =
+                self.block_start + top - line_top + ascent - item.unit.first_baseline_ascent.y;
+        }
+        self.total_block_size = line_bottom - line_top;
+    }
+
+    // place the units in the inline axis, distributing the free space by `text-align`
+    fn place_inline(&mut self, text_align: &TextAlign, is_last_line: bool) {
+        let free_space = self
+            .available_inline_size
+            .map(|x| x - self.total_inline_size)
+            .filter(|x| *x > Len::zero())
+            .unwrap_or(Len::zero());
+        // the fragmented texts report their own justification opportunities
+        let gaps = self
+            .items
+            .windows(2)
+            .filter(|x| x[0].fragment.is_none() && x[1].fragment.is_none())
+            .count()
+            + self
+                .items
+                .iter()
+                .filter_map(|x| x.fragment)
+                .map(|x| x.justification_opportunities as usize)
+                .sum::<usize>();
+        let (mut offset, gap) = match text_align {
+            TextAlign::End | TextAlign::Right => (free_space, Len::zero()),
+            TextAlign::Center => (free_space.div_f32(2.), Len::zero()),
+            TextAlign::Justify if !is_last_line && gaps > 0 => {
+                (Len::zero(), free_space.div_f32(gaps as f32))
+            }
+            TextAlign::JustifyAll if gaps > 0 => (Len::zero(), free_space.div_f32(gaps as f32)),
+            _ => (Len::zero(), Len::zero()),
+        };
+        offset += self.inline_start;
+        for index in 0..self.items.len() {
+            let gap_after = self.items.get(index + 1).is_some_and(|next| {
+                next.fragment.is_none() && self.items[index].fragment.is_none()
+            });
+            let item = &mut self.items[index];
+            if let Some(fragment) = item.fragment.as_mut() {
+                fragment.justify_gap = gap;
+                item.unit.size.width += gap.mul_f32(fragment.justification_opportunities as f32);
+            }
+            offset += item.inline_start_edge + item.margin.left.or_zero();
+            item.unit.offset.x = offset;
+            offset += item.unit.size.width + item.margin.right.or_zero() + item.inline_end_edge;
+            if gap_after {
+                offset += gap;
+            }
+        }
+    }
+}
+
+// end the current line box and start a new one below it
+fn break_line(lines: &mut Vec<Line>, current_line: &mut Line) {
+    let block_start = current_line.block_start + current_line.total_block_size;
+    lines.push(core::mem::take(current_line));
+    current_line.block_start = block_start;
+}
+
+fn is_exclusion_overlapped(
+    exclusion: &InlineExclusion<Len>,
+    block_start: Len,
+    block_end: Len,
+) -> bool {
+    let exclusion_start = exclusion.rect.origin.y;
+    let exclusion_end = exclusion.rect.origin.y + exclusion.rect.size.height;
+    if block_end > block_start {
+        exclusion_start < block_end && exclusion_end > block_start
+    } else {
+        exclusion_start <= block_start && exclusion_end > block_start
+    }
+}
+
+// the space taken by the exclusions at both sides of the line
+fn exclusion_insets(
+    exclusions: &[InlineExclusion<Len>],
+    block_start: Len,
+    block_end: Len,
+    inline_size: Len,
+) -> (Len, Len) {
+    let mut start = Len::zero();
+    let mut end = Len::zero();
+    for exclusion in exclusions {
+        if !is_exclusion_overlapped(exclusion, block_start, block_end) {
+            continue;
+        }
+        match exclusion.side {
+            FloatSide::Start => {
+                start = start.max(exclusion.rect.origin.x + exclusion.rect.size.width)
+            }
+            FloatSide::End => end = end.max(inline_size - exclusion.rect.origin.x),
+        }
+    }
+    (start, end)
+}
+
+// the nearest block end of the exclusions overlapping the line
+fn next_exclusion_end(
+    exclusions: &[InlineExclusion<Len>],
+    block_start: Len,
+    block_end: Len,
+) -> Option<Len> {
+    exclusions
+        .iter()
+        .filter(|exclusion| is_exclusion_overlapped(exclusion, block_start, block_end))
+        .map(|exclusion| exclusion.rect.origin.y + exclusion.rect.size.height)
+        .fold(None, |acc: Option<Len>, x| {
+            Some(acc.map_or(x, |acc| acc.min(x)))
+        })
+}
+
+fn resolve_length(node: &Node, length: Length, parent: Option<Len>) -> Option<Len> {
+    match length {
+        DefLength::Points(x) => Some(x),
+        DefLength::Percent(x) => Some(parent.unwrap_or(Len::zero()).mul_f32(x)),
+        DefLength::Custom(x) => Some(node.resolve_custom_length(&x, parent.unwrap_or(Len::zero()))),
+        _ => None,
+    }
+}
+
+// the nearest specified value of an inherited property, from the node up to the block container
+fn inherited_value<V: PartialEq>(
+    node: &Node,
+    block_node: &Node,
+    unspecified: V,
+    f: impl Fn(&Node) -> V,
+) -> V {
+    let mut cur = Some(node);
+    while let Some(node) = cur {
+        let value = f(node);
+        if value != unspecified || core::ptr::eq(node, block_node) {
+            return value;
+        }
+        cur = unsafe { node.parent() };
+    }
+    unspecified
+}
+
+// the inline boxes containing the node inside the block container, from the outermost one
+fn inline_box_ancestors<'a>(node: &'a Node, block_node: &Node) -> Vec<&'a Node> {
+    let mut ret = vec![];
+    let mut cur = unsafe { node.parent() };
+    while let Some(parent) = cur {
+        if core::ptr::eq(parent, block_node) || parent.display() != Display::Inline {
+            break;
+        }
+        ret.push(parent);
+        cur = unsafe { parent.parent() };
+    }
+    ret.reverse();
+    ret
+}
+
+fn inline_box_start_edge(node: &Node, parent_width: Option<Len>) -> Len {
+    [node.margin_left(), node.border_left(), node.padding_left()]
+        .into_iter()
+        .filter_map(|x| resolve_length(node, x, parent_width))
+        .fold(Len::zero(), |acc, x| acc + x)
+}
+
+fn inline_box_end_edge(node: &Node, parent_width: Option<Len>) -> Len {
+    [
+        node.margin_right(),
+        node.border_right(),
+        node.padding_right(),
+    ]
+    .into_iter()
+    .filter_map(|x| resolve_length(node, x, parent_width))
+    .fold(Len::zero(), |acc, x| acc + x)
+}
+
+fn allows_wrapping(white_space: WhiteSpace) -> bool {
+    !matches!(white_space, WhiteSpace::NoWrap | WhiteSpace::Pre)
+}
+
+/// Whether the measured node is in a `white-space: nowrap` or `pre` context, so it should not wrap.
+pub(crate) fn is_nowrap_text(node: &Node) -> bool {
+    let mut cur = Some(node);
+    while let Some(node) = cur {
+        let white_space = node.style_manager().white_space();
+        if white_space != WhiteSpace::Normal || node.display() != Display::Inline {
+            return !allows_wrapping(white_space);
+        }
+        cur = unsafe { node.parent() };
+    }
+    false
+}
+
+// whether the node starts the first line of the block container, so `text-indent` applies to it
+fn is_first_in_block(node: &Node, block_node: &Node) -> bool {
+    let mut cur = node;
+    while !core::ptr::eq(cur, block_node) {
+        let Some(parent) = (unsafe { cur.parent() }) else {
+            return false;
+        };
+        let first = unsafe { parent.children() }.into_iter().find(|child| {
+            child.display() != Display::None
+                && !matches!(child.position(), Position::Absolute | Position::Fixed)
+                && child.float() == Float::None
+        });
+        if !first.is_some_and(|first| core::ptr::eq(first, cur)) {
+            return false;
+        }
+        cur = parent;
+    }
+    true
+}
+
+fn resolve_inline_items(
+    block_node: &Node,
+    inline_nodes: Vec<InlineUnitMetadata<Node>>,
+    inline_size: Option<Len>,
+) -> Vec<InlineItem> {
+    let chains: Vec<Vec<&Node>> = inline_nodes
+        .iter()
+        .map(|x| inline_box_ancestors(x.unit.node(), block_node))
+        .collect();
+    let common_len = |a: &[&Node], b: &[&Node]| {
+        a.iter()
+            .zip(b.iter())
+            .take_while(|(a, b)| core::ptr::eq(**a, **b))
+            .count()
+    };
+    let mut prev_wraps = true;
+    inline_nodes
+        .into_iter()
+        .enumerate()
+        .map(|(index, InlineUnitMetadata { unit, margin })| {
+            let chain = &chains[index];
+            let opened_from = match index {
+                0 => 0,
+                _ => common_len(&chains[index - 1], chain),
+            };
+            let closed_from = match chains.get(index + 1) {
+                Some(next) => common_len(chain, next),
+                None => 0,
+            };
+            let inline_start_edge = chain[opened_from..].iter().fold(Len::zero(), |acc, x| {
+                acc + inline_box_start_edge(x, inline_size)
+            });
+            let inline_end_edge = chain[closed_from..].iter().fold(Len::zero(), |acc, x| {
+                acc + inline_box_end_edge(x, inline_size)
+            });
+            let node = unit.node();
+            let is_text = node.display() == Display::Inline;
+            let line_height = inherited_value(node, block_node, Length::Undefined, |x| {
+                x.style_manager().line_height()
+            });
+            let line_height = resolve_length(node, line_height, None);
+            let vertical_align = if is_text {
+                chain
+                    .iter()
+                    .rev()
+                    .map(|x| x.style_manager().vertical_align())
+                    .fold(node.style_manager().vertical_align(), |acc, x| {
+                        if acc == LayoutVerticalAlign::Baseline {
+                            x
+                        } else {
+                            acc
+                        }
+                    })
+            } else {
+                node.style_manager().vertical_align()
+            };
+            let wraps =
+                allows_wrapping(inherited_value(node, block_node, WhiteSpace::Normal, |x| {
+                    x.style_manager().white_space()
+                }));
+            let break_before = index > 0 && (prev_wraps || wraps);
+            prev_wraps = wraps;
+            InlineItem {
+                unit,
+                margin,
+                inline_start_edge,
+                inline_end_edge,
+                break_before,
+                wraps,
+                is_text,
+                line_height,
+                vertical_align,
+                fragment: None,
+            }
+        })
+        .collect()
+}
+
+pub struct LayoutInlineMeasure {}
+impl InlineMeasure<Node> for LayoutInlineMeasure {
+    type InlineUnit = LayoutInlineUnit;
+    type Env = Env;
+
+    fn block_size(
+        env: &mut Env,
+        block_node: &Node,
+        inline_nodes: Vec<InlineUnitMetadata<Node>>,
+        req_size: OptionSize<Len>,
+        max_content_with_max_size: OptionSize<Len>,
+        update_position: bool,
+        sizing_mode: SizingMode,
+    ) -> (Size<Len>, Vec<(Point<Len>, MeasureResult<Len>)>) {
+        Self::block_size_with_exclusions(
+            env,
+            block_node,
+            inline_nodes,
+            req_size,
+            max_content_with_max_size,
+            update_position,
+            sizing_mode,
+            &[],
+        )
+    }
+
+    fn block_size_with_exclusions(
+        _env: &mut Env,
+        block_node: &Node,
+        inline_nodes: Vec<InlineUnitMetadata<Node>>,
+        req_size: OptionSize<Len>,
+        _max_content_with_max_size: OptionSize<Len>,
+        update_position: bool,
+        sizing_mode: SizingMode,
+        exclusions: &[InlineExclusion<Len>],
+    ) -> (Size<Len>, Vec<(Point<Len>, MeasureResult<Len>)>) {
+        let (size, positions, _) = Self::measure_lines(
+            block_node,
+            inline_nodes,
+            req_size,
+            update_position,
+            sizing_mode,
+            exclusions,
+            None,
+        );
+        (size, positions)
+    }
+
+    fn block_size_with_line_clamp(
+        _env: &mut Env,
+        block_node: &Node,
+        inline_nodes: Vec<InlineUnitMetadata<Node>>,
+        req_size: OptionSize<Len>,
+        _max_content_with_max_size: OptionSize<Len>,
+        update_position: bool,
+        sizing_mode: SizingMode,
+        exclusions: &[InlineExclusion<Len>],
+        line_clamp: InlineLineClamp,
+    ) -> (Size<Len>, Vec<(Point<Len>, MeasureResult<Len>)>, u32) {
+        Self::measure_lines(
+            block_node,
+            inline_nodes,
+            req_size,
+            update_position,
+            sizing_mode,
+            exclusions,
+            Some(line_clamp),
+        )
+    }
+}
+
+impl LayoutInlineMeasure {
+    #[allow(clippy::type_complexity)]
+    fn measure_lines(
+        block_node: &Node,
+        inline_nodes: Vec<InlineUnitMetadata<Node>>,
+        req_size: OptionSize<Len>,
+        update_position: bool,
+        sizing_mode: SizingMode,
+        exclusions: &[InlineExclusion<Len>],
+        line_clamp: Option<InlineLineClamp>,
+    ) -> (Size<Len>, Vec<(Point<Len>, MeasureResult<Len>)>, u32) {
+        let suggested_width = req_size.width;
+        let suggested_height = req_size.height;
+        if inline_nodes.is_empty() {
+            return (
+                Size::new(
+                    suggested_width.unwrap_or(Len::zero()),
+                    suggested_height.unwrap_or(Len::zero()),
+                ),
+                Vec::with_capacity(0),
+                0,
+            );
+        }
+        for unit in inline_nodes.iter() {
+            unit.unit.node().set_line_clamp_ellipsis(false);
+        }
+        let text_indent = if is_first_in_block(inline_nodes[0].unit.node(), block_node) {
+            resolve_length(
+                block_node,
+                block_node.style_manager().text_indent(),
+                suggested_width.val(),
+            )
+            .unwrap_or(Len::zero())
+        } else {
+            Len::zero()
+        };
+        let items = resolve_inline_items(block_node, inline_nodes, suggested_width.val());
+
+        // break the units into lines, and the min-content size breaks at every opportunity
+        let line_width = match sizing_mode {
+            SizingMode::MinContent => suggested_width.val().or(Some(Len::zero())),
+            SizingMode::Normal | SizingMode::MaxContent => suggested_width.val(),
+        };
+        let mut lines: Vec<Line> = vec![];
+        let mut current_line = Line {
+            inline_start: text_indent,
+            ..Default::default()
+        };
+        for item in items {
+            if unsafe { item.unit.node().text_fragment_measure_func() }.is_some() {
+                Self::break_text_fragments(
+                    item,
+                    &mut lines,
+                    &mut current_line,
+                    line_width,
+                    text_indent,
+                    exclusions,
+                );
+                continue;
+            }
+            let Some(line_width) = line_width else {
+                current_line.collect_inline_item(item);
+                continue;
+            };
+            loop {
+                // shorten the line box to avoid the exclusions beside it
+                let block_end =
+                    current_line.block_start + current_line.total_block_size.max(item.block_size());
+                let (inset_start, inset_end) =
+                    exclusion_insets(exclusions, current_line.block_start, block_end, line_width);
+                let indent = if lines.is_empty() {
+                    text_indent
+                } else {
+                    Len::zero()
+                };
+                let available_width = line_width - inset_start - inset_end - indent;
+                if current_line.total_inline_size + item.inline_size() <= available_width
+                    || (!current_line.is_empty() && !item.break_before)
+                {
+                    current_line.inline_start = inset_start + indent;
+                    current_line.available_inline_size = Some(available_width);
+                } else if !current_line.is_empty() {
+                    break_line(&mut lines, &mut current_line);
+                    continue;
+                } else if let Some(next_block_start) =
+                    next_exclusion_end(exclusions, current_line.block_start, block_end)
+                {
+                    // move the empty line box below the exclusions
+                    current_line.block_start = next_block_start;
+                    continue;
+                } else {
+                    current_line.inline_start = inset_start + indent;
+                }
+                current_line.collect_inline_item(item);
+                break;
+            }
+        }
+        if !current_line.is_empty() {
+            lines.push(current_line);
+        }
+
+        // the line boxes after the clamp are still placed, but not counted in the size,
+        // and the lines inside a multi-line text unit are counted if it has a line clamp measure function
+        let mut shown_lines_count = lines.len();
+        let mut counted_lines = lines.len() as u32;
+        if let Some(clamp) = line_clamp {
+            let mut ellipsis_node = None;
+            counted_lines = 0;
+            for (index, line) in lines.iter_mut().enumerate() {
+                let remaining_lines = clamp.max_lines - counted_lines;
+                let mut line_count = 1;
+                for item in line.items.iter_mut() {
+                    let node = item.unit.node();
+                    let Some(measure_lines) = (unsafe { node.line_clamp_measure_func() }) else {
+                        continue;
+                    };
+                    let res = measure_lines(
+                        convert_node_ref_to_ptr(node),
+                        item.unit.size.width,
+                        remaining_lines,
+                    );
+                    line_count = line_count.max(res.line_count.min(remaining_lines));
+                    if res.line_count > remaining_lines {
+                        // only the remaining lines of the text are shown
+                        item.unit.size = Size::new(res.width, res.height);
+                        item.unit.last_baseline_ascent =
+                            Vector::new(Len::zero(), res.last_baseline);
+                        ellipsis_node = Some(node);
+                    }
+                }
+                counted_lines += line_count;
+                if counted_lines >= clamp.max_lines {
+                    shown_lines_count = index + 1;
+                    break;
+                }
+            }
+            if ellipsis_node.is_none()
+                && counted_lines >= clamp.max_lines
+                && (lines.len() > shown_lines_count || clamp.has_following_content)
+            {
+                ellipsis_node = lines[shown_lines_count - 1]
+                    .items
+                    .last()
+                    .map(|item| item.unit.node());
+            }
+            if let Some(node) = ellipsis_node {
+                node.set_line_clamp_ellipsis(true);
+            }
+        }
+
+        // construct the line boxes, which may be taller than estimated when breaking the lines
+        let mut block_shift = Len::zero();
+        let (mut block_width, mut block_height) = (Len::zero(), Len::zero());
+        for (index, line) in lines.iter_mut().enumerate() {
+            let estimated_block_end = line.block_start + line.total_block_size;
+            line.block_start += block_shift;
+            line.construct_line_box();
+            let block_start = line.block_start + line.total_block_size;
+            block_shift = block_start - estimated_block_end;
+            if index >= shown_lines_count {
+                continue;
+            }
+            let indent = if index == 0 { text_indent } else { Len::zero() };
+            block_width = block_width.max(indent + line.total_inline_size);
+            block_height = block_height.max(block_start);
+        }
+        let block_size = match sizing_mode {
+            SizingMode::Normal => Size::new(
+                suggested_width.unwrap_or(block_width),
+                suggested_height.unwrap_or(block_height),
+            ),
+            SizingMode::MinContent => Size::new(block_width, block_height),
+            SizingMode::MaxContent => Size::new(block_width, block_height),
+        };
+        let text_align = if suggested_width.is_some() {
+            block_node.style_manager().text_align()
+        } else {
+            TextAlign::Start
+        };
+        let lines_count = lines.len();
+
+        // the line fragments of a text unit are merged into its bounding box
+        let mut units: Vec<(LayoutInlineUnit, Vec<(Rect<Len>, InlineTextFragment)>)> = vec![];
+        for (index, mut line) in lines.into_iter().enumerate() {
+            line.place_inline(&text_align, index + 1 == lines_count);
+            for item in line.items {
+                let rect = Rect::new(item.unit.offset, item.unit.size);
+                match (item.fragment, units.last_mut()) {
+                    (Some(fragment), Some((unit, fragments)))
+                        if core::ptr::eq(unit.node, item.unit.node) =>
+                    {
+                        let bounds = Rect::new(unit.offset, unit.size).union(&rect);
+                        unit.first_baseline_ascent.y += unit.offset.y - bounds.origin.y;
+                        unit.last_baseline_ascent = Vector::new(
+                            Len::zero(),
+                            rect.origin.y + item.unit.last_baseline_ascent.y - bounds.origin.y,
+                        );
+                        unit.offset = bounds.origin;
+                        unit.size = bounds.size;
+                        fragments.push((rect, fragment));
+                    }
+                    (fragment, _) => {
+                        let fragments = fragment.map(|x| vec![(rect, x)]).unwrap_or_default();
+                        units.push((item.unit, fragments));
+                    }
+                }
+            }
+        }
+        let detailed_position = units
+            .into_iter()
+            .map(|(unit, fragments)| {
+                if update_position && !fragments.is_empty() {
+                    let fragments = fragments
+                        .into_iter()
+                        .map(|(rect, fragment)| LayoutTextFragment {
+                            start: fragment.start,
+                            position: LayoutPosition {
+                                left: rect.origin.x - unit.offset.x,
+                                top: rect.origin.y - unit.offset.y,
+                                width: rect.size.width,
+                                height: rect.size.height,
+                            },
+                            justify_gap: fragment.justify_gap,
+                        })
+                        .collect();
+                    unit.node().set_text_fragments(fragments);
+                }
+                unit.to_tuple()
+            })
+            .collect();
+        (block_size, detailed_position, counted_lines)
+    }
+
+    // break a text unit into the line fragments measured in the remaining space of each line
+    fn break_text_fragments(
+        item: InlineItem,
+        lines: &mut Vec<Line>,
+        current_line: &mut Line,
+        line_width: Option<Len>,
+        text_indent: Len,
+        exclusions: &[InlineExclusion<Len>],
+    ) {
+        let node = item.unit.node();
+        let Some(measure_fragment) = (unsafe { node.text_fragment_measure_func() }) else {
+            return;
+        };
+        let mut start = 0;
+        let mut is_first = true;
+        loop {
+            let start_edge = if is_first {
+                item.inline_start_edge + item.margin.left.or_zero()
+            } else {
+                Len::zero()
+            };
+            let indent = if lines.is_empty() {
+                text_indent
+            } else {
+                Len::zero()
+            };
+            let block_end = current_line.block_start + current_line.total_block_size;
+            let line_space = line_width.map(|line_width| {
+                let (inset_start, inset_end) =
+                    exclusion_insets(exclusions, current_line.block_start, block_end, line_width);
+                (
+                    inset_start + indent,
+                    line_width - inset_start - inset_end - indent,
+                )
+            });
+            let remaining_width = match line_space {
+                Some((_, available_width)) if item.wraps => {
+                    (available_width - current_line.total_inline_size - start_edge).max(Len::zero())
+                }
+                _ => Len::MAX,
+            };
+            let allow_empty = !current_line.is_empty() && item.break_before;
+            let fragment = measure_fragment(
+                convert_node_ref_to_ptr(node),
+                start,
+                remaining_width,
+                allow_empty,
+            );
+            if allow_empty && fragment.next == Some(start) {
+                // nothing fits the remaining space, so break before the text
+                break_line(lines, current_line);
+                continue;
+            }
+            if current_line.is_empty() && fragment.width > remaining_width {
+                if let Some(next_block_start) = next_exclusion_end(
+                    exclusions,
+                    current_line.block_start,
+                    current_line.block_start + fragment.height,
+                ) {
+                    // move the empty line box below the exclusions
+                    current_line.block_start = next_block_start;
+                    continue;
+                }
+            }
+            if let Some((inline_start, available_width)) = line_space {
+                current_line.inline_start = inline_start;
+                current_line.available_inline_size = Some(available_width);
+            }
+            let next = fragment.next.filter(|next| *next > start);
+            let baseline = Vector::new(Len::zero(), fragment.baseline);
+            let mut fragment_item = item.clone();
+            fragment_item.unit.size = Size::new(fragment.width, fragment.height);
+            fragment_item.unit.first_baseline_ascent = baseline;
+            fragment_item.unit.last_baseline_ascent = baseline;
+            if !is_first {
+                fragment_item.inline_start_edge = Len::zero();
+                fragment_item.margin.left = OptionNum::zero();
+            }
+            if next.is_some() {
+                fragment_item.inline_end_edge = Len::zero();
+                fragment_item.margin.right = OptionNum::zero();
+            }
+            fragment_item.fragment = Some(InlineTextFragment {
+                start,
+                justification_opportunities: fragment.justification_opportunities,
+                justify_gap: Len::zero(),
+            });
+            current_line.collect_inline_item(fragment_item);
+            let Some(next) = next else {
+                break;
+            };
+            break_line(lines, current_line);
+            start = next;
+            is_first = false;
+        }
+    }
+}
@@ -1,19 +1,24 @@
 use float_pigment_css::length_num::*;
-use float_pigment_css::typing::{GridAutoFlow, JustifyItems, JustifySelf, TextAlign};
+use float_pigment_css::typing::{
+    GridAutoFlow, JustifyItems, JustifySelf, MasonryAutoFlow, TextAlign,
+};
 use float_pigment_css::{
     num_traits::Zero,
     typing::{
-        AlignContent, AlignItems, AlignSelf, BoxSizing, Direction, Display, FlexDirection,
-        FlexWrap, JustifyContent, Position, WritingMode,
+        AlignContent, AlignItems, AlignSelf, BorderCollapse, BoxSizing, Break, BreakInside, Clear,
+        ColumnFill, ColumnSpan, Direction, Display, FlexDirection, FlexWrap, Float, JustifyContent,
+        ListStylePosition, Overflow, PointerEvents, Position, TableLayout, WritingMode,
     },
 };
 use float_pigment_layout::{
-    DefLength, EdgeOption, InlineMeasure, InlineUnit, InlineUnitMetadata, LayoutNode, LayoutStyle,
-    LayoutTreeNode, LayoutTreeVisitor, MeasureResult, OptionNum, OptionSize, Point, Size,
-    SizingMode, Vector,
+    DefLength, LayoutNode, LayoutStyle, LayoutTrace, LayoutTreeNode, LayoutTreeVisitor,
+    MeasureResult, OptionNum, OptionSize, Size, SizingMode, Vector,
 };
 
-use crate::{convert_node_ref_to_ptr, LayoutGridAuto, LayoutGridTemplate, Length};
+use super::inline::{is_nowrap_text, LayoutInlineMeasure, LayoutInlineUnit};
+use crate::{
+    convert_node_ref_to_ptr, LayoutGridAuto, LayoutGridTemplate, LayoutTransformItem, Length,
+};
 use crate::{
     env::Env,
     node::{ChildOperation, Node},
@@ -65,6 +70,11 @@ impl LayoutTreeNode for Node {
         self.has_measure_func()
     }
 
+    #[inline]
+    fn layout_trace(env: &mut Self::Env) -> Option<&mut LayoutTrace<Len>> {
+        env.trace.as_mut()
+    }
+
     #[inline]
     fn measure_block_size(
         &self,
@@ -244,8 +254,38 @@ impl LayoutTreeNode for Node {
         max_content: OptionSize<Self::Length>,
         sizing_mode: SizingMode,
     ) -> MeasureResult<Self::Length> {
+        // a text which should not wrap is measured in a single line
+        let max_content = if is_nowrap_text(self) {
+            OptionSize::new(OptionNum::none(), max_content.height)
+        } else {
+            max_content
+        };
         self.measure_block_size(env, req_size, min, max, max_content, false, sizing_mode)
     }
+
+    #[inline]
+    fn measure_marker(
+        &self,
+        _env: &mut Self::Env,
+        ordinal: i32,
+    ) -> Option<MeasureResult<Self::Length>> {
+        let func = unsafe { self.marker_measure_func() }?;
+        let size = func(convert_node_ref_to_ptr(self), ordinal);
+        let baseline = Vector::new(Len::zero(), size.height);
+        Some(MeasureResult {
+            size,
+            first_baseline_ascent: baseline,
+            last_baseline_ascent: baseline,
+        })
+    }
+
+    #[inline]
+    fn measured_lines(&self, _env: &mut Self::Env) -> Vec<(Self::Length, Self::Length)> {
+        self.unshifted_text_fragments()
+            .iter()
+            .map(|x| (x.position.top, x.position.top + x.position.height))
+            .collect()
+    }
 }
 
 impl LayoutTreeVisitor<Node> for Node {
@@ -281,211 +321,156 @@ impl LayoutTreeVisitor<Node> for Node {
         unsafe { self.children().into_iter() }
     }
 }
-#[derive(Debug, Clone)]
-pub struct LayoutInlineUnit {
-    offset: Point<Len>,
-    size: Size<Len>,
-    first_baseline_ascent: Vector<Len>,
-    last_baseline_ascent: Vector<Len>,
-}
 
-impl LayoutInlineUnit {
-    fn to_tuple(&self) -> (Point<Len>, MeasureResult<Len>) {
-        (
-            self.offset,
-            MeasureResult {
-                size: self.size,
-                first_baseline_ascent: self.first_baseline_ascent,
-                last_baseline_ascent: self.last_baseline_ascent,
-            },
-        )
+impl LayoutStyle<Len> for Node {
+    #[inline]
+    fn display(&self) -> Display {
+        self.style_manager().display()
     }
-}
 
-impl InlineUnit<Node> for LayoutInlineUnit {
-    type Env = Env;
-    fn new(_env: &mut Env, _node: &Node, res: MeasureResult<Len>) -> Self {
-        Self {
-            offset: Point::zero(),
-            size: res.size,
-            first_baseline_ascent: res.first_baseline_ascent,
-            last_baseline_ascent: res.last_baseline_ascent,
-        }
+    #[inline]
+    fn position(&self) -> Position {
+        self.style_manager().position()
     }
-}
 
-#[derive(Debug, Clone)]
-struct Line {
-    inline_units: Vec<LayoutInlineUnit>,
-    total_inline_size: Len,
-    total_block_size: Len,
-    block_start: Len,
-    inline_offset: Len,
-    first_baseline_ascent: Vector<Len>,
-    // text_align: TextAlign,
-}
+    #[inline]
+    fn float(&self) -> Float {
+        self.style_manager().float()
+    }
 
-impl Default for Line {
-    fn default() -> Self {
-        Self {
-            inline_units: vec![],
-            total_inline_size: Len::zero(),
-            total_block_size: Len::zero(),
-            block_start: Len::zero(),
-            inline_offset: Len::zero(),
-            first_baseline_ascent: Vector::default(),
-            // text_align: TextAlign::Start,
-        }
+    #[inline]
+    fn clear(&self) -> Clear {
+        self.style_manager().clear()
     }
-}
 
-impl Line {
-    fn is_empty(&self) -> bool {
-        self.inline_units.is_empty()
+    #[inline]
+    fn overflow_x(&self) -> Overflow {
+        self.style_manager().overflow_x()
     }
 
-    fn collect_inline_unit(&mut self, mut inline_unit: LayoutInlineUnit, margin: EdgeOption<Len>) {
-        inline_unit.offset.x += self.total_inline_size + margin.left.or_zero();
-        inline_unit.offset.y += self.block_start + margin.top.or_zero();
-        self.total_inline_size += inline_unit.size.width + margin.horizontal();
-        self.total_block_size = self
-            .total_block_size
-            .max(inline_unit.size.height + margin.vertical());
-        self.first_baseline_ascent = self
-            .first_baseline_ascent
-            .max(inline_unit.first_baseline_ascent);
-        self.inline_units.push(inline_unit);
+    #[inline]
+    fn overflow_y(&self) -> Overflow {
+        self.style_manager().overflow_y()
     }
 
-    fn adjust_inline_offset(&mut self) {
-        self.inline_units.iter_mut().for_each(|inline_unit| {
-            inline_unit.offset.x += self.inline_offset;
-        })
+    #[inline]
+    fn line_clamp(&self) -> Option<u32> {
+        self.style_manager().line_clamp()
     }
 
-    fn adjust_block_offset(&mut self) {
-        // TODO affected by vertical-align
-        self.inline_units.iter_mut().for_each(|inline_unit| {
-            let max_baseline = self.first_baseline_ascent.y;
-            let self_baseline = inline_unit.first_baseline_ascent.y;
-            inline_unit.offset.y += max_baseline - self_baseline;
-        })
+    #[inline]
+    fn break_before(&self) -> Break {
+        self.style_manager().break_before()
     }
 
-    fn set_inline_offset(&mut self, inline_offset: Len) {
-        self.inline_offset = inline_offset
+    #[inline]
+    fn break_after(&self) -> Break {
+        self.style_manager().break_after()
     }
-}
 
-pub struct LayoutInlineMeasure {}
-impl InlineMeasure<Node> for LayoutInlineMeasure {
-    type InlineUnit = LayoutInlineUnit;
-    type Env = Env;
+    #[inline]
+    fn break_inside(&self) -> BreakInside {
+        self.style_manager().break_inside()
+    }
 
-    fn block_size(
-        _env: &mut Env,
-        block_node: &Node,
-        inline_nodes: Vec<InlineUnitMetadata<Node>>,
-        req_size: OptionSize<Len>,
-        _max_content_with_max_size: OptionSize<Len>,
-        _update_position: bool,
-        sizing_mode: SizingMode,
-    ) -> (Size<Len>, Vec<(Point<Len>, MeasureResult<Len>)>) {
-        let suggested_width = req_size.width;
-        let suggested_height = req_size.height;
-        if inline_nodes.is_empty() {
-            return (
-                Size::new(
-                    suggested_width.unwrap_or(Len::zero()),
-                    suggested_height.unwrap_or(Len::zero()),
-                ),
-                Vec::with_capacity(0),
-            );
-        }
-        let mut lines: Vec<Line> = vec![];
-        let mut prev_line_block_start = Len::zero();
-        let mut current_line = Line::default();
-        if let Some(suggested_width) = suggested_width.val() {
-            inline_nodes
-                .into_iter()
-                .for_each(|InlineUnitMetadata { unit, margin }| {
-                    if (current_line.total_inline_size + unit.size.width + margin.horizontal()
-                        > suggested_width)
-                        && !current_line.is_empty()
-                    {
-                        prev_line_block_start += current_line.total_block_size;
-                        lines.push(current_line.clone());
-                        current_line = Line::default();
-                        current_line.block_start = prev_line_block_start;
-                    }
-                    current_line.collect_inline_unit(unit, margin);
-                });
-        } else {
-            inline_nodes
-                .into_iter()
-                .for_each(|InlineUnitMetadata { unit, margin }| {
-                    current_line.collect_inline_unit(unit, margin);
-                });
-        }
-        if !current_line.is_empty() {
-            lines.push(current_line.clone());
-        }
-        let (mut block_width, mut block_height) = (Len::zero(), Len::zero());
-        lines.iter_mut().for_each(|line| {
-            block_width = block_width.max(line.total_inline_size);
-            block_height += line.total_block_size;
-            line.adjust_block_offset();
-        });
-        let block_size = match sizing_mode {
-            SizingMode::Normal => Size::new(
-                suggested_width.unwrap_or(block_width),
-                suggested_height.unwrap_or(block_height),
-            ),
-            SizingMode::MinContent => Size::new(block_width, block_height),
-            SizingMode::MaxContent => Size::new(block_width, block_height),
-        };
-        if let Some(suggested_width) = suggested_width.val() {
-            if suggested_width > block_width {
-                let text_align = block_node.style().text_align();
-                match text_align {
-                    TextAlign::Start | TextAlign::Left => {}
-                    TextAlign::End | TextAlign::Right => lines.iter_mut().for_each(|line| {
-                        let inline_offset = suggested_width - line.total_inline_size;
-                        line.set_inline_offset(inline_offset);
-                        line.adjust_inline_offset()
-                    }),
-                    TextAlign::Center => lines.iter_mut().for_each(|line| {
-                        let inline_offset = (suggested_width - line.total_inline_size).div_f32(2.);
-                        line.set_inline_offset(inline_offset);
-                        line.adjust_inline_offset()
-                    }),
-                    TextAlign::Justify => {}
-                    TextAlign::JustifyAll => {}
-                    TextAlign::MatchParent => {}
-                }
-            }
-        }
-        let detailed_position = lines
-            .into_iter()
-            .flat_map(|line| {
-                line.inline_units
-                    .into_iter()
-                    .map(|inline_unit| inline_unit.to_tuple())
-            })
-            .collect();
-        (block_size, detailed_position)
+    #[inline]
+    fn orphans(&self) -> u32 {
+        self.style_manager().orphans()
     }
-}
 
-impl LayoutStyle<Len> for Node {
     #[inline]
-    fn display(&self) -> Display {
-        self.style_manager().display()
+    fn widows(&self) -> u32 {
+        self.style_manager().widows()
     }
 
     #[inline]
-    fn position(&self) -> Position {
-        self.style_manager().position()
+    fn column_count(&self) -> Option<u32> {
+        self.style_manager().column_count()
+    }
+
+    #[inline]
+    fn column_width(&self) -> Length {
+        self.style_manager().column_width()
+    }
+
+    #[inline]
+    fn column_span(&self) -> ColumnSpan {
+        self.style_manager().column_span()
+    }
+
+    #[inline]
+    fn column_fill(&self) -> ColumnFill {
+        self.style_manager().column_fill()
+    }
+
+    #[inline]
+    fn table_layout(&self) -> TableLayout {
+        self.style_manager().table_layout()
+    }
+
+    #[inline]
+    fn border_collapse(&self) -> BorderCollapse {
+        self.style_manager().border_collapse()
+    }
+
+    #[inline]
+    fn border_spacing_horizontal(&self) -> Length {
+        self.style_manager().border_spacing_horizontal()
+    }
+
+    #[inline]
+    fn border_spacing_vertical(&self) -> Length {
+        self.style_manager().border_spacing_vertical()
+    }
+
+    #[inline]
+    fn colspan(&self) -> u32 {
+        self.style_manager().colspan()
+    }
+
+    #[inline]
+    fn rowspan(&self) -> u32 {
+        self.style_manager().rowspan()
+    }
+
+    #[inline]
+    fn list_style_position(&self) -> ListStylePosition {
+        self.style_manager().list_style_position()
+    }
+
+    #[inline]
+    fn list_item_value(&self) -> Option<i32> {
+        self.style_manager().list_item_value()
+    }
+
+    #[inline]
+    fn list_start(&self) -> Option<i32> {
+        self.style_manager().list_start()
+    }
+
+    #[inline]
+    fn list_reversed(&self) -> bool {
+        self.style_manager().list_reversed()
+    }
+
+    #[inline]
+    fn z_index(&self) -> Option<i32> {
+        self.style_manager().z_index()
+    }
+
+    #[inline]
+    fn pointer_events(&self) -> PointerEvents {
+        self.style_manager().pointer_events()
+    }
+
+    #[inline]
+    fn transform(&self) -> Vec<LayoutTransformItem> {
+        self.style_manager().transform()
+    }
+
+    #[inline]
+    fn transform_origin(&self) -> (Length, Length, Len) {
+        self.style_manager().transform_origin()
     }
 
     #[inline]
@@ -716,4 +701,9 @@ impl LayoutStyle<Len> for Node {
     fn justify_self(&self) -> JustifySelf {
         self.style_manager().justify_self()
     }
+
+    #[inline]
+    fn masonry_auto_flow(&self) -> MasonryAutoFlow {
+        self.style_manager().masonry_auto_flow()
+    }
 }
@@ -1,7 +1,17 @@
 use crate::Len;
 
+pub(crate) mod inline;
 pub(crate) mod layout_impl;
 
+/// A monotonic clock in nanoseconds, which can be used as the clock of a `LayoutTrace`.
+pub fn trace_clock() -> u64 {
+    static START: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+    START
+        .get_or_init(std::time::Instant::now)
+        .elapsed()
+        .as_nanos() as u64
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct LayoutPosition {
     pub left: Len,
@@ -9,3 +19,62 @@ pub struct LayoutPosition {
     pub width: Len,
     pub height: Len,
 }
+
+/// A line fragment of a text node, measured by the text engine of the host.
+///
+/// The text offsets are in the unit of the host, e.g. the UTF-16 code unit index.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MeasuredTextFragment {
+    pub width: Len,
+    pub height: Len,
+    /// The distance from the top edge of the fragment to its baseline.
+    pub baseline: Len,
+    /// The text offset where the next fragment starts, or `None` if the text ends in this fragment.
+    ///
+    /// The next fragment is always placed at the start of the next line box,
+    /// so a forced line break inside the text also ends the fragment.
+    pub next: Option<usize>,
+    /// The number of the justification opportunities (e.g. the word separators) inside the fragment.
+    pub justification_opportunities: u32,
+}
+
+/// The shown lines of a multi-line text node in a line-clamped block container,
+/// measured by the text engine of the host.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MeasuredTextLines {
+    /// The width of the shown lines.
+    pub width: Len,
+    /// The height of the shown lines.
+    pub height: Len,
+    /// The distance from the top edge of the shown lines to the baseline of the last shown line.
+    pub last_baseline: Len,
+    /// The number of all the lines of the text, which may be more than the shown ones.
+    pub line_count: u32,
+}
+
+/// A line fragment of a text node after the layout.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LayoutTextFragment {
+    /// The text offset where the fragment starts, and it ends where the next fragment starts.
+    pub start: usize,
+    /// The rect of the fragment, relative to the border box of the text node.
+    pub position: LayoutPosition,
+    /// The extra space added to each justification opportunity by `text-align: justify`.
+    pub justify_gap: Len,
+}
+
+/// The `vertical-align` of an inline-level node.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum LayoutVerticalAlign {
+    #[default]
+    Baseline,
+    Top,
+    Middle,
+    Bottom,
+    TextTop,
+    TextBottom,
+    /// Raise the baseline by a fixed value.
+    Length(Len),
+    /// Raise the baseline by a ratio of the `line-height`.
+    Percent(f32),
+}
@@ -1,12 +1,23 @@
-use crate::{env::Env, layout::LayoutPosition, style::StyleManager};
+use crate::{
+    env::Env,
+    layout::{
+        LayoutPosition, LayoutTextFragment, LayoutVerticalAlign, MeasuredTextFragment,
+        MeasuredTextLines,
+    },
+    style::StyleManager,
+};
 use float_pigment_css::typing::{
-    AlignContent, AlignItems, AlignSelf, BoxSizing, Direction, FlexDirection, FlexWrap,
-    GridAutoFlow, JustifyContent, JustifyItems, JustifySelf, Overflow, Position, TextAlign,
-    WritingMode,
+    AlignContent, AlignItems, AlignSelf, BorderCollapse, BoxSizing, Break, BreakInside, Clear,
+    ColumnFill, ColumnSpan, Direction, FlexDirection, FlexWrap, Float, GridAutoFlow,
+    JustifyContent, JustifyItems, JustifySelf, ListStylePosition, MasonryAutoFlow, Overflow,
+    PointerEvents, Position, TableLayout, TextAlign, WhiteSpace, WritingMode,
 };
 
 use float_pigment_css::{length_num::*, typing::Display};
-use float_pigment_layout::{ComputedStyle, DefLength, LayoutNode};
+use float_pigment_layout::{
+    ComputedStyle, DefLength, LayoutJournalEntry, LayoutNode, LayoutQuad, LayoutSnapshot,
+    LayoutTrace, LayoutVirtualization, LayoutVirtualizedResult, Point, Rect, Vector,
+};
 pub use float_pigment_layout::{OptionNum, OptionSize, Size};
 use lru::LruCache;
 
@@ -19,6 +30,7 @@ pub type Len = float_pigment_css::fixed::FixedI32<float_pigment_css::fixed::type
 pub type Length = DefLength<Len>;
 pub type LayoutGridTemplate = float_pigment_layout::LayoutGridTemplate<Len>;
 pub type LayoutGridAuto = float_pigment_layout::LayoutGridAuto<Len>;
+pub type LayoutTransformItem = float_pigment_layout::LayoutTransformItem<Len>;
 pub type NodeId = usize;
 pub type NodePtr = *mut Node;
 
@@ -55,6 +67,19 @@ pub(crate) type MeasureFn<L> = dyn Fn(
 ) -> Size<L>;
 
 pub(crate) type BaselineFn<L> = dyn Fn(NodePtr, L, L) -> L;
+pub(crate) type MarkerMeasureFn<L> = dyn Fn(NodePtr, i32) -> Size<L>;
+pub(crate) type LineClampMaxLines = u32;
+pub(crate) type LineClampMeasureFn =
+    dyn Fn(NodePtr, MeasureMaxWidth, LineClampMaxLines) -> MeasuredTextLines;
+pub(crate) type TextFragmentStart = usize;
+pub(crate) type TextFragmentAvailableWidth = Len;
+pub(crate) type TextFragmentAllowEmpty = bool;
+pub(crate) type TextFragmentMeasureFn = dyn Fn(
+    NodePtr,
+    TextFragmentStart,
+    TextFragmentAvailableWidth,
+    TextFragmentAllowEmpty,
+) -> MeasuredTextFragment;
 pub(crate) type ResolveCalcFn<L> = dyn Fn(i32, L) -> L;
 pub(crate) type DirtyCallbackFn = dyn Fn(NodePtr);
 
@@ -97,6 +122,46 @@ pub enum DumpStyleMode {
 
 pub trait DumpNode {
     unsafe fn dump_to_html(&self, options: DumpOptions, current_depth: u8) -> String;
+    /// Dump the layout snapshot of the subtree as JSON, labeled with the display types.
+    unsafe fn dump_to_json(&self) -> String;
+    /// Dump the layout snapshot of the subtree as SVG, labeled with the display types.
+    unsafe fn dump_to_svg(&self) -> String;
+}
+
+impl Node {
+    fn dump_tag(&self) -> String {
+        let mut tag: String = match self.style_manager().display() {
+            Display::None => "None".into(),
+            Display::Block => "Block".into(),
+            Display::Flex => "Flex".into(),
+            Display::Inline => "Inline".into(),
+            Display::InlineBlock => "InlineBlock".into(),
+            Display::FlowRoot => "FlowRoot".into(),
+            Display::Grid => "Grid".into(),
+            Display::InlineFlex => "InlineFlex".into(),
+            Display::InlineGrid => "InlineGrid".into(),
+            Display::Contents => "Contents".into(),
+            Display::Table => "Table".into(),
+            Display::TableRow => "TableRow".into(),
+            Display::TableCell => "TableCell".into(),
+            Display::TableRowGroup => "TableRowGroup".into(),
+            Display::TableHeaderGroup => "TableHeaderGroup".into(),
+            Display::TableColumn => "TableColumn".into(),
+            Display::TableCaption => "TableCaption".into(),
+            Display::ListItem => "ListItem".into(),
+        };
+        if self.has_measure_func() {
+            tag = format!("Measurable{tag}");
+        }
+        tag
+    }
+
+    /// Collect the layout snapshot of the subtree, with a label for each node.
+    ///
+    /// Should be called after layout.
+    pub fn layout_snapshot(&self, mut label: impl FnMut(&Node) -> String) -> LayoutSnapshot<Len> {
+        LayoutSnapshot::new(self, &mut label)
+    }
 }
 
 impl DumpNode for Node {
@@ -125,20 +190,7 @@ impl DumpNode for Node {
             children_str.push('\n');
             children_str.to_string()
         });
-        let mut tag: String = match self.style_manager().display() {
-            Display::None => "None".into(),
-            Display::Block => "Block".into(),
-            Display::Flex => "Flex".into(),
-            Display::Inline => "Inline".into(),
-            Display::InlineBlock => "InlineBlock".into(),
-            Display::FlowRoot => "FlowRoot".into(),
-            Display::Grid => "Grid".into(),
-            Display::InlineFlex => "InlineFlex".into(),
-            Display::InlineGrid => "InlineGrid".into(),
-        };
-        if self.has_measure_func() {
-            tag = format!("Measurable{tag}");
-        }
+        let tag = self.dump_tag();
         if let Some(children) = children {
             if let Some(style) = style {
                 format!(
@@ -175,6 +227,14 @@ impl DumpNode for Node {
             )
         }
     }
+
+    unsafe fn dump_to_json(&self) -> String {
+        self.layout_snapshot(|node| node.dump_tag()).to_json()
+    }
+
+    unsafe fn dump_to_svg(&self) -> String {
+        self.layout_snapshot(|node| node.dump_tag()).to_svg()
+    }
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -190,6 +250,7 @@ pub enum NodeType {
 pub struct Node {
     node_type: Cell<NodeType>,
     is_dirty: Cell<bool>,
+    has_dirty_descendant: Cell<bool>,
     external_host: Cell<ExternalHostPtr>,
     parent: Cell<NodePtr>,
     children: RefCell<Vec<NodePtr>>,
@@ -199,8 +260,14 @@ pub struct Node {
     baseline_cache: UnsafeCell<Option<Box<BaselineCache>>>,
     baseline_func: UnsafeCell<Option<Box<BaselineFn<Len>>>>,
     measure_func: UnsafeCell<Option<Box<MeasureFn<Len>>>>,
+    marker_measure_func: UnsafeCell<Option<Box<MarkerMeasureFn<Len>>>>,
+    text_fragment_measure_func: UnsafeCell<Option<Box<TextFragmentMeasureFn>>>,
+    line_clamp_measure_func: UnsafeCell<Option<Box<LineClampMeasureFn>>>,
     resolve_calc: UnsafeCell<Option<Box<ResolveCalcFn<Len>>>>,
     dirty_callback: UnsafeCell<Option<Box<DirtyCallbackFn>>>,
+    line_clamp_ellipsis: Cell<bool>,
+    text_fragments: RefCell<Vec<LayoutTextFragment>>,
+    scroll_offset: Cell<Vector<Len>>,
 }
 
 impl Node {
@@ -213,12 +280,19 @@ impl Node {
             style_manager: RefCell::new(StyleManager::new()),
             layout_node: LayoutNode::new(),
             is_dirty: Cell::new(true),
+            has_dirty_descendant: Cell::new(false),
             baseline_func: UnsafeCell::new(None),
             measure_func: UnsafeCell::new(None),
+            marker_measure_func: UnsafeCell::new(None),
+            text_fragment_measure_func: UnsafeCell::new(None),
+            line_clamp_measure_func: UnsafeCell::new(None),
             resolve_calc: UnsafeCell::new(None),
             dirty_callback: UnsafeCell::new(None),
             measure_cache: UnsafeCell::new(None),
             baseline_cache: UnsafeCell::new(None),
+            line_clamp_ellipsis: Cell::new(false),
+            text_fragments: RefCell::new(Vec::with_capacity(0)),
+            scroll_offset: Cell::new(Vector::zero()),
         }
     }
     pub fn new_typed(node_type: NodeType) -> Self {
@@ -340,6 +414,69 @@ impl Node {
     pub fn has_measure_func(&self) -> bool {
         unsafe { (*self.measure_func.get()).is_some() }
     }
+    pub(crate) unsafe fn marker_measure_func(&self) -> Option<&MarkerMeasureFn<Len>> {
+        (*self.marker_measure_func.get()).as_deref()
+    }
+    /// Set the function to measure the marker box of a `display: list-item` node.
+    ///
+    /// It is called with the ordinal of the list item, and its baseline is the bottom edge of the returned size.
+    /// The list item does not generate a marker box if the function is not set,
+    /// so it should be cleared if both `list-style-type` and `list-style-image` are `none`.
+    pub fn set_marker_measure_func(&self, marker_measure_func: Option<Box<MarkerMeasureFn<Len>>>) {
+        drop(std::mem::replace(
+            unsafe { &mut *self.marker_measure_func.get() },
+            marker_measure_func,
+        ));
+        unsafe { self.mark_dirty_propagate() };
+    }
+    pub fn has_marker_measure_func(&self) -> bool {
+        unsafe { (*self.marker_measure_func.get()).is_some() }
+    }
+    pub(crate) unsafe fn text_fragment_measure_func(&self) -> Option<&TextFragmentMeasureFn> {
+        (*self.text_fragment_measure_func.get()).as_deref()
+    }
+    /// Set the function to measure the line fragments of a text node, so the text can break across line boxes.
+    ///
+    /// It is called with the text offset where the fragment starts, the remaining width of the current line,
+    /// and whether the fragment can be empty (i.e. the line can be broken before it).
+    /// It should return the longest fragment fitting the width,
+    /// or at least one unbreakable segment of the text if the fragment cannot be empty.
+    ///
+    /// The measure function is still used for the intrinsic sizes of the node.
+    pub fn set_text_fragment_measure_func(
+        &self,
+        text_fragment_measure_func: Option<Box<TextFragmentMeasureFn>>,
+    ) {
+        drop(std::mem::replace(
+            unsafe { &mut *self.text_fragment_measure_func.get() },
+            text_fragment_measure_func,
+        ));
+        unsafe { self.mark_dirty_propagate() };
+    }
+    pub fn has_text_fragment_measure_func(&self) -> bool {
+        unsafe { (*self.text_fragment_measure_func.get()).is_some() }
+    }
+    pub(crate) unsafe fn line_clamp_measure_func(&self) -> Option<&LineClampMeasureFn> {
+        (*self.line_clamp_measure_func.get()).as_deref()
+    }
+    /// Set the function to measure a multi-line text node in a line-clamped block container.
+    ///
+    /// It is called with the width of the text and the number of the lines that can still be shown,
+    /// and should return the size of the shown lines and the number of all the lines of the text.
+    /// The text is counted as one line box by the line clamp if the function is not set.
+    pub fn set_line_clamp_measure_func(
+        &self,
+        line_clamp_measure_func: Option<Box<LineClampMeasureFn>>,
+    ) {
+        drop(std::mem::replace(
+            unsafe { &mut *self.line_clamp_measure_func.get() },
+            line_clamp_measure_func,
+        ));
+        unsafe { self.mark_dirty_propagate() };
+    }
+    pub fn has_line_clamp_measure_func(&self) -> bool {
+        unsafe { (*self.line_clamp_measure_func.get()).is_some() }
+    }
     pub(crate) fn resolve_calc(&self) -> Option<&ResolveCalcFn<Len>> {
         unsafe { (*self.resolve_calc.get()).as_deref() }
     }
@@ -378,12 +515,51 @@ impl Node {
     pub(crate) fn is_dirty(&self) -> bool {
         self.is_dirty.get()
     }
+    /// Whether the node or any of its descendants should be laid out again.
+    ///
+    /// The dirty propagation stops at a relayout boundary, so the ancestors of the boundary are not dirty themselves,
+    /// but they still need a layout pass to reach the boundary.
+    pub fn needs_layout(&self) -> bool {
+        self.is_dirty() || self.has_dirty_descendant.get()
+    }
+    /// Whether the node ends the last shown line of a line-clamped block container.
+    pub fn has_line_clamp_ellipsis(&self) -> bool {
+        self.line_clamp_ellipsis.get()
+    }
+    pub(crate) fn set_line_clamp_ellipsis(&self, value: bool) {
+        self.line_clamp_ellipsis.set(value)
+    }
+    /// The line fragments of a text node with the text fragment measure function, in the text order.
+    ///
+    /// The lines pushed to the next fragmentainer by `layout_with_fragmentation` are moved with it.
+    pub fn text_fragments(&self) -> Vec<LayoutTextFragment> {
+        let line_shifts = self.layout_node.line_shifts();
+        let mut fragments = self.text_fragments.borrow().clone();
+        for (fragment, shift) in fragments.iter_mut().zip(line_shifts) {
+            fragment.position.top += shift;
+        }
+        fragments
+    }
+    pub(crate) fn unshifted_text_fragments(&self) -> Ref<'_, Vec<LayoutTextFragment>> {
+        self.text_fragments.borrow()
+    }
+    pub(crate) fn set_text_fragments(&self, fragments: Vec<LayoutTextFragment>) {
+        *self.text_fragments.borrow_mut() = fragments;
+    }
+    /// The scroll position of the node, which is used in hit testing.
+    pub fn scroll_offset(&self) -> Vector<Len> {
+        self.scroll_offset.get()
+    }
+    pub fn set_scroll_offset(&self, scroll_offset: Vector<Len>) {
+        self.scroll_offset.set(scroll_offset)
+    }
     pub(crate) fn clear_dirty(&self) {
         self.is_dirty.set(false)
     }
     pub(crate) unsafe fn clear_dirty_recursive(&self) {
-        if self.is_dirty() {
+        if self.is_dirty() || self.has_dirty_descendant.get() {
             self.clear_dirty();
+            self.has_dirty_descendant.set(false);
             self.children()
                 .iter()
                 .for_each(|child| child.clear_dirty_recursive());
@@ -412,12 +588,39 @@ impl Node {
                 .for_each(|node| (**node).mark_dirty_propagate_to_descendants())
         }
     }
+    /// The ordinals of the list items depend on their siblings, so they are marked dirty together.
+    pub(crate) unsafe fn mark_list_items_dirty(&self) {
+        for child in self.children().iter() {
+            if child.style_manager().display() == Display::ListItem {
+                child.mark_dirty_propagate();
+            }
+        }
+    }
     pub unsafe fn mark_dirty_propagate(&self) {
         if !self.is_dirty() {
             self.mark_self_dirty();
             if let Some(parent) = self.parent() {
-                parent.mark_dirty_propagate()
+                parent.mark_dirty_propagate_from_descendant()
+            }
+        }
+    }
+    unsafe fn mark_dirty_propagate_from_descendant(&self) {
+        if self.is_dirty() {
+            return;
+        }
+        self.mark_self_dirty();
+        // the propagation stops at a relayout boundary, which is laid out alone,
+        // but the ancestors should still be visited when clearing the dirty flags
+        if self.layout_node.is_relayout_boundary() {
+            let mut cur = self.parent();
+            while let Some(parent) = cur {
+                if parent.has_dirty_descendant.replace(true) {
+                    break;
+                }
+                cur = parent.parent();
             }
+        } else if let Some(parent) = self.parent() {
+            parent.mark_dirty_propagate_from_descendant()
         }
     }
     pub fn dry_layout(
@@ -430,6 +633,7 @@ impl Node {
             &mut Env {
                 screen_width: viewport_size.width,
                 screen_height: viewport_size.height,
+                trace: None,
             },
             self,
             available_size,
@@ -446,6 +650,7 @@ impl Node {
             &mut Env {
                 screen_width: viewport_size.width,
                 screen_height: viewport_size.height,
+                trace: None,
             },
             self,
             available_size,
@@ -454,6 +659,32 @@ impl Node {
         self.clear_dirty_recursive();
     }
 
+    /// Lay out the tree like `layout`, and record the statistics in `trace`.
+    ///
+    /// The summary can be read with `LayoutTrace::summary` after this call.
+    pub unsafe fn layout_with_trace(
+        &self,
+        available_size: OptionSize<Len>,
+        viewport_size: float_pigment_layout::Size<Len>,
+        trace: &mut LayoutTrace<Len>,
+    ) {
+        let mut env = Env {
+            screen_width: viewport_size.width,
+            screen_height: viewport_size.height,
+            trace: Some(core::mem::replace(trace, LayoutTrace::new(None))),
+        };
+        self.layout_node.update_with_containing_size(
+            &mut env,
+            self,
+            available_size,
+            available_size,
+        );
+        if let Some(x) = env.trace {
+            *trace = x;
+        }
+        self.clear_dirty_recursive();
+    }
+
     pub unsafe fn layout_with_containing_size(
         &self,
         available_size: OptionSize<Len>,
@@ -464,6 +695,7 @@ impl Node {
             &mut Env {
                 screen_width: viewport_size.width,
                 screen_height: viewport_size.height,
+                trace: None,
             },
             self,
             available_size,
@@ -472,6 +704,31 @@ impl Node {
         self.clear_dirty_recursive();
     }
 
+    /// Lay out the tree in fragmentation mode, breaking the content into fragmentainers (e.g. pages)
+    /// of `fragmentainer_block_size` in height.
+    ///
+    /// The layout positions are in a continuous coordinate, in which the fragmentainer `i`
+    /// starts at `i * fragmentainer_block_size`. Use `fragments` to get the part of a node in each fragmentainer.
+    pub unsafe fn layout_with_fragmentation(
+        &self,
+        available_size: OptionSize<Len>,
+        viewport_size: float_pigment_layout::Size<Len>,
+        fragmentainer_block_size: Len,
+    ) {
+        self.layout_node.update_with_fragmentation(
+            &mut Env {
+                screen_width: viewport_size.width,
+                screen_height: viewport_size.height,
+                trace: None,
+            },
+            self,
+            available_size,
+            available_size,
+            fragmentainer_block_size,
+        );
+        self.clear_dirty_recursive();
+    }
+
     pub fn layout_position(&self) -> LayoutPosition {
         let layout = self.layout_node.result();
         LayoutPosition {
@@ -481,6 +738,121 @@ impl Node {
             height: layout.size.height,
         }
     }
+
+    pub fn scrollable_overflow(&self) -> LayoutPosition {
+        let overflow = self.layout_node.scrollable_overflow(self);
+        LayoutPosition {
+            left: overflow.origin.x,
+            top: overflow.origin.y,
+            width: overflow.size.width,
+            height: overflow.size.height,
+        }
+    }
+
+    /// Get the sticky offset `(left, top)` of a `position: sticky` node,
+    /// when its nearest scroll container is scrolled to `(scroll_left, scroll_top)`.
+    pub fn sticky_offset(&self, scroll_left: Len, scroll_top: Len) -> (Len, Len) {
+        let offset = self.layout_node.sticky_offset(
+            self,
+            float_pigment_layout::Vector::new(scroll_left, scroll_top),
+        );
+        (offset.x, offset.y)
+    }
+
+    /// Get the marker box of a `display: list-item` node, relative to its border box.
+    pub fn marker_rect(&self) -> Option<LayoutPosition> {
+        self.layout_node.marker_rect().map(|rect| LayoutPosition {
+            left: rect.origin.x,
+            top: rect.origin.y,
+            width: rect.size.width,
+            height: rect.size.height,
+        })
+    }
+
+    /// Get the column boxes of a multi-column container, relative to its border box.
+    pub fn column_rects(&self) -> Vec<LayoutPosition> {
+        self.layout_node
+            .column_rects()
+            .into_iter()
+            .map(|rect| LayoutPosition {
+                left: rect.origin.x,
+                top: rect.origin.y,
+                width: rect.size.width,
+                height: rect.size.height,
+            })
+            .collect()
+    }
+
+    /// Find the path from this node to the deepest node under the `point`, see `float_pigment_layout::hit_test`.
+    ///
+    /// The `point` is relative to the parent of this node, and the scroll offsets are taken from `Node::scroll_offset`.
+    pub fn hit_test(&self, point: Point<Len>) -> Vec<&Node> {
+        float_pigment_layout::hit_test(self, point, |node| node.scroll_offset())
+    }
+
+    /// Get the transformed border box in the coordinate of the tree root, see `float_pigment_layout::transformed_quad`.
+    pub fn transformed_quad(&self) -> Option<LayoutQuad> {
+        float_pigment_layout::transformed_quad(self)
+    }
+
+    /// Get the bounding box of the transformed border box in the coordinate of the tree root.
+    pub fn transformed_bounds(&self) -> Option<Rect<f32>> {
+        float_pigment_layout::transformed_bounds(self)
+    }
+
+    /// Set the virtualization settings of a list container, see `float_pigment_layout::LayoutNode::set_virtualization`.
+    ///
+    /// The node is marked dirty if the settings changed, e.g. when the viewport range is scrolled.
+    pub unsafe fn set_virtualization(&self, virtualization: Option<LayoutVirtualization<Len>>) {
+        if self.layout_node.set_virtualization(virtualization) {
+            self.mark_dirty_propagate();
+        }
+    }
+
+    /// Get the results of a virtualized list container after layout, see `float_pigment_layout::LayoutNode::virtualized_result`.
+    pub fn virtualized_result(&self) -> Option<LayoutVirtualizedResult<Len>> {
+        self.layout_node.virtualized_result()
+    }
+
+    /// Whether the node is a child of a virtualized list which has only an estimated result.
+    pub fn is_virtualized_placeholder(&self) -> bool {
+        self.layout_node.is_virtualized_placeholder()
+    }
+
+    /// Enable or disable the layout change journal, see `float_pigment_layout::LayoutNode::set_journal_enabled`.
+    ///
+    /// Should only be called on the tree root node.
+    pub fn set_layout_journal_enabled(&self, enabled: bool) {
+        self.layout_node.set_journal_enabled(enabled)
+    }
+
+    /// Take the layout changes in the last layout, in which the `node` of each entry is the address of the `Node`.
+    ///
+    /// Should only be called on the tree root node.
+    pub fn take_layout_journal(&self) -> Vec<LayoutJournalEntry<Len>> {
+        self.layout_node.take_journal()
+    }
+
+    /// Get the fragments of the border box after `layout_with_fragmentation`,
+    /// as `(fragmentainer index, rect relative to the fragmentainer)`.
+    pub fn fragments(&self) -> Vec<(usize, LayoutPosition)> {
+        self.layout_node
+            .fragments()
+            .into_iter()
+            .map(|fragment| {
+                let rect = fragment.rect;
+                (
+                    fragment.index,
+                    LayoutPosition {
+                        left: rect.origin.x,
+                        top: rect.origin.y,
+                        width: rect.size.width,
+                        height: rect.size.height,
+                    },
+                )
+            })
+            .collect()
+    }
 }
 
 impl Default for Node {
@@ -522,7 +894,10 @@ impl ChildOperation for Node {
         }
         (*child).set_parent(Some(convert_node_ref_to_ptr(self)));
         self.children.borrow_mut().push(child);
-        self.mark_dirty_propagate()
+        self.mark_dirty_propagate();
+        if (*child).style_manager().display() == Display::ListItem {
+            self.mark_list_items_dirty();
+        }
     }
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     unsafe fn insert_child_at(&self, child: NodePtr, idx: usize) {
@@ -531,7 +906,10 @@ impl ChildOperation for Node {
         }
         (*child).set_parent(Some(convert_node_ref_to_ptr(self)));
         self.children.borrow_mut().insert(idx, child);
-        self.mark_dirty_propagate()
+        self.mark_dirty_propagate();
+        if (*child).style_manager().display() == Display::ListItem {
+            self.mark_list_items_dirty();
+        }
     }
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     unsafe fn insert_child_before(&self, child: NodePtr, pivot: NodePtr) {
@@ -548,6 +926,9 @@ impl ChildOperation for Node {
             self.children.borrow_mut().insert(idx, child)
         }
         self.mark_dirty_propagate();
+        if (*child).style_manager().display() == Display::ListItem {
+            self.mark_list_items_dirty();
+        }
     }
     unsafe fn remove_child(&self, child: NodePtr) {
         if self.children_len() == 0 {
@@ -569,17 +950,21 @@ impl ChildOperation for Node {
         }
 
         self.mark_dirty_propagate();
+        if (*child).style_manager().display() == Display::ListItem {
+            self.mark_list_items_dirty();
+        }
     }
     unsafe fn remove_child_at(&self, idx: usize) {
         let len = self.children_len();
         if len == 0 || idx >= len {
             return;
         }
-        if let Some(node) = self.children.borrow().get(idx) {
-            (**node).set_parent(None);
-        }
-        self.children.borrow_mut().remove(idx);
+        let child = self.children.borrow_mut().remove(idx);
+        (*child).set_parent(None);
         self.mark_dirty_propagate();
+        if (*child).style_manager().display() == Display::ListItem {
+            self.mark_list_items_dirty();
+        }
     }
     unsafe fn remove_all_children(&self) {
         self.for_each_child_node(|node, _| {
@@ -594,8 +979,8 @@ impl ChildOperation for Node {
     {
         let mut func = func;
         self.children
-            .borrow_mut()
-            .iter_mut()
+            .borrow()
+            .iter()
             .enumerate()
             .for_each(|(idx, node)| func(&**node, idx))
     }
@@ -607,6 +992,8 @@ pub trait StyleSetter {
     unsafe fn set_direction(&self, value: Direction);
     unsafe fn set_writing_mode(&self, value: WritingMode);
     unsafe fn set_position(&self, value: Position);
+    unsafe fn set_float(&self, value: Float);
+    unsafe fn set_clear(&self, value: Clear);
     unsafe fn set_left(&self, value: Length);
     unsafe fn set_top(&self, value: Length);
     unsafe fn set_right(&self, value: Length);
@@ -648,13 +1035,42 @@ pub trait StyleSetter {
     unsafe fn set_aspect_ratio(&self, value: Option<f32>);
     unsafe fn set_order(&self, value: i32);
     unsafe fn set_text_align(&self, value: TextAlign);
+    unsafe fn set_line_height(&self, value: Length);
+    unsafe fn set_vertical_align(&self, value: LayoutVerticalAlign);
+    unsafe fn set_text_indent(&self, value: Length);
+    unsafe fn set_white_space(&self, value: WhiteSpace);
+    unsafe fn set_line_clamp(&self, value: Option<u32>);
+    unsafe fn set_break_before(&self, value: Break);
+    unsafe fn set_break_after(&self, value: Break);
+    unsafe fn set_break_inside(&self, value: BreakInside);
+    unsafe fn set_orphans(&self, value: u32);
+    unsafe fn set_widows(&self, value: u32);
     unsafe fn set_row_gap(&self, value: Length);
     unsafe fn set_column_gap(&self, value: Length);
+    unsafe fn set_column_count(&self, value: Option<u32>);
+    unsafe fn set_column_width(&self, value: Length);
+    unsafe fn set_column_span(&self, value: ColumnSpan);
+    unsafe fn set_column_fill(&self, value: ColumnFill);
+    unsafe fn set_table_layout(&self, value: TableLayout);
+    unsafe fn set_border_collapse(&self, value: BorderCollapse);
+    unsafe fn set_border_spacing_horizontal(&self, value: Length);
+    unsafe fn set_border_spacing_vertical(&self, value: Length);
+    unsafe fn set_colspan(&self, value: u32);
+    unsafe fn set_rowspan(&self, value: u32);
+    unsafe fn set_list_style_position(&self, value: ListStylePosition);
+    unsafe fn set_list_item_value(&self, value: Option<i32>);
+    unsafe fn set_list_start(&self, value: Option<i32>);
+    unsafe fn set_list_reversed(&self, value: bool);
     unsafe fn set_grid_template_rows(&self, value: LayoutGridTemplate);
     unsafe fn set_grid_template_columns(&self, value: LayoutGridTemplate);
     unsafe fn set_grid_auto_flow(&self, value: GridAutoFlow);
     unsafe fn set_grid_auto_rows(&self, value: LayoutGridAuto);
     unsafe fn set_grid_auto_columns(&self, value: LayoutGridAuto);
+    unsafe fn set_masonry_auto_flow(&self, value: MasonryAutoFlow);
+    unsafe fn set_z_index(&self, value: Option<i32>);
+    unsafe fn set_pointer_events(&self, value: PointerEvents);
+    unsafe fn set_transform(&self, value: Vec<LayoutTransformItem>);
+    unsafe fn set_transform_origin(&self, value: (Length, Length, Len));
 }
 
 impl StyleSetter for Node {
@@ -733,8 +1149,15 @@ impl StyleSetter for Node {
         }
     }
     unsafe fn set_display(&self, display: Display) {
+        let was_list_item = self.style_manager().display() == Display::ListItem;
+        let is_list_item = display == Display::ListItem;
         if self.style_manager_mut().set_display(display) {
             self.mark_dirty_propagate();
+            if was_list_item || is_list_item {
+                if let Some(parent) = self.parent() {
+                    parent.mark_list_items_dirty();
+                }
+            }
         }
     }
     unsafe fn set_height(&self, height: Length) {
@@ -800,6 +1223,16 @@ impl StyleSetter for Node {
             self.mark_dirty_propagate();
         }
     }
+    unsafe fn set_float(&self, float: Float) {
+        if self.style_manager_mut().set_float(float) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_clear(&self, clear: Clear) {
+        if self.style_manager_mut().set_clear(clear) {
+            self.mark_dirty_propagate();
+        }
+    }
     unsafe fn set_overflow_x(&self, overflow_x: Overflow) {
         if self.style_manager_mut().set_overflow_x(overflow_x) {
             self.mark_dirty_propagate();
@@ -904,6 +1337,56 @@ impl StyleSetter for Node {
             self.mark_dirty_propagate();
         }
     }
+    unsafe fn set_line_height(&self, value: Length) {
+        if self.style_manager_mut().set_line_height(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_vertical_align(&self, value: LayoutVerticalAlign) {
+        if self.style_manager_mut().set_vertical_align(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_text_indent(&self, value: Length) {
+        if self.style_manager_mut().set_text_indent(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_white_space(&self, value: WhiteSpace) {
+        if self.style_manager_mut().set_white_space(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_line_clamp(&self, value: Option<u32>) {
+        if self.style_manager_mut().set_line_clamp(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_break_before(&self, value: Break) {
+        if self.style_manager_mut().set_break_before(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_break_after(&self, value: Break) {
+        if self.style_manager_mut().set_break_after(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_break_inside(&self, value: BreakInside) {
+        if self.style_manager_mut().set_break_inside(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_orphans(&self, value: u32) {
+        if self.style_manager_mut().set_orphans(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_widows(&self, value: u32) {
+        if self.style_manager_mut().set_widows(value) {
+            self.mark_dirty_propagate();
+        }
+    }
     unsafe fn set_row_gap(&self, value: Length) {
         if self.style_manager_mut().set_row_gap(value) {
             self.mark_dirty_propagate();
@@ -914,6 +1397,84 @@ impl StyleSetter for Node {
             self.mark_dirty_propagate();
         }
     }
+    unsafe fn set_column_count(&self, value: Option<u32>) {
+        if self.style_manager_mut().set_column_count(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_column_width(&self, value: Length) {
+        if self.style_manager_mut().set_column_width(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_column_span(&self, value: ColumnSpan) {
+        if self.style_manager_mut().set_column_span(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_column_fill(&self, value: ColumnFill) {
+        if self.style_manager_mut().set_column_fill(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_table_layout(&self, value: TableLayout) {
+        if self.style_manager_mut().set_table_layout(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_border_collapse(&self, value: BorderCollapse) {
+        if self.style_manager_mut().set_border_collapse(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_border_spacing_horizontal(&self, value: Length) {
+        if self
+            .style_manager_mut()
+            .set_border_spacing_horizontal(value)
+        {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_border_spacing_vertical(&self, value: Length) {
+        if self.style_manager_mut().set_border_spacing_vertical(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_colspan(&self, value: u32) {
+        if self.style_manager_mut().set_colspan(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_rowspan(&self, value: u32) {
+        if self.style_manager_mut().set_rowspan(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_list_style_position(&self, value: ListStylePosition) {
+        if self.style_manager_mut().set_list_style_position(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    unsafe fn set_list_item_value(&self, value: Option<i32>) {
+        if self.style_manager_mut().set_list_item_value(value) {
+            self.mark_dirty_propagate();
+            if let Some(parent) = self.parent() {
+                parent.mark_list_items_dirty();
+            }
+        }
+    }
+    unsafe fn set_list_start(&self, value: Option<i32>) {
+        if self.style_manager_mut().set_list_start(value) {
+            self.mark_dirty_propagate();
+            self.mark_list_items_dirty();
+        }
+    }
+    unsafe fn set_list_reversed(&self, value: bool) {
+        if self.style_manager_mut().set_list_reversed(value) {
+            self.mark_dirty_propagate();
+            self.mark_list_items_dirty();
+        }
+    }
     unsafe fn set_grid_template_rows(&self, value: LayoutGridTemplate) {
         if self.style_manager_mut().set_grid_template_rows(value) {
             self.mark_dirty_propagate();
@@ -939,6 +1500,24 @@ impl StyleSetter for Node {
             self.mark_dirty_propagate();
         }
     }
+    unsafe fn set_masonry_auto_flow(&self, value: MasonryAutoFlow) {
+        if self.style_manager_mut().set_masonry_auto_flow(value) {
+            self.mark_dirty_propagate();
+        }
+    }
+    // z-index, pointer-events and transforms do not affect layout, so the node is not marked dirty
+    unsafe fn set_z_index(&self, value: Option<i32>) {
+        self.style_manager_mut().set_z_index(value);
+    }
+    unsafe fn set_pointer_events(&self, value: PointerEvents) {
+        self.style_manager_mut().set_pointer_events(value);
+    }
+    unsafe fn set_transform(&self, value: Vec<LayoutTransformItem>) {
+        self.style_manager_mut().set_transform(value);
+    }
+    unsafe fn set_transform_origin(&self, value: (Length, Length, Len)) {
+        self.style_manager_mut().set_transform_origin(value);
+    }
 }
 
 #[cfg(test)]
@@ -1,12 +1,20 @@
 use std::cell::Cell;
 
-use float_pigment_css::{num_traits::Zero, typing::GridAutoFlow};
+use float_pigment_css::{
+    num_traits::Zero,
+    typing::{GridAutoFlow, MasonryAutoFlow},
+};
 // use float_pigment_forest_macro::{FieldCount, StyleManagerMutation};
 
-use crate::{LayoutGridAuto, LayoutGridTemplate, Len, Length};
+use crate::{
+    layout::LayoutVerticalAlign, LayoutGridAuto, LayoutGridTemplate, LayoutTransformItem, Len,
+    Length,
+};
 use float_pigment_css::typing::{
-    AlignContent, AlignItems, AlignSelf, BoxSizing, Direction, Display, FlexDirection, FlexWrap,
-    JustifyContent, JustifyItems, JustifySelf, Overflow, Position, TextAlign, WritingMode,
+    AlignContent, AlignItems, AlignSelf, BorderCollapse, BoxSizing, Break, BreakInside, Clear,
+    ColumnFill, ColumnSpan, Direction, Display, FlexDirection, FlexWrap, Float, JustifyContent,
+    JustifyItems, JustifySelf, ListStylePosition, Overflow, PointerEvents, Position, TableLayout,
+    TextAlign, WhiteSpace, WritingMode,
 };
 use lazy_static::lazy_static;
 
@@ -79,6 +87,34 @@ struct OtherStyle {
     pub aspect_ratio: Option<f32>,
     pub row_gap: Length,
     pub column_gap: Length,
+    pub column_count: Option<u32>,
+    pub column_width: Length,
+    pub column_span: ColumnSpan,
+    pub column_fill: ColumnFill,
+    pub table_layout: TableLayout,
+    pub border_collapse: BorderCollapse,
+    pub border_spacing_horizontal: Length,
+    pub border_spacing_vertical: Length,
+    pub colspan: u32,
+    pub rowspan: u32,
+    pub list_style_position: ListStylePosition,
+    pub list_item_value: Option<i32>,
+    pub list_start: Option<i32>,
+    pub list_reversed: bool,
+    pub line_height: Length,
+    pub vertical_align: LayoutVerticalAlign,
+    pub text_indent: Length,
+    pub white_space: WhiteSpace,
+    pub line_clamp: Option<u32>,
+    pub break_before: Break,
+    pub break_after: Break,
+    pub break_inside: BreakInside,
+    pub orphans: u32,
+    pub widows: u32,
+    pub z_index: Option<i32>,
+    pub pointer_events: PointerEvents,
+    pub transform: Vec<LayoutTransformItem>,
+    pub transform_origin: (Length, Length, Len),
 }
 
 impl Default for OtherStyle {
@@ -92,6 +128,34 @@ impl Default for OtherStyle {
             aspect_ratio: None,
             row_gap: Length::Undefined,
             column_gap: Length::Undefined,
+            column_count: None,
+            column_width: Length::Auto,
+            column_span: ColumnSpan::None,
+            column_fill: ColumnFill::Balance,
+            table_layout: TableLayout::Auto,
+            border_collapse: BorderCollapse::Separate,
+            border_spacing_horizontal: Length::Points(Len::zero()),
+            border_spacing_vertical: Length::Points(Len::zero()),
+            colspan: 1,
+            rowspan: 1,
+            list_style_position: ListStylePosition::Outside,
+            list_item_value: None,
+            list_start: None,
+            list_reversed: false,
+            line_height: Length::Undefined,
+            vertical_align: LayoutVerticalAlign::Baseline,
+            text_indent: Length::Undefined,
+            white_space: WhiteSpace::Normal,
+            line_clamp: None,
+            break_before: Break::Auto,
+            break_after: Break::Auto,
+            break_inside: BreakInside::Auto,
+            orphans: 2,
+            widows: 2,
+            z_index: None,
+            pointer_events: PointerEvents::Auto,
+            transform: Vec::new(),
+            transform_origin: (Length::Percent(0.5), Length::Percent(0.5), Len::zero()),
         }
     }
 }
@@ -99,6 +163,8 @@ impl Default for OtherStyle {
 #[derive(Debug)]
 struct PositionStyle {
     pub position: Position,
+    pub float: Float,
+    pub clear: Clear,
     pub left: Length,
     pub right: Length,
     pub top: Length,
@@ -108,6 +174,8 @@ impl Default for PositionStyle {
     fn default() -> Self {
         Self {
             position: Position::Relative,
+            float: Float::None,
+            clear: Clear::None,
             left: Length::Auto,
             right: Length::Auto,
             top: Length::Auto,
@@ -211,6 +279,7 @@ struct GridStyle {
     pub grid_auto_flow: GridAutoFlow,
     pub grid_auto_rows: LayoutGridAuto,
     pub grid_auto_columns: LayoutGridAuto,
+    pub masonry_auto_flow: MasonryAutoFlow,
 }
 
 impl Default for GridStyle {
@@ -221,6 +290,7 @@ impl Default for GridStyle {
             grid_auto_flow: GridAutoFlow::Row,
             grid_auto_rows: LayoutGridAuto::default(),
             grid_auto_columns: LayoutGridAuto::default(),
+            masonry_auto_flow: MasonryAutoFlow::Pack,
         }
     }
 }
@@ -630,6 +700,136 @@ impl StyleManager {
         true
     }
 
+    pub(crate) fn line_height(&self) -> Length {
+        self.other_style().line_height
+    }
+
+    pub(crate) fn set_line_height(&self, value: Length) -> bool {
+        if self.other_style().line_height == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().line_height = value;
+        true
+    }
+
+    pub(crate) fn vertical_align(&self) -> LayoutVerticalAlign {
+        self.other_style().vertical_align
+    }
+
+    pub(crate) fn set_vertical_align(&self, value: LayoutVerticalAlign) -> bool {
+        if self.other_style().vertical_align == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().vertical_align = value;
+        true
+    }
+
+    pub(crate) fn text_indent(&self) -> Length {
+        self.other_style().text_indent
+    }
+
+    pub(crate) fn set_text_indent(&self, value: Length) -> bool {
+        if self.other_style().text_indent == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().text_indent = value;
+        true
+    }
+
+    pub(crate) fn white_space(&self) -> WhiteSpace {
+        self.other_style().white_space.clone()
+    }
+
+    pub(crate) fn set_white_space(&self, value: WhiteSpace) -> bool {
+        if self.other_style().white_space == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().white_space = value;
+        true
+    }
+
+    pub(crate) fn line_clamp(&self) -> Option<u32> {
+        self.other_style().line_clamp
+    }
+
+    pub(crate) fn set_line_clamp(&self, value: Option<u32>) -> bool {
+        if self.other_style().line_clamp == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().line_clamp = value;
+        true
+    }
+
+    pub(crate) fn break_before(&self) -> Break {
+        self.other_style().break_before.clone()
+    }
+
+    pub(crate) fn set_break_before(&self, value: Break) -> bool {
+        if self.other_style().break_before == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().break_before = value;
+        true
+    }
+
+    pub(crate) fn break_after(&self) -> Break {
+        self.other_style().break_after.clone()
+    }
+
+    pub(crate) fn set_break_after(&self, value: Break) -> bool {
+        if self.other_style().break_after == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().break_after = value;
+        true
+    }
+
+    pub(crate) fn break_inside(&self) -> BreakInside {
+        self.other_style().break_inside.clone()
+    }
+
+    pub(crate) fn set_break_inside(&self, value: BreakInside) -> bool {
+        if self.other_style().break_inside == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().break_inside = value;
+        true
+    }
+
+    pub(crate) fn orphans(&self) -> u32 {
+        self.other_style().orphans
+    }
+
+    pub(crate) fn set_orphans(&self, value: u32) -> bool {
+        if self.other_style().orphans == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().orphans = value;
+        true
+    }
+
+    pub(crate) fn widows(&self) -> u32 {
+        self.other_style().widows
+    }
+
+    pub(crate) fn set_widows(&self, value: u32) -> bool {
+        if self.other_style().widows == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().widows = value;
+        true
+    }
+
     pub(crate) fn aspect_ratio(&self) -> Option<f32> {
         self.other_style().aspect_ratio
     }
@@ -656,6 +856,32 @@ impl StyleManager {
         true
     }
 
+    pub(crate) fn float(&self) -> Float {
+        self.position_style().float.clone()
+    }
+
+    pub(crate) fn set_float(&self, value: Float) -> bool {
+        if self.position_style().float == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Position);
+        self.position_style().float = value;
+        true
+    }
+
+    pub(crate) fn clear(&self) -> Clear {
+        self.position_style().clear.clone()
+    }
+
+    pub(crate) fn set_clear(&self, value: Clear) -> bool {
+        if self.position_style().clear == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Position);
+        self.position_style().clear = value;
+        true
+    }
+
     pub(crate) fn left(&self) -> Length {
         self.position_style().left
     }
@@ -1046,6 +1272,244 @@ impl StyleManager {
         true
     }
 
+    pub(crate) fn column_count(&self) -> Option<u32> {
+        self.other_style().column_count
+    }
+
+    pub(crate) fn set_column_count(&self, value: Option<u32>) -> bool {
+        if self.other_style().column_count == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().column_count = value;
+        true
+    }
+
+    pub(crate) fn column_width(&self) -> Length {
+        self.other_style().column_width.clone()
+    }
+
+    pub(crate) fn set_column_width(&self, value: Length) -> bool {
+        if self.other_style().column_width == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().column_width = value;
+        true
+    }
+
+    pub(crate) fn column_span(&self) -> ColumnSpan {
+        self.other_style().column_span.clone()
+    }
+
+    pub(crate) fn set_column_span(&self, value: ColumnSpan) -> bool {
+        if self.other_style().column_span == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().column_span = value;
+        true
+    }
+
+    pub(crate) fn column_fill(&self) -> ColumnFill {
+        self.other_style().column_fill.clone()
+    }
+
+    pub(crate) fn set_column_fill(&self, value: ColumnFill) -> bool {
+        if self.other_style().column_fill == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().column_fill = value;
+        true
+    }
+
+    pub(crate) fn table_layout(&self) -> TableLayout {
+        self.other_style().table_layout.clone()
+    }
+
+    pub(crate) fn set_table_layout(&self, value: TableLayout) -> bool {
+        if self.other_style().table_layout == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().table_layout = value;
+        true
+    }
+
+    pub(crate) fn border_collapse(&self) -> BorderCollapse {
+        self.other_style().border_collapse.clone()
+    }
+
+    pub(crate) fn set_border_collapse(&self, value: BorderCollapse) -> bool {
+        if self.other_style().border_collapse == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().border_collapse = value;
+        true
+    }
+
+    pub(crate) fn border_spacing_horizontal(&self) -> Length {
+        self.other_style().border_spacing_horizontal.clone()
+    }
+
+    pub(crate) fn set_border_spacing_horizontal(&self, value: Length) -> bool {
+        if self.other_style().border_spacing_horizontal == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().border_spacing_horizontal = value;
+        true
+    }
+
+    pub(crate) fn border_spacing_vertical(&self) -> Length {
+        self.other_style().border_spacing_vertical.clone()
+    }
+
+    pub(crate) fn set_border_spacing_vertical(&self, value: Length) -> bool {
+        if self.other_style().border_spacing_vertical == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().border_spacing_vertical = value;
+        true
+    }
+
+    pub(crate) fn colspan(&self) -> u32 {
+        self.other_style().colspan
+    }
+
+    /// The value is clamped to `1..=1000` as the HTML `colspan` attribute.
+    pub(crate) fn set_colspan(&self, value: u32) -> bool {
+        let value = value.clamp(1, 1000);
+        if self.other_style().colspan == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().colspan = value;
+        true
+    }
+
+    pub(crate) fn rowspan(&self) -> u32 {
+        self.other_style().rowspan
+    }
+
+    /// The value is clamped to `0..=65534` as the HTML `rowspan` attribute.
+    pub(crate) fn set_rowspan(&self, value: u32) -> bool {
+        let value = value.min(65534);
+        if self.other_style().rowspan == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().rowspan = value;
+        true
+    }
+
+    pub(crate) fn list_style_position(&self) -> ListStylePosition {
+        self.other_style().list_style_position.clone()
+    }
+
+    pub(crate) fn set_list_style_position(&self, value: ListStylePosition) -> bool {
+        if self.other_style().list_style_position == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().list_style_position = value;
+        true
+    }
+
+    pub(crate) fn list_item_value(&self) -> Option<i32> {
+        self.other_style().list_item_value
+    }
+
+    pub(crate) fn set_list_item_value(&self, value: Option<i32>) -> bool {
+        if self.other_style().list_item_value == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().list_item_value = value;
+        true
+    }
+
+    pub(crate) fn list_start(&self) -> Option<i32> {
+        self.other_style().list_start
+    }
+
+    pub(crate) fn set_list_start(&self, value: Option<i32>) -> bool {
+        if self.other_style().list_start == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().list_start = value;
+        true
+    }
+
+    pub(crate) fn z_index(&self) -> Option<i32> {
+        self.other_style().z_index
+    }
+
+    pub(crate) fn set_z_index(&self, value: Option<i32>) -> bool {
+        if self.other_style().z_index == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().z_index = value;
+        true
+    }
+
+    pub(crate) fn pointer_events(&self) -> PointerEvents {
+        self.other_style().pointer_events.clone()
+    }
+
+    pub(crate) fn set_pointer_events(&self, value: PointerEvents) -> bool {
+        if self.other_style().pointer_events == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().pointer_events = value;
+        true
+    }
+
+    pub(crate) fn transform(&self) -> Vec<LayoutTransformItem> {
+        self.other_style().transform.clone()
+    }
+
+    pub(crate) fn set_transform(&self, value: Vec<LayoutTransformItem>) -> bool {
+        if self.other_style().transform == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().transform = value;
+        true
+    }
+
+    pub(crate) fn transform_origin(&self) -> (Length, Length, Len) {
+        self.other_style().transform_origin
+    }
+
+    pub(crate) fn set_transform_origin(&self, value: (Length, Length, Len)) -> bool {
+        if self.other_style().transform_origin == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().transform_origin = value;
+        true
+    }
+
+    pub(crate) fn list_reversed(&self) -> bool {
+        self.other_style().list_reversed
+    }
+
+    pub(crate) fn set_list_reversed(&self, value: bool) -> bool {
+        if self.other_style().list_reversed == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Other);
+        self.other_style().list_reversed = value;
+        true
+    }
+
     pub(crate) fn grid_template_rows(&self) -> LayoutGridTemplate {
         self.grid_style().grid_template_rows.clone()
     }
@@ -1110,4 +1574,17 @@ impl StyleManager {
         self.grid_style().grid_auto_columns = value;
         true
     }
+
+    pub(crate) fn masonry_auto_flow(&self) -> MasonryAutoFlow {
+        self.grid_style().masonry_auto_flow.clone()
+    }
+
+    pub(crate) fn set_masonry_auto_flow(&self, value: MasonryAutoFlow) -> bool {
+        if self.grid_style().masonry_auto_flow == value {
+            return false;
+        }
+        self.clone_style(StyleBit::Grid);
+        self.grid_style().masonry_auto_flow = value;
+        true
+    }
 }
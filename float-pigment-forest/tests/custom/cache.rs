@@ -9,10 +9,6 @@
 use crate::*;
 use float_pigment_css::typing::*;
 
-unsafe fn as_ref<'a>(node: *mut Node) -> &'a Node {
-    &*node
-}
-
 // Case: Position cache invalidation when order changes
 // Spec points:
 // - Changing order property reorders flex items visually
@@ -523,3 +519,112 @@ pub fn test() {
         assert_eq!(inline.layout_position().top, 20.);
     }
 }
+
+// Case: Absolutely positioned descendants relayout with their containing block
+// Spec points:
+// - The containing block of an absolute box is its nearest positioned ancestor
+// - The absolute box is laid out again when the containing block changes,
+//   even if its parent is not changed
+// In this test:
+// - container(relative, w:200) > wrapper(static, 50x40) > abs(width: 50%, right: 0)
+// - After: container width becomes 300, then wrapper becomes relative
+#[test]
+pub fn position_cache_if_containing_block_changed() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let container = as_ref(Node::new_ptr());
+        container.set_position(Position::Relative);
+        container.set_width(DefLength::Points(Len::from_f32(200.)));
+        container.set_height(DefLength::Points(Len::from_f32(100.)));
+        root.append_child(convert_node_ref_to_ptr(container));
+        let wrapper = as_ref(Node::new_ptr());
+        wrapper.set_position(Position::Static);
+        wrapper.set_width(DefLength::Points(Len::from_f32(50.)));
+        wrapper.set_height(DefLength::Points(Len::from_f32(40.)));
+        container.append_child(convert_node_ref_to_ptr(wrapper));
+        let abs = as_ref(Node::new_ptr());
+        abs.set_position(Position::Absolute);
+        abs.set_right(DefLength::Points(Len::from_f32(0.)));
+        abs.set_width(DefLength::Percent(0.5));
+        abs.set_height(DefLength::Points(Len::from_f32(10.)));
+        wrapper.append_child(convert_node_ref_to_ptr(abs));
+        let layout = || {
+            root.layout(
+                OptionSize::new(
+                    OptionNum::some(Len::from_f32(375.)),
+                    OptionNum::some(Len::from_f32(750.)),
+                ),
+                Size::new(Len::from_f32(0.), Len::from_f32(0.)),
+            )
+        };
+
+        layout();
+        assert_eq!(abs.layout_position().width, 100.);
+        assert_eq!(abs.layout_position().left, 100.);
+
+        container.set_width(DefLength::Points(Len::from_f32(300.)));
+        layout();
+        assert_eq!(abs.layout_position().width, 150.);
+        assert_eq!(abs.layout_position().left, 150.);
+
+        wrapper.set_position(Position::Relative);
+        layout();
+        assert_eq!(abs.layout_position().width, 25.);
+        assert_eq!(abs.layout_position().left, 25.);
+    }
+}
+
+// Case: Dirty propagation through display: contents nodes
+// Spec points:
+// - A display: contents node does not generate a box, so it is never laid out
+// - Its children are laid out by the grandparent, which must be marked dirty
+// In this test:
+// - The flex container lays out an item inside a contents node
+// - The item is resized repeatedly, and the sibling after it should follow
+#[test]
+pub fn dirty_propagation_through_display_contents() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let container = as_ref(Node::new_ptr());
+        container.set_display(Display::Flex);
+        container.set_width(DefLength::Points(Len::from_f32(300.)));
+        container.set_height(DefLength::Points(Len::from_f32(100.)));
+        root.append_child(convert_node_ref_to_ptr(container));
+        let contents = as_ref(Node::new_ptr());
+        contents.set_display(Display::Contents);
+        container.append_child(convert_node_ref_to_ptr(contents));
+        let item = as_ref(Node::new_ptr());
+        item.set_width(DefLength::Points(Len::from_f32(10.)));
+        contents.append_child(convert_node_ref_to_ptr(item));
+        let sibling = as_ref(Node::new_ptr());
+        sibling.set_width(DefLength::Points(Len::from_f32(10.)));
+        container.append_child(convert_node_ref_to_ptr(sibling));
+        let layout = || {
+            root.layout(
+                OptionSize::new(
+                    OptionNum::some(Len::from_f32(375.)),
+                    OptionNum::some(Len::from_f32(750.)),
+                ),
+                Size::new(Len::from_f32(0.), Len::from_f32(0.)),
+            )
+        };
+
+        layout();
+        assert_eq!(contents.layout_position().width, 0.);
+        assert_eq!(sibling.layout_position().left, 10.);
+
+        for width in [20., 30., 40.] {
+            item.set_width(DefLength::Points(Len::from_f32(width)));
+            layout();
+            assert_eq!(item.layout_position().width, width);
+            assert_eq!(item.layout_position().height, 100.);
+            assert_eq!(sibling.layout_position().left, width);
+        }
+
+        contents.set_display(Display::Block);
+        layout();
+        assert_eq!(contents.layout_position().width, 40.);
+        assert_eq!(item.layout_position().left, 0.);
+        assert_eq!(sibling.layout_position().left, 40.);
+    }
+}
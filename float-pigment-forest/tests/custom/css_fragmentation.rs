@@ -0,0 +1,263 @@
+// Tests for the fragmentation mode (CSS Fragmentation 3)
+// Focus: forced breaks, monolithic boxes, break avoidance, orphans / widows, and fragment slicing.
+
+use crate::*;
+
+use float_pigment_css::typing::{Break, BreakInside, Display, FlexDirection, FlexWrap, Overflow};
+use float_pigment_forest::{
+    convert_node_ref_to_ptr, layout::MeasuredTextFragment, ChildOperation, Node, NodeType,
+    StyleSetter,
+};
+use float_pigment_layout::{DefLength, OptionNum, OptionSize, Size};
+
+// a text of `count` lines in 60x30, measured by the text fragment measure function
+unsafe fn new_text_lines<'a>(count: usize) -> &'a Node {
+    let node = as_ref(Node::new_ptr());
+    node.set_display(Display::Inline);
+    node.set_node_type(NodeType::Text);
+    node.set_measure_func(Some(Box::new(move |_, _, _, _, _, _, _, _, _| {
+        Size::new(Len::from_f32(60.), Len::from_f32(30. * count as f32))
+    })));
+    node.set_text_fragment_measure_func(Some(Box::new(move |_, start, _, _| {
+        MeasuredTextFragment {
+            width: Len::from_f32(60.),
+            height: Len::from_f32(30.),
+            baseline: Len::from_f32(30.),
+            next: (start + 1 < count).then_some(start + 1),
+            justification_opportunities: 0,
+        }
+    })));
+    node
+}
+
+unsafe fn new_root<'a>(children: &[&Node]) -> &'a Node {
+    let root = as_ref(Node::new_ptr());
+    root.set_width(DefLength::Points(Len::from_f32(100.)));
+    for child in children {
+        root.append_child(convert_node_ref_to_ptr(child));
+    }
+    root
+}
+
+unsafe fn layout(node: &Node, page_height: f32) {
+    node.layout_with_fragmentation(
+        OptionSize::new(OptionNum::some(Len::from_f32(375.)), OptionNum::none()),
+        Size::new(Len::from_f32(0.), Len::from_f32(0.)),
+        Len::from_f32(page_height),
+    );
+}
+
+fn fragment_tops_and_heights(node: &Node) -> Vec<(usize, f32, f32)> {
+    node.fragments()
+        .into_iter()
+        .map(|(index, rect)| (index, rect.top.to_f32(), rect.height.to_f32()))
+        .collect()
+}
+
+// root(page: 100) > [a(h:30), b(h:30, break-before: page)]
+// - b starts a new page, and the root grows to hold the strut
+#[test]
+fn forced_break() {
+    unsafe {
+        let a = new_box(30.);
+        let b = new_box(30.);
+        b.set_break_before(Break::Page);
+        let root = new_root(&[a, b]);
+        layout(root, 100.);
+
+        assert_eq!(b.layout_position().top, 100.);
+        assert_eq!(root.layout_position().height, 130.);
+        assert_eq!(fragment_tops_and_heights(b), [(1, 0., 30.)]);
+        assert_eq!(
+            fragment_tops_and_heights(root),
+            [(0, 0., 100.), (1, 0., 30.)]
+        );
+    }
+}
+
+// root(page: 100) > a(h:250)
+// - a is sliced into three fragments
+#[test]
+fn fragment_slicing() {
+    unsafe {
+        let a = new_box(250.);
+        let root = new_root(&[a]);
+        layout(root, 100.);
+
+        assert_eq!(
+            fragment_tops_and_heights(a),
+            [(0, 0., 100.), (1, 0., 100.), (2, 0., 50.)]
+        );
+    }
+}
+
+// root(page: 100) > [a(h:80), b(h:40, overflow: hidden), c(h:40, break-inside: avoid)]
+// - b is monolithic and pushed to the second page
+// - c avoids breaking inside and is pushed to the third page
+#[test]
+fn monolithic_and_break_inside_avoid() {
+    unsafe {
+        let a = new_box(80.);
+        let b = new_box(40.);
+        b.set_overflow_y(Overflow::Hidden);
+        let c = new_box(80.);
+        c.set_break_inside(BreakInside::Avoid);
+        let root = new_root(&[a, b, c]);
+        layout(root, 100.);
+
+        assert_eq!(b.layout_position().top, 100.);
+        assert_eq!(c.layout_position().top, 200.);
+        assert_eq!(root.layout_position().height, 280.);
+        assert_eq!(fragment_tops_and_heights(c), [(2, 0., 80.)]);
+    }
+}
+
+// root(page: 100) > [a(h:50), b(h:30, break-after: avoid), c(h:40, break-inside: avoid)]
+// - c should be pushed, and b is moved together with it
+#[test]
+fn break_after_avoid() {
+    unsafe {
+        let a = new_box(50.);
+        let b = new_box(30.);
+        b.set_break_after(Break::Avoid);
+        let c = new_box(40.);
+        c.set_break_inside(BreakInside::Avoid);
+        let root = new_root(&[a, b, c]);
+        layout(root, 100.);
+
+        assert_eq!(b.layout_position().top, 100.);
+        assert_eq!(c.layout_position().top, 130.);
+    }
+}
+
+// root(page: 100) > container(w:100) > [x5 inline-block(60x30)]
+// - lines are at 0, 30, 60, 90, 120, and the fourth one crosses the page boundary
+// - the break is moved earlier to keep `widows` lines on the next page
+#[test]
+fn widows() {
+    unsafe {
+        let container = as_ref(Node::new_ptr());
+        let lines: Vec<_> = (0..5).map(|_| new_inline_block(60., 30.)).collect();
+        for line in lines.iter() {
+            container.append_child(convert_node_ref_to_ptr(line));
+        }
+        let root = new_root(&[container]);
+        layout(root, 100.);
+        let tops: Vec<_> = lines.iter().map(|x| x.layout_position().top).collect();
+        assert_eq!(tops, [0., 30., 60., 100., 130.]);
+        assert_eq!(container.layout_position().height, 160.);
+
+        container.set_widows(3);
+        layout(root, 100.);
+        let tops: Vec<_> = lines.iter().map(|x| x.layout_position().top).collect();
+        assert_eq!(tops, [0., 30., 100., 130., 160.]);
+        assert_eq!(container.layout_position().height, 190.);
+    }
+}
+
+// root(page: 100) > [a(h:20), container(w:100, orphans: 4) > [x5 inline-block(60x30)]]
+// - the third line crosses the page boundary, but only two lines could stay on the first page
+// - the whole paragraph is pushed to the next page, and breaks again before the fourth line
+#[test]
+fn orphans() {
+    unsafe {
+        let a = new_box(20.);
+        let container = as_ref(Node::new_ptr());
+        container.set_orphans(4);
+        let lines: Vec<_> = (0..5).map(|_| new_inline_block(60., 30.)).collect();
+        for line in lines.iter() {
+            container.append_child(convert_node_ref_to_ptr(line));
+        }
+        let root = new_root(&[a, container]);
+        layout(root, 100.);
+
+        assert_eq!(container.layout_position().top, 20.);
+        let tops: Vec<_> = lines.iter().map(|x| x.layout_position().top).collect();
+        assert_eq!(tops, [80., 110., 140., 180., 210.]);
+        assert_eq!(container.layout_position().height, 240.);
+    }
+}
+
+// an unfragmented layout after the fragmented one restores the continuous results
+#[test]
+fn unfragmented_layout_after_fragmentation() {
+    unsafe {
+        let a = new_box(30.);
+        let b = new_box(30.);
+        b.set_break_before(Break::Page);
+        let root = new_root(&[a, b]);
+        layout(root, 100.);
+        assert_eq!(b.layout_position().top, 100.);
+
+        root.layout(
+            OptionSize::new(OptionNum::some(Len::from_f32(375.)), OptionNum::none()),
+            Size::new(Len::from_f32(0.), Len::from_f32(0.)),
+        );
+        assert_eq!(b.layout_position().top, 30.);
+        assert_eq!(root.layout_position().height, 60.);
+        assert!(b.fragments().is_empty());
+        assert!(root.fragments().is_empty());
+    }
+}
+
+// root(page: 100) > container(w:100) > text(5 lines of 60x30)
+// - the lines inside the text are broken like the line boxes, so the fourth one is pushed to the next page
+// - the break is moved earlier to keep `widows` lines on the next page
+#[test]
+fn widows_inside_text() {
+    unsafe {
+        let container = as_ref(Node::new_ptr());
+        let text = new_text_lines(5);
+        container.append_child(convert_node_ref_to_ptr(text));
+        let root = new_root(&[container]);
+        layout(root, 100.);
+        let tops: Vec<_> = text
+            .text_fragments()
+            .iter()
+            .map(|x| x.position.top.to_f32())
+            .collect();
+        assert_eq!(tops, [0., 30., 60., 100., 130.]);
+        assert_eq!(text.layout_position().height, 160.);
+        assert_eq!(
+            fragment_tops_and_heights(text),
+            [(0, 0., 100.), (1, 0., 60.)]
+        );
+
+        container.set_widows(3);
+        layout(root, 100.);
+        let tops: Vec<_> = text
+            .text_fragments()
+            .iter()
+            .map(|x| x.position.top.to_f32())
+            .collect();
+        assert_eq!(tops, [0., 30., 100., 130., 160.]);
+        assert_eq!(container.layout_position().height, 190.);
+    }
+}
+
+// root(page: 100) > flex(column) > [a(h:80), b(h:40, overflow: hidden)]
+// - the single-line column flex container is broken between its items, so b is pushed to the second page
+// - a multi-line flex container is monolithic
+#[test]
+fn single_line_column_flex_container() {
+    unsafe {
+        let flex = as_ref(Node::new_ptr());
+        flex.set_display(Display::Flex);
+        flex.set_flex_direction(FlexDirection::Column);
+        let a = new_box(80.);
+        let b = new_box(40.);
+        b.set_overflow_y(Overflow::Hidden);
+        flex.append_child(convert_node_ref_to_ptr(a));
+        flex.append_child(convert_node_ref_to_ptr(b));
+        let root = new_root(&[flex]);
+        layout(root, 100.);
+
+        assert_eq!(b.layout_position().top, 100.);
+        assert_eq!(flex.layout_position().height, 140.);
+
+        flex.set_flex_wrap(FlexWrap::Wrap);
+        layout(root, 100.);
+        assert_eq!(b.layout_position().top, 80.);
+        assert_eq!(flex.layout_position().height, 120.);
+    }
+}
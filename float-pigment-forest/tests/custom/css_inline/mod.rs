@@ -1 +1,2 @@
 mod inline;
+mod text_fragment;
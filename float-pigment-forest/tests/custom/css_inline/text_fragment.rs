@@ -0,0 +1,192 @@
+// Tests for the texts broken across line boxes by the text fragment measure function
+// Focus: the fragments are measured in the remaining width of each line,
+// and `text-align: justify` expands the justification opportunities inside them.
+
+use crate::*;
+
+use float_pigment_css::typing::{Display, TextAlign, WhiteSpace};
+use float_pigment_forest::{
+    convert_node_ref_to_ptr, layout::MeasuredTextFragment, ChildOperation, Node, NodeType,
+    StyleSetter,
+};
+use float_pigment_layout::{DefLength, OptionNum, OptionSize, Size};
+
+unsafe fn as_ref<'a>(node: *mut Node) -> &'a Node {
+    &*node
+}
+
+const CHAR_WIDTH: f32 = 10.;
+const LINE_HEIGHT: f32 = 10.;
+
+// a text engine in which every char is 10x10 and the words are separated by single spaces
+fn measure_fragment(
+    text: &str,
+    start: usize,
+    available_width: Len,
+    allow_empty: bool,
+) -> MeasuredTextFragment {
+    let chars: Vec<char> = text.chars().collect();
+    let mut end = start;
+    let mut pos = start;
+    loop {
+        let word_end = chars[pos..]
+            .iter()
+            .position(|c| *c == ' ')
+            .map_or(chars.len(), |x| pos + x);
+        let width = Len::from_f32((word_end - start) as f32 * CHAR_WIDTH);
+        if width > available_width && (end > start || allow_empty) {
+            break;
+        }
+        end = word_end;
+        if end >= chars.len() {
+            break;
+        }
+        pos = end + 1;
+    }
+    let next = if end >= chars.len() {
+        None
+    } else if end == start {
+        Some(start)
+    } else {
+        // the space at the line break is collapsed
+        Some(end + 1)
+    };
+    MeasuredTextFragment {
+        width: Len::from_f32((end - start) as f32 * CHAR_WIDTH),
+        height: Len::from_f32(LINE_HEIGHT),
+        baseline: Len::from_f32(8.),
+        next,
+        justification_opportunities: chars[start..end].iter().filter(|c| **c == ' ').count() as u32,
+    }
+}
+
+unsafe fn new_text<'a>(text: &'static str) -> &'a Node {
+    let node = as_ref(Node::new_ptr());
+    node.set_display(Display::Inline);
+    node.set_node_type(NodeType::Text);
+    node.set_measure_func(Some(Box::new(move |_, _, _, _, _, _, _, _, _| {
+        Size::new(
+            Len::from_f32(text.chars().count() as f32 * CHAR_WIDTH),
+            Len::from_f32(LINE_HEIGHT),
+        )
+    })));
+    node.set_baseline_func(Some(Box::new(|_, _, _| Len::from_f32(8.))));
+    node.set_text_fragment_measure_func(Some(Box::new(
+        move |_, start, available_width, allow_empty| {
+            measure_fragment(text, start, available_width, allow_empty)
+        },
+    )));
+    node
+}
+
+unsafe fn new_container<'a>(width: f32) -> &'a Node {
+    let node = as_ref(Node::new_ptr());
+    node.set_width(DefLength::Points(Len::from_f32(width)));
+    node
+}
+
+unsafe fn layout(node: &Node) {
+    node.layout(
+        OptionSize::new(OptionNum::some(Len::from_f32(375.)), OptionNum::none()),
+        Size::new(Len::from_f32(0.), Len::from_f32(0.)),
+    );
+}
+
+// the fragments as (start, left, top, width) relative to the text node
+fn fragments(node: &Node) -> Vec<(usize, f32, f32, f32)> {
+    node.text_fragments()
+        .into_iter()
+        .map(|x| {
+            (
+                x.start,
+                x.position.left.to_f32(),
+                x.position.top.to_f32(),
+                x.position.width.to_f32(),
+            )
+        })
+        .collect()
+}
+
+// container(w:100) > ["x", "aaaa bbbb cccc"]
+// - the second text continues on the first line after "x", in the remaining 90px
+// - "cccc" is broken to the second line
+#[test]
+fn text_continues_on_the_same_line() {
+    unsafe {
+        let container = new_container(100.);
+        let a = new_text("x");
+        let b = new_text("aaaa bbbb cccc");
+        container.append_child(convert_node_ref_to_ptr(a));
+        container.append_child(convert_node_ref_to_ptr(b));
+        layout(container);
+
+        assert_eq!(container.layout_position().height, 20.);
+        assert_eq!(a.layout_position().left, 0.);
+        assert_eq!(b.layout_position().left, 0.);
+        assert_eq!(b.layout_position().top, 0.);
+        assert_eq!(b.layout_position().width, 100.);
+        assert_eq!(b.layout_position().height, 20.);
+        assert_eq!(fragments(b), [(0, 10., 0., 90.), (10, 0., 10., 40.)]);
+    }
+}
+
+// container(w:100) > [inline-block(95x10), "aaaa bb"]
+// - nothing of the text fits the remaining 5px, so the line is broken before it
+#[test]
+fn text_breaks_before_when_nothing_fits() {
+    unsafe {
+        let container = new_container(100.);
+        let a = as_ref(Node::new_ptr());
+        a.set_display(Display::InlineBlock);
+        a.set_width(DefLength::Points(Len::from_f32(95.)));
+        a.set_height(DefLength::Points(Len::from_f32(10.)));
+        let b = new_text("aaaa bb");
+        container.append_child(convert_node_ref_to_ptr(a));
+        container.append_child(convert_node_ref_to_ptr(b));
+        layout(container);
+
+        assert_eq!(container.layout_position().height, 20.);
+        assert_eq!(b.layout_position().left, 0.);
+        assert_eq!(b.layout_position().top, 10.);
+        assert_eq!(fragments(b), [(0, 0., 0., 70.)]);
+    }
+}
+
+// container(w:100, text-align: justify) > "aa bb cc dd ee ff"
+// - "aa bb cc" takes 80px of the first line, and its 2 word separators share the 20px free space
+// - the last line is not justified
+#[test]
+fn text_justified_at_word_separators() {
+    unsafe {
+        let container = new_container(100.);
+        container.set_text_align(TextAlign::Justify);
+        let text = new_text("aa bb cc dd ee ff");
+        container.append_child(convert_node_ref_to_ptr(text));
+        layout(container);
+
+        assert_eq!(container.layout_position().height, 20.);
+        assert_eq!(fragments(text), [(0, 0., 0., 100.), (9, 0., 10., 80.)]);
+        let gaps: Vec<_> = text
+            .text_fragments()
+            .iter()
+            .map(|x| x.justify_gap.to_f32())
+            .collect();
+        assert_eq!(gaps, [10., 0.]);
+    }
+}
+
+// container(w:100, white-space: nowrap) > "aaaa bbbb cccc"
+// - the text is measured without the width limit, so it overflows in one line
+#[test]
+fn text_nowrap_in_one_fragment() {
+    unsafe {
+        let container = new_container(100.);
+        container.set_white_space(WhiteSpace::NoWrap);
+        let text = new_text("aaaa bbbb cccc");
+        container.append_child(convert_node_ref_to_ptr(text));
+        layout(container);
+
+        assert_eq!(container.layout_position().height, 10.);
+        assert_eq!(fragments(text), [(0, 0., 0., 140.)]);
+    }
+}
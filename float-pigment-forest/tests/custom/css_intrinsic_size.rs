@@ -0,0 +1,56 @@
+// Tests for the intrinsic sizing keywords (CSS Box Sizing Module Level 3)
+// Focus: the intrinsic sizes are updated when the content changes.
+
+use crate::*;
+
+use float_pigment_forest::{convert_node_ref_to_ptr, ChildOperation, Node, StyleSetter};
+use float_pigment_layout::DefLength;
+
+// container(width:max-content) > child(w:50 -> 120)
+#[test]
+fn max_content_updated() {
+    unsafe {
+        let container = as_ref(Node::new_ptr());
+        container.set_width(DefLength::MaxContent);
+        let child = as_ref(Node::new_ptr());
+        child.set_width(DefLength::Points(Len::from_f32(50.)));
+        child.set_height(DefLength::Points(Len::from_f32(10.)));
+        container.append_child(convert_node_ref_to_ptr(child));
+        layout(container);
+        assert_eq!(container.layout_position().width, 50.);
+
+        child.set_width(DefLength::Points(Len::from_f32(120.)));
+        layout(container);
+        assert_eq!(container.layout_position().width, 120.);
+    }
+}
+
+// root > container(width:fit-content(100px)) > [child(w:60), child(w:60 -> 150)]
+#[test]
+fn fit_content_updated() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let container = as_ref(Node::new_ptr());
+        container.set_width(DefLength::FitContentPoints(Len::from_f32(100.)));
+        root.append_child(convert_node_ref_to_ptr(container));
+        let children: Vec<_> = (0..2)
+            .map(|_| {
+                let child = as_ref(Node::new_ptr());
+                child.set_width(DefLength::Points(Len::from_f32(60.)));
+                child.set_height(DefLength::Points(Len::from_f32(10.)));
+                container.append_child(convert_node_ref_to_ptr(child));
+                child
+            })
+            .collect();
+        layout(root);
+        assert_eq!(container.layout_position().width, 60.);
+
+        children[1].set_width(DefLength::Points(Len::from_f32(150.)));
+        layout(root);
+        assert_eq!(container.layout_position().width, 150.);
+
+        children[1].set_width(DefLength::Points(Len::from_f32(90.)));
+        layout(root);
+        assert_eq!(container.layout_position().width, 90.);
+    }
+}
@@ -0,0 +1,108 @@
+// Tests for the line clamp (CSS Overflow 4 §4.1)
+// Focus: the line box which carries the ellipsis of a clamped block container.
+
+use crate::*;
+
+use float_pigment_css::typing::Display;
+use float_pigment_forest::{
+    convert_node_ref_to_ptr, layout::MeasuredTextLines, ChildOperation, Node, NodeType, StyleSetter,
+};
+use float_pigment_layout::{DefLength, Size};
+
+// container(w:100, line-clamp: 2) > [a, b, c, d](60x10)
+// - each inline-block takes a line, and c, d are hidden
+// - b ends the last shown line and carries the ellipsis
+#[test]
+fn line_clamp_ellipsis_on_last_shown_line() {
+    unsafe {
+        let container = as_ref(Node::new_ptr());
+        container.set_width(DefLength::Points(Len::from_f32(100.)));
+        container.set_line_clamp(Some(2));
+        let children: Vec<_> = (0..4).map(|_| new_inline_block(60., 10.)).collect();
+        for child in children.iter() {
+            container.append_child(convert_node_ref_to_ptr(child));
+        }
+        layout(container);
+
+        assert_eq!(container.layout_position().height, 20.);
+        assert_eq!(children[3].layout_position().top, 30.);
+        let ellipsis: Vec<_> = children
+            .iter()
+            .map(|x| x.has_line_clamp_ellipsis())
+            .collect();
+        assert_eq!(ellipsis, [false, true, false, false]);
+
+        // no ellipsis if all lines are shown
+        container.set_line_clamp(Some(4));
+        layout(container);
+        assert_eq!(container.layout_position().height, 40.);
+        assert!(children.iter().all(|x| !x.has_line_clamp_ellipsis()));
+    }
+}
+
+// container(w:100, line-clamp: 1) > [block > a(60x10), block > b(60x10)]
+// - the clamp is reached at the end of the first block, and the second block is hidden
+// - a carries the ellipsis since some content follows it
+#[test]
+fn line_clamp_ellipsis_before_following_block() {
+    unsafe {
+        let container = as_ref(Node::new_ptr());
+        container.set_width(DefLength::Points(Len::from_f32(100.)));
+        container.set_line_clamp(Some(1));
+        let first = as_ref(Node::new_ptr());
+        let a = new_inline_block(60., 10.);
+        first.append_child(convert_node_ref_to_ptr(a));
+        let second = as_ref(Node::new_ptr());
+        let b = new_inline_block(60., 10.);
+        second.append_child(convert_node_ref_to_ptr(b));
+        container.append_child(convert_node_ref_to_ptr(first));
+        container.append_child(convert_node_ref_to_ptr(second));
+        layout(container);
+
+        assert_eq!(container.layout_position().height, 10.);
+        assert_eq!(second.layout_position().top, 10.);
+        assert!(a.has_line_clamp_ellipsis());
+        assert!(!b.has_line_clamp_ellipsis());
+    }
+}
+
+// container(w:100, line-clamp: 2) > text(5 lines of 100x10)
+// - the text is measured in the remaining 2 lines, and its lines are counted by the clamp
+// - the text is cut by the clamp, so it carries the ellipsis
+#[test]
+fn line_clamp_inside_multi_line_text() {
+    unsafe {
+        let container = as_ref(Node::new_ptr());
+        container.set_width(DefLength::Points(Len::from_f32(100.)));
+        container.set_line_clamp(Some(2));
+        let text = as_ref(Node::new_ptr());
+        text.set_display(Display::Inline);
+        text.set_node_type(NodeType::Text);
+        text.set_measure_func(Some(Box::new(|_, _, _, _, _, _, _, _, _| {
+            Size::new(Len::from_f32(100.), Len::from_f32(50.))
+        })));
+        text.set_baseline_func(Some(Box::new(|_, _, _| Len::from_f32(8.))));
+        text.set_line_clamp_measure_func(Some(Box::new(|_, max_width, max_lines| {
+            let shown_lines = max_lines.min(5) as f32;
+            MeasuredTextLines {
+                width: max_width,
+                height: Len::from_f32(shown_lines * 10.),
+                last_baseline: Len::from_f32(shown_lines * 10. - 2.),
+                line_count: 5,
+            }
+        })));
+        container.append_child(convert_node_ref_to_ptr(text));
+        layout(container);
+
+        assert_eq!(container.layout_position().height, 20.);
+        assert_eq!(text.layout_position().height, 20.);
+        assert!(text.has_line_clamp_ellipsis());
+
+        // no ellipsis if all lines are shown
+        container.set_line_clamp(Some(6));
+        layout(container);
+        assert_eq!(container.layout_position().height, 50.);
+        assert_eq!(text.layout_position().height, 50.);
+        assert!(!text.has_line_clamp_ellipsis());
+    }
+}
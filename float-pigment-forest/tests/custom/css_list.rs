@@ -0,0 +1,190 @@
+// Tests for list items (CSS Lists and Counters Module Level 3)
+// Focus: the marker boxes measured by the host and the ordinals of the list items.
+
+use crate::*;
+
+use float_pigment_css::typing::{Display, ListStylePosition};
+use float_pigment_forest::{
+    convert_node_ref_to_ptr, layout::LayoutPosition, ChildOperation, Node, StyleSetter,
+};
+use float_pigment_layout::{DefLength, Size};
+
+// The marker is 10px wide per ordinal and 16px high, so the ordinal can be read from the marker width.
+unsafe fn new_list_item<'a>() -> &'a Node {
+    let node = as_ref(Node::new_ptr());
+    node.set_display(Display::ListItem);
+    node.set_marker_measure_func(Some(Box::new(|_, ordinal| {
+        Size::new(Len::from_f32(ordinal as f32 * 10.), Len::from_f32(16.))
+    })));
+    node
+}
+
+unsafe fn new_list<'a>(count: usize) -> (&'a Node, Vec<&'a Node>) {
+    let list = as_ref(Node::new_ptr());
+    let items: Vec<_> = (0..count).map(|_| new_list_item()).collect();
+    for item in items.iter() {
+        list.append_child(convert_node_ref_to_ptr(item));
+    }
+    (list, items)
+}
+
+fn rect(left: f32, top: f32, width: f32, height: f32) -> LayoutPosition {
+    LayoutPosition {
+        left: Len::from_f32(left),
+        top: Len::from_f32(top),
+        width: Len::from_f32(width),
+        height: Len::from_f32(height),
+    }
+}
+
+unsafe fn marker_width(node: &Node) -> f32 {
+    node.marker_rect().unwrap().width.to_f32()
+}
+
+// list(padding-left:40) > item(h:30)
+// - the outside marker is placed before the content box of the item (in the padding of the list),
+//   at the top since there is no baseline
+// - the marker does not affect the layout
+#[test]
+fn outside_marker() {
+    unsafe {
+        let (list, items) = new_list(1);
+        list.set_padding_left(DefLength::Points(Len::from_f32(40.)));
+        items[0].set_height(DefLength::Points(Len::from_f32(30.)));
+        layout(list);
+
+        assert_eq!(items[0].layout_position().left, 40.);
+        assert_eq!(items[0].layout_position().width, 335.);
+        assert_eq!(items[0].marker_rect(), Some(rect(-10., 0., 10., 16.)));
+    }
+}
+
+// item(list-style-position:inside) > inline-block(30x30)
+// - the inside marker takes the start of the first line
+// - the marker baseline (its bottom) is aligned with the baseline of the inline-block (its bottom)
+#[test]
+fn inside_marker() {
+    unsafe {
+        let (list, items) = new_list(1);
+        items[0].set_list_style_position(ListStylePosition::Inside);
+        let child = as_ref(Node::new_ptr());
+        child.set_display(Display::InlineBlock);
+        child.set_width(DefLength::Points(Len::from_f32(30.)));
+        child.set_height(DefLength::Points(Len::from_f32(30.)));
+        items[0].append_child(convert_node_ref_to_ptr(child));
+        layout(list);
+
+        assert_eq!(child.layout_position().left, 10.);
+        assert_eq!(items[0].layout_position().height, 30.);
+        assert_eq!(items[0].marker_rect(), Some(rect(0., 14., 10., 16.)));
+    }
+}
+
+// item(list-style-position:inside) without content
+// - the list item is at least as high as the marker
+#[test]
+fn inside_marker_height() {
+    unsafe {
+        let (list, items) = new_list(1);
+        items[0].set_list_style_position(ListStylePosition::Inside);
+        layout(list);
+
+        assert_eq!(items[0].layout_position().height, 16.);
+        assert_eq!(items[0].marker_rect(), Some(rect(0., 0., 10., 16.)));
+    }
+}
+
+// list(start:5) > [item, item(value:10), item]
+#[test]
+fn ordinals() {
+    unsafe {
+        let (list, items) = new_list(3);
+        list.set_list_start(Some(5));
+        items[1].set_list_item_value(Some(10));
+        layout(list);
+
+        assert_eq!(marker_width(items[0]), 50.);
+        assert_eq!(marker_width(items[1]), 100.);
+        assert_eq!(marker_width(items[2]), 110.);
+    }
+}
+
+// list(reversed) > [item, block, item, item]
+// - the list counts down from the number of the list items
+// - the other children are not counted
+#[test]
+fn reversed_ordinals() {
+    unsafe {
+        let (list, items) = new_list(3);
+        list.set_list_reversed(true);
+        let block = as_ref(Node::new_ptr());
+        list.insert_child_at(convert_node_ref_to_ptr(block), 1);
+        layout(list);
+
+        assert_eq!(marker_width(items[0]), 30.);
+        assert_eq!(marker_width(items[1]), 20.);
+        assert_eq!(marker_width(items[2]), 10.);
+        assert_eq!(block.marker_rect(), None);
+    }
+}
+
+// list(reversed) > [item × 1000]
+// - the ordinals are prepared once by the layout pass of the list
+#[test]
+fn ordinals_of_long_list() {
+    unsafe {
+        let (list, items) = new_list(1000);
+        list.set_list_reversed(true);
+        layout(list);
+
+        assert_eq!(marker_width(items[0]), 10000.);
+        assert_eq!(marker_width(items[500]), 5000.);
+        assert_eq!(marker_width(items[999]), 10.);
+    }
+}
+
+// item(value:3)
+// - the ordinal of a list item which is laid out as the root comes from its own value
+#[test]
+fn ordinal_of_root_item() {
+    unsafe {
+        let item = new_list_item();
+        item.set_list_item_value(Some(3));
+        layout(item);
+
+        assert_eq!(marker_width(item), 30.);
+    }
+}
+
+// The ordinals of the following list items are updated when a list item is inserted.
+#[test]
+fn ordinals_after_insertion() {
+    unsafe {
+        let (list, items) = new_list(2);
+        layout(list);
+        assert_eq!(marker_width(items[1]), 20.);
+
+        let item = new_list_item();
+        list.insert_child_at(convert_node_ref_to_ptr(item), 0);
+        layout(list);
+        assert_eq!(marker_width(item), 10.);
+        assert_eq!(marker_width(items[0]), 20.);
+        assert_eq!(marker_width(items[1]), 30.);
+    }
+}
+
+// The marker box is removed when the node is no longer a list item or the marker measure function is cleared.
+#[test]
+fn marker_cleared() {
+    unsafe {
+        let (list, items) = new_list(2);
+        layout(list);
+        assert!(items[0].marker_rect().is_some());
+
+        items[0].set_display(Display::Block);
+        items[1].set_marker_measure_func(None);
+        layout(list);
+        assert_eq!(items[0].marker_rect(), None);
+        assert_eq!(items[1].marker_rect(), None);
+    }
+}
@@ -273,10 +273,6 @@ use crate::*;
 use float_pigment_forest::{convert_node_ref_to_ptr, ChildOperation, Node, StyleSetter};
 use float_pigment_layout::{DefLength, LayoutTreeNode, OptionNum, OptionSize, Size};
 
-unsafe fn as_ref<'a>(node: *mut Node) -> &'a Node {
-    &*node
-}
-
 // Case: Margin on root element
 // Spec: CSS 2.1 §8.3.1 — root element margins do not collapse.
 // container is the layout entry (root role) and does not collapse with root.
@@ -7,10 +7,6 @@ use crate::*;
 use float_pigment_forest::{convert_node_ref_to_ptr, ChildOperation, Node, StyleSetter};
 use float_pigment_layout::{DefLength, OptionNum, OptionSize, Size};
 
-unsafe fn as_ref<'a>(node: *mut Node) -> &'a Node {
-    &*node
-}
-
 // CSS 2.1 §8.3.1: "Margins of the root element's box do not collapse."
 // The layout-entry node (no parent) plays the root-element role. Its margin
 // stays independent — the child's margin does not merge into it.
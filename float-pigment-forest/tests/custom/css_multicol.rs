@@ -0,0 +1,65 @@
+// Tests for multi-column layout (CSS Multi-column Layout)
+// Focus: the column boxes exposed to the host for painting column rules.
+
+use crate::*;
+
+use float_pigment_css::typing::ColumnSpan;
+use float_pigment_forest::{
+    convert_node_ref_to_ptr, layout::LayoutPosition, ChildOperation, Node, StyleSetter,
+};
+use float_pigment_layout::DefLength;
+
+fn rect(left: f32, top: f32, width: f32, height: f32) -> LayoutPosition {
+    LayoutPosition {
+        left: Len::from_f32(left),
+        top: Len::from_f32(top),
+        width: Len::from_f32(width),
+        height: Len::from_f32(height),
+    }
+}
+
+// container(w:220 content box, padding:10, column-count:2, column-gap:20) > [a(h:40), b(h:40), span(h:20, column-span:all), c(h:30)]
+// - the first column set has 2 columns of 40px
+// - the last column set only uses the first column
+#[test]
+fn column_rects() {
+    unsafe {
+        let container = as_ref(Node::new_ptr());
+        container.set_width(DefLength::Points(Len::from_f32(220.)));
+        container.set_padding(DefLength::Points(Len::from_f32(10.)));
+        container.set_column_count(Some(2));
+        container.set_column_gap(DefLength::Points(Len::from_f32(20.)));
+        let span = new_box(20.);
+        span.set_column_span(ColumnSpan::All);
+        for child in [new_box(40.), new_box(40.), span, new_box(30.)] {
+            container.append_child(convert_node_ref_to_ptr(child));
+        }
+        layout(container);
+
+        assert_eq!(container.layout_position().height, 110.);
+        assert_eq!(
+            container.column_rects(),
+            vec![
+                rect(10., 10., 100., 40.),
+                rect(130., 10., 100., 40.),
+                rect(10., 70., 100., 30.),
+            ]
+        );
+    }
+}
+
+// Column boxes are cleared when the node is no longer a multi-column container.
+#[test]
+fn column_rects_cleared() {
+    unsafe {
+        let container = as_ref(Node::new_ptr());
+        container.set_column_count(Some(2));
+        container.append_child(convert_node_ref_to_ptr(new_box(40.)));
+        layout(container);
+        assert_eq!(container.column_rects().len(), 1);
+
+        container.set_column_count(None);
+        layout(container);
+        assert!(container.column_rects().is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+// Tests for the scrollable overflow rect (CSS Overflow §2.2)
+// Focus: the union of the padding box and descendant border boxes,
+// clipped by the descendants with non-visible overflow.
+
+use crate::*;
+
+use float_pigment_css::typing::Overflow;
+use float_pigment_forest::{convert_node_ref_to_ptr, ChildOperation, Node, StyleSetter};
+use float_pigment_layout::{DefLength, OptionNum, OptionSize, Size};
+
+// No overflowing content: the scrollable overflow is the padding box.
+#[test]
+fn scrollable_overflow_without_overflowing_content() {
+    unsafe {
+        let container = new_sized_box(100., 100.);
+        container.set_border_left(DefLength::Points(Len::from_f32(5.)));
+        container.set_border_top(DefLength::Points(Len::from_f32(5.)));
+        container.set_overflow_y(Overflow::Scroll);
+        let child = new_sized_box(50., 50.);
+        container.append_child(convert_node_ref_to_ptr(child));
+
+        container.layout(
+            OptionSize::new(OptionNum::some(Len::from_f32(375.)), OptionNum::none()),
+            Size::new(Len::from_f32(0.), Len::from_f32(0.)),
+        );
+
+        let overflow = container.scrollable_overflow();
+        assert_eq!(overflow.left, 5.);
+        assert_eq!(overflow.top, 5.);
+        assert_eq!(overflow.width, 100.);
+        assert_eq!(overflow.height, 100.);
+    }
+}
+
+// container(100x100, overflow-y: scroll) > [a(h:80), b(w:150, h:60)]
+// - scrollWidth = 150 (b overflows horizontally)
+// - scrollHeight = 80 + 60 = 140
+#[test]
+fn scrollable_overflow_includes_descendants() {
+    unsafe {
+        let container = new_sized_box(100., 100.);
+        container.set_overflow_y(Overflow::Scroll);
+        let a = as_ref(Node::new_ptr());
+        a.set_height(DefLength::Points(Len::from_f32(80.)));
+        let b = new_sized_box(150., 60.);
+        let grandchild = new_sized_box(20., 90.);
+        b.append_child(convert_node_ref_to_ptr(grandchild));
+        container.append_child(convert_node_ref_to_ptr(a));
+        container.append_child(convert_node_ref_to_ptr(b));
+
+        container.layout(
+            OptionSize::new(OptionNum::some(Len::from_f32(375.)), OptionNum::none()),
+            Size::new(Len::from_f32(0.), Len::from_f32(0.)),
+        );
+
+        let overflow = container.scrollable_overflow();
+        assert_eq!(overflow.left, 0.);
+        assert_eq!(overflow.top, 0.);
+        assert_eq!(overflow.width, 150.);
+        // the grandchild overflows b (not clipped): 80 + 90
+        assert_eq!(overflow.height, 170.);
+    }
+}
+
+// The descendants of a box with non-visible overflow are clipped by its padding box.
+#[test]
+fn scrollable_overflow_clipped_by_descendant() {
+    unsafe {
+        let container = new_sized_box(100., 100.);
+        container.set_overflow_y(Overflow::Scroll);
+        let clip = new_sized_box(50., 50.);
+        clip.set_overflow_x(Overflow::Hidden);
+        clip.set_overflow_y(Overflow::Hidden);
+        let grandchild = new_sized_box(300., 300.);
+        clip.append_child(convert_node_ref_to_ptr(grandchild));
+        container.append_child(convert_node_ref_to_ptr(clip));
+
+        container.layout(
+            OptionSize::new(OptionNum::some(Len::from_f32(375.)), OptionNum::none()),
+            Size::new(Len::from_f32(0.), Len::from_f32(0.)),
+        );
+
+        let overflow = container.scrollable_overflow();
+        assert_eq!(overflow.width, 100.);
+        assert_eq!(overflow.height, 100.);
+
+        // the clipping box itself still scrolls its own content
+        let overflow = clip.scrollable_overflow();
+        assert_eq!(overflow.width, 300.);
+        assert_eq!(overflow.height, 300.);
+    }
+}
+
+// Clipping in one axis only.
+#[test]
+fn scrollable_overflow_clipped_in_one_axis() {
+    unsafe {
+        let container = new_sized_box(100., 100.);
+        let clip = new_sized_box(50., 50.);
+        clip.set_overflow_x(Overflow::Hidden);
+        let grandchild = new_sized_box(300., 300.);
+        clip.append_child(convert_node_ref_to_ptr(grandchild));
+        container.append_child(convert_node_ref_to_ptr(clip));
+
+        container.layout(
+            OptionSize::new(OptionNum::some(Len::from_f32(375.)), OptionNum::none()),
+            Size::new(Len::from_f32(0.), Len::from_f32(0.)),
+        );
+
+        let overflow = container.scrollable_overflow();
+        assert_eq!(overflow.width, 100.);
+        assert_eq!(overflow.height, 300.);
+    }
+}
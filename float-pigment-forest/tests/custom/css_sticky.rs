@@ -0,0 +1,84 @@
+// Tests for sticky positioning (CSS Positioned Layout §3.4)
+// Focus: sticky boxes are laid out in flow, and the sticky offset
+// is computed from the scroll offset of the nearest scroll container.
+
+use crate::*;
+
+use float_pigment_css::typing::{Overflow, Position};
+use float_pigment_forest::{convert_node_ref_to_ptr, ChildOperation, Node, StyleSetter};
+use float_pigment_layout::DefLength;
+
+// list(h:100, overflow-y: scroll) > [section(h:200) > [header(h:20, sticky, top:0), item(h:180)], section(h:200)]
+// - the header is in flow: the item is placed below it
+// - the header sticks to the top of the scrollport until the end of its section
+#[test]
+fn sticky_list_header() {
+    unsafe {
+        let list = new_box(100.);
+        list.set_overflow_y(Overflow::Scroll);
+        let section = as_ref(Node::new_ptr());
+        let header = new_box(20.);
+        header.set_position(Position::Sticky);
+        header.set_top(DefLength::Points(Len::from_f32(0.)));
+        let item = new_box(180.);
+        section.append_child(convert_node_ref_to_ptr(header));
+        section.append_child(convert_node_ref_to_ptr(item));
+        let next_section = new_box(200.);
+        list.append_child(convert_node_ref_to_ptr(section));
+        list.append_child(convert_node_ref_to_ptr(next_section));
+        layout(list);
+
+        assert_eq!(header.layout_position().top, 0.);
+        assert_eq!(item.layout_position().top, 20.);
+        assert_eq!(list.scrollable_overflow().height, 400.);
+
+        let offset = |scroll_top: f32| {
+            let (left, top) = header.sticky_offset(Len::from_f32(0.), Len::from_f32(scroll_top));
+            (left.to_f32(), top.to_f32())
+        };
+        assert_eq!(offset(0.), (0., 0.));
+        assert_eq!(offset(50.), (0., 50.));
+        assert_eq!(offset(180.), (0., 180.));
+        // pushed out by the end of the section
+        assert_eq!(offset(250.), (0., 180.));
+    }
+}
+
+// The insets of a sticky box do not affect the layout position.
+#[test]
+fn sticky_insets_not_applied_in_layout() {
+    unsafe {
+        let root = new_box(300.);
+        let sticky = new_box(20.);
+        sticky.set_width(DefLength::Points(Len::from_f32(100.)));
+        sticky.set_position(Position::Sticky);
+        sticky.set_top(DefLength::Points(Len::from_f32(10.)));
+        sticky.set_left(DefLength::Points(Len::from_f32(10.)));
+        root.append_child(convert_node_ref_to_ptr(sticky));
+        layout(root);
+
+        assert_eq!(sticky.layout_position().left, 0.);
+        assert_eq!(sticky.layout_position().top, 0.);
+        let (left, top) = sticky.sticky_offset(Len::from_f32(0.), Len::from_f32(0.));
+        assert_eq!(left.to_f32(), 10.);
+        assert_eq!(top.to_f32(), 10.);
+    }
+}
+
+// Non-sticky nodes have no sticky offset.
+#[test]
+fn non_sticky_offset_is_zero() {
+    unsafe {
+        let root = new_box(300.);
+        let child = new_box(20.);
+        child.set_position(Position::Relative);
+        child.set_top(DefLength::Points(Len::from_f32(10.)));
+        root.append_child(convert_node_ref_to_ptr(child));
+        layout(root);
+
+        assert_eq!(child.layout_position().top, 10.);
+        let (left, top) = child.sticky_offset(Len::from_f32(0.), Len::from_f32(50.));
+        assert_eq!(left.to_f32(), 0.);
+        assert_eq!(top.to_f32(), 0.);
+    }
+}
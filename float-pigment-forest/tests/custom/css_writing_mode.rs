@@ -0,0 +1,84 @@
+// Tests for the orthogonal flows (CSS Writing Modes §7.3)
+// Focus: the available inline space of an orthogonal flow in a parent with an auto block size,
+// or in a flex container.
+
+use crate::*;
+
+use float_pigment_css::typing::{Display, WritingMode};
+use float_pigment_forest::{convert_node_ref_to_ptr, ChildOperation, Node, StyleSetter};
+use float_pigment_layout::{DefLength, Size};
+
+// A vertical text of 1000px, which is broken into 20px wide columns at the max-content height.
+unsafe fn new_vertical_text<'a>() -> &'a Node {
+    let text = as_ref(Node::new_ptr());
+    text.set_measure_func(Some(Box::new(
+        |_, _, _, _, _, _, _, _, max_content_height| {
+            let height = max_content_height.min(Len::from_f32(1000.));
+            let columns = (1000. / height.to_f32()).ceil();
+            Size::new(Len::from_f32(columns * 20.), height)
+        },
+    )));
+    text
+}
+
+// parent(w:300, h:auto) > child(vertical-rl) > text
+// - the available inline space of child falls back to the viewport height
+#[test]
+fn orthogonal_flow_in_auto_height_parent() {
+    unsafe {
+        let parent = as_ref(Node::new_ptr());
+        parent.set_width(DefLength::Points(Len::from_f32(300.)));
+        let child = as_ref(Node::new_ptr());
+        child.set_writing_mode(WritingMode::VerticalRl);
+        parent.append_child(convert_node_ref_to_ptr(child));
+        let text = new_vertical_text();
+        child.append_child(convert_node_ref_to_ptr(text));
+        layout(parent);
+
+        assert_eq!(text.layout_position().height, 750.);
+        assert_eq!(child.layout_position().width, 40.);
+        assert_eq!(child.layout_position().height, 750.);
+        assert_eq!(parent.layout_position().height, 750.);
+    }
+}
+
+// parent(w:300, h:400) > child(vertical-rl) > text
+// - the available inline space of child is the definite height of parent
+#[test]
+fn orthogonal_flow_in_fixed_height_parent() {
+    unsafe {
+        let parent = as_ref(Node::new_ptr());
+        parent.set_width(DefLength::Points(Len::from_f32(300.)));
+        parent.set_height(DefLength::Points(Len::from_f32(400.)));
+        let child = as_ref(Node::new_ptr());
+        child.set_writing_mode(WritingMode::VerticalRl);
+        parent.append_child(convert_node_ref_to_ptr(child));
+        let text = new_vertical_text();
+        child.append_child(convert_node_ref_to_ptr(text));
+        layout(parent);
+
+        assert_eq!(text.layout_position().height, 400.);
+        assert_eq!(child.layout_position().width, 60.);
+    }
+}
+
+// parent(flex, w:300, h:auto) > child(vertical-rl) > text
+// - the available inline space of the flex item falls back to the viewport height
+#[test]
+fn orthogonal_flow_in_flex_container() {
+    unsafe {
+        let parent = as_ref(Node::new_ptr());
+        parent.set_display(Display::Flex);
+        parent.set_width(DefLength::Points(Len::from_f32(300.)));
+        let child = as_ref(Node::new_ptr());
+        child.set_writing_mode(WritingMode::VerticalRl);
+        parent.append_child(convert_node_ref_to_ptr(child));
+        let text = new_vertical_text();
+        child.append_child(convert_node_ref_to_ptr(text));
+        layout(parent);
+
+        assert_eq!(text.layout_position().height, 750.);
+        assert_eq!(child.layout_position().width, 40.);
+        assert_eq!(parent.layout_position().height, 750.);
+    }
+}
@@ -0,0 +1,155 @@
+// Tests for the transform-aware geometry queries
+// Focus: percentages against the border box, transform-origin, the ancestor chain and perspective.
+
+use crate::*;
+
+use float_pigment_css::property::NodeProperties;
+use float_pigment_forest::{LayoutTransformItem, Node, StyleSetter};
+use float_pigment_layout::{DefLength, Point};
+
+fn assert_points(node: &Node, expected: [(f32, f32); 4]) {
+    let quad = node.transformed_quad().unwrap();
+    for (point, (x, y)) in quad.points.iter().zip(expected) {
+        assert!(
+            (point.x - x).abs() < 1e-3 && (point.y - y).abs() < 1e-3,
+            "{:?} != {:?}",
+            quad.points,
+            expected,
+        );
+    }
+}
+
+fn px(x: f32) -> DefLength<Len> {
+    DefLength::Points(Len::from_f32(x))
+}
+
+// root > [header(h:50), box(w:100, h:40, translate(50%, 10px))]
+#[test]
+fn geometry_translate_percentage() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        new_child(root, 375., 50.);
+        let item = new_child(root, 100., 40.);
+        item.set_transform(vec![LayoutTransformItem::Translate(
+            DefLength::Percent(0.5),
+            px(10.),
+            Len::zero(),
+        )]);
+        layout(root);
+
+        // transforms do not affect layout
+        assert_eq!(item.layout_position().left, 0.);
+        assert_eq!(item.layout_position().top, 50.);
+        assert_points(item, [(50., 60.), (150., 60.), (150., 100.), (50., 100.)]);
+        let bounds = item.transformed_bounds().unwrap();
+        assert_eq!(bounds.origin, Point::new(50., 60.));
+        assert_eq!(bounds.size.width, 100.);
+        assert_eq!(bounds.size.height, 40.);
+    }
+}
+
+// root > box(w:100, h:40, rotate(90deg) with different origins)
+#[test]
+fn geometry_rotate_origin() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let item = new_child(root, 100., 40.);
+        item.set_transform(vec![LayoutTransformItem::Rotate(
+            0.,
+            0.,
+            1.,
+            90f32.to_radians(),
+        )]);
+        layout(root);
+
+        // around the center (50, 20)
+        let bounds = item.transformed_bounds().unwrap();
+        assert!((bounds.origin.x - 30.).abs() < 1e-3);
+        assert!((bounds.origin.y + 30.).abs() < 1e-3);
+        assert!((bounds.size.width - 40.).abs() < 1e-3);
+        assert!((bounds.size.height - 100.).abs() < 1e-3);
+
+        // around the top-left corner, clockwise
+        item.set_transform_origin((px(0.), px(0.), Len::zero()));
+        assert_points(item, [(0., 0.), (0., 100.), (-40., 100.), (-40., 0.)]);
+    }
+}
+
+// root > outer(w:200, h:200, margin-left:10, scale(2) at top-left) > inner(w:10, h:10, margin-left:5, translate(5px, 0))
+#[test]
+fn geometry_ancestor_chain() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let outer = new_child(root, 200., 200.);
+        outer.set_margin_left(px(10.));
+        outer.set_transform(vec![LayoutTransformItem::Scale(2., 2., 1.)]);
+        outer.set_transform_origin((px(0.), px(0.), Len::zero()));
+        let inner = new_child(outer, 10., 10.);
+        inner.set_margin_left(px(5.));
+        inner.set_transform(vec![LayoutTransformItem::Translate(
+            px(5.),
+            px(0.),
+            Len::zero(),
+        )]);
+        layout(root);
+
+        assert_points(inner, [(30., 0.), (50., 0.), (50., 20.), (30., 20.)]);
+
+        // the functions are applied from right to left: the translation is scaled too
+        inner.set_transform(vec![
+            LayoutTransformItem::Scale(2., 2., 1.),
+            LayoutTransformItem::Translate(px(5.), px(0.), Len::zero()),
+        ]);
+        inner.set_transform_origin((px(0.), px(0.), Len::zero()));
+        assert_points(inner, [(40., 0.), (80., 0.), (80., 40.), (40., 40.)]);
+    }
+}
+
+// root > box(w:100, h:100, perspective(100px) translateZ(50px))
+#[test]
+fn geometry_perspective() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let item = new_child(root, 100., 100.);
+        item.set_transform(vec![
+            LayoutTransformItem::Perspective(Len::from_f32(100.)),
+            LayoutTransformItem::Translate(px(0.), px(0.), Len::from_f32(50.)),
+        ]);
+        layout(root);
+
+        // doubled around the center
+        assert_points(
+            item,
+            [(-50., -50.), (150., -50.), (150., 150.), (-50., 150.)],
+        );
+
+        // behind the viewer
+        item.set_transform(vec![
+            LayoutTransformItem::Perspective(Len::from_f32(100.)),
+            LayoutTransformItem::Translate(px(0.), px(0.), Len::from_f32(150.)),
+        ]);
+        assert!(item.transformed_quad().is_none());
+        assert!(item.transformed_bounds().is_none());
+    }
+}
+
+// root > [header(h:50), box(w:100, h:40, style="transform: translate(50%, 10px) rotate(0.5turn)")]
+#[test]
+fn geometry_inline_style_transform() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        new_child(root, 375., 50.);
+        let item = new_child(root, 100., 40.);
+        let mut node_props = NodeProperties::new(None);
+        TestCtx::apply_inline_style(
+            item,
+            "transform: translate(50%, 10px) rotate(0.5turn)",
+            &mut node_props,
+            None,
+        );
+        layout(root);
+
+        // the half turn around the center swaps the corners
+        assert_points(item, [(150., 100.), (50., 100.), (50., 60.), (150., 60.)]);
+    }
+}
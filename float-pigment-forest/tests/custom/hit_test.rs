@@ -0,0 +1,145 @@
+// Tests for the hit testing over the layout results
+// Focus: the deepest node path, display: none, pointer-events, z-order, scroll offsets and clipping.
+
+use crate::*;
+
+use float_pigment_css::typing::{Display, Overflow, PointerEvents, Position};
+use float_pigment_forest::{Node, StyleSetter};
+use float_pigment_layout::{DefLength, Point, Vector};
+
+fn hit(root: &Node, x: f32, y: f32) -> Vec<*const Node> {
+    root.hit_test(Point::new(Len::from_f32(x), Len::from_f32(y)))
+        .into_iter()
+        .map(|node| node as *const Node)
+        .collect()
+}
+
+fn path(nodes: &[&Node]) -> Vec<*const Node> {
+    nodes.iter().map(|node| *node as *const Node).collect()
+}
+
+// root(h:200) > [a(h:50) > b(w:10, h:10), hidden(display:none)]
+#[test]
+fn hit_test_deepest_path() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        root.set_height(DefLength::Points(Len::from_f32(200.)));
+        let a = new_child(root, 375., 50.);
+        let b = new_child(a, 10., 10.);
+        let hidden = new_child(root, 375., 100.);
+        hidden.set_display(Display::None);
+        layout(root);
+
+        assert_eq!(hit(root, 5., 5.), path(&[root, a, b]));
+        assert_eq!(hit(root, 100., 5.), path(&[root, a]));
+        assert_eq!(hit(root, 100., 60.), path(&[root]));
+        assert_eq!(hit(root, 100., 250.), path(&[]));
+    }
+}
+
+// root > [bottom(h:100), top(pointer-events:none, margin-top:-100, h:100) > inner(h:50)]
+#[test]
+fn hit_test_pointer_events() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let bottom = new_child(root, 375., 100.);
+        let top = new_child(root, 375., 100.);
+        top.set_margin_top(DefLength::Points(Len::from_f32(-100.)));
+        top.set_pointer_events(PointerEvents::None);
+        let inner = new_child(top, 375., 50.);
+        layout(root);
+
+        assert_eq!(hit(root, 5., 10.), path(&[root, top, inner]));
+        assert_eq!(hit(root, 5., 60.), path(&[root, bottom]));
+    }
+}
+
+// root > [first(absolute, h:100), second(absolute, h:100)]
+#[test]
+fn hit_test_z_index() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        root.set_height(DefLength::Points(Len::from_f32(100.)));
+        let first = new_child(root, 100., 100.);
+        first.set_position(Position::Absolute);
+        let second = new_child(root, 100., 100.);
+        second.set_position(Position::Absolute);
+        layout(root);
+
+        // the later one is on top
+        assert_eq!(hit(root, 5., 5.), path(&[root, second]));
+        first.set_z_index(Some(1));
+        assert_eq!(hit(root, 5., 5.), path(&[root, first]));
+        second.set_z_index(Some(2));
+        assert_eq!(hit(root, 5., 5.), path(&[root, second]));
+        second.set_z_index(Some(-1));
+        assert_eq!(hit(root, 5., 5.), path(&[root, first]));
+    }
+}
+
+// root > scroller(h:50, overflow:hidden) > [item1(h:50), item2(h:50)]
+#[test]
+fn hit_test_scroll_offset() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let scroller = new_child(root, 375., 50.);
+        scroller.set_overflow_x(Overflow::Hidden);
+        scroller.set_overflow_y(Overflow::Hidden);
+        let item1 = new_child(scroller, 375., 50.);
+        let item2 = new_child(scroller, 375., 50.);
+        layout(root);
+
+        assert_eq!(hit(root, 5., 10.), path(&[root, scroller, item1]));
+        // the overflowing item is clipped
+        assert_eq!(hit(root, 5., 60.), path(&[]));
+
+        scroller.set_scroll_offset(Vector::new(Len::from_f32(0.), Len::from_f32(50.)));
+        assert_eq!(hit(root, 5., 10.), path(&[root, scroller, item2]));
+    }
+}
+
+// root > scroller(h:100, overflow:hidden) > [item1(h:100), item2(h:100), fixed(w:20, h:20, position:fixed)]
+#[test]
+fn hit_test_fixed_in_scroller() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let scroller = new_child(root, 375., 100.);
+        scroller.set_overflow_x(Overflow::Hidden);
+        scroller.set_overflow_y(Overflow::Hidden);
+        let item1 = new_child(scroller, 375., 100.);
+        new_child(scroller, 375., 100.);
+        let fixed = new_child(scroller, 20., 20.);
+        fixed.set_position(Position::Fixed);
+        fixed.set_left(DefLength::Points(Len::from_f32(0.)));
+        fixed.set_top(DefLength::Points(Len::from_f32(0.)));
+        layout(root);
+
+        // the fixed box does not move with the scroll offset of the scroller
+        scroller.set_scroll_offset(Vector::new(Len::from_f32(0.), Len::from_f32(50.)));
+        assert_eq!(hit(root, 5., 5.), path(&[root, scroller, fixed]));
+        assert_eq!(hit(root, 5., 30.), path(&[root, scroller, item1]));
+    }
+}
+
+// root > scroller(h:100, overflow:hidden) > [header(h:20, position:sticky, top:0, z-index:1), item1(h:100), item2(h:100)]
+#[test]
+fn hit_test_sticky_in_scroller() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let scroller = new_child(root, 375., 100.);
+        scroller.set_overflow_x(Overflow::Hidden);
+        scroller.set_overflow_y(Overflow::Hidden);
+        let header = new_child(scroller, 375., 20.);
+        header.set_position(Position::Sticky);
+        header.set_top(DefLength::Points(Len::from_f32(0.)));
+        header.set_z_index(Some(1));
+        let item1 = new_child(scroller, 375., 100.);
+        new_child(scroller, 375., 100.);
+        layout(root);
+
+        // the header sticks to the top of the scrollport
+        scroller.set_scroll_offset(Vector::new(Len::from_f32(0.), Len::from_f32(50.)));
+        assert_eq!(hit(root, 5., 5.), path(&[root, scroller, header]));
+        assert_eq!(hit(root, 5., 30.), path(&[root, scroller, item1]));
+    }
+}
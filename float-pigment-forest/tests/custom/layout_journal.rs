@@ -0,0 +1,123 @@
+// Tests for the layout change journal
+// Focus: recording the changed nodes only, with the old and new values, including relayout boundaries and display: none.
+
+use crate::*;
+
+use float_pigment_css::typing::{Display, Overflow};
+use float_pigment_forest::{convert_node_ref_to_ptr, ChildOperation, Node, StyleSetter};
+use float_pigment_layout::{DefLength, LayoutJournalEntry};
+
+fn entry<'a>(journal: &'a [LayoutJournalEntry<Len>], node: &Node) -> &'a LayoutJournalEntry<Len> {
+    journal
+        .iter()
+        .find(|x| x.node == node as *const Node as usize)
+        .unwrap()
+}
+
+// root > [a(h:50), b(h:50) > c(h:10)]
+#[test]
+fn layout_journal_changes() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        root.set_layout_journal_enabled(true);
+        let a = append(root, new_box(50.));
+        let b = append(root, new_box(50.));
+        let c = append(b, new_box(10.));
+        layout(root);
+
+        // all nodes are new
+        let journal = root.take_layout_journal();
+        assert_eq!(journal.len(), 4);
+        assert!(journal.iter().all(|x| x.old.is_none()));
+        assert_eq!(entry(&journal, b).new.rect.origin.y, 50.);
+
+        // nothing changed
+        layout(root);
+        assert!(root.take_layout_journal().is_empty());
+
+        a.set_height(DefLength::Points(Len::from_f32(80.)));
+        layout(root);
+        let journal = root.take_layout_journal();
+        assert_eq!(journal.len(), 3);
+        let x = entry(&journal, a);
+        assert_eq!(x.old.unwrap().rect.size.height, 50.);
+        assert_eq!(x.new.rect.size.height, 80.);
+        let x = entry(&journal, b);
+        assert_eq!(x.old.unwrap().rect.origin.y, 50.);
+        assert_eq!(x.new.rect.origin.y, 80.);
+        let x = entry(&journal, root);
+        assert_eq!(x.old.unwrap().rect.size.height, 100.);
+        assert_eq!(x.new.rect.size.height, 130.);
+        // the rect of c is relative to b, which is not changed
+        assert!(!journal.iter().any(|x| x.node == c as *const Node as usize));
+
+        // padding and border
+        b.set_height(DefLength::Auto);
+        b.set_padding_top(DefLength::Points(Len::from_f32(5.)));
+        b.set_border_left(DefLength::Points(Len::from_f32(2.)));
+        layout(root);
+        let journal = root.take_layout_journal();
+        let x = entry(&journal, b);
+        assert_eq!(x.old.unwrap().padding.top, 0.);
+        assert_eq!(x.new.padding.top, 5.);
+        assert_eq!(x.new.border.left, 2.);
+        assert_eq!(x.new.rect.size.height, 15.);
+        let x = entry(&journal, c);
+        assert_eq!(
+            x.old.unwrap().rect.origin,
+            float_pigment_layout::Point::new(Len::from_f32(0.), Len::from_f32(0.))
+        );
+        assert_eq!(x.new.rect.origin.x, 2.);
+        assert_eq!(x.new.rect.origin.y, 5.);
+
+        // display: none
+        a.set_display(Display::None);
+        layout(root);
+        let journal = root.take_layout_journal();
+        let x = entry(&journal, a);
+        assert_eq!(x.old.unwrap().rect.size.height, 80.);
+        assert_eq!(x.new.rect.size.height, 0.);
+        assert_eq!(entry(&journal, b).new.rect.origin.y, 0.);
+
+        // disabled
+        root.set_layout_journal_enabled(false);
+        a.set_display(Display::Block);
+        layout(root);
+        assert!(root.take_layout_journal().is_empty());
+    }
+}
+
+// root > [card(w:100, h:100, overflow:hidden) > inner > leaf(h:10), footer(h:20)]
+#[test]
+fn layout_journal_relayout_boundary() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        root.set_layout_journal_enabled(true);
+        let card = append(root, new_box(100.));
+        card.set_width(DefLength::Points(Len::from_f32(100.)));
+        card.set_overflow_x(Overflow::Hidden);
+        card.set_overflow_y(Overflow::Hidden);
+        let inner = as_ref(Node::new_ptr());
+        card.append_child(convert_node_ref_to_ptr(inner));
+        let leaf = append(inner, new_box(10.));
+        let footer = append(root, new_box(20.));
+        layout(root);
+        root.take_layout_journal();
+
+        leaf.set_height(DefLength::Points(Len::from_f32(30.)));
+        layout(root);
+        let journal = root.take_layout_journal();
+        assert_eq!(journal.len(), 3);
+        assert_eq!(entry(&journal, leaf).new.rect.size.height, 30.);
+        let x = entry(&journal, inner);
+        assert_eq!(x.old.unwrap().rect.size.height, 10.);
+        assert_eq!(x.new.rect.size.height, 30.);
+        // the size of the card is fixed, but the baseline is taken from the content
+        let x = entry(&journal, card);
+        assert_eq!(x.old.unwrap().rect, x.new.rect);
+        assert_eq!(x.new.first_baseline_ascent.y, 30.);
+        assert!(!journal
+            .iter()
+            .any(|x| x.node == footer as *const Node as usize));
+    }
+}
@@ -0,0 +1,109 @@
+// Tests for the layout instrumentation
+// Focus: compute calls, cache hits and misses, measure calls, and algorithm passes in the trace summary.
+
+use crate::*;
+
+use float_pigment_css::typing::{Display, Float};
+use float_pigment_forest::{
+    convert_node_ref_to_ptr, layout::trace_clock, ChildOperation, Node, StyleSetter,
+};
+use float_pigment_layout::{
+    DefLength, LayoutAlgorithm, LayoutMeasureKind, LayoutRequestKind, LayoutTrace,
+    LayoutTraceEvent, OptionNum, Size,
+};
+
+// root > container(flex) > [a(w:10), measured]
+#[test]
+fn trace_summary() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let container = as_ref(Node::new_ptr());
+        container.set_display(Display::Flex);
+        root.append_child(convert_node_ref_to_ptr(container));
+        let a = as_ref(Node::new_ptr());
+        a.set_width(DefLength::Points(Len::from_f32(10.)));
+        container.append_child(convert_node_ref_to_ptr(a));
+        let measured = as_ref(Node::new_ptr());
+        measured.set_measure_func(Some(Box::new(|_, _, _, _, _, _, _, _, _| {
+            Size::new(Len::from_f32(20.), Len::from_f32(10.))
+        })));
+        container.append_child(convert_node_ref_to_ptr(measured));
+
+        let mut trace = LayoutTrace::new(Some(trace_clock));
+        trace.record_events(true);
+        layout_with_trace(root, &mut trace);
+        assert_eq!(measured.layout_position().height, 10.);
+
+        let summary = trace.summary();
+        assert!(summary.compute_calls >= 4);
+        assert!(summary.total_cache().misses >= 4);
+        assert!(summary.algorithms[&LayoutAlgorithm::Flex].passes >= 1);
+        assert!(summary.algorithms[&LayoutAlgorithm::BlockMeasure].passes >= 1);
+        let measured_stats = &summary.nodes[&(measured as *const Node as usize)];
+        assert!(measured_stats.measure_calls[&LayoutMeasureKind::Block] >= 1);
+        assert_eq!(
+            summary.total_measure_calls(),
+            measured_stats.measure_calls[&LayoutMeasureKind::Block]
+        );
+        // the root compute call finishes last
+        assert!(matches!(
+            trace.events().last(),
+            Some(LayoutTraceEvent::Compute {
+                depth: 0,
+                kind: LayoutRequestKind::Position,
+                ..
+            })
+        ));
+
+        // nothing changed, so the root position cache is hit
+        trace.take_summary();
+        layout_with_trace(root, &mut trace);
+        let summary = trace.summary();
+        assert_eq!(summary.compute_calls, 1);
+        assert_eq!(summary.cache_by_kind(LayoutRequestKind::Position).hits, 1);
+        // the hit is bucketed by the root position request key
+        let (key, stats) = summary.cache.iter().next().unwrap();
+        assert_eq!(summary.cache.len(), 1);
+        assert_eq!(key.kind, LayoutRequestKind::Position);
+        assert_eq!(key.size.width, OptionNum::some(Len::from_f32(375.)));
+        assert_eq!(key.sizing_mode, None);
+        assert_eq!(stats.hits, 1);
+        assert_eq!(summary.total_measure_calls(), 0);
+    }
+}
+
+// root > [float(w:50, h:20), a(h:30), b > leaf(h:10)]
+#[test]
+fn trace_block_below_floats_cached() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let float = as_ref(Node::new_ptr());
+        float.set_float(Float::Left);
+        float.set_width(DefLength::Points(Len::from_f32(50.)));
+        float.set_height(DefLength::Points(Len::from_f32(20.)));
+        root.append_child(convert_node_ref_to_ptr(float));
+        let a = as_ref(Node::new_ptr());
+        a.set_height(DefLength::Points(Len::from_f32(30.)));
+        root.append_child(convert_node_ref_to_ptr(a));
+        let b = as_ref(Node::new_ptr());
+        root.append_child(convert_node_ref_to_ptr(b));
+        let leaf = as_ref(Node::new_ptr());
+        leaf.set_height(DefLength::Points(Len::from_f32(10.)));
+        b.append_child(convert_node_ref_to_ptr(leaf));
+
+        let mut trace = LayoutTrace::new(None);
+        layout_with_trace(root, &mut trace);
+        assert_eq!(b.layout_position().top, 30.);
+
+        // b is below the float, so it does not depend on the float and is not laid out again
+        trace.take_summary();
+        a.set_height(DefLength::Points(Len::from_f32(40.)));
+        layout_with_trace(root, &mut trace);
+        assert_eq!(b.layout_position().top, 40.);
+        let summary = trace.summary();
+        let b_stats = &summary.nodes[&(b as *const Node as usize)];
+        assert_eq!(b_stats.cache.misses, 0);
+        assert!(b_stats.cache.hits >= 1);
+        assert!(!summary.nodes.contains_key(&(leaf as *const Node as usize)));
+    }
+}
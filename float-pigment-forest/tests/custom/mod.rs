@@ -1,5 +1,19 @@
 mod cache;
+mod css_fragmentation;
 mod css_inline;
+mod css_intrinsic_size;
+mod css_line_clamp;
+mod css_list;
 mod css_margin;
 mod css_margin_collapse;
+mod css_multicol;
+mod css_overflow;
+mod css_sticky;
+mod css_writing_mode;
+mod geometry;
+mod hit_test;
 mod imperative_api_check;
+mod layout_journal;
+mod layout_trace;
+mod relayout_boundary;
+mod virtualized_list;
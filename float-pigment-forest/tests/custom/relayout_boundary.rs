@@ -0,0 +1,126 @@
+// Tests for the relayout boundaries
+// Focus: stopping the dirty propagation at fixed-size boundaries, and falling back to a full relayout when needed.
+
+use crate::*;
+
+use float_pigment_css::typing::{Display, Overflow, Position};
+use float_pigment_forest::{Node, StyleSetter};
+use float_pigment_layout::{DefLength, LayoutTrace};
+
+fn node_key(node: &Node) -> usize {
+    node as *const Node as usize
+}
+
+// root > [header(h:50), card(w:100, h:100, overflow:hidden) > inner > leaf(h:10), footer(h:20)]
+#[test]
+fn relayout_boundary_stops_propagation() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let header = append(root, as_ref(Node::new_ptr()));
+        header.set_height(DefLength::Points(Len::from_f32(50.)));
+        let card = append(root, as_ref(Node::new_ptr()));
+        card.set_width(DefLength::Points(Len::from_f32(100.)));
+        card.set_height(DefLength::Points(Len::from_f32(100.)));
+        card.set_overflow_x(Overflow::Hidden);
+        card.set_overflow_y(Overflow::Hidden);
+        let inner = append(card, as_ref(Node::new_ptr()));
+        let leaf = append(inner, as_ref(Node::new_ptr()));
+        leaf.set_height(DefLength::Points(Len::from_f32(10.)));
+        let footer = append(root, as_ref(Node::new_ptr()));
+        footer.set_height(DefLength::Points(Len::from_f32(20.)));
+
+        let mut trace = LayoutTrace::new(None);
+        layout_with_trace(root, &mut trace);
+        assert_eq!(inner.layout_position().height, 10.);
+        assert_eq!(footer.layout_position().top, 150.);
+
+        trace.take_summary();
+        leaf.set_height(DefLength::Points(Len::from_f32(30.)));
+        // the root is not marked dirty, but it still needs a layout pass to reach the card
+        assert!(root.needs_layout());
+        assert!(!footer.needs_layout());
+        layout_with_trace(root, &mut trace);
+        assert!(!root.needs_layout());
+        assert!(!leaf.needs_layout());
+        assert_eq!(leaf.layout_position().height, 30.);
+        assert_eq!(inner.layout_position().height, 30.);
+        assert_eq!(card.layout_position().top, 50.);
+        assert_eq!(card.layout_position().height, 100.);
+        assert_eq!(footer.layout_position().top, 150.);
+
+        // only the card subtree is laid out again
+        let summary = trace.summary();
+        let root_stats = &summary.nodes[&node_key(root)];
+        assert_eq!(root_stats.compute_calls, 1);
+        assert_eq!(root_stats.cache.hits, 1);
+        assert!(summary.nodes[&node_key(card)].cache.misses >= 1);
+        assert!(summary.nodes[&node_key(leaf)].cache.misses >= 1);
+        assert!(!summary.nodes.contains_key(&node_key(header)));
+        assert!(!summary.nodes.contains_key(&node_key(footer)));
+
+        // nothing changed
+        trace.take_summary();
+        layout_with_trace(root, &mut trace);
+        assert_eq!(trace.summary().compute_calls, 1);
+    }
+}
+
+// root(flex) > [card(w:100, h:100) > leaf(h:10), sibling(w:10)]
+#[test]
+fn relayout_boundary_content_based_flex_item() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        root.set_display(Display::Flex);
+        let card = append(root, as_ref(Node::new_ptr()));
+        card.set_width(DefLength::Points(Len::from_f32(100.)));
+        card.set_height(DefLength::Points(Len::from_f32(100.)));
+        let leaf = append(card, as_ref(Node::new_ptr()));
+        leaf.set_height(DefLength::Points(Len::from_f32(10.)));
+        let sibling = append(root, as_ref(Node::new_ptr()));
+        sibling.set_width(DefLength::Points(Len::from_f32(10.)));
+
+        let mut trace = LayoutTrace::new(None);
+        layout_with_trace(root, &mut trace);
+        assert_eq!(sibling.layout_position().left, 100.);
+
+        // the automatic minimum size of a flex item depends on its content, so it is not a boundary
+        trace.take_summary();
+        leaf.set_height(DefLength::Points(Len::from_f32(30.)));
+        layout_with_trace(root, &mut trace);
+        assert_eq!(leaf.layout_position().height, 30.);
+        assert_eq!(card.layout_position().height, 100.);
+        assert!(trace.summary().nodes[&node_key(root)].cache.misses >= 1);
+    }
+}
+
+// root > [card(static, w:100, h:100, overflow:hidden) > abs(absolute, h:10), footer(h:20)]
+#[test]
+fn relayout_boundary_escaping_absolute() {
+    unsafe {
+        let root = as_ref(Node::new_ptr());
+        let card = append(root, as_ref(Node::new_ptr()));
+        card.set_position(Position::Static);
+        card.set_width(DefLength::Points(Len::from_f32(100.)));
+        card.set_height(DefLength::Points(Len::from_f32(100.)));
+        card.set_overflow_x(Overflow::Hidden);
+        card.set_overflow_y(Overflow::Hidden);
+        let abs = append(card, as_ref(Node::new_ptr()));
+        abs.set_position(Position::Absolute);
+        abs.set_width(DefLength::Points(Len::from_f32(10.)));
+        abs.set_height(DefLength::Points(Len::from_f32(10.)));
+        let footer = append(root, as_ref(Node::new_ptr()));
+        footer.set_height(DefLength::Points(Len::from_f32(20.)));
+
+        let mut trace = LayoutTrace::new(None);
+        layout_with_trace(root, &mut trace);
+        assert_eq!(abs.layout_position().height, 10.);
+
+        // the absolutely positioned child is laid out by the root, so the whole tree is laid out again
+        trace.take_summary();
+        abs.set_height(DefLength::Points(Len::from_f32(40.)));
+        layout_with_trace(root, &mut trace);
+        assert_eq!(abs.layout_position().height, 40.);
+        assert_eq!(footer.layout_position().top, 100.);
+        assert!(trace.summary().nodes[&node_key(root)].cache.misses >= 1);
+    }
+}
@@ -0,0 +1,139 @@
+// Tests for the virtualized list layout
+// Focus: laying out the children in the viewport range only, estimated placeholders, corrections, and flex column lists.
+
+use crate::*;
+
+use float_pigment_css::typing::{AlignItems, Display, FlexDirection, Overflow};
+use float_pigment_forest::{convert_node_ref_to_ptr, ChildOperation, Node, StyleSetter};
+use float_pigment_layout::{DefLength, LayoutTrace, LayoutVirtualization};
+
+unsafe fn new_list<'a>(count: usize, child_height: f32) -> (&'a Node, &'a Node, Vec<&'a Node>) {
+    let root = as_ref(Node::new_ptr());
+    let list = as_ref(Node::new_ptr());
+    list.set_height(DefLength::Points(Len::from_f32(500.)));
+    list.set_overflow_x(Overflow::Hidden);
+    list.set_overflow_y(Overflow::Hidden);
+    root.append_child(convert_node_ref_to_ptr(list));
+    let children = (0..count)
+        .map(|_| {
+            let child = as_ref(Node::new_ptr());
+            child.set_height(DefLength::Points(Len::from_f32(child_height)));
+            list.append_child(convert_node_ref_to_ptr(child));
+            child
+        })
+        .collect();
+    (root, list, children)
+}
+
+fn viewport(start: f32, end: f32) -> Option<LayoutVirtualization<Len>> {
+    Some(LayoutVirtualization {
+        viewport_start: Len::from_f32(start),
+        viewport_end: Len::from_f32(end),
+        estimated_child_size: Len::from_f32(40.),
+    })
+}
+
+// root > list(h:500, overflow:hidden) > 1000 * item(h:50)
+#[test]
+fn virtualized_list_viewport() {
+    unsafe {
+        let (root, list, items) = new_list(1000, 50.);
+        list.set_virtualization(viewport(0., 500.));
+        let mut trace = LayoutTrace::new(None);
+        layout_with_trace(root, &mut trace);
+
+        let result = list.virtualized_result().unwrap();
+        assert_eq!(result.laid_out, 0..10);
+        assert_eq!(result.total_extent, 10. * 50. + 990. * 40.);
+        assert_eq!(list.layout_position().height, 500.);
+        assert!(!items[9].is_virtualized_placeholder());
+        assert_eq!(items[9].layout_position().top, 450.);
+        assert_eq!(items[9].layout_position().width, 375.);
+        assert!(items[10].is_virtualized_placeholder());
+        assert_eq!(items[10].layout_position().top, 500.);
+        assert_eq!(items[10].layout_position().height, 40.);
+        assert_eq!(items[999].layout_position().top, 500. + 989. * 40.);
+
+        // only the children in the viewport are laid out
+        let summary = trace.take_summary();
+        assert!(summary.nodes.len() < 20);
+
+        // scroll down: the laid out children keep their real sizes
+        list.set_virtualization(viewport(1000., 1500.));
+        layout_with_trace(root, &mut trace);
+        let result = list.virtualized_result().unwrap();
+        assert_eq!(result.laid_out, 22..33);
+        assert_eq!(result.total_extent, 21. * 50. + 979. * 40.);
+        assert!(items[0].is_virtualized_placeholder());
+        assert_eq!(items[0].layout_position().height, 50.);
+        assert_eq!(items[10].layout_position().top, 500.);
+        assert_eq!(items[22].layout_position().top, 980.);
+        assert_eq!(items[23].layout_position().top, 1030.);
+        assert!(!items[32].is_virtualized_placeholder());
+        assert!(items[33].is_virtualized_placeholder());
+        assert!(trace.take_summary().nodes.len() < 20);
+
+        // scroll back: the placeholders are laid out again
+        list.set_virtualization(viewport(0., 500.));
+        layout_with_trace(root, &mut trace);
+        assert_eq!(list.virtualized_result().unwrap().laid_out, 0..10);
+        assert!(!items[0].is_virtualized_placeholder());
+        assert_eq!(items[1].layout_position().top, 50.);
+
+        // disable it
+        list.set_virtualization(None);
+        layout_with_trace(root, &mut trace);
+        assert!(list.virtualized_result().is_none());
+        assert!(!items[999].is_virtualized_placeholder());
+        assert_eq!(items[999].layout_position().top, 999. * 50.);
+    }
+}
+
+// root > list(h:500, overflow:hidden) > 100 * item(h:50)
+#[test]
+fn virtualized_list_dirty_child() {
+    unsafe {
+        let (root, list, items) = new_list(100, 50.);
+        list.set_virtualization(viewport(0., 120.));
+        let mut trace = LayoutTrace::new(None);
+        layout_with_trace(root, &mut trace);
+        assert_eq!(list.virtualized_result().unwrap().laid_out, 0..3);
+
+        // the estimate is corrected by the new size
+        items[1].set_height(DefLength::Points(Len::from_f32(100.)));
+        layout_with_trace(root, &mut trace);
+        let result = list.virtualized_result().unwrap();
+        assert_eq!(result.laid_out, 0..2);
+        assert_eq!(result.total_extent, 50. + 100. + 50. + 97. * 40.);
+        assert_eq!(items[1].layout_position().height, 100.);
+        assert!(items[2].is_virtualized_placeholder());
+        assert_eq!(items[2].layout_position().top, 150.);
+        assert_eq!(items[2].layout_position().height, 50.);
+    }
+}
+
+// root > list(flex, column, align-items:center, row-gap:10, h:500) > 100 * item(w:100, h:50)
+#[test]
+fn virtualized_list_flex_column() {
+    unsafe {
+        let (root, list, items) = new_list(100, 50.);
+        list.set_display(Display::Flex);
+        list.set_flex_direction(FlexDirection::Column);
+        list.set_align_items(AlignItems::Center);
+        list.set_row_gap(DefLength::Points(Len::from_f32(10.)));
+        for item in items.iter() {
+            item.set_width(DefLength::Points(Len::from_f32(100.)));
+        }
+        list.set_virtualization(viewport(0., 200.));
+        let mut trace = LayoutTrace::new(None);
+        layout_with_trace(root, &mut trace);
+
+        let result = list.virtualized_result().unwrap();
+        assert_eq!(result.laid_out, 0..4);
+        assert_eq!(result.total_extent, 4. * 50. + 96. * 40. + 99. * 10.);
+        assert_eq!(items[1].layout_position().left, 137.5);
+        assert_eq!(items[1].layout_position().top, 60.);
+        assert!(items[4].is_virtualized_placeholder());
+        assert_eq!(items[4].layout_position().top, 240.);
+    }
+}
@@ -6,13 +6,16 @@ use float_pigment_css::{
     parser::parse_inline_style,
     property::{NodeProperties, Property, PropertyValueWithGlobal},
     sheet::PropertyMeta,
-    typing::{AspectRatio, Display, Gap, GridAuto, GridTemplate, TrackListItem, TrackSize},
+    typing::{
+        AspectRatio, ColumnCount, Display, Gap, GridAuto, GridTemplate, LineHeight, RepeatCount,
+        TrackListItem, TrackSize, VerticalAlign,
+    },
 };
 pub use float_pigment_forest::Len;
-use float_pigment_forest::{node::Length, *};
+use float_pigment_forest::{layout::LayoutVerticalAlign, node::Length, *};
 use float_pigment_layout::{
-    DefLength, LayoutGridAuto, LayoutGridTemplate, LayoutTrackListItem, LayoutTrackSize,
-    LayoutTreeNode,
+    DefLength, LayoutGridAuto, LayoutGridTemplate, LayoutRepeatCount, LayoutTrace,
+    LayoutTrackListItem, LayoutTrackSize, LayoutTreeNode, OptionNum, OptionSize, Size,
 };
 
 use rustc_hash::FxHashMap;
@@ -22,12 +25,81 @@ thread_local! {
 static NODE_ID: Cell<usize> = const { Cell::new(1000) };
 }
 
+pub(crate) unsafe fn as_ref<'a>(node: *mut Node) -> &'a Node {
+    &*node
+}
+
+/// Create a node with the fixed height.
+pub(crate) unsafe fn new_box<'a>(height: f32) -> &'a Node {
+    let node = as_ref(Node::new_ptr());
+    node.set_height(DefLength::Points(Len::from_f32(height)));
+    node
+}
+
+/// Create a node with the fixed width and height.
+pub(crate) unsafe fn new_sized_box<'a>(width: f32, height: f32) -> &'a Node {
+    let node = new_box(height);
+    node.set_width(DefLength::Points(Len::from_f32(width)));
+    node
+}
+
+/// Create an inline-block node with the fixed width and height.
+pub(crate) unsafe fn new_inline_block<'a>(width: f32, height: f32) -> &'a Node {
+    let node = new_sized_box(width, height);
+    node.set_display(Display::InlineBlock);
+    node
+}
+
+/// Append the `child` to the `parent`, and return the `child`.
+pub(crate) unsafe fn append<'a>(parent: &Node, child: &'a Node) -> &'a Node {
+    parent.append_child(convert_node_ref_to_ptr(child));
+    child
+}
+
+/// Create a node with the fixed width and height as the last child of the `parent`.
+pub(crate) unsafe fn new_child<'a>(parent: &Node, width: f32, height: f32) -> &'a Node {
+    append(parent, new_sized_box(width, height))
+}
+
+/// Lay out the tree in a 375px wide available space and a 375x750 viewport.
+pub(crate) unsafe fn layout(node: &Node) {
+    node.layout(
+        OptionSize::new(OptionNum::some(Len::from_f32(375.)), OptionNum::none()),
+        Size::new(Len::from_f32(375.), Len::from_f32(750.)),
+    );
+}
+
+/// Lay out the tree like `layout`, and record the statistics in `trace`.
+pub(crate) unsafe fn layout_with_trace(node: &Node, trace: &mut LayoutTrace<Len>) {
+    node.layout_with_trace(
+        OptionSize::new(OptionNum::some(Len::from_f32(375.)), OptionNum::none()),
+        Size::new(Len::from_f32(375.), Len::from_f32(750.)),
+        trace,
+    );
+}
+
+pub(crate) fn column_count(count: ColumnCount) -> Option<u32> {
+    match count {
+        ColumnCount::Auto => None,
+        ColumnCount::Num(x) => Some(x.to_i32().max(1) as u32),
+    }
+}
+
 pub(crate) fn def_length(length: float_pigment_css::typing::Length) -> Length {
     match length {
         float_pigment_css::typing::Length::Auto => DefLength::Auto,
         float_pigment_css::typing::Length::Undefined => DefLength::Undefined,
         float_pigment_css::typing::Length::Px(x) => DefLength::Points(Len::from_f32(x)),
         float_pigment_css::typing::Length::Ratio(x) => DefLength::Percent(x),
+        float_pigment_css::typing::Length::MinContent => DefLength::MinContent,
+        float_pigment_css::typing::Length::MaxContent => DefLength::MaxContent,
+        float_pigment_css::typing::Length::FitContent => DefLength::FitContent,
+        float_pigment_css::typing::Length::FitContentFunction(x) => match def_length(*x) {
+            DefLength::Points(x) => DefLength::FitContentPoints(x),
+            DefLength::Percent(x) => DefLength::FitContentPercent(x),
+            _ => unreachable!("fit-content() only takes a length or a percentage"),
+        },
+        float_pigment_css::typing::Length::Stretch => DefLength::Stretch,
         _ => todo!(),
     }
 }
@@ -187,6 +259,8 @@ enum BuildNode {
         /// `build_imperative_tree` will attach a TextInfo measure func with
         /// the given text length and font size.
         measure_text: Option<(usize, f32)>,
+        /// The `colspan` and `rowspan` attributes of a table cell or a table column.
+        table_span: Option<(u32, u32)>,
     },
     Text {
         text: String,
@@ -222,6 +296,7 @@ impl TestCtx {
             style: None,
             parent: None,
             measure_text: None,
+            table_span: None,
         });
         h
     }
@@ -255,6 +330,13 @@ impl TestCtx {
         }
     }
 
+    /// Set the `colspan` and `rowspan` attributes of a table cell or a table column.
+    pub fn set_table_span(&mut self, n: NodeHandle, colspan: u32, rowspan: u32) {
+        if let BuildNode::Element { table_span, .. } = &mut self.build_nodes[n.0] {
+            *table_span = Some((colspan, rowspan));
+        }
+    }
+
     pub fn append(&mut self, parent: NodeHandle, child: NodeHandle) {
         if let Some(node) = self.build_nodes.get_mut(child.0) {
             node.set_parent(parent);
@@ -374,6 +456,66 @@ impl TestCtx {
                 .to_f32()
         }
     }
+    /// Compare the layout snapshot of the tree against the golden files
+    /// `tests/cases/<case>.snapshot.json` and `tests/cases/<case>.snapshot.svg`.
+    ///
+    /// Set `UPDATE_SNAPSHOTS=1` to (re)write the golden files instead.
+    pub fn assert_snapshot(&self, case: &str) {
+        let Some(root) = self.imperative_root else {
+            panic!("assert_snapshot should be called after layout_imperative");
+        };
+        let labels: FxHashMap<*const Node, String> = self
+            .imperative_built
+            .iter()
+            .zip(self.build_nodes.iter())
+            .map(|(node, build_node)| {
+                let label = match build_node {
+                    BuildNode::Element { tag, .. } => tag.clone(),
+                    BuildNode::Text { .. } => "#text".to_string(),
+                };
+                (*node as *const Node, label)
+            })
+            .collect();
+        let snapshot = unsafe {
+            (*root).layout_snapshot(|node| {
+                labels
+                    .get(&(node as *const Node))
+                    .cloned()
+                    .unwrap_or_default()
+            })
+        };
+        let base = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/cases")
+            .join(case);
+        let update = std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|x| x == "1");
+        for (ext, actual) in [("json", snapshot.to_json()), ("svg", snapshot.to_svg())] {
+            let path = base.with_extension(format!("snapshot.{ext}"));
+            if update {
+                std::fs::write(&path, &actual).unwrap();
+                continue;
+            }
+            let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+                panic!(
+                    "missing golden file {}, run with UPDATE_SNAPSHOTS=1 to create it",
+                    path.display()
+                )
+            });
+            if expected != actual {
+                let line = expected
+                    .lines()
+                    .zip(actual.lines())
+                    .position(|(a, b)| a != b)
+                    .unwrap_or_else(|| expected.lines().count().min(actual.lines().count()));
+                panic!(
+                    "snapshot mismatch with {} at line {}\n--- expected\n{}\n+++ actual\n{}\n(run with UPDATE_SNAPSHOTS=1 to update)",
+                    path.display(),
+                    line + 1,
+                    expected.lines().nth(line).unwrap_or_default(),
+                    actual.lines().nth(line).unwrap_or_default(),
+                );
+            }
+        }
+    }
     pub fn gen_node_id() -> NodeId {
         NODE_ID.with(|x| {
             x.replace(x.get() + 1);
@@ -469,6 +611,8 @@ impl TestCtx {
                 "direction" => node.set_direction(node_props.direction()),
                 "writing-mode" => node.set_writing_mode(node_props.writing_mode()),
                 "position" => node.set_position(node_props.position()),
+                "float" => node.set_float(node_props.float()),
+                "clear" => node.set_clear(node_props.clear()),
                 "left" => node.set_left(def_length(node_props.left())),
                 "top" => node.set_top(def_length(node_props.top())),
                 "right" => node.set_right(def_length(node_props.right())),
@@ -522,6 +666,46 @@ impl TestCtx {
                 },
                 "order" => node.set_order(node_props.order().to_i32()),
                 "text-align" => node.set_text_align(node_props.text_align()),
+                "line-height" => node.set_line_height(match node_props.line_height() {
+                    LineHeight::Normal => Length::Undefined,
+                    LineHeight::Num(x) => Length::Points(Len::from_f32(
+                        x.to_f32() * convert_font_size_to_px(node_props.font_size()),
+                    )),
+                    LineHeight::Length(float_pigment_css::typing::Length::Ratio(x)) => {
+                        Length::Points(Len::from_f32(
+                            x * convert_font_size_to_px(node_props.font_size()),
+                        ))
+                    }
+                    LineHeight::Length(x) => def_length(x),
+                }),
+                "vertical-align" => node.set_vertical_align(match node_props.vertical_align() {
+                    VerticalAlign::Baseline => LayoutVerticalAlign::Baseline,
+                    VerticalAlign::Top => LayoutVerticalAlign::Top,
+                    VerticalAlign::Middle => LayoutVerticalAlign::Middle,
+                    VerticalAlign::Bottom => LayoutVerticalAlign::Bottom,
+                    VerticalAlign::TextTop => LayoutVerticalAlign::TextTop,
+                    VerticalAlign::TextBottom => LayoutVerticalAlign::TextBottom,
+                    VerticalAlign::Length(x) => match def_length(x) {
+                        Length::Percent(x) => LayoutVerticalAlign::Percent(x),
+                        Length::Points(x) => LayoutVerticalAlign::Length(x),
+                        _ => LayoutVerticalAlign::Baseline,
+                    },
+                }),
+                "text-indent" => node.set_text_indent(def_length(node_props.text_indent())),
+                "white-space" => node.set_white_space(node_props.white_space()),
+                "-wx-line-clamp" | "-webkit-line-clamp" => {
+                    let value = node_props.wx_line_clamp().to_f32();
+                    node.set_line_clamp(if value >= 1. {
+                        Some(value as u32)
+                    } else {
+                        None
+                    })
+                }
+                "break-before" => node.set_break_before(node_props.break_before()),
+                "break-after" => node.set_break_after(node_props.break_after()),
+                "break-inside" => node.set_break_inside(node_props.break_inside()),
+                "orphans" => node.set_orphans(node_props.orphans().to_f32().max(1.) as u32),
+                "widows" => node.set_widows(node_props.widows().to_f32().max(1.) as u32),
                 "gap" => {
                     node.set_row_gap({
                         match node_props.row_gap() {
@@ -552,6 +736,24 @@ impl TestCtx {
                         }
                     });
                 }
+                "column-count" => node.set_column_count(column_count(node_props.column_count())),
+                "column-width" => node.set_column_width(def_length(node_props.column_width())),
+                "columns" => {
+                    node.set_column_count(column_count(node_props.column_count()));
+                    node.set_column_width(def_length(node_props.column_width()));
+                }
+                "column-span" => node.set_column_span(node_props.column_span()),
+                "column-fill" => node.set_column_fill(node_props.column_fill()),
+                "table-layout" => node.set_table_layout(node_props.table_layout()),
+                "border-collapse" => node.set_border_collapse(node_props.border_collapse()),
+                "border-spacing-horizontal" => node.set_border_spacing_horizontal(def_length(
+                    node_props.border_spacing_horizontal(),
+                )),
+                "border-spacing-vertical" => node
+                    .set_border_spacing_vertical(def_length(node_props.border_spacing_vertical())),
+                "list-style-position" => {
+                    node.set_list_style_position(node_props.list_style_position())
+                }
                 "grid-template-rows" => {
                     node.set_grid_template_rows({
                         convert_grid_template(node_props.grid_template_rows())
@@ -571,6 +773,14 @@ impl TestCtx {
                 "grid-auto-columns" => {
                     node.set_grid_auto_columns(convert_grid_auto(node_props.grid_auto_columns()));
                 }
+                "masonry-auto-flow" => {
+                    node.set_masonry_auto_flow(node_props.masonry_auto_flow());
+                }
+                "transform" => {
+                    if let Some(value) = ffi::convert_transform(&node_props.transform()) {
+                        node.set_transform(value);
+                    }
+                }
                 _ => {}
             }
         });
@@ -594,6 +804,7 @@ unsafe fn build_dfs(
             tag,
             style,
             measure_text,
+            table_span,
             ..
         } => {
             let node = Node::new_ptr();
@@ -604,6 +815,12 @@ unsafe fn build_dfs(
             if let Some(s) = style {
                 TestCtx::apply_inline_style(&*node, s, &mut node_props, parent_props);
             }
+            // writing-mode is inherited, so it is applied even if not specified in the inline style
+            (*node).set_writing_mode(node_props.writing_mode());
+            if let Some((colspan, rowspan)) = table_span {
+                (*node).set_colspan(*colspan);
+                (*node).set_rowspan(*rowspan);
+            }
             // Measure-text slot: convert the node into a Text measure node
             // (mirrors `prepare_measure_node` in the legacy
             // `create_node_recursive`). Done after style so the final node
@@ -639,41 +856,57 @@ unsafe fn build_dfs(
     }
 }
 
+fn convert_track_size(track_size: TrackSize) -> LayoutTrackSize<Len> {
+    match track_size {
+        TrackSize::MinContent => LayoutTrackSize::MinContent,
+        TrackSize::MaxContent => LayoutTrackSize::MaxContent,
+        TrackSize::Fr(x) => LayoutTrackSize::Fr(x),
+        TrackSize::Length(x) => LayoutTrackSize::Length(def_length(x)),
+        TrackSize::MinMax(min, max) => LayoutTrackSize::MinMax(
+            Box::new(convert_track_size(*min)),
+            Box::new(convert_track_size(*max)),
+        ),
+        TrackSize::FitContent(x) => LayoutTrackSize::FitContent(def_length(x)),
+    }
+}
+
+fn convert_track_list_item(item: TrackListItem) -> LayoutTrackListItem<Len> {
+    match item {
+        TrackListItem::LineNames(line_names) => {
+            LayoutTrackListItem::LineNames(line_names.into_iter().map(|x| x.to_string()).collect())
+        }
+        TrackListItem::TrackSize(track_size) => {
+            LayoutTrackListItem::TrackSize(convert_track_size(track_size))
+        }
+        TrackListItem::Repeat(count, list) => LayoutTrackListItem::Repeat(
+            match count {
+                RepeatCount::Count(x) => LayoutRepeatCount::Count(x),
+                RepeatCount::AutoFill => LayoutRepeatCount::AutoFill,
+                RepeatCount::AutoFit => LayoutRepeatCount::AutoFit,
+            },
+            list.into_iter().map(convert_track_list_item).collect(),
+        ),
+    }
+}
+
 fn convert_grid_template(grid_template: GridTemplate) -> LayoutGridTemplate<Len> {
     match grid_template {
         GridTemplate::None => LayoutGridTemplate::None,
-        GridTemplate::TrackList(x) => LayoutGridTemplate::TrackList({
+        GridTemplate::TrackList(x) => {
+            LayoutGridTemplate::TrackList(x.into_iter().map(convert_track_list_item).collect())
+        }
+        GridTemplate::Subgrid(x) => LayoutGridTemplate::Subgrid(
             x.into_iter()
-                .map(|x| match x {
-                    TrackListItem::LineNames(line_names) => LayoutTrackListItem::LineNames(
-                        line_names.into_iter().map(|x| x.to_string()).collect(),
-                    ),
-                    TrackListItem::TrackSize(track_size) => {
-                        LayoutTrackListItem::TrackSize(match track_size {
-                            TrackSize::MinContent => LayoutTrackSize::MinContent,
-                            TrackSize::MaxContent => LayoutTrackSize::MaxContent,
-                            TrackSize::Fr(x) => LayoutTrackSize::Fr(x),
-                            TrackSize::Length(x) => LayoutTrackSize::Length(def_length(x)),
-                        })
-                    }
-                })
-                .collect()
-        }),
+                .map(|line_names| line_names.into_iter().map(|x| x.to_string()).collect())
+                .collect(),
+        ),
+        GridTemplate::Masonry => LayoutGridTemplate::Masonry,
     }
 }
 
 fn convert_grid_auto(grid_auto: GridAuto) -> LayoutGridAuto<Len> {
     match grid_auto {
-        GridAuto::List(list) => LayoutGridAuto(
-            list.into_iter()
-                .map(|track_size| match track_size {
-                    TrackSize::MinContent => LayoutTrackSize::MinContent,
-                    TrackSize::MaxContent => LayoutTrackSize::MaxContent,
-                    TrackSize::Fr(x) => LayoutTrackSize::Fr(x),
-                    TrackSize::Length(x) => LayoutTrackSize::Length(def_length(x)),
-                })
-                .collect(),
-        ),
+        GridAuto::List(list) => LayoutGridAuto(list.into_iter().map(convert_track_size).collect()),
     }
 }
 
@@ -69,6 +69,51 @@ fn cross_axis_gap<T: LayoutTreeNode>(
     }
 }
 
+/// The physical main and cross axes of a flex container.
+///
+/// CSS Flexbox §5.1: `row` is the inline axis and `column` is the block axis of the writing mode.
+/// In `vertical-rl`, the block axis runs from right to left.
+pub(crate) fn flex_axis_info(
+    writing_mode: WritingMode,
+    flex_direction: FlexDirection,
+    flex_wrap: FlexWrap,
+) -> AxisInfo {
+    let block_axis = AxisInfo::from_writing_mode(writing_mode);
+    let is_row = matches!(
+        flex_direction,
+        FlexDirection::Row | FlexDirection::RowReverse
+    );
+    let (dir, main_dir_rev, cross_dir_rev) = if is_row {
+        let dir = match block_axis.dir {
+            AxisDirection::Horizontal => AxisDirection::Vertical,
+            AxisDirection::Vertical => AxisDirection::Horizontal,
+        };
+        (dir, AxisReverse::NotReversed, block_axis.main_dir_rev)
+    } else {
+        (
+            block_axis.dir,
+            block_axis.main_dir_rev,
+            AxisReverse::NotReversed,
+        )
+    };
+    let flip = |rev: AxisReverse, flip: bool| match (rev, flip) {
+        (AxisReverse::NotReversed, true) => AxisReverse::Reversed,
+        (AxisReverse::Reversed, true) => AxisReverse::NotReversed,
+        (rev, false) => rev,
+    };
+    AxisInfo {
+        dir,
+        main_dir_rev: flip(
+            main_dir_rev,
+            matches!(
+                flex_direction,
+                FlexDirection::RowReverse | FlexDirection::ColumnReverse
+            ),
+        ),
+        cross_dir_rev: flip(cross_dir_rev, flex_wrap == FlexWrap::WrapReverse),
+    }
+}
+
 pub(crate) fn align_self<T: LayoutTreeNode>(child: &T::Style, parent: &T::Style) -> AlignSelf {
     let s = child.align_self();
     if s == AlignSelf::Auto {
@@ -77,6 +122,7 @@ pub(crate) fn align_self<T: LayoutTreeNode>(child: &T::Style, parent: &T::Style)
             AlignItems::FlexEnd => AlignSelf::FlexEnd,
             AlignItems::Center => AlignSelf::Center,
             AlignItems::Baseline => AlignSelf::Baseline,
+            AlignItems::LastBaseline => AlignSelf::LastBaseline,
             AlignItems::Stretch => AlignSelf::Stretch,
             AlignItems::Normal => AlignSelf::Normal,
             AlignItems::Start => AlignSelf::Start,
@@ -159,7 +205,7 @@ struct FlexLine<'a, T: LayoutTreeNode> {
 #[allow(clippy::too_many_arguments)]
 fn compute_min_content_main_size<T: LayoutTreeNode>(
     env: &mut T::Env,
-    node: &T,
+    children: &[&T],
     flex_lines: &[FlexLine<T>],
     dir: AxisDirection,
     main_size_request_kind: ComputeRequestKind,
@@ -171,10 +217,7 @@ fn compute_min_content_main_size<T: LayoutTreeNode>(
     let mut acc = zero;
     for line in flex_lines {
         for flex_child in line.items.iter() {
-            let child_node = node
-                .tree_visitor()
-                .child_at(flex_child.child_index)
-                .unwrap();
+            let child_node = children[flex_child.child_index];
             let mut child = child_node.layout_node().unit();
             let (_, child_border, child_padding_border) = child.margin_border_padding(
                 child_node,
@@ -201,6 +244,7 @@ fn compute_min_content_main_size<T: LayoutTreeNode>(
                         kind: main_size_request_kind.shift_to_all_size(),
                         parent_is_block: false,
                         sizing_mode,
+                        line_clamp: None,
                     },
                 )
                 .size
@@ -240,18 +284,11 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
         let style = node.style();
         let flex_direction = style.flex_direction();
         let flex_wrap = style.flex_wrap();
-        let dir = match flex_direction {
-            FlexDirection::Row | FlexDirection::RowReverse => AxisDirection::Horizontal,
-            FlexDirection::Column | FlexDirection::ColumnReverse => AxisDirection::Vertical,
-        };
-        let main_dir_rev = match flex_direction {
-            FlexDirection::Row | FlexDirection::Column => AxisReverse::NotReversed,
-            FlexDirection::RowReverse | FlexDirection::ColumnReverse => AxisReverse::Reversed,
-        };
-        let cross_dir_rev = match flex_wrap {
-            FlexWrap::NoWrap | FlexWrap::Wrap => AxisReverse::NotReversed,
-            FlexWrap::WrapReverse => AxisReverse::Reversed,
-        };
+        let AxisInfo {
+            dir,
+            main_dir_rev,
+            cross_dir_rev,
+        } = flex_axis_info(style.writing_mode(), flex_direction, flex_wrap);
 
         let axis_info = AxisInfo::from_writing_mode(style.writing_mode());
 
@@ -289,8 +326,10 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
 
         // 1. Generate anonymous flex items as described in §4 Flex Items.
 
+        // CSS Display §2.5: the children of `display: contents` children are flex items as well
+        let children = node.tree_visitor().layout_children();
         let mut flex_items: Vec<FlexItem<T>> =
-            generate_anonymous_flex_items(node, style, &requested_inner_size, dir);
+            generate_anonymous_flex_items(&children, style, &requested_inner_size, dir);
 
         // 9.2. Line Length Determination
 
@@ -317,18 +356,26 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
         // 3. Determine the flex base size and hypothetical main size of each item:
 
         for flex_child in &mut flex_items {
-            let child_node = node
-                .tree_visitor()
-                .child_at(flex_child.child_index)
-                .unwrap();
+            let child_node = children[flex_child.child_index];
             let mut child = child_node.layout_node().unit();
             let child_style = child_node.style();
 
             // A. If the item has a definite used flex basis, that’s the flex base size.
 
-            let flex_basis = child_style
-                .flex_basis()
-                .resolve(requested_inner_size.main_size(dir), child_node);
+            let flex_basis = if child_style.flex_basis().is_intrinsic() {
+                child.resolve_intrinsic_length(
+                    child_node,
+                    child_style.flex_basis(),
+                    dir,
+                    requested_inner_size.main_size(dir),
+                    available_space.main_size(dir) - flex_child.margin.main_axis_sum(dir),
+                    T::Length::zero(),
+                )
+            } else {
+                child_style
+                    .flex_basis()
+                    .resolve(requested_inner_size.main_size(dir), child_node)
+            };
 
             if flex_basis.is_some() {
                 flex_child.flex_basis = flex_basis.or_zero();
@@ -425,6 +472,7 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
                         kind: main_size_request_kind.shift_to_all_size(),
                         parent_is_block: false,
                         sizing_mode: request.sizing_mode,
+                        line_clamp: None,
                     },
                 )
                 .size
@@ -554,7 +602,7 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
             let available = inner_max_content.main_size(dir).val();
             let min_content_size = compute_min_content_main_size::<T>(
                 env,
-                node,
+                &children,
                 &flex_lines,
                 dir,
                 main_size_request_kind,
@@ -608,10 +656,7 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
             //      smaller than its hypothetical main size
 
             for flex_child in line.items.iter_mut() {
-                let child_node = node
-                    .tree_visitor()
-                    .child_at(flex_child.child_index)
-                    .unwrap();
+                let child_node = children[flex_child.child_index];
                 let child_style = child_node.style();
                 let hypothetical_inner_main_size =
                     flex_child.hypothetical_inner_size.main_size(dir);
@@ -852,10 +897,7 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
         let flex_wrap = style.flex_wrap() == FlexWrap::Wrap;
         for line in &mut flex_lines {
             for flex_child in line.items.iter_mut() {
-                let child_node = node
-                    .tree_visitor()
-                    .child_at(flex_child.child_index)
-                    .unwrap();
+                let child_node = children[flex_child.child_index];
                 let mut child = child_node.layout_node().unit();
 
                 let mut size = size_to_option(flex_child.target_size);
@@ -910,6 +952,7 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
                         ),
                         parent_is_block: false,
                         sizing_mode: request.sizing_mode,
+                        line_clamp: None,
                     },
                 );
                 flex_child
@@ -1083,6 +1126,7 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
                 first_baseline_ascent: Vector::zero(),
                 last_baseline_ascent: Vector::zero(),
                 collapsed_margin,
+                line_count: 0,
             };
             self.cache.write_all_size(node, &request, ret);
             return ret;
@@ -1118,10 +1162,7 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
         for line in &mut flex_lines {
             let line_cross_size = line.cross_size;
             for flex_child in line.items.iter_mut() {
-                let child_node = node
-                    .tree_visitor()
-                    .child_at(flex_child.child_index)
-                    .unwrap();
+                let child_node = children[flex_child.child_index];
                 let mut child = child_node.layout_node().unit();
 
                 let child_margin_cross = flex_child.margin.cross_axis_sum(dir);
@@ -1153,6 +1194,7 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
                                 kind: ComputeRequestKind::Position,
                                 parent_is_block: false,
                                 sizing_mode: request.sizing_mode,
+                                line_clamp: None,
                             },
                         );
                     }
@@ -1353,7 +1395,12 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
                         AlignSelf::FlexStart | AlignSelf::Start | AlignSelf::SelfStart => {
                             T::Length::zero()
                         }
-                        AlignSelf::FlexEnd | AlignSelf::End | AlignSelf::SelfEnd => free_space,
+                        // last-baseline alignment is not supported in flex lines,
+                        // so its fallback alignment (`end`) is used
+                        AlignSelf::FlexEnd
+                        | AlignSelf::End
+                        | AlignSelf::SelfEnd
+                        | AlignSelf::LastBaseline => free_space,
                         AlignSelf::Center => free_space.div_i32(2),
                         AlignSelf::Baseline => {
                             max_baseline_margin = max_baseline_margin.max(
@@ -1431,10 +1478,7 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
                 total_offset_cross += line.extra_offset_cross;
 
                 for flex_child in line.items.iter_mut() {
-                    let child_node = node
-                        .tree_visitor()
-                        .child_at(flex_child.child_index)
-                        .unwrap();
+                    let child_node = children[flex_child.child_index];
                     let mut child = child_node.layout_node().unit();
 
                     let offset_main = total_offset_main
@@ -1494,6 +1538,7 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
             last_baseline_ascent: self_last_baseline_ascent
                 .unwrap_or_else(|| container_size.to_vector()),
             collapsed_margin,
+            line_count: 0,
         };
         compute_special_position_children(
             env,
@@ -1524,16 +1569,18 @@ impl<T: LayoutTreeNode> FlexBox<T> for LayoutUnit<T> {
 ///
 #[inline]
 fn generate_anonymous_flex_items<T: LayoutTreeNode>(
-    node: &T,
+    children: &[&T],
     style: &T::Style,
     inner_size: &Normalized<OptionSize<T::Length>>,
     dir: AxisDirection,
 ) -> Vec<FlexItem<T>> {
-    let mut flex_items: Vec<FlexItem<T>> = Vec::with_capacity(node.tree_visitor().children_len());
+    let mut flex_items: Vec<FlexItem<T>> = Vec::with_capacity(children.len());
     let mut needs_sort = false;
     let mut prev_order = i32::MIN;
-    node.tree_visitor()
-        .for_each_child(|child_node, child_index| {
+    children
+        .iter()
+        .enumerate()
+        .for_each(|(child_index, &child_node)| {
             if is_independent_positioning(child_node) {
                 return;
             }
@@ -0,0 +1,368 @@
+//! Float placement and clearance in block flow layout.
+//!
+//! CSS 2.1 §9.5 Floats
+//! <https://www.w3.org/TR/CSS2/visuren.html#floats>
+//!
+//! The floats belong to the block formatting context, i.e. a float affects:
+//! * the following floats in the same BFC;
+//! * the line boxes of the inline content in the same BFC (as `InlineExclusion`s);
+//! * the BFC-establishing blocks in the same BFC, which are placed beside or below the floats;
+//! * the blocks with `clear` in the same BFC.
+//!
+//! The context of the BFC root is handed to its non-BFC block descendants (see `FloatContext::nested`),
+//! and the floats placed inside them are handed back (see `FloatContext::merge_nested`).
+//! A nested block is given the floats at its hypothetical position,
+//! i.e. the margins collapsed through its own first child are not counted.
+//!
+//! All offsets here are relative to the content box of the container,
+//! in the main (block) axis and the non-reversed cross (inline) axis.
+
+use crate::*;
+
+/// Whether the node is a float box.
+///
+/// The `float` property does not apply to the absolutely positioned boxes.
+#[inline]
+pub(crate) fn is_float<T: LayoutTreeNode>(style: &T::Style) -> bool {
+    style.float() != Float::None && !is_out_of_flow::<T>(style)
+}
+
+/// CSS Logical Properties §3.1: resolve `float` to the line-left or line-right side.
+#[inline]
+pub(crate) fn float_side(float: Float, direction: &Direction) -> Option<FloatSide> {
+    let rtl = *direction == Direction::RTL;
+    match float {
+        Float::None => None,
+        Float::Left => Some(FloatSide::Start),
+        Float::Right => Some(FloatSide::End),
+        Float::InlineStart if rtl => Some(FloatSide::End),
+        Float::InlineStart => Some(FloatSide::Start),
+        Float::InlineEnd if rtl => Some(FloatSide::Start),
+        Float::InlineEnd => Some(FloatSide::End),
+    }
+}
+
+/// Resolve `clear` to whether the line-left floats and the line-right floats are cleared.
+#[inline]
+pub(crate) fn clear_sides(clear: Clear, direction: &Direction) -> (bool, bool) {
+    let rtl = *direction == Direction::RTL;
+    match clear {
+        Clear::None => (false, false),
+        Clear::Left => (true, false),
+        Clear::Right => (false, true),
+        Clear::Both => (true, true),
+        Clear::InlineStart => (!rtl, rtl),
+        Clear::InlineEnd => (rtl, !rtl),
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PlacedFloat<L: LengthNum> {
+    side: FloatSide,
+    main_start: L,
+    main_end: L,
+    /// The distance between the container edge of `side` and the margin box.
+    offset: L,
+    cross_size: L,
+}
+
+impl<L: LengthNum> PlacedFloat<L> {
+    fn inset(&self) -> L {
+        self.offset + self.cross_size
+    }
+
+    fn overlaps(&self, main_start: L, main_end: L) -> bool {
+        if main_end > main_start {
+            self.main_start < main_end && self.main_end > main_start
+        } else {
+            self.main_start <= main_start && self.main_end > main_start
+        }
+    }
+}
+
+/// The floats placed in a block container.
+#[derive(Debug, Clone)]
+pub(crate) struct FloatContext<L: LengthNum> {
+    inline_size: OptionNum<L>,
+    floats: Vec<PlacedFloat<L>>,
+    /// CSS 2.1 §9.5.1 rule 5: the outer top of a float may not be higher than
+    /// the outer top of any earlier float.
+    min_main_start: L,
+    /// The floats before this index are placed by the ancestors, and are not handed back.
+    nested_from: usize,
+}
+
+impl<L: LengthNum> FloatContext<L> {
+    pub(crate) fn new(inline_size: OptionNum<L>) -> Self {
+        Self {
+            inline_size,
+            floats: vec![],
+            min_main_start: L::zero(),
+            nested_from: 0,
+        }
+    }
+
+    /// The context seen by a nested non-BFC block,
+    /// whose content box starts at `main_offset` and `cross_start` in this container.
+    ///
+    /// The floats which end above the content box are dropped, since they do not affect the nested block.
+    /// If the result is empty, the nested block can be laid out (and cached) on its own.
+    pub(crate) fn nested(&self, main_offset: L, cross_start: L, inline_size: OptionNum<L>) -> Self {
+        let cross_end = self.inline_size.or_zero() - cross_start - inline_size.or_zero();
+        let floats: Vec<_> = self
+            .floats
+            .iter()
+            .filter(|f| f.main_end > main_offset)
+            .map(|f| PlacedFloat {
+                side: f.side,
+                main_start: f.main_start - main_offset,
+                main_end: f.main_end - main_offset,
+                offset: match f.side {
+                    FloatSide::Start => f.offset - cross_start,
+                    FloatSide::End => f.offset - cross_end,
+                },
+                cross_size: f.cross_size,
+            })
+            .collect();
+        let nested_from = floats.len();
+        Self {
+            inline_size,
+            floats,
+            min_main_start: self.min_main_start - main_offset,
+            nested_from,
+        }
+    }
+
+    /// Take back the floats placed in a nested non-BFC block (see `nested`).
+    ///
+    /// The content box of the nested block starts at `main_offset` and `cross_start` in this container.
+    pub(crate) fn merge_nested(&mut self, nested: Self, main_offset: L, cross_start: L) {
+        if !nested.has_nested_floats() {
+            return;
+        }
+        let cross_end = self.inline_size.or_zero() - cross_start - nested.inline_size.or_zero();
+        self.floats.extend(
+            nested.floats[nested.nested_from..]
+                .iter()
+                .map(|f| PlacedFloat {
+                    side: f.side,
+                    main_start: f.main_start + main_offset,
+                    main_end: f.main_end + main_offset,
+                    offset: match f.side {
+                        FloatSide::Start => f.offset + cross_start,
+                        FloatSide::End => f.offset + cross_end,
+                    },
+                    cross_size: f.cross_size,
+                }),
+        );
+        self.min_main_start = self.min_main_start.max(nested.min_main_start + main_offset);
+    }
+
+    /// Whether any float is placed in this container (rather than by the ancestors).
+    #[inline]
+    pub(crate) fn has_nested_floats(&self) -> bool {
+        self.floats.len() > self.nested_from
+    }
+
+    /// Keep the floats placed so far in this container, i.e. they are not handed back to the ancestors.
+    #[inline]
+    pub(crate) fn keep_placed(&mut self) {
+        self.nested_from = self.floats.len();
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.floats.is_empty()
+    }
+
+    /// The space taken by the floats on both sides, in the band `[main_start, main_end)`.
+    pub(crate) fn insets(&self, main_start: L, main_end: L) -> (L, L) {
+        let mut start = L::zero();
+        let mut end = L::zero();
+        for f in self.floats.iter() {
+            if !f.overlaps(main_start, main_end) {
+                continue;
+            }
+            match f.side {
+                FloatSide::Start => start = start.max(f.inset()),
+                FloatSide::End => end = end.max(f.inset()),
+            }
+        }
+        (start, end)
+    }
+
+    /// The nearest outer bottom of the floats overlapping the band, which is
+    /// the next position worth trying when something does not fit beside the floats.
+    pub(crate) fn next_main_end(&self, main_start: L, main_end: L) -> Option<L> {
+        self.floats
+            .iter()
+            .filter(|f| f.overlaps(main_start, main_end))
+            .map(|f| f.main_end)
+            .fold(None, |acc: Option<L>, x| {
+                Some(acc.map_or(x, |acc| acc.min(x)))
+            })
+    }
+
+    /// Whether a box of `cross_size` fits beside the floats in the band.
+    fn fits(&self, main_start: L, main_end: L, cross_size: L) -> bool {
+        let (start, end) = self.insets(main_start, main_end);
+        if start.is_zero() && end.is_zero() {
+            return true;
+        }
+        match self.inline_size.val() {
+            Some(inline_size) => start + end + cross_size <= inline_size,
+            None => true,
+        }
+    }
+
+    /// CSS 2.1 §9.5.1: place a float with the specified margin box size,
+    /// as high as possible but not higher than `main_min`.
+    ///
+    /// Returns the main offset and the distance between the container edge of `side` and the margin box.
+    pub(crate) fn place(
+        &mut self,
+        side: FloatSide,
+        main_min: L,
+        main_size: L,
+        cross_size: L,
+    ) -> (L, L) {
+        let mut main = main_min.max(self.min_main_start);
+        while !self.fits(main, main + main_size, cross_size) {
+            match self.next_main_end(main, main + main_size) {
+                Some(x) => main = x,
+                None => break,
+            }
+        }
+        let (start, end) = self.insets(main, main + main_size);
+        let offset = match side {
+            FloatSide::Start => start,
+            FloatSide::End => end,
+        };
+        self.floats.push(PlacedFloat {
+            side,
+            main_start: main,
+            main_end: main + main_size,
+            offset,
+            cross_size,
+        });
+        self.min_main_start = main;
+        (main, offset)
+    }
+
+    /// CSS 2.1 §9.5.2: the position below the outer bottom of the cleared floats.
+    pub(crate) fn clearance_edge(&self, clear_start: bool, clear_end: bool) -> Option<L> {
+        self.floats
+            .iter()
+            .filter(|f| match f.side {
+                FloatSide::Start => clear_start,
+                FloatSide::End => clear_end,
+            })
+            .map(|f| f.main_end)
+            .fold(None, |acc: Option<L>, x| {
+                Some(acc.map_or(x, |acc| acc.max(x)))
+            })
+    }
+
+    /// The outer bottom of all floats.
+    ///
+    /// CSS 2.1 §10.6.7: the auto height of a BFC root grows to contain its floats.
+    pub(crate) fn main_end(&self) -> L {
+        self.floats
+            .iter()
+            .fold(L::zero(), |acc, f| acc.max(f.main_end))
+    }
+
+    /// The cross offset of the margin box of a placed float.
+    pub(crate) fn cross_offset(&self, side: FloatSide, offset: L, cross_size: L) -> L {
+        match side {
+            FloatSide::Start => offset,
+            FloatSide::End => self.inline_size.or_zero() - offset - cross_size,
+        }
+    }
+
+    /// The floats which may affect the inline content starting at `main_origin`.
+    pub(crate) fn exclusions(&self, main_origin: L, dir: AxisDirection) -> Vec<InlineExclusion<L>> {
+        if self.inline_size.is_none() {
+            return vec![];
+        }
+        self.floats
+            .iter()
+            .filter(|f| f.main_end > main_origin)
+            .map(|f| InlineExclusion {
+                rect: Rect::new(
+                    Vector::new_with_dir(
+                        dir,
+                        f.main_start - main_origin,
+                        self.cross_offset(f.side, f.offset, f.cross_size),
+                    )
+                    .to_point(),
+                    Size::new_with_dir(dir, f.main_end - f.main_start, f.cross_size),
+                ),
+                side: f.side,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn place_floats_side_by_side() {
+        let mut ctx = FloatContext::<f32>::new(OptionNum::some(100.));
+        assert_eq!(ctx.place(FloatSide::Start, 0., 20., 30.), (0., 0.));
+        assert_eq!(ctx.place(FloatSide::Start, 0., 10., 30.), (0., 30.));
+        assert_eq!(ctx.place(FloatSide::End, 0., 10., 30.), (0., 0.));
+        assert_eq!(ctx.insets(0., 10.), (60., 30.));
+        assert_eq!(ctx.insets(10., 20.), (30., 0.));
+        assert_eq!(ctx.cross_offset(FloatSide::End, 0., 30.), 70.);
+    }
+
+    #[test]
+    fn place_float_below_when_not_fit() {
+        let mut ctx = FloatContext::<f32>::new(OptionNum::some(100.));
+        ctx.place(FloatSide::Start, 0., 20., 60.);
+        assert_eq!(ctx.place(FloatSide::End, 0., 10., 50.), (20., 0.));
+        // not higher than the earlier float
+        assert_eq!(ctx.place(FloatSide::Start, 0., 10., 10.), (20., 0.));
+        assert_eq!(ctx.place(FloatSide::Start, 0., 10., 40.), (20., 10.));
+        assert_eq!(ctx.place(FloatSide::Start, 0., 10., 10.), (30., 0.));
+    }
+
+    #[test]
+    fn nested_floats() {
+        let mut ctx = FloatContext::<f32>::new(OptionNum::some(100.));
+        ctx.place(FloatSide::Start, 0., 20., 30.);
+        ctx.place(FloatSide::End, 0., 20., 20.);
+        let mut nested = ctx.nested(10., 5., OptionNum::some(80.));
+        assert_eq!(nested.insets(0., 10.), (25., 5.));
+        assert_eq!(nested.clearance_edge(true, false), Some(10.));
+        assert!(!nested.has_nested_floats());
+        assert_eq!(nested.place(FloatSide::Start, 0., 10., 20.), (0., 25.));
+        ctx.merge_nested(nested, 10., 5.);
+        assert_eq!(ctx.insets(10., 20.), (50., 20.));
+        assert_eq!(ctx.main_end(), 20.);
+        // the floats above the nested block are dropped
+        assert!(ctx.nested(30., 5., OptionNum::some(80.)).is_empty());
+    }
+
+    #[test]
+    fn clearance() {
+        let mut ctx = FloatContext::<f32>::new(OptionNum::some(100.));
+        ctx.place(FloatSide::Start, 0., 20., 10.);
+        ctx.place(FloatSide::End, 0., 50., 10.);
+        assert_eq!(ctx.clearance_edge(true, false), Some(20.));
+        assert_eq!(ctx.clearance_edge(false, true), Some(50.));
+        assert_eq!(ctx.clearance_edge(false, false), None);
+        assert_eq!(ctx.main_end(), 50.);
+        assert_eq!(
+            clear_sides(Clear::InlineEnd, &Direction::RTL),
+            (true, false)
+        );
+        assert_eq!(
+            float_side(Float::InlineStart, &Direction::RTL),
+            Some(FloatSide::End)
+        );
+    }
+}
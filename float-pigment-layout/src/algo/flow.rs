@@ -2,11 +2,24 @@ use crate::*;
 
 use float_pigment_css::num_traits::{Signed, Zero};
 
+use super::float::{clear_sides, float_side, is_float, FloatContext};
+use super::list::{marker_main_offset, marker_rect, measure_marker};
+use super::multicol::is_multicol_container;
+use super::table::{compute_anonymous_table, is_misparented_table_part, is_table_wrapper_box};
+
 enum BlockOrInlineSeries<'a, T: LayoutTreeNode> {
     Block(&'a T),
+    Float(&'a T),
+    /// An absolutely positioned child between the block-level children,
+    /// which is placed at its static position by the containing block.
+    OutOfFlow(&'a T),
+    /// CSS 2.1 §17.2.1: consecutive misparented table parts are wrapped in an anonymous table.
+    AnonymousTable(Vec<&'a T>),
     InlineSeries {
         end_nodes: Vec<&'a T>,
         middle_nodes: Vec<&'a T>,
+        /// The absolutely positioned nodes inside the inline content, after the given number of `end_nodes`.
+        out_of_flow_nodes: Vec<(usize, &'a T)>,
     },
 }
 
@@ -17,8 +30,7 @@ enum BlockOrInlineSeries<'a, T: LayoutTreeNode> {
 ///
 /// `bfc_established` short-circuits to false. It is true when the parent establishes
 /// a BFC (root element per CSS 2.1 §8.3.1, or flex/grid/inline-block/absolute/
-/// fixed/flow-root — see `establishes_bfc`). TODO: overflow≠visible, float
-/// (need LayoutStyle accessors).
+/// fixed/flow-root/float/overflow≠visible — see `establishes_bfc`).
 #[inline]
 pub(crate) fn is_margin_start_collapsible<L: LengthNum>(
     bfc_established: bool,
@@ -101,15 +113,18 @@ pub(crate) fn is_empty_block<L: LengthNum>(
 /// A BFC-establishing box's margins do NOT collapse with its parent (from the
 /// child side) nor with its in-flow children (from the parent side).
 ///
-/// Covers what the current `LayoutStyle` accessors can detect:
+/// Covers:
 /// - Root element (no parent, per CSS 2.1 §8.3.1)
 /// - flex / inline-flex containers (CSS Flexbox §3)
 /// - grid / inline-grid containers (CSS Grid Layout §3)
 /// - inline-block
 /// - `position: absolute | fixed` (out-of-flow)
 /// - `display: flow-root` (dedicated BFC marker)
-///
-/// TODO: overflow != visible, float — require new accessors on LayoutStyle.
+/// - floats (CSS 2.1 §9.4.1)
+/// - `overflow` other than `visible` (CSS 2.1 §9.4.1)
+/// - multi-column containers (CSS Multi-column §2)
+/// - orthogonal flows (CSS Writing Modes §7.3)
+/// - tables, table cells and table captions (CSS 2.1 §17.4)
 #[inline]
 pub(crate) fn establishes_bfc<T: LayoutTreeNode>(node: &T) -> bool {
     if node.tree_visitor().parent().is_none() {
@@ -124,15 +139,106 @@ pub(crate) fn establishes_bfc<T: LayoutTreeNode>(node: &T) -> bool {
             | Display::InlineGrid
             | Display::InlineBlock
             | Display::FlowRoot
+            | Display::Table
+            | Display::TableCell
+            | Display::TableCaption
     ) {
         return true;
     }
     if matches!(style.position(), Position::Absolute | Position::Fixed) {
         return true;
     }
+    if style.float() != Float::None {
+        return true;
+    }
+    if style.overflow_x() != Overflow::Visible || style.overflow_y() != Overflow::Visible {
+        return true;
+    }
+    if is_multicol_container::<T>(style) {
+        return true;
+    }
+    if let Some(parent) = node.tree_visitor().layout_parent() {
+        if is_orthogonal_flow::<T>(parent, node) {
+            return true;
+        }
+    }
     false
 }
 
+/// Whether the `child` is in an orthogonal flow, i.e. its block axis is perpendicular to the block axis of `parent`.
+///
+/// CSS Writing Modes §7.3: an orthogonal flow establishes an independent formatting context.
+#[inline]
+pub(crate) fn is_orthogonal_flow<T: LayoutTreeNode>(parent: &T, child: &T) -> bool {
+    AxisInfo::from_writing_mode(parent.style().writing_mode()).dir
+        != AxisInfo::from_writing_mode(child.style().writing_mode()).dir
+}
+
+/// The available inline size of an orthogonal flow root if it is not given by the parent,
+/// e.g. for a flex item or a grid item.
+///
+/// CSS Writing Modes §7.3: it falls back to the size of the initial containing block (the viewport),
+/// which is ignored if it is unknown, i.e. zero.
+fn orthogonal_fallback_inline_size<T: LayoutTreeNode>(
+    env: &T::Env,
+    node: &T,
+    axis_info: AxisInfo,
+) -> OptionNum<T::Length> {
+    let Some(parent) = node.tree_visitor().layout_parent() else {
+        return OptionNum::none();
+    };
+    if !is_orthogonal_flow::<T>(parent, node) {
+        return OptionNum::none();
+    }
+    let viewport_size =
+        Size::new(env.screen_width(), env.screen_height()).cross_size(axis_info.dir);
+    if viewport_size.is_positive() {
+        OptionNum::some(viewport_size)
+    } else {
+        OptionNum::none()
+    }
+}
+
+#[inline]
+fn border_box_size_or_zero<L: LengthNum>(
+    inner_size: OptionSize<L>,
+    padding_border: Edge<L>,
+) -> Size<L> {
+    let size = inner_size.or_zero();
+    Size::new(
+        size.width + padding_border.horizontal(),
+        size.height + padding_border.vertical(),
+    )
+}
+
+/// Move the in-flow children along a reversed main axis (e.g. `vertical-rl`),
+/// after the main size of `node` is resolved from its content.
+///
+/// The children are placed against the main size known before the children layout,
+/// which is zero if the main size is `auto`.
+fn adjust_reversed_main_axis<T: LayoutTreeNode>(
+    node: &T,
+    axis_info: AxisInfo,
+    placed_size: Size<T::Length>,
+    size: Size<T::Length>,
+) {
+    if axis_info.main_dir_rev != AxisReverse::Reversed {
+        return;
+    }
+    let delta = size.main_size(axis_info.dir) - placed_size.main_size(axis_info.dir);
+    if delta.is_zero() {
+        return;
+    }
+    let offset = Vector::new_with_dir(axis_info.dir, delta, T::Length::zero());
+    node.tree_visitor().for_each_layout_child(|child_node, _| {
+        let child_style = child_node.style();
+        if is_display_none::<T>(child_style) || is_out_of_flow::<T>(child_style) {
+            return;
+        }
+        child_node.layout_node().unit().result.origin += offset;
+    });
+}
+
 fn for_each_block_or_inline_series<T: LayoutTreeNode>(
     env: &mut T::Env,
     node: &T,
@@ -140,16 +246,57 @@ fn for_each_block_or_inline_series<T: LayoutTreeNode>(
 ) {
     let mut end_nodes = vec![];
     let mut middle_nodes = vec![];
+    let mut out_of_flow_nodes = vec![];
+    let mut table_nodes = vec![];
     fn search_inline_rec<'a, T: LayoutTreeNode>(
         env: &mut T::Env,
         node: &'a T,
         f: &mut impl FnMut(&mut T::Env, BlockOrInlineSeries<T>),
         end_nodes: &mut Vec<&'a T>,
         middle_nodes: &mut Vec<&'a T>,
+        out_of_flow_nodes: &mut Vec<(usize, &'a T)>,
+        table_nodes: &mut Vec<&'a T>,
     ) {
-        node.tree_visitor().for_each_child(|child_node, _| {
+        node.tree_visitor().for_each_layout_child(|child_node, _| {
             let child_style = child_node.style();
-            if is_display_none::<T>(child_style) || is_out_of_flow::<T>(child_style) {
+            if is_display_none::<T>(child_style) {
+                return;
+            }
+            if is_out_of_flow::<T>(child_style) {
+                // the static position is decided by the content before it (CSS 2.1 §10.3.7, §10.6.4)
+                if child_style.position() == Position::Absolute && table_nodes.is_empty() {
+                    if end_nodes.is_empty() {
+                        f(env, BlockOrInlineSeries::OutOfFlow(child_node));
+                    } else {
+                        out_of_flow_nodes.push((end_nodes.len(), child_node));
+                    }
+                }
+                return;
+            }
+            if is_misparented_table_part::<T>(child_style) {
+                if !end_nodes.is_empty() {
+                    f(
+                        env,
+                        BlockOrInlineSeries::InlineSeries {
+                            end_nodes: core::mem::take(end_nodes),
+                            middle_nodes: core::mem::take(middle_nodes),
+                            out_of_flow_nodes: core::mem::take(out_of_flow_nodes),
+                        },
+                    );
+                }
+                table_nodes.push(child_node);
+                return;
+            }
+            if !table_nodes.is_empty() {
+                f(
+                    env,
+                    BlockOrInlineSeries::AnonymousTable(core::mem::take(table_nodes)),
+                );
+            }
+            // Floats are taken out of the inline series without breaking it,
+            // so they are placed before the line boxes that flow around them.
+            if is_float::<T>(child_style) {
+                f(env, BlockOrInlineSeries::Float(child_node));
                 return;
             }
             match child_style.display() {
@@ -157,7 +304,15 @@ fn for_each_block_or_inline_series<T: LayoutTreeNode>(
                     if child_node.should_measure(env) {
                         end_nodes.push(child_node);
                     } else {
-                        search_inline_rec(env, child_node, f, end_nodes, middle_nodes);
+                        search_inline_rec(
+                            env,
+                            child_node,
+                            f,
+                            end_nodes,
+                            middle_nodes,
+                            out_of_flow_nodes,
+                            table_nodes,
+                        );
                         middle_nodes.push(child_node);
                     }
                 }
@@ -167,37 +322,97 @@ fn for_each_block_or_inline_series<T: LayoutTreeNode>(
                 // CSS Display 3 §2.7: flow-root is block-level (block
                 // container box) and participates in block flow like Block.
                 // Its BFC semantics are handled by establishes_bfc().
-                Display::Block | Display::Flex | Display::Grid | Display::FlowRoot => {
+                Display::Block
+                | Display::ListItem
+                | Display::Flex
+                | Display::Grid
+                | Display::FlowRoot
+                | Display::Table
+                | Display::TableColumn
+                | Display::TableCaption => {
                     if !end_nodes.is_empty() {
                         f(
                             env,
                             BlockOrInlineSeries::InlineSeries {
                                 end_nodes: core::mem::take(end_nodes),
                                 middle_nodes: core::mem::take(middle_nodes),
+                                out_of_flow_nodes: core::mem::take(out_of_flow_nodes),
                             },
                         );
                     }
                     f(env, BlockOrInlineSeries::Block(child_node));
                 }
-                Display::None => unreachable!(),
+                Display::None
+                | Display::Contents
+                | Display::TableRow
+                | Display::TableCell
+                | Display::TableRowGroup
+                | Display::TableHeaderGroup => unreachable!(),
             }
         });
     }
-    search_inline_rec(env, node, &mut f, &mut end_nodes, &mut middle_nodes);
+    search_inline_rec(
+        env,
+        node,
+        &mut f,
+        &mut end_nodes,
+        &mut middle_nodes,
+        &mut out_of_flow_nodes,
+        &mut table_nodes,
+    );
+    if !table_nodes.is_empty() {
+        f(env, BlockOrInlineSeries::AnonymousTable(table_nodes));
+    }
     f(
         env,
         BlockOrInlineSeries::InlineSeries {
             end_nodes,
             middle_nodes,
+            out_of_flow_nodes,
         },
     );
 }
 
+/// The number of in-flow block children and non-empty inline series, in which the line boxes are counted.
+fn in_flow_series_count<T: LayoutTreeNode>(env: &mut T::Env, node: &T) -> usize {
+    let mut count = 0;
+    for_each_block_or_inline_series(env, node, |_, series| match series {
+        BlockOrInlineSeries::Block(_) | BlockOrInlineSeries::AnonymousTable(_) => count += 1,
+        BlockOrInlineSeries::InlineSeries { end_nodes, .. } if !end_nodes.is_empty() => count += 1,
+        _ => {}
+    });
+    count
+}
+
+/// The line clamp of a block container, which is the tighter one of its own and the inherited one.
+#[inline]
+fn effective_line_clamp<T: LayoutTreeNode>(
+    node: &T,
+    inherited: Option<InlineLineClamp>,
+) -> Option<InlineLineClamp> {
+    let own = node
+        .style()
+        .line_clamp()
+        .filter(|x| *x > 0)
+        .map(|max_lines| InlineLineClamp {
+            max_lines,
+            has_following_content: false,
+        });
+    match (inherited, own) {
+        (Some(inherited), Some(own)) if own.max_lines < inherited.max_lines => Some(own),
+        (Some(inherited), _) => Some(inherited),
+        (None, own) => own,
+    }
+}
+
 pub(crate) struct BlockOrInlineSeriesComputeResult<L: LengthNum> {
     pub(crate) size: Size<L>,
     pub(crate) first_baseline_ascent_option: Option<Vector<L>>,
     pub(crate) last_baseline_ascent_option: Option<Vector<L>>,
     pub(crate) collapsed_margin: CollapsedBlockMargin<L>,
+    pub(crate) marker: Option<MeasureResult<L>>,
+    pub(crate) line_count: u32,
+    pub(crate) float_context: FloatContext<L>,
 }
 
 pub(crate) trait Flow<T: LayoutTreeNode> {
@@ -222,6 +437,7 @@ pub(crate) trait Flow<T: LayoutTreeNode> {
         min_max_limit: MinMaxLimit<T::Length>,
         padding_border: Edge<T::Length>,
         margin: EdgeOption<T::Length>,
+        inherited_floats: Option<&FloatContext<T::Length>>,
     ) -> BlockOrInlineSeriesComputeResult<T::Length>;
 }
 
@@ -239,6 +455,12 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
         if let Some(x) = self.is_requested_size_fixed(&request, None) {
             return x;
         }
+        // CSS 2.1 §9.5: a non-BFC block is laid out with the floats of the enclosing BFC
+        let establishes_bfc = establishes_bfc::<T>(node);
+        let inherited_floats = self
+            .float_context
+            .take()
+            .filter(|_| request.parent_is_block && !establishes_bfc);
         let style = node.style();
         let axis_info = AxisInfo::from_writing_mode(style.writing_mode());
         let node_size = request.size.0;
@@ -257,7 +479,9 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                 axis_info.dir,
                 node_inner_size
                     .cross_size(axis_info.dir)
-                    .or(request.max_content.cross_size(axis_info.dir)),
+                    .or(request.max_content.cross_size(axis_info.dir))
+                    .or(orthogonal_fallback_inline_size(env, node, axis_info)
+                        - padding_border.cross_axis_sum(axis_info.dir)),
             ),
         );
         // if cross size is infinite, should get the max cross size first
@@ -276,6 +500,7 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                     min_max_limit,
                     padding_border,
                     margin,
+                    inherited_floats.as_ref(),
                 )
                 .size
                 .cross_size(axis_info.dir);
@@ -294,6 +519,7 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
             min_max_limit,
             padding_border,
             margin,
+            inherited_floats.as_ref(),
         );
         let total_main_size = || {
             compute_res.size.main_size(axis_info.dir) + padding_border.main_axis_sum(axis_info.dir)
@@ -355,9 +581,35 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
             first_baseline_ascent,
             last_baseline_ascent,
             collapsed_margin: compute_res.collapsed_margin,
+            line_count: compute_res.line_count,
         };
 
         if request.kind == ComputeRequestKind::Position {
+            adjust_reversed_main_axis(
+                node,
+                axis_info,
+                border_box_size_or_zero(node_inner_size, padding_border),
+                ret.size.0,
+            );
+            let list_style_position = style.list_style_position();
+            self.result_marker_rect = compute_res.marker.map(|marker| {
+                let main_offset = marker_main_offset(
+                    axis_info,
+                    list_style_position.clone(),
+                    padding_border,
+                    compute_res.first_baseline_ascent_option,
+                    &marker,
+                );
+                marker_rect(
+                    axis_info,
+                    float_side(Float::InlineStart, &style.direction()).unwrap_or(FloatSide::Start),
+                    list_style_position.clone(),
+                    ret.size.0,
+                    padding_border,
+                    marker.size,
+                    main_offset,
+                )
+            });
             compute_special_position_children(
                 env,
                 node,
@@ -371,6 +623,17 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                 false,
             );
             self.result = Rect::new(Point::zero(), ret.size.0);
+        }
+
+        // the floats placed here are handed back to the parent, which is in the same BFC
+        if request.parent_is_block
+            && !establishes_bfc
+            && (inherited_floats.is_some() || compute_res.float_context.has_nested_floats())
+        {
+            self.float_context = Some(compute_res.float_context);
+            self.cache.touch(node);
+            self.cache.clear_position_cache();
+        } else if request.kind == ComputeRequestKind::Position {
             self.cache.write_position(node, &request, ret);
         } else {
             self.cache.write_all_size(node, &request, ret);
@@ -389,6 +652,7 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
         min_max_limit: MinMaxLimit<T::Length>,
         padding_border: Edge<T::Length>,
         margin: EdgeOption<T::Length>,
+        inherited_floats: Option<&FloatContext<T::Length>>,
     ) -> BlockOrInlineSeriesComputeResult<T::Length> {
         let mut total_main_size = T::Length::zero();
         let mut max_cross_size = T::Length::zero();
@@ -399,9 +663,39 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
         // The parent-side of BFC-based margin isolation: a BFC-establishing
         // parent does not collapse with its in-flow children. See
         // `establishes_bfc` for what counts as BFC in this engine.
-        // TODO: overflow != visible, float (need new LayoutStyle accessors).
         let bfc_established = establishes_bfc::<T>(node);
 
+        // the origins are relative to the border box, so a reversed main axis starts from its end
+        let origin_base_size = border_box_size_or_zero(node_inner_size, padding_border);
+
+        let direction = node.style().direction();
+        let mut float_context = match inherited_floats {
+            Some(x) => x.clone(),
+            None => FloatContext::new(node_inner_size.cross_size(axis_info.dir)),
+        };
+
+        // CSS Lists §3.5: an `inside` marker is the first inline box of the list item,
+        // so it takes the start of the first lines like a start float.
+        let marker = measure_marker(env, node, self.list_item_ordinal);
+        let inside_marker = marker
+            .filter(|_| node.style().list_style_position() == ListStylePosition::Inside)
+            .map(|marker| {
+                Size::new_with_dir(
+                    axis_info.dir,
+                    marker.size.main_size(axis_info.dir),
+                    marker.size.cross_size(axis_info.dir),
+                )
+            });
+        if let Some(marker_size) = inside_marker {
+            float_context.place(
+                float_side(Float::InlineStart, &direction).unwrap_or(FloatSide::Start),
+                T::Length::zero(),
+                marker_size.main_size(axis_info.dir),
+                marker_size.cross_size(axis_info.dir),
+            );
+            float_context.keep_placed();
+        }
+
         let parent_margin_start_collapsible = is_margin_start_collapsible(
             bfc_established,
             request.parent_is_block,
@@ -415,6 +709,29 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
         );
         let mut prev_sibling_collapsed_margin: Option<(CollapsedMargin<T::Length>, bool)> = None;
 
+        // CSS Overflow 4 §4.1: the content after the last shown line box of a clamped block container
+        // is hidden, including the line boxes in the nested block containers of the same BFC.
+        // The hidden content is still positioned, but does not contribute to the size.
+        let line_clamp = effective_line_clamp(node, request.line_clamp);
+        let in_flow_series_count = if line_clamp.is_some() {
+            in_flow_series_count(env, node)
+        } else {
+            0
+        };
+        let remaining_line_clamp = |line_count: u32, series_index: usize| {
+            line_clamp.and_then(|clamp| {
+                let max_lines = clamp.max_lines.saturating_sub(line_count);
+                (max_lines > 0).then_some(InlineLineClamp {
+                    max_lines,
+                    has_following_content: clamp.has_following_content
+                        || series_index + 1 < in_flow_series_count,
+                })
+            })
+        };
+        let mut line_count = 0;
+        let mut series_index = 0;
+        let mut clamped_state = None;
+
         for_each_block_or_inline_series(env, node, |env, block_or_inline_series| {
             match block_or_inline_series {
                 // for child block
@@ -435,8 +752,17 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                         child_border,
                         child_padding_border,
                     );
-                    let stretched_cross_size = node_inner_size.cross_size(axis_info.dir)
-                        - child_margin.cross_axis_sum(axis_info.dir);
+                    // CSS Writing Modes §7.3: the inline axis of an orthogonal child is the block axis
+                    // of this node, so it is not stretched, and fits into the available block space.
+                    // CSS 2.1 §17.4: tables are shrink-to-fit and not stretched either.
+                    let orthogonal = is_orthogonal_flow::<T>(node, child_node);
+                    let stretched_cross_size =
+                        if orthogonal || is_table_wrapper_box::<T>(child_node.style()) {
+                            OptionNum::none()
+                        } else {
+                            node_inner_size.cross_size(axis_info.dir)
+                                - child_margin.cross_axis_sum(axis_info.dir)
+                        };
                     let aspect_ratio = child_node.style().aspect_ratio();
                     let has_aspect_ratio = aspect_ratio.is_some() && aspect_ratio.unwrap() > 0.;
                     if has_aspect_ratio {
@@ -515,6 +841,7 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                             }
                         }
                     }
+                    let cross_size_is_auto = css_size.cross_size(axis_info.dir).is_none();
                     css_size.set_cross_size(
                         axis_info.dir,
                         css_size.cross_size(axis_info.dir).or(stretched_cross_size),
@@ -527,8 +854,90 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                             .or(request.max_content.cross_size(axis_info.dir)
                                 - child_margin.cross_axis_sum(axis_info.dir)),
                     );
+                    if orthogonal {
+                        // CSS Writing Modes §7.3: the available inline space of an orthogonal flow
+                        // is the block size of this node, limited by the initial containing block (the viewport)
+                        // (the viewport is ignored if it is unknown, i.e. zero)
+                        let viewport_size = Size::new(env.screen_width(), env.screen_height())
+                            .main_size(axis_info.dir);
+                        let available_size = if viewport_size.is_positive() {
+                            OptionNum::some(match node_inner_size.main_size(axis_info.dir).val() {
+                                Some(x) => x.min(viewport_size),
+                                None => viewport_size,
+                            })
+                        } else {
+                            node_inner_size.main_size(axis_info.dir)
+                        };
+                        max_content.set_main_size(
+                            axis_info.dir,
+                            size.main_size(axis_info.dir)
+                                .or(available_size - child_margin.main_axis_sum(axis_info.dir)),
+                        );
+                    }
                     let max_content = min_max_limit.normalized_size(max_content);
-                    let child_res = child.compute_internal(
+                    // the line boxes inside an independent formatting context are not counted
+                    let child_line_clamp = if orthogonal || establishes_bfc::<T>(child_node) {
+                        None
+                    } else {
+                        remaining_line_clamp(line_count, series_index)
+                    };
+                    // CSS 2.1 §9.5: the floats are shared with a non-BFC child, placed at its
+                    // hypothetical position (its own first child margin is not collapsed here)
+                    let (clear_start, clear_end) =
+                        clear_sides(child_node.style().clear(), &direction);
+                    let child_establishes_bfc = establishes_bfc::<T>(child_node);
+                    let child_cross_start = if child_margin
+                        .cross_axis_start(axis_info.dir, AxisReverse::NotReversed)
+                        .is_none()
+                        && child_margin
+                            .cross_axis_end(axis_info.dir, AxisReverse::NotReversed)
+                            .is_none()
+                    {
+                        (node_inner_size.cross_size(axis_info.dir) - size.cross_size(axis_info.dir))
+                            .or_zero()
+                            .div_i32(2)
+                    } else {
+                        child_margin
+                            .cross_axis_start(axis_info.dir, AxisReverse::NotReversed)
+                            .or_zero()
+                    } + child_padding_border
+                        .cross_axis_start(axis_info.dir, AxisReverse::NotReversed);
+                    if !child_establishes_bfc && !float_context.is_empty() {
+                        let child_margin_start = CollapsedMargin::new(
+                            child_margin
+                                .main_axis_start(axis_info.dir, axis_info.main_dir_rev)
+                                .or_zero(),
+                        );
+                        let mut border_box_start = total_main_size
+                            + match prev_sibling_collapsed_margin {
+                                Some((prev_margin, collapsed_through)) => {
+                                    let adjoined = prev_margin.adjoin(&child_margin_start).solve();
+                                    if collapsed_through {
+                                        adjoined - prev_margin.solve()
+                                    } else {
+                                        adjoined
+                                    }
+                                }
+                                None if parent_margin_start_collapsible => T::Length::zero(),
+                                None => child_margin_start.solve(),
+                            };
+                        if let Some(edge) = float_context.clearance_edge(clear_start, clear_end) {
+                            border_box_start = border_box_start.max(edge);
+                        }
+                        // a child below all the floats is not given the context, so that it is still cached
+                        let nested = float_context.nested(
+                            border_box_start
+                                + child_padding_border
+                                    .main_axis_start(axis_info.dir, axis_info.main_dir_rev),
+                            child_cross_start,
+                            size.cross_size(axis_info.dir)
+                                - child_padding_border.cross_axis_sum(axis_info.dir),
+                        );
+                        if !nested.is_empty() {
+                            child.float_context = Some(nested);
+                        }
+                    }
+                    let mut child_res = child.compute_internal(
                         env,
                         child_node,
                         ComputeRequest {
@@ -538,8 +947,10 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                             kind: request.kind.shift_to_all_size(),
                             parent_is_block: true,
                             sizing_mode: request.sizing_mode,
+                            line_clamp: child_line_clamp,
                         },
                     );
+                    let nested_floats = child.float_context.take();
                     let mut main_offset = padding_border
                         .main_axis_start(axis_info.dir, axis_info.main_dir_rev)
                         + total_main_size;
@@ -550,7 +961,14 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                     // margins as direct offsets, and seed the chain with a
                     // zero sentinel so the next non-BFC sibling takes the
                     // sibling-collapse branch (not the first-child branch).
-                    let child_establishes_bfc = establishes_bfc::<T>(child_node);
+                    if orthogonal {
+                        // the collapsed margins of an orthogonal child are in its own block axis
+                        let child_margin = child_margin.or_zero();
+                        child_res.collapsed_margin = CollapsedBlockMargin::from_margin(
+                            child_margin.main_axis_start(axis_info.dir, axis_info.main_dir_rev),
+                            child_margin.main_axis_end(axis_info.dir, axis_info.main_dir_rev),
+                        );
+                    }
                     if child_establishes_bfc {
                         // 1) Settle prior sibling collapse chain into offsets.
                         if let Some((prev_sibling_margin, _prev_sibling_collapsed_through)) =
@@ -647,39 +1065,141 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                             total_main_size += current_collapsed_margin.solve();
                         }
                     }
+                    let padding_border_main_start =
+                        padding_border.main_axis_start(axis_info.dir, axis_info.main_dir_rev);
+                    // CSS 2.1 §9.5.2: clearance moves the border box below the cleared floats.
+                    if let Some(edge) = float_context.clearance_edge(clear_start, clear_end) {
+                        let border_box_start = main_offset - padding_border_main_start;
+                        if border_box_start < edge {
+                            main_offset += edge - border_box_start;
+                            total_main_size += edge - border_box_start;
+                        }
+                    }
+                    if let Some(nested_floats) = nested_floats {
+                        float_context.merge_nested(
+                            nested_floats,
+                            main_offset - padding_border_main_start
+                                + child_padding_border
+                                    .main_axis_start(axis_info.dir, axis_info.main_dir_rev),
+                            child_cross_start,
+                        );
+                    }
+                    // CSS 2.1 §9.5: the border box of a BFC-establishing block must not
+                    // overlap the margin boxes of the floats. It is narrowed to fit beside
+                    // the floats if its width is auto, or moved below them if it does not fit.
+                    let mut float_insets = (T::Length::zero(), T::Length::zero());
+                    if child_establishes_bfc && !float_context.is_empty() {
+                        if let Some(inline_size) = node_inner_size.cross_size(axis_info.dir).val() {
+                            let origin_border_box_start = main_offset - padding_border_main_start;
+                            let mut border_box_start = origin_border_box_start;
+                            loop {
+                                let border_box_end =
+                                    border_box_start + child_res.size.main_size(axis_info.dir);
+                                let (start, end) =
+                                    float_context.insets(border_box_start, border_box_end);
+                                if start.is_zero() && end.is_zero() {
+                                    break;
+                                }
+                                let available = inline_size
+                                    - start
+                                    - end
+                                    - child_margin.cross_axis_sum(axis_info.dir);
+                                if cross_size_is_auto && available > T::Length::zero() {
+                                    let mut size = css_size;
+                                    size.set_cross_size(axis_info.dir, OptionNum::some(available));
+                                    let mut max_content = *max_content;
+                                    max_content
+                                        .set_cross_size(axis_info.dir, OptionNum::some(available));
+                                    child_res = child.compute_internal(
+                                        env,
+                                        child_node,
+                                        ComputeRequest {
+                                            size: min_max_limit.normalized_size(size),
+                                            parent_inner_size: Normalized(node_inner_size),
+                                            max_content: min_max_limit.normalized_size(max_content),
+                                            kind: request.kind.shift_to_all_size(),
+                                            parent_is_block: true,
+                                            sizing_mode: request.sizing_mode,
+                                            line_clamp: None,
+                                        },
+                                    );
+                                    float_insets = (start, end);
+                                    break;
+                                }
+                                if !cross_size_is_auto
+                                    && child_res.size.cross_size(axis_info.dir) <= available
+                                {
+                                    float_insets = (start, end);
+                                    break;
+                                }
+                                match float_context.next_main_end(border_box_start, border_box_end)
+                                {
+                                    Some(x) => border_box_start = x,
+                                    None => break,
+                                }
+                            }
+                            main_offset += border_box_start - origin_border_box_start;
+                            total_main_size += border_box_start - origin_border_box_start;
+                        }
+                    }
                     total_main_size += child_res.size.main_size(axis_info.dir);
                     max_cross_size = max_cross_size.max(
                         child_res.size.cross_size(axis_info.dir)
-                            + child_margin.cross_axis_sum(axis_info.dir),
+                            + child_margin.cross_axis_sum(axis_info.dir)
+                            + float_insets.0
+                            + float_insets.1,
                     );
-                    if request.kind == ComputeRequestKind::Position {
-                        let cross_offset: <T as LayoutTreeNode>::Length = padding_border
-                            .cross_axis_start(axis_info.dir, axis_info.cross_dir_rev)
-                            + if child_margin
+                    // the baselines are also returned to size requests (e.g. for baseline alignment),
+                    // without positioning the child
+                    let baseline_diff = match request.kind {
+                        ComputeRequestKind::Position => {
+                            let cross_offset: <T as LayoutTreeNode>::Length = padding_border
                                 .cross_axis_start(axis_info.dir, axis_info.cross_dir_rev)
-                                .is_none()
-                                && child_margin
-                                    .cross_axis_end(axis_info.dir, axis_info.cross_dir_rev)
-                                    .is_none()
-                            {
-                                (node_inner_size.cross_size(axis_info.dir)
-                                    - child_res.size.cross_size(axis_info.dir))
-                                .or_zero()
-                                .div_i32(2)
-                            } else {
-                                child_margin
+                                + float_insets.0
+                                + if child_margin
                                     .cross_axis_start(axis_info.dir, axis_info.cross_dir_rev)
+                                    .is_none()
+                                    && child_margin
+                                        .cross_axis_end(axis_info.dir, axis_info.cross_dir_rev)
+                                        .is_none()
+                                {
+                                    (node_inner_size.cross_size(axis_info.dir)
+                                        - float_insets.0
+                                        - float_insets.1
+                                        - child_res.size.cross_size(axis_info.dir))
                                     .or_zero()
-                            };
-                        let baseline_diff = child.gen_origin(
-                            AxisInfo {
-                                cross_dir_rev: AxisReverse::NotReversed,
-                                ..axis_info
-                            },
-                            node_inner_size.or_zero(),
-                            main_offset,
-                            cross_offset,
-                        );
+                                    .div_i32(2)
+                                } else {
+                                    child_margin
+                                        .cross_axis_start(axis_info.dir, axis_info.cross_dir_rev)
+                                        .or_zero()
+                                };
+                            Some(child.gen_origin(
+                                AxisInfo {
+                                    cross_dir_rev: AxisReverse::NotReversed,
+                                    ..axis_info
+                                },
+                                origin_base_size,
+                                main_offset,
+                                cross_offset,
+                            ))
+                        }
+                        ComputeRequestKind::AllSize => Some(
+                            origin_in_parent(
+                                AxisInfo {
+                                    cross_dir_rev: AxisReverse::NotReversed,
+                                    ..axis_info
+                                },
+                                origin_base_size,
+                                *child_res.size,
+                                main_offset,
+                                T::Length::zero(),
+                            )
+                            .to_vector(),
+                        ),
+                        _ => None,
+                    };
+                    if let Some(baseline_diff) = baseline_diff {
                         if first_baseline_ascent_option.is_none() {
                             first_baseline_ascent_option =
                                 Some(child_res.first_baseline_ascent + baseline_diff);
@@ -687,12 +1207,228 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                         last_baseline_ascent_option =
                             Some(child_res.last_baseline_ascent + baseline_diff);
                     }
+                    if child_line_clamp.is_some() {
+                        line_count += child_res.line_count;
+                    }
+                    series_index += 1;
+                    if clamped_state.is_none()
+                        && line_clamp.is_some_and(|clamp| line_count >= clamp.max_lines)
+                    {
+                        clamped_state = Some((
+                            total_main_size,
+                            prev_sibling_collapsed_margin,
+                            last_baseline_ascent_option,
+                        ));
+                    }
+                }
+
+                // for a series of misparented table parts, layout as an anonymous table,
+                // which establishes a BFC and has no margins
+                BlockOrInlineSeries::AnonymousTable(table_nodes) => {
+                    if let Some((prev_sibling_margin, _)) = prev_sibling_collapsed_margin {
+                        total_main_size += prev_sibling_margin.solve();
+                    }
+                    prev_sibling_collapsed_margin = Some((CollapsedMargin::zero(), false));
+                    let mut available_width = node_inner_size.width.or(request.max_content.width);
+                    let mut size = compute_anonymous_table(
+                        env,
+                        &table_nodes,
+                        node_inner_size,
+                        available_width,
+                        request.sizing_mode,
+                        None,
+                    );
+                    // CSS 2.1 §9.5: the table is narrowed to fit beside the floats, or moved below them
+                    let mut border_box_start = total_main_size;
+                    let mut float_insets = (T::Length::zero(), T::Length::zero());
+                    if !float_context.is_empty() {
+                        if let Some(inline_size) = node_inner_size.cross_size(axis_info.dir).val() {
+                            loop {
+                                let border_box_end =
+                                    border_box_start + size.main_size(axis_info.dir);
+                                let (start, end) =
+                                    float_context.insets(border_box_start, border_box_end);
+                                if start.is_zero() && end.is_zero() {
+                                    break;
+                                }
+                                let available = inline_size - start - end;
+                                if available > T::Length::zero() {
+                                    available_width = OptionNum::some(available);
+                                    size = compute_anonymous_table(
+                                        env,
+                                        &table_nodes,
+                                        node_inner_size,
+                                        available_width,
+                                        request.sizing_mode,
+                                        None,
+                                    );
+                                    float_insets = (start, end);
+                                    break;
+                                }
+                                match float_context.next_main_end(border_box_start, border_box_end)
+                                {
+                                    Some(x) => border_box_start = x,
+                                    None => break,
+                                }
+                            }
+                        }
+                    }
+                    total_main_size = border_box_start + size.main_size(axis_info.dir);
+                    max_cross_size = max_cross_size
+                        .max(size.cross_size(axis_info.dir) + float_insets.0 + float_insets.1);
+                    let origin = origin_in_parent(
+                        AxisInfo {
+                            cross_dir_rev: AxisReverse::NotReversed,
+                            ..axis_info
+                        },
+                        origin_base_size,
+                        size,
+                        padding_border.main_axis_start(axis_info.dir, axis_info.main_dir_rev)
+                            + border_box_start,
+                        padding_border.cross_axis_start(axis_info.dir, axis_info.cross_dir_rev)
+                            + float_insets.0,
+                    );
+                    if request.kind == ComputeRequestKind::Position {
+                        compute_anonymous_table(
+                            env,
+                            &table_nodes,
+                            node_inner_size,
+                            available_width,
+                            request.sizing_mode,
+                            Some(origin),
+                        );
+                    }
+                    if matches!(
+                        request.kind,
+                        ComputeRequestKind::Position | ComputeRequestKind::AllSize
+                    ) {
+                        // the baseline of a table is the bottom of its border box
+                        let baseline_ascent =
+                            origin.to_vector() + Vector::new(T::Length::zero(), size.height);
+                        if first_baseline_ascent_option.is_none() {
+                            first_baseline_ascent_option = Some(baseline_ascent);
+                        }
+                        last_baseline_ascent_option = Some(baseline_ascent);
+                    }
+                    series_index += 1;
+                    if clamped_state.is_none()
+                        && line_clamp.is_some_and(|clamp| line_count >= clamp.max_lines)
+                    {
+                        clamped_state = Some((
+                            total_main_size,
+                            prev_sibling_collapsed_margin,
+                            last_baseline_ascent_option,
+                        ));
+                    }
+                }
+
+                // for absolutely positioned child, record the static position as a block with zero margins
+                BlockOrInlineSeries::OutOfFlow(child_node) => {
+                    if axis_info.main_dir_rev == AxisReverse::NotReversed {
+                        let mut main_offset = padding_border
+                            .main_axis_start(axis_info.dir, axis_info.main_dir_rev)
+                            + total_main_size;
+                        if let Some((prev_sibling_margin, false)) = prev_sibling_collapsed_margin {
+                            main_offset += prev_sibling_margin.solve();
+                        }
+                        child_node.layout_node().unit().static_position =
+                            Some(Vector::new_with_dir(
+                                axis_info.dir,
+                                main_offset,
+                                padding_border
+                                    .cross_axis_start(axis_info.dir, AxisReverse::NotReversed),
+                            ));
+                    }
+                }
+
+                // for child float, layout as a shrink-to-fit block and place beside the earlier floats
+                BlockOrInlineSeries::Float(child_node) => {
+                    let mut child = child_node.layout_node().unit();
+                    let (child_margin, child_border, child_padding_border) =
+                        child.margin_border_padding(child_node, node_inner_size);
+                    let min_max_limit = child.normalized_min_max_limit(
+                        child_node,
+                        node_inner_size,
+                        child_border,
+                        child_padding_border,
+                    );
+                    let css_size = child.css_border_box_size(
+                        child_node,
+                        node_inner_size,
+                        child_border,
+                        child_padding_border,
+                    );
+                    let size = min_max_limit.normalized_size(css_size);
+                    let mut max_content = OptionSize::new(OptionNum::none(), OptionNum::none());
+                    max_content.set_cross_size(
+                        axis_info.dir,
+                        css_size
+                            .cross_size(axis_info.dir)
+                            .or(request.max_content.cross_size(axis_info.dir)
+                                - child_margin.cross_axis_sum(axis_info.dir)),
+                    );
+                    let max_content = min_max_limit.normalized_size(max_content);
+                    let child_res = child.compute_internal(
+                        env,
+                        child_node,
+                        ComputeRequest {
+                            size,
+                            parent_inner_size: Normalized(node_inner_size),
+                            max_content,
+                            kind: request.kind.shift_to_all_size(),
+                            parent_is_block: true,
+                            sizing_mode: request.sizing_mode,
+                            line_clamp: None,
+                        },
+                    );
+                    let child_margin = child_margin.or_zero();
+                    let outer_main_size = child_res.size.main_size(axis_info.dir)
+                        + child_margin.main_axis_sum(axis_info.dir);
+                    let outer_cross_size = child_res.size.cross_size(axis_info.dir)
+                        + child_margin.cross_axis_sum(axis_info.dir);
+                    let side = float_side(child_node.style().float(), &direction)
+                        .unwrap_or(FloatSide::Start);
+
+                    // CSS 2.1 §9.5.1 rule 4: the outer top may not be higher than the
+                    // current position, i.e. after the pending sibling margin.
+                    let mut main_min = total_main_size;
+                    if let Some((prev_sibling_margin, false)) = prev_sibling_collapsed_margin {
+                        main_min += prev_sibling_margin.solve();
+                    }
+                    let (clear_start, clear_end) =
+                        clear_sides(child_node.style().clear(), &direction);
+                    if let Some(edge) = float_context.clearance_edge(clear_start, clear_end) {
+                        main_min = main_min.max(edge);
+                    }
+                    let (main, offset) =
+                        float_context.place(side, main_min, outer_main_size, outer_cross_size);
+                    max_cross_size = max_cross_size.max(offset + outer_cross_size);
+
+                    if request.kind == ComputeRequestKind::Position {
+                        let cross = float_context.cross_offset(side, offset, outer_cross_size);
+                        child.gen_origin(
+                            AxisInfo {
+                                cross_dir_rev: AxisReverse::NotReversed,
+                                ..axis_info
+                            },
+                            origin_base_size,
+                            padding_border.main_axis_start(axis_info.dir, axis_info.main_dir_rev)
+                                + main
+                                + child_margin
+                                    .main_axis_start(axis_info.dir, axis_info.main_dir_rev),
+                            padding_border.cross_axis_start(axis_info.dir, axis_info.cross_dir_rev)
+                                + cross
+                                + child_margin
+                                    .cross_axis_start(axis_info.dir, AxisReverse::NotReversed),
+                        );
+                    }
                 }
 
                 // for a series of child inlines, group them and layout together
                 BlockOrInlineSeries::InlineSeries {
                     end_nodes,
                     middle_nodes,
+                    out_of_flow_nodes,
                 } => {
                     if !end_nodes.is_empty() {
                         let inline_units = end_nodes
@@ -748,6 +1484,7 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                                             kind: request.kind.shift_to_all_size(),
                                             parent_is_block: true,
                                             sizing_mode: request.sizing_mode,
+                                            line_clamp: None,
                                         },
                                     );
                                     T::InlineUnit::new(
@@ -766,19 +1503,47 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                                 }
                             })
                             .collect();
-                        let (block_size, positions) = T::InlineMeasure::block_size(
-                            env,
-                            node,
-                            inline_units,
-                            Size::new_with_dir(
-                                axis_info.dir,
-                                OptionNum::none(),
-                                node_inner_size.cross_size(axis_info.dir),
-                            ),
-                            *request.max_content,
-                            request.kind == ComputeRequestKind::Position,
-                            request.sizing_mode,
+                        // the line boxes start after the pending sibling margin (if not empty)
+                        let mut series_main_start = total_main_size;
+                        if let Some((prev_sibling_margin, false)) = prev_sibling_collapsed_margin {
+                            series_main_start += prev_sibling_margin.solve();
+                        }
+                        let exclusions = float_context.exclusions(series_main_start, axis_info.dir);
+                        let req_size = Size::new_with_dir(
+                            axis_info.dir,
+                            OptionNum::none(),
+                            node_inner_size.cross_size(axis_info.dir),
                         );
+                        trace_measure(env, node, LayoutMeasureKind::InlineContent);
+                        let (block_size, positions) =
+                            match remaining_line_clamp(line_count, series_index) {
+                                Some(clamp) => {
+                                    let (block_size, positions, series_line_count) =
+                                        T::InlineMeasure::block_size_with_line_clamp(
+                                            env,
+                                            node,
+                                            inline_units,
+                                            req_size,
+                                            *request.max_content,
+                                            request.kind == ComputeRequestKind::Position,
+                                            request.sizing_mode,
+                                            &exclusions,
+                                            clamp,
+                                        );
+                                    line_count += series_line_count.min(clamp.max_lines);
+                                    (block_size, positions)
+                                }
+                                None => T::InlineMeasure::block_size_with_exclusions(
+                                    env,
+                                    node,
+                                    inline_units,
+                                    req_size,
+                                    *request.max_content,
+                                    request.kind == ComputeRequestKind::Position,
+                                    request.sizing_mode,
+                                    &exclusions,
+                                ),
+                            };
 
                         if block_size.main_size(axis_info.dir) > T::Length::zero() {
                             if let Some((prev_collapsed_margin, prev_collapsed_through)) =
@@ -794,26 +1559,51 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                             .main_axis_start(axis_info.dir, axis_info.main_dir_rev)
                             + total_main_size;
 
+                        let (float_inset_start, float_inset_end) = float_context.insets(
+                            series_main_start,
+                            series_main_start + block_size.main_size(axis_info.dir),
+                        );
                         total_main_size += block_size.main_size(axis_info.dir);
-                        max_cross_size = max_cross_size.max(block_size.cross_size(axis_info.dir));
-                        if request.kind == ComputeRequestKind::Position {
-                            let mut baseline_diff = Vector::zero();
-                            baseline_diff.set_main_axis(axis_info.dir, main_offset);
-                            baseline_diff.set_cross_axis(axis_info.dir, cross_offset);
-
+                        max_cross_size = max_cross_size.max(
+                            block_size.cross_size(axis_info.dir)
+                                + float_inset_start
+                                + float_inset_end,
+                        );
+                        let mut baseline_diff = Vector::zero();
+                        baseline_diff.set_main_axis(axis_info.dir, main_offset);
+                        baseline_diff.set_cross_axis(axis_info.dir, cross_offset);
+                        if request.kind == ComputeRequestKind::AllSize {
+                            // the baselines of the first and the last line boxes, without positioning
+                            if let Some((child_origin, child_res)) = positions.first() {
+                                first_baseline_ascent_option.get_or_insert(
+                                    child_res.first_baseline_ascent
+                                        + baseline_diff
+                                        + child_origin.to_vector(),
+                                );
+                            }
+                            if let Some((child_origin, child_res)) = positions.last() {
+                                last_baseline_ascent_option = Some(
+                                    child_res.last_baseline_ascent
+                                        + baseline_diff
+                                        + child_origin.to_vector(),
+                                );
+                            }
+                        } else if request.kind == ComputeRequestKind::Position {
                             for ((child_origin, child_res), child_node) in
-                                positions.into_iter().zip(end_nodes)
+                                positions.into_iter().zip(end_nodes.iter().copied())
                             {
                                 let baseline_diff = baseline_diff + child_origin.to_vector();
                                 let mut child = child_node.layout_node().unit();
                                 child.result = Rect::new(Point::zero(), child_res.size);
+                                child.result_first_baseline_ascent =
+                                    child_res.first_baseline_ascent;
                                 child.cache.touch(child_node);
                                 child.gen_origin(
                                     AxisInfo {
                                         cross_dir_rev: AxisReverse::NotReversed,
                                         ..axis_info
                                     },
-                                    node_inner_size.or_zero(),
+                                    origin_base_size,
                                     main_offset + child_origin.main_axis(axis_info.dir),
                                     cross_offset + child_origin.cross_axis(axis_info.dir),
                                 );
@@ -839,26 +1629,125 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                                     });
                                 }
                             }
+                            if axis_info.main_dir_rev == AxisReverse::NotReversed {
+                                for (index, child_node) in out_of_flow_nodes {
+                                    let prev_node = end_nodes[index - 1];
+                                    let prev_rect = prev_node.layout_node().unit().result;
+                                    let prev_main_start = prev_rect.origin.main_axis(axis_info.dir);
+                                    let prev_cross_start =
+                                        prev_rect.origin.cross_axis(axis_info.dir);
+                                    // after an inline-level box, it is on the same line;
+                                    // after a block-level one, it starts a new line
+                                    let static_position = if matches!(
+                                        prev_node.style().display(),
+                                        Display::Inline
+                                            | Display::InlineBlock
+                                            | Display::InlineFlex
+                                            | Display::InlineGrid
+                                    ) {
+                                        Vector::new_with_dir(
+                                            axis_info.dir,
+                                            prev_main_start,
+                                            prev_cross_start
+                                                + prev_rect.size.cross_size(axis_info.dir),
+                                        )
+                                    } else {
+                                        Vector::new_with_dir(
+                                            axis_info.dir,
+                                            prev_main_start
+                                                + prev_rect.size.main_size(axis_info.dir),
+                                            cross_offset,
+                                        )
+                                    };
+                                    child_node.layout_node().unit().static_position =
+                                        Some(static_position);
+                                }
+                            }
+                        }
+                        series_index += 1;
+                        if clamped_state.is_none()
+                            && line_clamp.is_some_and(|clamp| line_count >= clamp.max_lines)
+                        {
+                            clamped_state = Some((
+                                total_main_size,
+                                prev_sibling_collapsed_margin,
+                                last_baseline_ascent_option,
+                            ));
                         }
                     }
                     if request.kind == ComputeRequestKind::Position {
                         for middle_node in middle_nodes {
-                            let mut merged_rect = Rect::zero();
-                            middle_node.tree_visitor().for_each_child(|child_node, _| {
-                                let child = child_node.layout_node().unit();
-                                if is_independent_positioning(child_node) {
-                                    return;
-                                }
-                                merged_rect = merged_rect.union(&child.result);
-                            });
+                            let mut merged_rect: Option<Rect<T::Length>> = None;
+                            let mut first_child_rect = None;
+                            let mut last_child_rect = None;
+                            let mut has_block_level_child = false;
+                            middle_node
+                                .tree_visitor()
+                                .for_each_layout_child(|child_node, _| {
+                                    let child = child_node.layout_node().unit();
+                                    if is_independent_positioning(child_node)
+                                        || is_float::<T>(child_node.style())
+                                    {
+                                        return;
+                                    }
+                                    merged_rect = Some(match merged_rect {
+                                        Some(x) => x.union(&child.result),
+                                        None => child.result,
+                                    });
+                                    first_child_rect.get_or_insert(child.result);
+                                    last_child_rect = Some(child.result);
+                                    has_block_level_child |= !matches!(
+                                        child_node.style().display(),
+                                        Display::Inline
+                                            | Display::InlineBlock
+                                            | Display::InlineFlex
+                                            | Display::InlineGrid
+                                    );
+                                });
                             let mut middle_node_layout_unit = middle_node.layout_node().unit();
+                            // the paddings and borders of the inline box surround its first and last fragments,
+                            // unless the inline box is split by a block-level child
+                            let (_, _, padding_border) = middle_node_layout_unit
+                                .margin_border_padding(middle_node, node_inner_size);
+                            let merged_rect = match (merged_rect, first_child_rect, last_child_rect)
+                            {
+                                (Some(rect), Some(first), Some(last)) if !has_block_level_child => {
+                                    let left =
+                                        rect.min_x().min(first.min_x() - padding_border.left);
+                                    let right =
+                                        rect.max_x().max(last.max_x() + padding_border.right);
+                                    let top = rect.min_y() - padding_border.top;
+                                    let bottom = rect.max_y() + padding_border.bottom;
+                                    Rect::new(
+                                        Point::new(left, top),
+                                        Size::new(right - left, bottom - top),
+                                    )
+                                }
+                                (Some(rect), _, _) => rect,
+                                _ => Rect::zero(),
+                            };
                             middle_node_layout_unit.result = merged_rect;
                             middle_node_layout_unit.cache.touch(middle_node);
                             let child_diff = merged_rect.origin.to_vector();
-                            middle_node.tree_visitor().for_each_child(|child_node, _| {
-                                let mut child = child_node.layout_node().unit();
-                                child.result.origin -= child_diff;
-                            });
+                            let mut first_baseline_ascent = None;
+                            middle_node
+                                .tree_visitor()
+                                .for_each_layout_child(|child_node, _| {
+                                    let mut child = child_node.layout_node().unit();
+                                    child.result.origin -= child_diff;
+                                    if let Some(static_position) = &mut child.static_position {
+                                        *static_position -= child_diff;
+                                    }
+                                    if first_baseline_ascent.is_none() {
+                                        first_baseline_ascent = Some(
+                                            child.result.origin.to_vector()
+                                                + child.result_first_baseline_ascent,
+                                        );
+                                    }
+                                });
+                            middle_node_layout_unit.result_first_baseline_ascent =
+                                first_baseline_ascent
+                                    .unwrap_or_else(|| merged_rect.size.to_vector());
                             middle_node_layout_unit.save_all_results(
                                 middle_node,
                                 env,
@@ -877,6 +1766,7 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                                         .unwrap_or_else(|| merged_rect.size.to_vector()),
                                     last_baseline_ascent: last_baseline_ascent_option
                                         .unwrap_or_else(|| merged_rect.size.to_vector()),
+                                    line_count: 0,
                                 },
                                 border,
                                 padding_border,
@@ -888,6 +1778,11 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                 }
             }
         });
+        if let Some((main_size, prev_margin, last_baseline_ascent)) = clamped_state {
+            total_main_size = main_size;
+            prev_sibling_collapsed_margin = prev_margin;
+            last_baseline_ascent_option = last_baseline_ascent;
+        }
 
         // CSS 2.1 §8.3.1 relation (c): parent-last-child bottom collapse requires
         // the parent's height to be auto. DefLength::Undefined is the
@@ -922,6 +1817,17 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
                 total_main_size += prev_collapsed_margin.solve();
             }
         }
+        // CSS 2.1 §10.6.7: the auto height of a BFC root includes its floats.
+        if bfc_established {
+            total_main_size = total_main_size.max(float_context.main_end());
+        }
+        if let Some(marker_size) = inside_marker {
+            total_main_size = total_main_size.max(marker_size.main_size(axis_info.dir));
+            // the intrinsic inline size includes the marker, which is in the first line
+            if node_inner_size.cross_size(axis_info.dir).is_none() {
+                max_cross_size += marker_size.cross_size(axis_info.dir);
+            }
+        }
         let mut collapsed_margin = CollapsedBlockMargin::from_collapsed_margin(
             parent_collapsed_margin_start,
             parent_collapsed_margin_end,
@@ -940,6 +1846,9 @@ impl<T: LayoutTreeNode> Flow<T> for LayoutUnit<T> {
             first_baseline_ascent_option,
             last_baseline_ascent_option,
             collapsed_margin,
+            marker,
+            line_count,
+            float_context,
         }
     }
 }
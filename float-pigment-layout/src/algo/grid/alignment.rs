@@ -45,6 +45,7 @@ pub(crate) fn resolve_grid_align_self<T: LayoutTreeNode>(
             AlignItems::Start | AlignItems::FlexStart | AlignItems::SelfStart => AlignSelf::Start,
             AlignItems::End | AlignItems::FlexEnd | AlignItems::SelfEnd => AlignSelf::End,
             AlignItems::Baseline => AlignSelf::Baseline,
+            AlignItems::LastBaseline => AlignSelf::LastBaseline,
             // Grid default: "normal" behaves as "stretch" for non-replaced grid items
             // https://www.w3.org/TR/css-align-3/#align-items-property
             AlignItems::Normal => AlignSelf::Stretch,
@@ -97,6 +98,8 @@ pub(crate) fn resolve_grid_justify_self<T: LayoutTreeNode>(
 /// - `end`/`flex-end`/`self-end`: Item at the end edge (offset = available_space)
 /// - `center`: Item centered (offset = available_space / 2)
 /// - `stretch`: Item stretches to fill (offset = 0, handled elsewhere)
+/// - `baseline` / `last baseline`: handled in the baseline-sharing group;
+///   otherwise the fallback alignment (`start` / `end`) is used
 pub(crate) fn calculate_alignment_offset<L: LengthNum>(
     align_self: AlignSelf,
     item_size: L,
@@ -108,7 +111,9 @@ pub(crate) fn calculate_alignment_offset<L: LengthNum>(
     let available_space = cell_size - item_size;
     match align_self {
         AlignSelf::Start | AlignSelf::FlexStart | AlignSelf::SelfStart => L::zero(),
-        AlignSelf::End | AlignSelf::FlexEnd | AlignSelf::SelfEnd => available_space,
+        AlignSelf::End | AlignSelf::FlexEnd | AlignSelf::SelfEnd | AlignSelf::LastBaseline => {
+            available_space
+        }
         AlignSelf::Center => available_space.div_f32(2.0),
         AlignSelf::Stretch | AlignSelf::Auto | AlignSelf::Normal | AlignSelf::Baseline => L::zero(),
     }
@@ -0,0 +1,267 @@
+//! Baseline Alignment
+//!
+//! CSS Box Alignment §9: https://www.w3.org/TR/css-align-3/#baseline-rules
+//! CSS Grid §10.6: https://www.w3.org/TR/css-grid-1/#grid-baselines
+//!
+//! Grid items with `align-self: baseline` / `last baseline` in the same row
+//! form a baseline-sharing group and are aligned to each other by their
+//! first / last baselines.
+//!
+//! Only the block axis (rows) is supported, since `justify-self` does not
+//! accept baseline values here.
+
+use alloc::vec::Vec;
+
+use float_pigment_css::{length_num::LengthNum, typing::AlignSelf};
+
+use crate::{types::MinMax, ComputeResult, EdgeOption, Vector};
+
+/// The baseline an item is aligned by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BaselinePosition {
+    /// `baseline` / `first baseline`
+    First,
+    /// `last baseline`
+    Last,
+}
+
+impl BaselinePosition {
+    /// Get the baseline position of a (resolved) `align-self` value.
+    pub(crate) fn of(align_self: &AlignSelf) -> Option<Self> {
+        match align_self {
+            AlignSelf::Baseline => Some(Self::First),
+            AlignSelf::LastBaseline => Some(Self::Last),
+            _ => None,
+        }
+    }
+}
+
+/// The baseline of a baseline-aligned grid item in the block axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ItemBaseline<L: LengthNum> {
+    /// The row of the item.
+    pub(crate) row: usize,
+    /// The baseline-sharing group the item belongs to.
+    pub(crate) position: BaselinePosition,
+    /// The distance from the margin-box start edge to the baseline.
+    pub(crate) ascent: L,
+    /// The distance from the baseline to the margin-box end edge.
+    pub(crate) descent: L,
+}
+
+impl<L: LengthNum> ItemBaseline<L> {
+    /// Collect the baseline of an item from its layout result.
+    ///
+    /// CSS Box Alignment §9.3: an item with `auto` margins in the block axis
+    /// does not participate in baseline alignment (its fallback alignment is used).
+    pub(crate) fn new(
+        row: usize,
+        align_self: &AlignSelf,
+        result: &ComputeResult<L>,
+        margin: &EdgeOption<L>,
+    ) -> Option<Self> {
+        let position = BaselinePosition::of(align_self)?;
+        if margin.is_top_bottom_either_none() {
+            return None;
+        }
+        let baseline = match position {
+            BaselinePosition::First => result.first_baseline_ascent.y,
+            BaselinePosition::Last => result.last_baseline_ascent.y,
+        };
+        let ascent = margin.top.or_zero() + baseline;
+        let descent = result.size.height + margin.vertical() - ascent;
+        Some(Self {
+            row,
+            position,
+            ascent,
+            descent,
+        })
+    }
+}
+
+/// The baseline-sharing groups of the grid rows.
+///
+/// CSS Box Alignment §9.1: each row has a first-baseline group and a last-baseline group.
+pub(crate) struct RowBaselines<L: LengthNum> {
+    /// The largest ascent of the first-baseline group of each row.
+    max_ascent: Vec<L>,
+    /// The largest descent of the last-baseline group of each row.
+    max_descent: Vec<L>,
+}
+
+impl<L: LengthNum> RowBaselines<L> {
+    /// Build the baseline-sharing groups from the baselines of the items.
+    pub(crate) fn new<'a>(
+        row_count: usize,
+        items: impl Iterator<Item = &'a ItemBaseline<L>>,
+    ) -> Self
+    where
+        L: 'a,
+    {
+        let mut max_ascent = alloc::vec![L::zero(); row_count];
+        let mut max_descent = alloc::vec![L::zero(); row_count];
+        for item in items {
+            match item.position {
+                BaselinePosition::First => {
+                    max_ascent[item.row] = max_ascent[item.row].max(item.ascent)
+                }
+                BaselinePosition::Last => {
+                    max_descent[item.row] = max_descent[item.row].max(item.descent)
+                }
+            }
+        }
+        Self {
+            max_ascent,
+            max_descent,
+        }
+    }
+
+    /// The baseline alignment shim of an item.
+    ///
+    /// CSS Grid §11.5 Step 1: the extra space added before (first baseline)
+    /// or after (last baseline) the item's margin box, so that its intrinsic
+    /// size contribution reflects the baseline alignment.
+    pub(crate) fn shim(&self, item: &ItemBaseline<L>) -> L {
+        match item.position {
+            BaselinePosition::First => self.max_ascent[item.row] - item.ascent,
+            BaselinePosition::Last => self.max_descent[item.row] - item.descent,
+        }
+    }
+
+    /// The offset of the item's margin box within its row.
+    ///
+    /// The first-baseline group is aligned to the start of the row,
+    /// and the last-baseline group to the end of the row.
+    pub(crate) fn alignment_offset(&self, item: &ItemBaseline<L>, track_size: L) -> L {
+        match item.position {
+            BaselinePosition::First => self.shim(item),
+            BaselinePosition::Last => track_size - self.shim(item) - item.descent - item.ascent,
+        }
+    }
+}
+
+/// The candidate item for the grid container baseline of one side.
+///
+/// CSS Grid §10.6: the baseline of the grid container is the shared baseline of
+/// the baseline-aligned items in the first (last) row, or otherwise the baseline
+/// of the first (last) item of that row in grid order.
+pub(crate) struct ContainerBaselineCandidate<L: LengthNum> {
+    key: Option<(usize, bool, usize)>,
+    baseline: Vector<L>,
+}
+
+impl<L: LengthNum> ContainerBaselineCandidate<L> {
+    /// Create an empty candidate.
+    pub(crate) fn new() -> Self {
+        Self {
+            key: None,
+            baseline: Vector::zero(),
+        }
+    }
+
+    /// Offer the baseline of an item for the first baseline of the grid container.
+    ///
+    /// Earlier rows win, then baseline-aligned items, then earlier columns.
+    pub(crate) fn offer_first(
+        &mut self,
+        row: usize,
+        column: usize,
+        aligned: bool,
+        baseline: Vector<L>,
+    ) {
+        let key = (usize::MAX - row, aligned, usize::MAX - column);
+        self.offer(key, baseline);
+    }
+
+    /// Offer the baseline of an item for the last baseline of the grid container.
+    ///
+    /// Later rows win, then baseline-aligned items, then later columns.
+    pub(crate) fn offer_last(
+        &mut self,
+        row: usize,
+        column: usize,
+        aligned: bool,
+        baseline: Vector<L>,
+    ) {
+        self.offer((row, aligned, column), baseline);
+    }
+
+    fn offer(&mut self, key: (usize, bool, usize), baseline: Vector<L>) {
+        if self.key.is_none_or(|x| key > x) {
+            self.key = Some(key);
+            self.baseline = baseline;
+        }
+    }
+
+    /// The resulting baseline, if there is any item.
+    pub(crate) fn baseline(&self) -> Option<Vector<L>> {
+        self.key.map(|_| self.baseline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(
+        row: usize,
+        position: BaselinePosition,
+        ascent: f32,
+        descent: f32,
+    ) -> ItemBaseline<f32> {
+        ItemBaseline {
+            row,
+            position,
+            ascent,
+            descent,
+        }
+    }
+
+    #[test]
+    fn first_baseline_group() {
+        let items = [
+            item(0, BaselinePosition::First, 20., 30.),
+            item(0, BaselinePosition::First, 40., 0.),
+            item(1, BaselinePosition::First, 10., 0.),
+        ];
+        let baselines = RowBaselines::new(2, items.iter());
+        assert_eq!(baselines.shim(&items[0]), 20.);
+        assert_eq!(baselines.shim(&items[1]), 0.);
+        // groups are separated by rows
+        assert_eq!(baselines.shim(&items[2]), 0.);
+        assert_eq!(baselines.alignment_offset(&items[0], 70.), 20.);
+    }
+
+    #[test]
+    fn last_baseline_group() {
+        let items = [
+            item(0, BaselinePosition::Last, 20., 0.),
+            item(0, BaselinePosition::Last, 40., 10.),
+            item(0, BaselinePosition::First, 30., 0.),
+        ];
+        let baselines = RowBaselines::new(1, items.iter());
+        assert_eq!(baselines.shim(&items[0]), 10.);
+        assert_eq!(baselines.shim(&items[1]), 0.);
+        assert_eq!(baselines.alignment_offset(&items[0], 100.), 70.);
+        assert_eq!(baselines.alignment_offset(&items[1], 100.), 50.);
+        assert_eq!(baselines.alignment_offset(&items[2], 100.), 0.);
+    }
+
+    #[test]
+    fn container_baseline() {
+        let mut first = ContainerBaselineCandidate::<f32>::new();
+        assert_eq!(first.baseline(), None);
+        first.offer_first(1, 0, true, Vector::new(0., 100.));
+        first.offer_first(0, 1, false, Vector::new(0., 20.));
+        first.offer_first(0, 0, false, Vector::new(0., 10.));
+        assert_eq!(first.baseline(), Some(Vector::new(0., 10.)));
+        first.offer_first(0, 2, true, Vector::new(0., 30.));
+        assert_eq!(first.baseline(), Some(Vector::new(0., 30.)));
+
+        let mut last = ContainerBaselineCandidate::<f32>::new();
+        last.offer_last(0, 0, true, Vector::new(0., 10.));
+        last.offer_last(1, 0, false, Vector::new(0., 50.));
+        last.offer_last(1, 1, false, Vector::new(0., 60.));
+        assert_eq!(last.baseline(), Some(Vector::new(0., 60.)));
+    }
+}
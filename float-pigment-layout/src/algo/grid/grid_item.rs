@@ -8,8 +8,11 @@
 
 use core::fmt::Debug;
 
+use float_pigment_css::num_traits::Zero;
+
 use crate::{
-    algo::grid::track_size::TrackSize, DefLength, EdgeOption, LayoutTreeNode, OptionNum, Size,
+    algo::grid::{subgrid::SubgridContributions, track_size::TrackSize},
+    DefLength, EdgeOption, LayoutTreeNode, OptionNum, Size,
 };
 
 /// Grid item with computed layout information.
@@ -30,6 +33,10 @@ pub(crate) struct GridLayoutItem<'a, T: LayoutTreeNode> {
     pub(crate) row: usize,
     /// The item's column index in the grid (0-based)
     pub(crate) column: usize,
+    /// The number of rows spanned by the item
+    pub(crate) row_span: usize,
+    /// The number of columns spanned by the item
+    pub(crate) column_span: usize,
     /// Reference to the DOM node
     pub(crate) node: &'a T,
     /// The item's margin (top, right, bottom, left)
@@ -44,10 +51,18 @@ pub(crate) struct GridLayoutItem<'a, T: LayoutTreeNode> {
     pub(crate) max_content_size: Option<Size<T::Length>>,
     /// The item's final computed size
     pub(crate) computed_size: Size<T::Length>,
+    /// The extra space added to the block size contributions of a baseline-aligned item
+    pub(crate) baseline_shim: T::Length,
+    /// The contributions of a subgrid to each of the spanned columns
+    pub(crate) subgrid_column_contributions: Option<SubgridContributions<T::Length>>,
+    /// The contributions of a subgrid to each of the spanned rows
+    pub(crate) subgrid_row_contributions: Option<SubgridContributions<T::Length>>,
 }
 
 impl<'a, T: LayoutTreeNode> GridLayoutItem<'a, T> {
     /// Create a new layout item with the given placement and sizing info.
+    ///
+    /// The item occupies a single cell; use `with_span` for a spanning item.
     pub(crate) fn new(
         row: usize,
         column: usize,
@@ -59,6 +74,8 @@ impl<'a, T: LayoutTreeNode> GridLayoutItem<'a, T> {
         Self {
             row,
             column,
+            row_span: 1,
+            column_span: 1,
             node,
             margin,
             css_size,
@@ -66,6 +83,9 @@ impl<'a, T: LayoutTreeNode> GridLayoutItem<'a, T> {
             min_content_size: None,
             max_content_size: None,
             computed_size: Size::zero(),
+            baseline_shim: T::Length::zero(),
+            subgrid_column_contributions: None,
+            subgrid_row_contributions: None,
         }
     }
 
@@ -81,14 +101,37 @@ impl<'a, T: LayoutTreeNode> GridLayoutItem<'a, T> {
         self.column
     }
 
-    /// Get the track size along the inline (width) axis.
-    pub(crate) fn track_inline_size(&self) -> OptionNum<T::Length> {
-        self.track_size.width.clone()
+    /// Set the number of rows and columns spanned by the item.
+    pub(crate) fn with_span(mut self, row_span: usize, column_span: usize) -> Self {
+        self.row_span = row_span;
+        self.column_span = column_span;
+        self
+    }
+
+    /// Set the contributions of a subgrid to each of the spanned (column, row) tracks.
+    ///
+    /// CSS Grid §9: the items of a subgrid contribute to the parent tracks they are in,
+    /// instead of the subgrid contributing as a whole.
+    pub(crate) fn with_subgrid_contributions(
+        mut self,
+        column: Option<SubgridContributions<T::Length>>,
+        row: Option<SubgridContributions<T::Length>>,
+    ) -> Self {
+        self.subgrid_column_contributions = column;
+        self.subgrid_row_contributions = row;
+        self
+    }
+
+    /// Get the number of rows spanned by the item.
+    #[inline(always)]
+    pub(crate) fn row_span(&self) -> usize {
+        self.row_span
     }
 
-    /// Get the track size along the block (height) axis.
-    pub(crate) fn track_block_size(&self) -> OptionNum<T::Length> {
-        self.track_size.height.clone()
+    /// Get the number of columns spanned by the item.
+    #[inline(always)]
+    pub(crate) fn column_span(&self) -> usize {
+        self.column_span
     }
 
     /// Get the item's min-content size, if computed.
@@ -120,6 +163,13 @@ impl<'a, T: LayoutTreeNode> GridLayoutItem<'a, T> {
     pub(crate) fn set_computed_size(&mut self, computed_size: Size<T::Length>) {
         self.computed_size = computed_size;
     }
+
+    /// Set the baseline alignment shim of the item.
+    ///
+    /// CSS Grid §11.5 Step 1: the shim is added to the block size contributions.
+    pub(crate) fn set_baseline_shim(&mut self, baseline_shim: T::Length) {
+        self.baseline_shim = baseline_shim;
+    }
 }
 
 /// Grid item during the placement phase.
@@ -129,7 +179,7 @@ impl<'a, T: LayoutTreeNode> GridLayoutItem<'a, T> {
 ///
 /// This structure represents a grid item during the auto-placement phase,
 /// before track sizes have been fully resolved. It stores:
-/// - The item's position in the grid (row, column)
+/// - The item's position in the grid (row, column) and its span
 /// - The track sizing function for its row/column
 #[derive(Clone, PartialEq)]
 pub(crate) struct GridItem<'a, T: LayoutTreeNode> {
@@ -137,6 +187,10 @@ pub(crate) struct GridItem<'a, T: LayoutTreeNode> {
     row: usize,
     /// The item's column index in the grid (0-based)
     column: usize,
+    /// The number of rows spanned by the item
+    row_span: usize,
+    /// The number of columns spanned by the item
+    column_span: usize,
     /// Reference to the DOM node
     pub(crate) node: &'a T,
     /// Track sizing function for the block (row) axis
@@ -149,17 +203,28 @@ impl<'a, T: LayoutTreeNode> Debug for GridItem<'a, T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "GridItem {{ row: {}, column: {}, track_block_size: {:?}, track_inline_size: {:?} }}",
-            self.row, self.column, self.track_block_size, self.track_inline_size
+            "GridItem {{ row: {}, column: {}, span: {}x{}, track_block_size: {:?}, track_inline_size: {:?} }}",
+            self.row,
+            self.column,
+            self.row_span,
+            self.column_span,
+            self.track_block_size,
+            self.track_inline_size
         )
     }
 }
 
 impl<'a, T: LayoutTreeNode> GridItem<'a, T> {
-    pub fn new(node: &'a T, row: usize, column: usize) -> Self {
+    pub fn new(
+        node: &'a T,
+        (row, column): (usize, usize),
+        (row_span, column_span): (usize, usize),
+    ) -> Self {
         Self {
             row,
             column,
+            row_span,
+            column_span,
             node,
             track_block_size: TrackSize::Original(DefLength::Auto),
             track_inline_size: TrackSize::Original(DefLength::Auto),
@@ -178,6 +243,18 @@ impl<'a, T: LayoutTreeNode> GridItem<'a, T> {
         self.column
     }
 
+    /// Get the number of rows spanned by the item.
+    #[inline(always)]
+    pub(crate) fn row_span(&self) -> usize {
+        self.row_span
+    }
+
+    /// Get the number of columns spanned by the item.
+    #[inline(always)]
+    pub(crate) fn column_span(&self) -> usize {
+        self.column_span
+    }
+
     /// Update the track sizing function for the block (row) axis.
     pub(crate) fn update_track_block_size(&mut self, track_block_size: TrackSize<T>) {
         self.track_block_size = track_block_size;
@@ -0,0 +1,447 @@
+//! Masonry Layout
+//!
+//! CSS Grid 3: https://www.w3.org/TR/css-grid-3/#masonry-layout
+//!
+//! A grid container with `grid-template-rows: masonry` is a masonry container.
+//! Its columns form the grid axis and are sized by the regular track sizing algorithm,
+//! while its rows are replaced by the masonry axis, in which the items are stacked
+//! into the columns one after another (waterfall layout).
+//!
+//! Only `grid-template-rows: masonry` is supported here.
+//! `grid-template-columns: masonry` behaves as `none`.
+
+use alloc::vec::Vec;
+
+use float_pigment_css::{
+    length_num::LengthNum,
+    num_traits::Zero,
+    typing::{JustifyContent, JustifySelf, MasonryAutoFlow},
+};
+
+use crate::{
+    algo::grid::{
+        alignment::{
+            calculate_align_content_offset, calculate_justify_content_offset,
+            calculate_justify_offset, calculate_justify_offset_rtl, resolve_grid_justify_self,
+        },
+        baseline::ContainerBaselineCandidate,
+        grid_item::GridLayoutItem,
+        matrix::GridLayoutMatrix,
+        template::ExplicitTrackList,
+        track_sizing::{classify_track_at_index, compute_track_sizes},
+    },
+    is_display_none, is_independent_positioning, AxisDirection, AxisInfo, AxisReverse,
+    ComputeRequest, ComputeResult, DefLength, LayoutGridTemplate, LayoutStyle, LayoutTreeNode,
+    LayoutTreeVisitor, Normalized, OptionNum, OptionSize, Size, SizingMode, Vector,
+};
+
+/// Whether the grid container is a masonry container.
+pub(crate) fn is_masonry_container<T: LayoutTreeNode>(style: &T::Style) -> bool {
+    matches!(style.grid_template_rows(), LayoutGridTemplate::Masonry)
+}
+
+/// The running positions of the grid-axis tracks in the masonry axis.
+///
+/// CSS Grid 3 §3.2: each item is placed into the track with the smallest running
+/// position (`pack`), or into the track next to the previous item (`next`).
+/// The items are always auto-placed, since line-based placement is not supported,
+/// so `definite-first` and `ordered` are equivalent.
+pub(crate) struct MasonryPlacement<L: LengthNum> {
+    /// The end edge of the last item of each track, or `None` if the track is empty.
+    running_positions: Vec<Option<L>>,
+    next: bool,
+    last_track: Option<usize>,
+    gap: L,
+}
+
+impl<L: LengthNum> MasonryPlacement<L> {
+    /// Create the placement state of `track_count` empty tracks.
+    pub(crate) fn new(track_count: usize, auto_flow: &MasonryAutoFlow, gap: L) -> Self {
+        Self {
+            running_positions: alloc::vec![None; track_count],
+            next: matches!(
+                auto_flow,
+                MasonryAutoFlow::Next | MasonryAutoFlow::NextOrdered
+            ),
+            last_track: None,
+            gap,
+        }
+    }
+
+    fn start_position(&self, track: usize) -> L {
+        match self.running_positions[track] {
+            Some(x) => x + self.gap,
+            None => L::zero(),
+        }
+    }
+
+    /// The track the next item is placed into.
+    ///
+    /// Ties are broken by choosing the first track.
+    pub(crate) fn next_track(&self) -> usize {
+        if self.next {
+            return self
+                .last_track
+                .map_or(0, |x| (x + 1) % self.running_positions.len());
+        }
+        let mut track = 0;
+        for i in 1..self.running_positions.len() {
+            if self.start_position(i) < self.start_position(track) {
+                track = i;
+            }
+        }
+        track
+    }
+
+    /// Stack an item with the outer size `size` into `track`, and return its position.
+    pub(crate) fn push(&mut self, track: usize, size: L) -> L {
+        let position = self.start_position(track);
+        self.running_positions[track] = Some(position + size);
+        self.last_track = Some(track);
+        position
+    }
+
+    /// The size of the grid in the masonry axis, i.e. the largest running position.
+    pub(crate) fn block_size(&self) -> L {
+        self.running_positions
+            .iter()
+            .flatten()
+            .fold(L::zero(), |acc, x| if *x > acc { *x } else { acc })
+    }
+}
+
+/// The result of a masonry layout.
+pub(crate) struct MasonryLayout<L: LengthNum> {
+    /// The size of the grid, excluding the padding and border of the container.
+    pub(crate) grid_size: Size<L>,
+    /// The first baseline of the container, if any item provides one.
+    pub(crate) first_baseline: Option<Vector<L>>,
+    /// The last baseline of the container, if any item provides one.
+    pub(crate) last_baseline: Option<Vector<L>>,
+}
+
+/// Lay out the items of a masonry container.
+///
+/// 1. Size the columns with the track sizing algorithm (§11.5-11.8).
+///    CSS Grid 3 §3.1: the items are auto-placed, so each of them contributes to every column.
+/// 2. Stack each item into a column according to `masonry-auto-flow`,
+///    using the row gap between the items of the same column.
+/// 3. Align the columns with `justify-content` and the items with `justify-self`.
+///    `align-content` aligns the whole grid in the masonry axis.
+///    `align-self` does not apply in the masonry axis.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn compute_masonry_layout<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &T,
+    request: &ComputeRequest<T::Length>,
+    axis_info: AxisInfo,
+    explicit_columns: &ExplicitTrackList<T::Length, T::LengthCustom>,
+    column_gap: T::Length,
+    row_gap: T::Length,
+    requested_inner_size: Normalized<OptionSize<T::Length>>,
+    mut available_grid_space: OptionSize<T::Length>,
+) -> MasonryLayout<T::Length> {
+    let style = node.style();
+    let children = node
+        .tree_visitor()
+        .layout_children()
+        .into_iter()
+        .filter(|child| !is_independent_positioning(*child) && !is_display_none::<T>(child.style()))
+        .collect::<Vec<_>>();
+    let children_count = children.len();
+
+    // Without explicit columns, there is a single implicit column.
+    let column_track_list = &explicit_columns.tracks;
+    let grid_auto_columns = style.grid_auto_columns();
+    let grid_auto_rows = style.grid_auto_rows();
+    let column_count = column_track_list.len().max(1);
+
+    // CSS Grid §7.2.3.2: empty auto-fit tracks are collapsed.
+    // The first items are always placed into the first tracks, one in each.
+    let collapsed_columns = explicit_columns.collapsed_tracks(column_count, |i| i < children_count);
+    let visible_column_count = collapsed_columns.iter().filter(|x| !**x).count();
+    let total_column_gaps = if visible_column_count > 1 {
+        column_gap.mul_i32(visible_column_count as i32 - 1)
+    } else {
+        T::Length::zero()
+    };
+    available_grid_space.width = available_grid_space.width - total_column_gaps;
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Grid Axis Track Sizing
+    // CSS Grid 3 §3.1: https://www.w3.org/TR/css-grid-3/#masonry-layout-algorithm
+    // ═══════════════════════════════════════════════════════════════════════
+    let column_track_types: Vec<_> = (0..column_count)
+        .map(|i| classify_track_at_index(i, column_track_list, &grid_auto_columns))
+        .collect();
+    let needs_min_content = column_track_types.iter().any(|x| x.needs_min_content());
+    let needs_max_content = column_track_types.iter().any(|x| x.needs_max_content());
+    let unconstrained = Size::new(OptionNum::none(), OptionNum::none());
+
+    let mut grid_layout_matrix =
+        GridLayoutMatrix::new(1, column_count, children_count * column_count);
+    for child in children.iter() {
+        let mut child_layout_node = child.layout_node().unit();
+        let (child_margin, child_border, child_padding_border) =
+            child_layout_node.margin_border_padding(*child, unconstrained);
+        let css_size = child_layout_node.css_border_box_size(
+            *child,
+            unconstrained,
+            child_border,
+            child_padding_border,
+        );
+        let has_definite_css_size = css_size.width.is_some() && css_size.height.is_some();
+        let mut content_size = |sizing_mode| {
+            child_layout_node
+                .compute_internal(
+                    env,
+                    *child,
+                    ComputeRequest {
+                        size: Normalized(unconstrained),
+                        parent_inner_size: Normalized(unconstrained),
+                        max_content: Normalized(unconstrained),
+                        kind: crate::ComputeRequestKind::AllSize,
+                        parent_is_block: false,
+                        sizing_mode,
+                        line_clamp: None,
+                    },
+                )
+                .size
+                .0
+        };
+        let min_content_size = (!has_definite_css_size && needs_min_content)
+            .then(|| content_size(SizingMode::MinContent));
+        let max_content_size = (!has_definite_css_size && needs_max_content)
+            .then(|| content_size(SizingMode::MaxContent));
+        for column in 0..column_count {
+            let mut grid_layout_item =
+                GridLayoutItem::new(0, column, *child, child_margin, css_size, unconstrained);
+            grid_layout_item.set_min_content_size(min_content_size);
+            grid_layout_item.set_max_content_size(max_content_size);
+            grid_layout_item.set_computed_size(max_content_size.unwrap_or(Size::zero()));
+            grid_layout_matrix.add_item(grid_layout_item);
+        }
+    }
+
+    let (mut column_tracks, _) = compute_track_sizes(
+        node,
+        &grid_layout_matrix,
+        column_track_list,
+        &[],
+        &collapsed_columns,
+        &[],
+        available_grid_space,
+        &grid_auto_columns,
+        &grid_auto_rows,
+        (column_gap, row_gap),
+    );
+
+    // CSS Grid §11.6 Maximize Tracks and §11.8 Stretch auto Tracks
+    if !matches!(style.width(), DefLength::Auto) {
+        if let Some(container_width) = requested_inner_size.0.width.val() {
+            let free_space = container_width - column_tracks.total_base_size() - total_column_gaps;
+            column_tracks.maximize(free_space);
+            if matches!(
+                style.justify_content(),
+                JustifyContent::Normal | JustifyContent::Stretch
+            ) {
+                let free_space =
+                    container_width - column_tracks.total_base_size() - total_column_gaps;
+                column_tracks.stretch_auto_tracks(free_space);
+            }
+        }
+    }
+
+    let each_inline_size = column_tracks.resolved_sizes();
+    let total_inline_size = each_inline_size
+        .iter()
+        .fold(T::Length::zero(), |acc, cur| acc + *cur)
+        + total_column_gaps;
+
+    // CSS Grid §10.5: justify-content distributes the columns
+    let container_content_width = requested_inner_size.0.width.unwrap_or(total_inline_size);
+    let (inline_content_offset, inline_gap_addition) = calculate_justify_content_offset(
+        style.justify_content(),
+        total_inline_size,
+        container_content_width,
+        visible_column_count,
+    );
+    grid_layout_matrix.set_column_sizes(
+        &each_inline_size,
+        column_gap + inline_gap_addition,
+        &collapsed_columns,
+    );
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Masonry Axis Placement
+    // CSS Grid 3 §3.2: https://www.w3.org/TR/css-grid-3/#masonry-auto-flow
+    // ═══════════════════════════════════════════════════════════════════════
+    let mut placement = MasonryPlacement::new(column_count, &style.masonry_auto_flow(), row_gap);
+    let item_layouts: Vec<_> = children
+        .iter()
+        .map(|child| {
+            let track = placement.next_track();
+            let track_size = Size::new(OptionNum::some(each_inline_size[track]), OptionNum::none());
+            let mut layout_node = child.layout_node().unit();
+            let (margin, border, padding_border) =
+                layout_node.margin_border_padding(*child, track_size);
+            let css_size =
+                layout_node.css_border_box_size(*child, track_size, border, padding_border);
+            let justify_self = resolve_grid_justify_self::<T>(child.style(), style);
+            let width = if justify_self == JustifySelf::Stretch
+                && css_size.width.is_none()
+                && !margin.is_left_right_either_none()
+            {
+                track_size.width - margin.horizontal()
+            } else {
+                css_size.width.or(track_size.width)
+            };
+            let compute_result = layout_node.compute_internal(
+                env,
+                *child,
+                ComputeRequest {
+                    size: Normalized(Size::new(width, css_size.height)),
+                    parent_inner_size: Normalized(track_size),
+                    max_content: Normalized(track_size),
+                    kind: request.kind,
+                    parent_is_block: false,
+                    sizing_mode: request.sizing_mode,
+                    line_clamp: None,
+                },
+            );
+            let outer_block_size = compute_result.size.height + margin.vertical();
+            let block_position = placement.push(track, outer_block_size);
+            (track, block_position, margin, justify_self, compute_result)
+        })
+        .collect();
+
+    let total_block_size = placement.block_size();
+    let container_content_height = requested_inner_size.0.height.unwrap_or(total_block_size);
+    // The grid is a single alignment subject in the masonry axis.
+    let (block_content_offset, _) = calculate_align_content_offset(
+        style.align_content(),
+        total_block_size,
+        container_content_height,
+        1,
+    );
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Item Positioning
+    // ═══════════════════════════════════════════════════════════════════════
+    let is_inline_reversed = matches!(axis_info.cross_dir_rev, AxisReverse::Reversed);
+    let container_inline_size = match axis_info.dir {
+        AxisDirection::Vertical => container_content_width,
+        AxisDirection::Horizontal => container_content_height,
+    };
+    let axis_info_for_origin = if is_inline_reversed {
+        AxisInfo {
+            cross_dir_rev: AxisReverse::NotReversed,
+            ..axis_info
+        }
+    } else {
+        axis_info
+    };
+
+    // The items at the start of each column provide the first baseline,
+    // and the items at the end of them provide the last baseline.
+    let mut first_baseline = ContainerBaselineCandidate::new();
+    let mut last_baseline = ContainerBaselineCandidate::new();
+    let mut track_item_counts = alloc::vec![0; column_count];
+    for (child, (track, block_position, margin, justify_self, compute_result)) in
+        children.iter().zip(item_layouts.into_iter())
+    {
+        let ComputeResult {
+            size: Normalized(item_size),
+            first_baseline_ascent,
+            last_baseline_ascent,
+            ..
+        } = compute_result;
+        let track_width = each_inline_size[track];
+        let inline_offset = if is_inline_reversed {
+            container_inline_size
+                - inline_content_offset
+                - grid_layout_matrix.get_column_offset(track)
+                - track_width
+        } else {
+            inline_content_offset + grid_layout_matrix.get_column_offset(track)
+        };
+        let justify_offset = if is_inline_reversed {
+            calculate_justify_offset_rtl(justify_self, item_size.width, track_width)
+        } else {
+            calculate_justify_offset(justify_self, item_size.width, track_width)
+        };
+        let track_size = Size::new(track_width, item_size.height + margin.vertical());
+        let baseline_diff = child.layout_node().unit().gen_origin(
+            axis_info_for_origin,
+            track_size,
+            block_content_offset
+                + block_position
+                + margin
+                    .main_axis_start(axis_info.dir, axis_info.main_dir_rev)
+                    .or_zero(),
+            inline_offset
+                + justify_offset
+                + margin
+                    .cross_axis_start(axis_info.dir, axis_info.cross_dir_rev)
+                    .or_zero(),
+        );
+
+        let index_in_track = track_item_counts[track];
+        track_item_counts[track] += 1;
+        first_baseline.offer_first(
+            index_in_track,
+            track,
+            false,
+            first_baseline_ascent + baseline_diff,
+        );
+        last_baseline.offer_last(
+            index_in_track,
+            track,
+            false,
+            last_baseline_ascent + baseline_diff,
+        );
+    }
+
+    MasonryLayout {
+        grid_size: Size::new(total_inline_size, total_block_size),
+        first_baseline: first_baseline.baseline(),
+        last_baseline: last_baseline.baseline(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pack_into_shortest_track() {
+        let mut placement = MasonryPlacement::<f32>::new(3, &MasonryAutoFlow::Pack, 10.);
+        let sizes = [50., 20., 30., 10., 40.];
+        let tracks: Vec<_> = sizes
+            .iter()
+            .map(|size| {
+                let track = placement.next_track();
+                (track, placement.push(track, *size))
+            })
+            .collect();
+        assert_eq!(
+            tracks,
+            [(0, 0.), (1, 0.), (2, 0.), (1, 30.), (2, 40.)].to_vec()
+        );
+        assert_eq!(placement.block_size(), 80.);
+    }
+
+    #[test]
+    fn next_track_in_order() {
+        let mut placement = MasonryPlacement::<f32>::new(2, &MasonryAutoFlow::Next, 0.);
+        let tracks: Vec<_> = [50., 20., 30.]
+            .iter()
+            .map(|size| {
+                let track = placement.next_track();
+                (track, placement.push(track, *size))
+            })
+            .collect();
+        assert_eq!(tracks, [(0, 0.), (1, 0.), (0, 50.)].to_vec());
+        assert_eq!(placement.block_size(), 80.);
+    }
+}
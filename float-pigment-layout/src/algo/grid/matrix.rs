@@ -21,7 +21,7 @@ use float_pigment_css::typing::GridAutoFlow;
 
 use crate::{
     algo::grid::grid_item::{GridItem, GridLayoutItem},
-    LayoutTreeNode,
+    LayoutTreeNode, OptionNum,
 };
 
 /// Bitmap-based occupancy tracker for the grid.
@@ -102,9 +102,14 @@ impl OccupiedBitmap {
     }
 
     /// Check if the cell at (row, col) is occupied.
-    #[cfg(test)]
+    ///
+    /// The cells beyond the stride are never occupied, since the stride grows with the placed items.
     #[inline]
     fn get(&self, row: usize, col: usize) -> bool {
+        let offset = if self.row_order { col } else { row };
+        if offset >= self.stride {
+            return false;
+        }
         let (byte, bit) = self.byte_and_bit(row, col);
         if byte >= self.bits.len() {
             return false;
@@ -252,21 +257,23 @@ impl<'a, T: LayoutTreeNode> GridMatrix<'a, T> {
         }
     }
 
-    /// Place an item at the specified position, expanding the grid if needed.
+    /// Place an item at its position, expanding the grid if needed.
     ///
     /// This method:
     /// 1. Grows the bitmap stride if the item exceeds the current fixed dimension
-    /// 2. Marks the cell as occupied in the bitmap
+    /// 2. Marks the cells of its grid area as occupied in the bitmap
     /// 3. Updates grid boundaries
     /// 4. Adds the item to the items Vec
-    pub(crate) fn place_item(&mut self, row: usize, column: usize, item: GridItem<'a, T>) {
+    pub(crate) fn place_item(&mut self, item: GridItem<'a, T>) {
+        let (row, column) = (item.row(), item.column());
+        let (row_span, column_span) = (item.row_span(), item.column_span());
         // Check if stride needs to grow for implicit grid tracks.
         // Row flow: stride = column count, so check if col exceeds stride.
         // Column flow: stride = row count, so check if row exceeds stride.
         let stride = if self.occupied.row_order {
-            column + 1
+            column + column_span
         } else {
-            row + 1
+            row + row_span
         };
         if stride > self.occupied.stride() {
             let max_lines = if self.occupied.row_order {
@@ -277,11 +284,15 @@ impl<'a, T: LayoutTreeNode> GridMatrix<'a, T> {
             self.occupied.grow_stride(stride, max_lines);
         }
 
-        // Mark cell as occupied
-        self.occupied.set(row, column);
+        // Mark cells as occupied
+        for r in row..(row + row_span) {
+            for c in column..(column + column_span) {
+                self.occupied.set(r, c);
+            }
+        }
         // Update boundaries
-        self.max_row = self.max_row.max(row + 1);
-        self.max_col = self.max_col.max(column + 1);
+        self.max_row = self.max_row.max(row + row_span);
+        self.max_col = self.max_col.max(column + column_span);
         // Store item in the Vec
         self.items.push(item);
     }
@@ -299,6 +310,55 @@ impl<'a, T: LayoutTreeNode> GridMatrix<'a, T> {
         }
     }
 
+    /// Check if no cell of the grid area is occupied.
+    pub(crate) fn is_area_free(
+        &self,
+        (row, column): (usize, usize),
+        (row_span, column_span): (usize, usize),
+    ) -> bool {
+        (row..(row + row_span))
+            .all(|r| (column..(column + column_span)).all(|c| !self.occupied.get(r, c)))
+    }
+
+    /// Find the first position in the auto-flow order where a grid area of the span fits.
+    ///
+    /// The span in the fixed dimension is clamped to the stride.
+    /// The hint line is only updated for single cells.
+    pub(crate) fn find_first_fit(
+        &self,
+        hint_line: &mut usize,
+        (row_span, column_span): (usize, usize),
+    ) -> ((usize, usize), (usize, usize)) {
+        if row_span == 1 && column_span == 1 {
+            return (self.find_first_unoccupied(hint_line), (1, 1));
+        }
+        let stride = self.occupied.stride();
+        let (line_span, offset_span) = if self.occupied.row_order {
+            (row_span, column_span.min(stride))
+        } else {
+            (column_span, row_span.min(stride))
+        };
+        let span = if self.occupied.row_order {
+            (line_span, offset_span)
+        } else {
+            (offset_span, line_span)
+        };
+        let mut line = *hint_line;
+        loop {
+            for offset in 0..=(stride - offset_span) {
+                let pos = if self.occupied.row_order {
+                    (line, offset)
+                } else {
+                    (offset, line)
+                };
+                if self.is_area_free(pos, span) {
+                    return (pos, span);
+                }
+            }
+            line += 1;
+        }
+    }
+
     /// Get an iterator over all placed items.
     pub(crate) fn items(&self) -> impl Iterator<Item = &GridItem<'a, T>> {
         self.items.iter()
@@ -310,15 +370,19 @@ impl<'a, T: LayoutTreeNode> GridMatrix<'a, T> {
     }
 
     /// Returns the current number of rows (may exceed explicit grid).
+    ///
+    /// CSS Grid §7.1: the explicit grid always exists, even if some of its tracks are empty.
     #[inline(always)]
     pub(crate) fn row_count(&self) -> usize {
-        self.max_row
+        self.max_row.max(self.explicit_row_count)
     }
 
     /// Returns the current number of columns (may exceed explicit grid).
+    ///
+    /// CSS Grid §7.1: the explicit grid always exists, even if some of its tracks are empty.
     #[inline(always)]
     pub(crate) fn column_count(&self) -> usize {
-        self.max_col
+        self.max_col.max(self.explicit_column_count)
     }
 
     /// Returns the explicit row count defined by grid-template-rows.
@@ -416,32 +480,80 @@ impl<'a, T: LayoutTreeNode> GridLayoutMatrix<'a, T> {
     /// Set the row sizes and compute cumulative offsets.
     ///
     /// After calling this, `get_row_offset(i)` returns the y position of row i.
-    pub(crate) fn set_row_sizes(&mut self, sizes: &[T::Length], gap: T::Length) {
+    ///
+    /// Gutters next to collapsed tracks (empty `auto-fit` tracks) collapse,
+    /// so a gap is only placed between two non-collapsed tracks.
+    pub(crate) fn set_row_sizes(
+        &mut self,
+        sizes: &[T::Length],
+        gap: T::Length,
+        collapsed: &[bool],
+    ) {
         use float_pigment_css::num_traits::Zero;
         let mut offset = T::Length::zero();
-        self.row_offsets[0] = offset;
+        let mut has_visible_track = false;
         for (i, &size) in sizes.iter().enumerate() {
-            offset += size;
-            if i < sizes.len() - 1 {
-                offset += gap;
+            let is_collapsed = collapsed.get(i).copied().unwrap_or(false);
+            if !is_collapsed {
+                if has_visible_track {
+                    offset += gap;
+                }
+                has_visible_track = true;
             }
-            self.row_offsets[i + 1] = offset;
+            self.row_offsets[i] = offset;
+            offset += size;
         }
+        self.row_offsets[sizes.len()] = offset;
     }
 
     /// Set the column sizes and compute cumulative offsets.
     ///
     /// After calling this, `get_column_offset(j)` returns the x position of column j.
-    pub(crate) fn set_column_sizes(&mut self, sizes: &[T::Length], gap: T::Length) {
+    ///
+    /// Gutters next to collapsed tracks (empty `auto-fit` tracks) collapse,
+    /// so a gap is only placed between two non-collapsed tracks.
+    pub(crate) fn set_column_sizes(
+        &mut self,
+        sizes: &[T::Length],
+        gap: T::Length,
+        collapsed: &[bool],
+    ) {
         use float_pigment_css::num_traits::Zero;
         let mut offset = T::Length::zero();
-        self.column_offsets[0] = offset;
+        let mut has_visible_track = false;
         for (i, &size) in sizes.iter().enumerate() {
-            offset += size;
-            if i < sizes.len() - 1 {
-                offset += gap;
+            let is_collapsed = collapsed.get(i).copied().unwrap_or(false);
+            if !is_collapsed {
+                if has_visible_track {
+                    offset += gap;
+                }
+                has_visible_track = true;
             }
-            self.column_offsets[i + 1] = offset;
+            self.column_offsets[i] = offset;
+            offset += size;
+        }
+        self.column_offsets[sizes.len()] = offset;
+    }
+
+    /// Set the track size of each item to the size of its grid area,
+    /// the gaps between the spanned tracks included.
+    ///
+    /// The offsets must be set by `set_row_sizes` and `set_column_sizes` first.
+    pub(crate) fn set_item_area_sizes(
+        &mut self,
+        row_sizes: &[T::Length],
+        column_sizes: &[T::Length],
+    ) {
+        for item in self.items.iter_mut() {
+            let last_row = item.row + item.row_span - 1;
+            let last_column = item.column + item.column_span - 1;
+            item.track_size.width = OptionNum::some(
+                self.column_offsets[last_column] + column_sizes[last_column]
+                    - self.column_offsets[item.column],
+            );
+            item.track_size.height = OptionNum::some(
+                self.row_offsets[last_row] + row_sizes[last_row] - self.row_offsets[item.row],
+            );
         }
     }
 
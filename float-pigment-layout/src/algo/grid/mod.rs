@@ -29,6 +29,7 @@
 //!
 //! ## Related Specifications
 //! - CSS Grid Layout Module Level 1: <https://www.w3.org/TR/css-grid-1/>
+//! - CSS Grid Layout Module Level 3 (masonry): <https://www.w3.org/TR/css-grid-3/>
 //! - CSS Box Alignment Module Level 3: <https://www.w3.org/TR/css-align-3/>
 
 use alloc::vec::Vec;
@@ -38,9 +39,12 @@ use float_pigment_css::num_traits::Zero;
 use float_pigment_css::typing::{AlignSelf, JustifySelf};
 
 mod alignment;
+mod baseline;
 mod grid_item;
+mod masonry;
 mod matrix;
 mod placement;
+pub(crate) mod subgrid;
 mod template;
 mod track;
 mod track_size;
@@ -53,15 +57,22 @@ use crate::{
             calculate_justify_content_offset, calculate_justify_offset,
             calculate_justify_offset_rtl, resolve_grid_align_self, resolve_grid_justify_self,
         },
+        baseline::{BaselinePosition, ContainerBaselineCandidate, ItemBaseline, RowBaselines},
         grid_item::GridLayoutItem,
+        masonry::{compute_masonry_layout, is_masonry_container},
         matrix::{GridLayoutMatrix, GridMatrix},
         placement::place_grid_items,
-        template::initialize_track_list,
+        subgrid::{
+            adopt_tracks, adopted_track, inset_adopted_tracks, measure_subgrid, outset_track_sizes,
+            resolved_tracks, spanned_track_functions, subgrid_gap, AdoptedTracks, SubgridAxes,
+            SubgridContributions, SubgridTrackSizes,
+        },
+        template::{initialize_track_list, ExplicitTrackList},
         track_size::apply_track_size,
         track_sizing::{classify_track_at_index, compute_track_sizes},
     },
-    compute_special_position_children, is_display_none, is_independent_positioning, AxisInfo,
-    AxisReverse, CollapsedBlockMargin, ComputeRequest, ComputeRequestKind, ComputeResult,
+    compute_special_position_children, is_display_none, is_independent_positioning, AxisDirection,
+    AxisInfo, AxisReverse, CollapsedBlockMargin, ComputeRequest, ComputeRequestKind, ComputeResult,
     DefLength, Edge, EdgeOption, LayoutStyle, LayoutTreeNode, LayoutTreeVisitor, LayoutUnit,
     Normalized, OptionNum, OptionSize, Point, Size, SizingMode, Vector,
 };
@@ -159,10 +170,16 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
             return size;
         }
 
-        let requested_size = request.size;
+        // CSS Writing Modes §6: the sizes below are logical,
+        // i.e. the width is the inline size and the height is the block size.
+        let requested_size = logical_size(axis_info, *request.size);
+        let padding_border_inline = logical_size(
+            axis_info,
+            Size::new(padding_border.horizontal(), padding_border.vertical()),
+        );
         let requested_inner_size = Normalized(OptionSize::new(
-            requested_size.width - padding_border.horizontal(),
-            requested_size.height - padding_border.vertical(),
+            requested_size.width - padding_border_inline.width,
+            requested_size.height - padding_border_inline.height,
         ));
         // CSS Grid §11.3 "available grid space" (per dimension):
         //   - Definite container size => use its content box.
@@ -170,12 +187,14 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
         //     (still indefinite).
         //   - Otherwise => indefinite.
         let available_grid_space_source = match request.sizing_mode {
-            SizingMode::Normal => *requested_size,
-            SizingMode::MinContent | SizingMode::MaxContent => *request.max_content,
+            SizingMode::Normal => requested_size,
+            SizingMode::MinContent | SizingMode::MaxContent => {
+                logical_size(axis_info, *request.max_content)
+            }
         };
         let mut available_grid_space = OptionSize::new(
-            available_grid_space_source.width - padding_border.horizontal(),
-            available_grid_space_source.height - padding_border.vertical(),
+            available_grid_space_source.width - padding_border_inline.width,
+            available_grid_space_source.height - padding_border_inline.height,
         );
 
         // ═══════════════════════════════════════════════════════════════════════
@@ -185,14 +204,24 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
         // The row-gap and column-gap properties define the size of the gutters
         // between grid rows and columns respectively.
         // ═══════════════════════════════════════════════════════════════════════
-        let column_gap = style
-            .column_gap()
-            .resolve(available_grid_space.width, node)
-            .or_zero();
-        let row_gap = style
-            .row_gap()
-            .resolve(available_grid_space.height, node)
-            .or_zero();
+        // CSS Grid §9: a subgrid inherits the gaps of its parent grid in the subgridded axes.
+        let subgrid_axes = SubgridAxes::of(node);
+        let column_gap = if subgrid_axes.columns {
+            subgrid_gap(node, |style| style.column_gap()).or_zero()
+        } else {
+            style
+                .column_gap()
+                .resolve(available_grid_space.width, node)
+                .or_zero()
+        };
+        let row_gap = if subgrid_axes.rows {
+            subgrid_gap(node, |style| style.row_gap()).or_zero()
+        } else {
+            style
+                .row_gap()
+                .resolve(available_grid_space.height, node)
+                .or_zero()
+        };
 
         // ═══════════════════════════════════════════════════════════════════════
         // STEP 3: Resolve the Explicit Grid
@@ -210,8 +239,86 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
         let grid_auto_rows = style.grid_auto_rows();
         let grid_auto_columns = style.grid_auto_columns();
 
-        let row_track_list = initialize_track_list::<T>(&grid_template_rows);
-        let column_track_list = initialize_track_list::<T>(&grid_template_columns);
+        // CSS Grid §7.2.3: repeat() is expanded here, and the number of
+        // auto-fill / auto-fit repetitions is resolved against the available space.
+        let explicit_rows = initialize_track_list(
+            &grid_template_rows,
+            node,
+            available_grid_space.height,
+            row_gap,
+        );
+        let explicit_columns = initialize_track_list(
+            &grid_template_columns,
+            node,
+            available_grid_space.width,
+            column_gap,
+        );
+
+        // CSS Grid §9: a subgrid adopts the parent tracks it spans in the subgridded axes,
+        // without the margin, border, and padding at its edges.
+        let margin_border_padding = margin.or_zero() + padding_border;
+        let adopted_row_tracks = match self.subgrid_tracks.rows.clone() {
+            Some(mut tracks) => {
+                inset_adopted_tracks(
+                    &mut tracks,
+                    margin_border_padding.main_axis_start(axis_info.dir, axis_info.main_dir_rev),
+                    margin_border_padding.main_axis_end(axis_info.dir, axis_info.main_dir_rev),
+                );
+                tracks
+            }
+            None => alloc::vec![adopted_track(available_grid_space.height)],
+        };
+        let adopted_column_tracks = match self.subgrid_tracks.columns.clone() {
+            Some(mut tracks) => {
+                inset_adopted_tracks(
+                    &mut tracks,
+                    margin_border_padding.cross_axis_start(axis_info.dir, axis_info.cross_dir_rev),
+                    margin_border_padding.cross_axis_end(axis_info.dir, axis_info.cross_dir_rev),
+                );
+                tracks
+            }
+            None => alloc::vec![adopted_track(available_grid_space.width)],
+        };
+        let explicit_rows = if subgrid_axes.rows {
+            ExplicitTrackList::adopted(&adopted_row_tracks)
+        } else {
+            explicit_rows
+        };
+        let explicit_columns = if subgrid_axes.columns {
+            ExplicitTrackList::adopted(&adopted_column_tracks)
+        } else {
+            explicit_columns
+        };
+        let row_track_list = &explicit_rows.tracks;
+        let column_track_list = &explicit_columns.tracks;
+
+        // CSS Grid 3: a masonry container only has tracks in its grid axis,
+        // and the items are stacked in the masonry axis instead of being placed into rows.
+        if is_masonry_container::<T>(style) {
+            let masonry = compute_masonry_layout(
+                env,
+                node,
+                &request,
+                axis_info,
+                &explicit_columns,
+                column_gap,
+                row_gap,
+                requested_inner_size,
+                available_grid_space,
+            );
+            return finish_grid_layout(
+                self,
+                env,
+                node,
+                &request,
+                border,
+                padding_border,
+                axis_info,
+                masonry.grid_size,
+                masonry.first_baseline,
+                masonry.last_baseline,
+            );
+        }
 
         // ═══════════════════════════════════════════════════════════════════════
         // STEP 4: Grid Item Placement (Single-Pass with Dynamic Expansion)
@@ -227,7 +334,8 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
         // https://www.w3.org/TR/css-grid-1/#abspos
         let children = node
             .tree_visitor()
-            .children_iter()
+            .layout_children()
+            .into_iter()
             .filter(|child| {
                 !is_independent_positioning(*child) && !is_display_none::<T>(child.style())
             })
@@ -242,21 +350,38 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
         );
 
         // Single-pass placement with automatic grid expansion
-        place_grid_items(&mut grid_matrix, children.into_iter());
+        place_grid_items(&mut grid_matrix, children.into_iter(), subgrid_axes);
 
         // After placement, get the actual grid dimensions (may include implicit tracks)
         let actual_row_count = grid_matrix.row_count();
         let actual_column_count = grid_matrix.column_count();
 
+        // CSS Grid §7.2.3.2: empty tracks generated by auto-fit are collapsed
+        let (collapsed_rows, collapsed_columns) = {
+            let mut occupied_rows = alloc::vec![false; actual_row_count];
+            let mut occupied_columns = alloc::vec![false; actual_column_count];
+            for item in grid_matrix.items() {
+                occupied_rows[item.row()..(item.row() + item.row_span())].fill(true);
+                occupied_columns[item.column()..(item.column() + item.column_span())].fill(true);
+            }
+            (
+                explicit_rows.collapsed_tracks(actual_row_count, |i| occupied_rows[i]),
+                explicit_columns.collapsed_tracks(actual_column_count, |i| occupied_columns[i]),
+            )
+        };
+        let visible_row_count = collapsed_rows.iter().filter(|x| !**x).count();
+        let visible_column_count = collapsed_columns.iter().filter(|x| !**x).count();
+
         // Calculate total gap space: (n-1) gaps for n tracks
         // CSS Grid §10.1: Gutters are only placed between tracks, not at edges
-        let total_column_gaps = if actual_column_count > 1 {
-            column_gap.mul_i32(actual_column_count as i32 - 1)
+        // (gutters around collapsed tracks collapse as well)
+        let total_column_gaps = if visible_column_count > 1 {
+            column_gap.mul_i32(visible_column_count as i32 - 1)
         } else {
             T::Length::zero()
         };
-        let total_row_gaps = if actual_row_count > 1 {
-            row_gap.mul_i32(actual_row_count as i32 - 1)
+        let total_row_gaps = if visible_row_count > 1 {
+            row_gap.mul_i32(visible_row_count as i32 - 1)
         } else {
             T::Length::zero()
         };
@@ -362,127 +487,210 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
 
         // Pre-compute which tracks need intrinsic layout passes.
         //
-        // min-content pass needed for: auto / fr / min-content / max-content tracks.
-        // max-content pass needed for: auto / max-content tracks (§11.5 Step 4).
-        // Fixed tracks (length/percentage) don't need either.
+        // min-content pass needed for: auto / fr / min-content / max-content / fit-content tracks.
+        // max-content pass needed for: auto / max-content / fit-content tracks (§11.5 Step 4).
+        // Fixed tracks (length/percentage, or `minmax()` of them) don't need either.
         let column_track_types: Vec<_> = (0..actual_column_count)
-            .map(|i| classify_track_at_index(i, &column_track_list, &grid_auto_columns))
+            .map(|i| classify_track_at_index(i, column_track_list, &grid_auto_columns))
             .collect();
         let row_track_types: Vec<_> = (0..actual_row_count)
-            .map(|i| classify_track_at_index(i, &row_track_list, &grid_auto_rows))
+            .map(|i| classify_track_at_index(i, row_track_list, &grid_auto_rows))
             .collect();
 
+        let mut item_baselines = Vec::with_capacity(children_count);
         for grid_item in grid_matrix.items() {
             let row = grid_item.row();
             let column = grid_item.column();
+            let rows = row..(row + grid_item.row_span());
+            let columns = column..(column + grid_item.column_span());
             let child_node = grid_item.node;
             let mut child_layout_node = child_node.layout_node().unit();
 
+            // CSS Grid §9: a subgrid is measured with the sizing functions of the spanned tracks,
+            // so that its items contribute to them
+            let child_subgrid_axes = SubgridAxes::of(child_node);
+            if child_subgrid_axes != SubgridAxes::default() {
+                adopt_tracks(
+                    &mut child_layout_node,
+                    AdoptedTracks {
+                        rows: child_subgrid_axes.rows.then(|| {
+                            spanned_track_functions(row_track_list, &grid_auto_rows, rows.clone())
+                        }),
+                        columns: child_subgrid_axes.columns.then(|| {
+                            spanned_track_functions(
+                                column_track_list,
+                                &grid_auto_columns,
+                                columns.clone(),
+                            )
+                        }),
+                    },
+                );
+            }
+
             let fixed_track_inline_size = grid_item.fixed_track_inline_size().unwrap().clone();
             let fixed_track_block_size = grid_item.fixed_track_block_size().unwrap().clone();
 
             let track_size = Size::new(fixed_track_inline_size, fixed_track_block_size);
+            let physical_track_size = logical_size(axis_info, track_size);
 
             let (child_margin, child_border, child_padding_border) =
-                child_layout_node.margin_border_padding(child_node, track_size);
+                child_layout_node.margin_border_padding(child_node, physical_track_size);
             let css_size = child_layout_node.css_border_box_size(
                 child_node,
-                track_size,
+                physical_track_size,
                 child_border,
                 child_padding_border,
             );
             let min_max_limit_css_size = child_layout_node
                 .normalized_min_max_limit(
                     child_node,
-                    track_size,
+                    physical_track_size,
                     child_border,
                     child_padding_border,
                 )
                 .normalized_size(css_size);
 
             let size = Normalized(Size::new(
-                min_max_limit_css_size.0.width.or(track_size.width),
-                min_max_limit_css_size.0.height.or(track_size.height),
+                min_max_limit_css_size.0.width.or(physical_track_size.width),
+                min_max_limit_css_size
+                    .0
+                    .height
+                    .or(physical_track_size.height),
             ));
+            let css_size = logical_size(axis_info, css_size);
 
             let has_definite_css_width = css_size.width.is_some();
             let has_definite_css_height = css_size.height.is_some();
-            let item_tracks_all_fixed = !column_track_types[column].needs_min_content()
-                && !row_track_types[row].needs_min_content();
+            let item_tracks_all_fixed = !column_track_types[columns.clone()]
+                .iter()
+                .any(|x| x.needs_min_content())
+                && !row_track_types[rows.clone()]
+                    .iter()
+                    .any(|x| x.needs_min_content());
             let unconstrained = Size::new(OptionNum::none(), OptionNum::none());
 
+            // A subgrid also reports the sizes of its tracks,
+            // which are its contributions to each of the spanned tracks.
+            let is_subgrid = child_subgrid_axes != SubgridAxes::default();
+            let mut measure = |unit: &mut LayoutUnit<T>, request: ComputeRequest<T::Length>| {
+                if is_subgrid {
+                    measure_subgrid(unit, env, child_node, request)
+                } else {
+                    (unit.compute_internal(env, child_node, request), None)
+                }
+            };
+
             // §11.5 Step 2: min-content contribution for base_size
+            let mut min_content_tracks = None;
             let min_content_size =
                 if (has_definite_css_width && has_definite_css_height) || item_tracks_all_fixed {
                     None
                 } else {
-                    Some(
-                        child_layout_node
-                            .compute_internal(
-                                env,
-                                child_node,
-                                ComputeRequest {
-                                    size: Normalized(unconstrained),
-                                    parent_inner_size: Normalized(unconstrained),
-                                    max_content: Normalized(unconstrained),
-                                    kind: ComputeRequestKind::AllSize,
-                                    parent_is_block: false,
-                                    sizing_mode: SizingMode::MinContent,
-                                },
-                            )
-                            .size
-                            .0,
-                    )
-                };
+                    let (res, tracks) = measure(
+                        &mut child_layout_node,
+                        ComputeRequest {
+                            size: Normalized(unconstrained),
+                            parent_inner_size: Normalized(unconstrained),
+                            max_content: Normalized(unconstrained),
+                            kind: ComputeRequestKind::AllSize,
+                            parent_is_block: false,
+                            sizing_mode: SizingMode::MinContent,
+                            line_clamp: None,
+                        },
+                    );
+                    min_content_tracks = tracks;
+                    Some(res.size.0)
+                }
+                .map(|size| logical_size(axis_info, size));
 
             // §11.5 Step 4: max-content contribution for growth_limit
             // Must use unconstrained (infinite) available space, not track_size.
-            let needs_max_content = column_track_types[column].needs_max_content()
-                || row_track_types[row].needs_max_content();
+            let needs_max_content = column_track_types[columns.clone()]
+                .iter()
+                .any(|x| x.needs_max_content())
+                || row_track_types[rows.clone()]
+                    .iter()
+                    .any(|x| x.needs_max_content());
+            let mut max_content_tracks = None;
             let max_content_size =
                 if (has_definite_css_width && has_definite_css_height) || !needs_max_content {
                     None
                 } else {
-                    Some(
-                        child_layout_node
-                            .compute_internal(
-                                env,
-                                child_node,
-                                ComputeRequest {
-                                    size: Normalized(unconstrained),
-                                    parent_inner_size: Normalized(unconstrained),
-                                    max_content: Normalized(unconstrained),
-                                    kind: ComputeRequestKind::AllSize,
-                                    parent_is_block: false,
-                                    sizing_mode: SizingMode::MaxContent,
-                                },
-                            )
-                            .size
-                            .0,
-                    )
-                };
-
-            let res = child_layout_node.compute_internal(
-                env,
-                child_node,
+                    let (res, tracks) = measure(
+                        &mut child_layout_node,
+                        ComputeRequest {
+                            size: Normalized(unconstrained),
+                            parent_inner_size: Normalized(unconstrained),
+                            max_content: Normalized(unconstrained),
+                            kind: ComputeRequestKind::AllSize,
+                            parent_is_block: false,
+                            sizing_mode: SizingMode::MaxContent,
+                            line_clamp: None,
+                        },
+                    );
+                    max_content_tracks = tracks;
+                    Some(res.size.0)
+                }
+                .map(|size| logical_size(axis_info, size));
+
+            // The size request also returns the baselines of the baseline-aligned items;
+            // they are positioned in the final pass.
+            let (align_self, _) = resolve_item_self_alignment(child_node, style);
+            let (res, tracks) = measure(
+                &mut child_layout_node,
                 ComputeRequest {
                     size,
-                    parent_inner_size: Normalized(track_size),
-                    max_content: Normalized(track_size),
+                    parent_inner_size: Normalized(physical_track_size),
+                    max_content: Normalized(physical_track_size),
                     kind: ComputeRequestKind::AllSize,
                     parent_is_block: false,
                     sizing_mode: request.sizing_mode,
+                    line_clamp: None,
                 },
             );
+            item_baselines.push(ItemBaseline::new(row, &align_self, &res, &child_margin));
 
-            let mut grid_layout_item =
-                GridLayoutItem::new(row, column, child_node, child_margin, css_size, track_size);
+            let mut grid_layout_item = GridLayoutItem::new(
+                row,
+                column,
+                child_node,
+                logical_edge(axis_info, child_margin),
+                css_size,
+                track_size,
+            )
+            .with_span(rows.len(), columns.len())
+            .with_subgrid_contributions(
+                SubgridContributions::new(
+                    min_content_tracks.as_ref().and_then(|x| x.columns.clone()),
+                    max_content_tracks.and_then(|x| x.columns),
+                    columns.len(),
+                ),
+                SubgridContributions::new(
+                    min_content_tracks.and_then(|x| x.rows),
+                    tracks.and_then(|x| x.rows),
+                    rows.len(),
+                ),
+            );
             grid_layout_item.set_min_content_size(min_content_size);
             grid_layout_item.set_max_content_size(max_content_size);
-            grid_layout_item.set_computed_size(res.size.0);
+            grid_layout_item.set_computed_size(logical_size(axis_info, res.size.0));
             grid_layout_matrix.add_item(grid_layout_item);
         }
 
+        // CSS Grid §11.5 Step 1: shim baseline-aligned items,
+        // so that their intrinsic size contributions reflect their baseline alignment.
+        let row_baselines = RowBaselines::new(
+            grid_layout_matrix.row_count(),
+            item_baselines.iter().flatten(),
+        );
+        for (grid_layout_item, baseline) in
+            grid_layout_matrix.items_mut().zip(item_baselines.iter())
+        {
+            if let Some(baseline) = baseline {
+                grid_layout_item.set_baseline_shim(row_baselines.shim(baseline));
+            }
+        }
+
         drop(grid_matrix);
 
         // ═══════════════════════════════════════════════════════════════════════
@@ -498,16 +706,28 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
         // - For fr tracks: distribute remaining space via iterative algorithm
         // ═══════════════════════════════════════════════════════════════════════
         let (mut column_tracks, mut row_tracks) = compute_track_sizes(
+            node,
             &grid_layout_matrix,
-            &column_track_list,
-            &row_track_list,
+            column_track_list,
+            row_track_list,
+            &collapsed_columns,
+            &collapsed_rows,
             available_grid_space,
             &grid_auto_columns,
             &grid_auto_rows,
+            (column_gap, row_gap),
         );
 
-        let has_definite_width = !matches!(style.width(), DefLength::Auto);
-        let has_definite_height = !matches!(style.height(), DefLength::Auto);
+        let (has_definite_width, has_definite_height) = {
+            let definite = logical_size(
+                axis_info,
+                Size::new(
+                    !matches!(style.width(), DefLength::Auto),
+                    !matches!(style.height(), DefLength::Auto),
+                ),
+            );
+            (definite.width, definite.height)
+        };
 
         // ═══════════════════════════════════════════════════════════════════════
         // STEP 7b: Maximize Tracks (§11.6)
@@ -572,13 +792,26 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
         // Get final track sizes
         let each_inline_size = column_tracks.resolved_sizes();
         let each_block_size = row_tracks.resolved_sizes();
-
-        // Update items with maximized track sizes
-        for item in grid_layout_matrix.items_mut() {
-            let row = item.row();
-            let column = item.column();
-            item.track_size.width = OptionNum::some(each_inline_size[column]);
-            item.track_size.height = OptionNum::some(each_block_size[row]);
+        if subgrid_axes != SubgridAxes::default() {
+            self.subgrid_track_sizes = Some(SubgridTrackSizes {
+                rows: subgrid_axes.rows.then(|| {
+                    outset_track_sizes(
+                        &each_block_size,
+                        margin_border_padding
+                            .main_axis_start(axis_info.dir, axis_info.main_dir_rev),
+                        margin_border_padding.main_axis_end(axis_info.dir, axis_info.main_dir_rev),
+                    )
+                }),
+                columns: subgrid_axes.columns.then(|| {
+                    outset_track_sizes(
+                        &each_inline_size,
+                        margin_border_padding
+                            .cross_axis_start(axis_info.dir, axis_info.cross_dir_rev),
+                        margin_border_padding
+                            .cross_axis_end(axis_info.dir, axis_info.cross_dir_rev),
+                    )
+                }),
+            });
         }
 
         let total_inline_size: T::Length = each_inline_size
@@ -609,22 +842,28 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
             style.align_content(),
             total_block_size,
             container_content_height,
-            grid_layout_matrix.row_count(),
+            visible_row_count,
         );
 
         let (inline_content_offset, inline_gap_addition) = calculate_justify_content_offset(
             style.justify_content(),
             total_inline_size,
             container_content_width,
-            grid_layout_matrix.column_count(),
+            visible_column_count,
         );
 
         // Precompute row and column offsets for O(1) lookup during positioning
         // This includes content alignment gaps
         let row_gap_with_alignment = row_gap + block_gap_addition;
         let column_gap_with_alignment = column_gap + inline_gap_addition;
-        grid_layout_matrix.set_row_sizes(&each_block_size, row_gap_with_alignment);
-        grid_layout_matrix.set_column_sizes(&each_inline_size, column_gap_with_alignment);
+        grid_layout_matrix.set_row_sizes(&each_block_size, row_gap_with_alignment, &collapsed_rows);
+        grid_layout_matrix.set_column_sizes(
+            &each_inline_size,
+            column_gap_with_alignment,
+            &collapsed_columns,
+        );
+
+        grid_layout_matrix.set_item_area_sizes(&each_block_size, &each_inline_size);
 
         // ═══════════════════════════════════════════════════════════════════════
         // STEP 9: Item Positioning and Self-Alignment
@@ -636,36 +875,125 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
         //
         // Items are positioned at: base_offset + alignment_offset + margin
         // ═══════════════════════════════════════════════════════════════════════
-        // Check if inline axis is reversed (RTL)
+        // Check if inline axis is reversed (RTL),
+        // or the block axis is reversed (`vertical-rl`)
         let is_inline_reversed = matches!(axis_info.cross_dir_rev, AxisReverse::Reversed);
+        let is_block_reversed = matches!(axis_info.main_dir_rev, AxisReverse::Reversed);
 
-        let container_inline_size = match axis_info.dir {
-            crate::AxisDirection::Vertical => container_content_width,
-            crate::AxisDirection::Horizontal => container_content_height,
+        let axis_info_for_origin = AxisInfo {
+            main_dir_rev: AxisReverse::NotReversed,
+            cross_dir_rev: AxisReverse::NotReversed,
+            ..axis_info
         };
 
-        let axis_info_for_origin = if is_inline_reversed {
-            AxisInfo {
-                cross_dir_rev: AxisReverse::NotReversed,
-                ..axis_info
-            }
-        } else {
-            axis_info
-        };
+        // First, lay out each item in its grid area, so that the baselines of
+        // the baseline-aligned items are known before positioning.
+        let item_layouts: Vec<_> = grid_layout_matrix
+            .items()
+            .map(|grid_layout_item| {
+                let mut layout_node = grid_layout_item.node.layout_node().unit();
+
+                // CSS Grid §9: a subgrid adopts the resolved sizes of the spanned tracks
+                let child_subgrid_axes = SubgridAxes::of(grid_layout_item.node);
+                if child_subgrid_axes != SubgridAxes::default() {
+                    let rows = grid_layout_item.row()
+                        ..(grid_layout_item.row() + grid_layout_item.row_span());
+                    let columns = grid_layout_item.column()
+                        ..(grid_layout_item.column() + grid_layout_item.column_span());
+                    adopt_tracks(
+                        &mut layout_node,
+                        AdoptedTracks {
+                            rows: child_subgrid_axes
+                                .rows
+                                .then(|| resolved_tracks(&each_block_size[rows])),
+                            columns: child_subgrid_axes
+                                .columns
+                                .then(|| resolved_tracks(&each_inline_size[columns])),
+                        },
+                    );
+                }
+
+                // CSS Box Alignment §6.1: Resolve align-self/justify-self for stretch
+                // https://www.w3.org/TR/css-align-3/#self-alignment
+                let (align_self, justify_self) =
+                    resolve_item_self_alignment(grid_layout_item.node, style);
+
+                let has_auto_horizontal_margin =
+                    grid_layout_item.margin.is_left_right_either_none();
+                let has_auto_vertical_margin = grid_layout_item.margin.is_top_bottom_either_none();
+
+                let stretch_width = if justify_self == JustifySelf::Stretch
+                    && grid_layout_item.css_size.width.is_none()
+                    && !has_auto_horizontal_margin
+                {
+                    grid_layout_item.track_size.width - grid_layout_item.margin.horizontal()
+                } else {
+                    grid_layout_item
+                        .css_size
+                        .width
+                        .or(grid_layout_item.track_size.width)
+                };
+                let stretch_height = if align_self == AlignSelf::Stretch
+                    && grid_layout_item.css_size.height.is_none()
+                    && !has_auto_vertical_margin
+                {
+                    grid_layout_item.track_size.height - grid_layout_item.margin.vertical()
+                } else {
+                    grid_layout_item
+                        .css_size
+                        .height
+                        .or(grid_layout_item.track_size.height)
+                };
+
+                let size = logical_size(axis_info, Size::new(stretch_width, stretch_height));
+                let physical_track_size = logical_size(axis_info, grid_layout_item.track_size);
+
+                let compute_result = layout_node.compute_internal(
+                    env,
+                    grid_layout_item.node,
+                    ComputeRequest {
+                        size: Normalized(size),
+                        parent_inner_size: Normalized(physical_track_size),
+                        max_content: Normalized(physical_track_size),
+                        kind: request.kind,
+                        parent_is_block: false,
+                        sizing_mode: request.sizing_mode,
+                        line_clamp: None,
+                    },
+                );
+                let baseline = ItemBaseline::new(
+                    grid_layout_item.row(),
+                    &align_self,
+                    &compute_result,
+                    &grid_layout_item.margin,
+                );
+                (align_self, justify_self, compute_result, baseline)
+            })
+            .collect();
 
-        for grid_layout_item in grid_layout_matrix.items() {
+        // CSS Box Alignment §9.1: baseline-sharing groups of each row
+        let row_baselines = RowBaselines::new(
+            grid_layout_matrix.row_count(),
+            item_layouts
+                .iter()
+                .filter_map(|(_, _, _, baseline)| baseline.as_ref()),
+        );
+        let mut first_baseline = ContainerBaselineCandidate::new();
+        let mut last_baseline = ContainerBaselineCandidate::new();
+
+        for (grid_layout_item, (align_self, justify_self, compute_result, baseline)) in
+            grid_layout_matrix.items().zip(item_layouts.into_iter())
+        {
             let row = grid_layout_item.row();
             let column = grid_layout_item.column();
 
-            let block_offset = block_content_offset + grid_layout_matrix.get_row_offset(row);
-
             let track_width = grid_layout_item
                 .track_size
                 .width
                 .val()
                 .unwrap_or(T::Length::zero());
             let inline_offset = if is_inline_reversed {
-                container_inline_size
+                container_content_width
                     - inline_content_offset
                     - grid_layout_matrix.get_column_offset(column)
                     - track_width
@@ -676,67 +1004,32 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
 
             let mut layout_node = grid_layout_item.node.layout_node().unit();
 
-            // CSS Box Alignment §6.1: Resolve align-self/justify-self for stretch
-            // https://www.w3.org/TR/css-align-3/#self-alignment
-            let child_style = grid_layout_item.node.style();
-            let align_self = resolve_grid_align_self::<T>(child_style, style);
-            let justify_self = resolve_grid_justify_self::<T>(child_style, style);
-
-            let has_auto_horizontal_margin = grid_layout_item.margin.is_left_right_either_none();
-            let has_auto_vertical_margin = grid_layout_item.margin.is_top_bottom_either_none();
-
-            let stretch_width = if justify_self == JustifySelf::Stretch
-                && grid_layout_item.css_size.width.is_none()
-                && !has_auto_horizontal_margin
-            {
-                grid_layout_item.track_size.width - grid_layout_item.margin.horizontal()
-            } else {
-                grid_layout_item
-                    .css_size
-                    .width
-                    .or(grid_layout_item.track_size.width)
-            };
-            let stretch_height = if align_self == AlignSelf::Stretch
-                && grid_layout_item.css_size.height.is_none()
-                && !has_auto_vertical_margin
-            {
-                grid_layout_item.track_size.height - grid_layout_item.margin.vertical()
-            } else {
-                grid_layout_item
-                    .css_size
-                    .height
-                    .or(grid_layout_item.track_size.height)
-            };
-
-            let size = Size::new(stretch_width, stretch_height);
-
-            let compute_result = layout_node.compute_internal(
-                env,
-                grid_layout_item.node,
-                ComputeRequest {
-                    size: Normalized(size),
-                    parent_inner_size: Normalized(grid_layout_item.track_size),
-                    max_content: Normalized(grid_layout_item.track_size),
-                    kind: request.kind,
-                    parent_is_block: false,
-                    sizing_mode: request.sizing_mode,
-                },
-            );
-
             let track_height = grid_layout_item
                 .track_size
                 .height
                 .val()
                 .unwrap_or(T::Length::zero());
 
+            let block_offset = if is_block_reversed {
+                container_content_height
+                    - block_content_offset
+                    - grid_layout_matrix.get_row_offset(row)
+                    - track_height
+            } else {
+                block_content_offset + grid_layout_matrix.get_row_offset(row)
+            };
+
             let track_size = Size::new(track_width, track_height);
 
             // Get the actual item size (computed size)
-            let item_size = compute_result.size.0;
+            let item_size = logical_size(axis_info, compute_result.size.0);
 
             // Calculate alignment offset in block axis (vertical)
-            let align_offset =
-                calculate_alignment_offset(align_self, item_size.height, track_size.height);
+            // Baseline-aligned items are aligned within their baseline-sharing group
+            let align_offset = match baseline.as_ref() {
+                Some(baseline) => row_baselines.alignment_offset(baseline, track_size.height),
+                None => calculate_alignment_offset(align_self, item_size.height, track_size.height),
+            };
 
             // Calculate justify offset in inline axis (horizontal)
             // For RTL, reverse the justify alignment direction
@@ -746,57 +1039,153 @@ impl<T: LayoutTreeNode> GridContainer<T> for LayoutUnit<T> {
                 calculate_justify_offset(justify_self, item_size.width, track_size.width)
             };
 
-            layout_node.gen_origin(
+            // the reversed axes are resolved here, so the origin is generated without reversing
+            let margin = &grid_layout_item.margin;
+            let block_position = if is_block_reversed {
+                block_offset + track_size.height
+                    - item_size.height
+                    - align_offset
+                    - margin.bottom.or_zero()
+            } else {
+                block_offset + align_offset + margin.top.or_zero()
+            };
+            let inline_margin = if is_inline_reversed {
+                margin.right
+            } else {
+                margin.left
+            };
+            let baseline_diff = layout_node.gen_origin(
                 axis_info_for_origin,
-                track_size,
-                block_offset
-                    + align_offset
-                    + grid_layout_item
-                        .margin
-                        .main_axis_start(axis_info.dir, axis_info.main_dir_rev)
-                        .or_zero(),
-                inline_offset
-                    + justify_offset
-                    + grid_layout_item
-                        .margin
-                        .cross_axis_start(axis_info.dir, axis_info.cross_dir_rev)
-                        .or_zero(),
+                logical_size(axis_info, track_size),
+                block_position,
+                inline_offset + justify_offset + inline_margin.or_zero(),
             );
-        }
 
-        let size = Size::new(
-            requested_size
-                .width
-                .unwrap_or(total_inline_size + padding_border.horizontal()),
-            requested_size
-                .height
-                .unwrap_or(total_block_size + padding_border.vertical()),
-        );
-        let ret = ComputeResult {
-            size: Normalized(size),
-            first_baseline_ascent: Vector::zero(),
-            last_baseline_ascent: Vector::zero(),
-            collapsed_margin: CollapsedBlockMargin::zero(),
-        };
-        if request.kind != ComputeRequestKind::Position {
-            self.cache.write_all_size(node, &request, ret);
-        } else {
-            compute_special_position_children(
-                env,
-                node,
-                &ret,
-                border,
-                padding_border,
-                AxisInfo {
-                    dir: axis_info.dir,
-                    main_dir_rev: axis_info.main_dir_rev,
-                    cross_dir_rev: axis_info.cross_dir_rev,
-                },
-                true,
+            // CSS Grid §10.6: Grid Container Baselines
+            let position = baseline.map(|x| x.position);
+            first_baseline.offer_first(
+                row,
+                column,
+                position == Some(BaselinePosition::First),
+                compute_result.first_baseline_ascent + baseline_diff,
+            );
+            last_baseline.offer_last(
+                row,
+                column,
+                position == Some(BaselinePosition::Last),
+                compute_result.last_baseline_ascent + baseline_diff,
             );
-            self.result = Rect::new(Point::zero(), ret.size.0);
-            self.cache.write_position(node, &request, ret);
         }
-        ret
+
+        let content_size = logical_size(axis_info, Size::new(total_inline_size, total_block_size));
+        finish_grid_layout(
+            self,
+            env,
+            node,
+            &request,
+            border,
+            padding_border,
+            axis_info,
+            content_size,
+            first_baseline.baseline(),
+            last_baseline.baseline(),
+        )
+    }
+}
+
+/// Convert a size between the physical and the logical (inline, block) form.
+///
+/// CSS Writing Modes §6: in vertical writing modes, the inline axis is vertical,
+/// so the conversion swaps the width and the height. It is its own inverse.
+#[inline]
+fn logical_size<L>(axis_info: AxisInfo, size: Size<L>) -> Size<L> {
+    match axis_info.dir {
+        AxisDirection::Vertical => size,
+        AxisDirection::Horizontal => Size::new(size.height, size.width),
     }
 }
+
+/// Convert the physical margins of a grid item to the logical form.
+///
+/// In the result, `left`/`right` are the inline-axis margins and `top`/`bottom` are the block-axis margins.
+/// Only the axes are mapped; the start sides are still resolved against the reversed axes when positioning.
+#[inline]
+fn logical_edge<L: LengthNum>(axis_info: AxisInfo, edge: EdgeOption<L>) -> EdgeOption<L> {
+    match axis_info.dir {
+        AxisDirection::Vertical => edge,
+        AxisDirection::Horizontal => EdgeOption {
+            left: edge.top,
+            right: edge.bottom,
+            top: edge.left,
+            bottom: edge.right,
+        },
+    }
+}
+
+/// Build the result of a grid container from the size of its grid and its baselines.
+///
+/// The container is sized by the grid unless its size is definite.
+/// It falls back to the bottom edge as its baselines if no item provides one.
+/// For position requests, the absolutely positioned children are laid out as well.
+#[allow(clippy::too_many_arguments)]
+fn finish_grid_layout<T: LayoutTreeNode>(
+    unit: &mut LayoutUnit<T>,
+    env: &mut T::Env,
+    node: &T,
+    request: &ComputeRequest<T::Length>,
+    border: Edge<T::Length>,
+    padding_border: Edge<T::Length>,
+    axis_info: AxisInfo,
+    grid_size: Size<T::Length>,
+    first_baseline: Option<Vector<T::Length>>,
+    last_baseline: Option<Vector<T::Length>>,
+) -> ComputeResult<T::Length> {
+    let size = Size::new(
+        request
+            .size
+            .width
+            .unwrap_or(grid_size.width + padding_border.horizontal()),
+        request
+            .size
+            .height
+            .unwrap_or(grid_size.height + padding_border.vertical()),
+    );
+    let ret = ComputeResult {
+        size: Normalized(size),
+        first_baseline_ascent: first_baseline.unwrap_or_else(|| size.to_vector()),
+        last_baseline_ascent: last_baseline.unwrap_or_else(|| size.to_vector()),
+        collapsed_margin: CollapsedBlockMargin::zero(),
+        line_count: 0,
+    };
+    if request.kind != ComputeRequestKind::Position {
+        unit.cache.write_all_size(node, request, ret);
+    } else {
+        compute_special_position_children(env, node, &ret, border, padding_border, axis_info, true);
+        unit.result = Rect::new(Point::zero(), ret.size.0);
+        unit.cache.write_position(node, request, ret);
+    }
+    ret
+}
+
+/// Resolve the `align-self` and `justify-self` of a grid item.
+///
+/// CSS Box Alignment §6.1: `auto` computes to the parent's `align-items` / `justify-items`.
+/// CSS Grid §9: a subgrid is always stretched in its subgridded axes.
+fn resolve_item_self_alignment<T: LayoutTreeNode>(
+    child: &T,
+    parent_style: &T::Style,
+) -> (AlignSelf, JustifySelf) {
+    let child_style = child.style();
+    let subgrid_axes = SubgridAxes::of(child);
+    let align_self = if subgrid_axes.rows {
+        AlignSelf::Stretch
+    } else {
+        resolve_grid_align_self::<T>(child_style, parent_style)
+    };
+    let justify_self = if subgrid_axes.columns {
+        JustifySelf::Stretch
+    } else {
+        resolve_grid_justify_self::<T>(child_style, parent_style)
+    };
+    (align_self, justify_self)
+}
@@ -6,7 +6,11 @@
 use float_pigment_css::typing::GridAutoFlow;
 
 use crate::{
-    algo::grid::{grid_item::GridItem, GridMatrix},
+    algo::grid::{
+        grid_item::GridItem,
+        subgrid::{item_span, SubgridAxes},
+        GridMatrix,
+    },
     LayoutTreeNode,
 };
 
@@ -37,6 +41,16 @@ use crate::{
 /// When items don't fit in the explicit grid, new rows or columns are
 /// created automatically (implicit grid tracks).
 ///
+/// ## Subgrids (CSS Grid 2 §9)
+/// <https://www.w3.org/TR/css-grid-2/#subgrids>
+///
+/// A subgrid has no implicit tracks in its subgridded axes, so items are
+/// clamped into the explicit grid in those axes.
+///
+/// A subgrid item spans multiple tracks (see `item_span`).
+/// Its span in the fixed dimension of the auto-flow is clamped to the explicit track count,
+/// instead of creating implicit tracks.
+///
 /// ## Performance Optimization
 ///
 /// Dense mode uses a search hint to avoid re-scanning filled rows/columns:
@@ -46,6 +60,7 @@ use crate::{
 pub(crate) fn place_grid_items<'a, T: LayoutTreeNode>(
     grid_matrix: &mut GridMatrix<'a, T>,
     children_iter: impl Iterator<Item = &'a T>,
+    subgrid_axes: SubgridAxes,
 ) {
     // Get dimensions from explicit grid template
     let explicit_row_count = grid_matrix.explicit_row_count();
@@ -63,46 +78,57 @@ pub(crate) fn place_grid_items<'a, T: LayoutTreeNode>(
     // Process each grid item according to grid-auto-flow
     // CSS Grid §8.5: Auto-placement algorithm
     // https://www.w3.org/TR/css-grid-1/#auto-placement-algo
-    children_iter.for_each(|child| match flow {
-        // Items are placed row by row, cursor only moves forward.
-        GridAutoFlow::Row => {
-            // Wrap to next row if we've filled the current row
-            if cur_column >= explicit_column_count.max(1) {
-                cur_column = 0;
-                cur_row += 1;
+    children_iter.for_each(|child| {
+        let (row_span, column_span) = item_span(child);
+        let (pos, span) = match flow {
+            // Items are placed row by row, cursor only moves forward.
+            GridAutoFlow::Row => {
+                let stride = explicit_column_count.max(1);
+                let span = (row_span, column_span.min(stride));
+                // Wrap to next row if the item does not fit in the current row,
+                // and skip the cells occupied by the items spanning multiple rows
+                loop {
+                    if cur_column + span.1 > stride {
+                        cur_column = 0;
+                        cur_row += 1;
+                    }
+                    if grid_matrix.is_area_free((cur_row, cur_column), span) {
+                        break;
+                    }
+                    cur_column += 1;
+                }
+                let pos = (cur_row, cur_column);
+                cur_column += span.1;
+                (pos, span)
             }
-
-            // Create and place the grid item
-            let grid_item = GridItem::new(child, cur_row, cur_column);
-            grid_matrix.place_item(cur_row, cur_column, grid_item);
-            cur_column += 1;
-        }
-        // For each item, search from hint for first unoccupied cell.
-        GridAutoFlow::RowDense => {
-            let (row, col) = grid_matrix.find_first_unoccupied(&mut dense_hint);
-
-            let grid_item = GridItem::new(child, row, col);
-            grid_matrix.place_item(row, col, grid_item);
-        }
-        // Items are placed column by column, cursor only moves forward.
-        GridAutoFlow::Column => {
-            // Wrap to next column if we've filled the current column
-            if cur_row >= explicit_row_count.max(1) {
-                cur_row = 0;
-                cur_column += 1;
+            // Items are placed column by column, cursor only moves forward.
+            GridAutoFlow::Column => {
+                let stride = explicit_row_count.max(1);
+                let span = (row_span.min(stride), column_span);
+                // Wrap to next column if the item does not fit in the current column,
+                // and skip the cells occupied by the items spanning multiple columns
+                loop {
+                    if cur_row + span.0 > stride {
+                        cur_row = 0;
+                        cur_column += 1;
+                    }
+                    if grid_matrix.is_area_free((cur_row, cur_column), span) {
+                        break;
+                    }
+                    cur_row += 1;
+                }
+                let pos = (cur_row, cur_column);
+                cur_row += span.0;
+                (pos, span)
             }
+            // For each item, search from hint for the first area that fits.
+            GridAutoFlow::RowDense | GridAutoFlow::ColumnDense => {
+                grid_matrix.find_first_fit(&mut dense_hint, (row_span, column_span))
+            }
+        };
 
-            // Create and place the grid item
-            let grid_item = GridItem::new(child, cur_row, cur_column);
-            grid_matrix.place_item(cur_row, cur_column, grid_item);
-            cur_row += 1;
-        }
-        // For each item, search from hint for first unoccupied cell.
-        GridAutoFlow::ColumnDense => {
-            let (row, col) = grid_matrix.find_first_unoccupied(&mut dense_hint);
-
-            let grid_item = GridItem::new(child, row, col);
-            grid_matrix.place_item(row, col, grid_item);
-        }
+        // Create and place the grid item
+        let (pos, span) = subgrid_axes.clamp(pos, span, explicit_row_count, explicit_column_count);
+        grid_matrix.place_item(GridItem::new(child, pos, span));
     });
 }
@@ -0,0 +1,304 @@
+//! Subgrids
+//!
+//! CSS Grid §9: https://www.w3.org/TR/css-grid-2/#subgrids
+//!
+//! A grid item with `grid-template-rows: subgrid` or `grid-template-columns: subgrid`
+//! does not define its own tracks in that axis, but adopts the tracks of its parent grid
+//! covered by its grid area.
+//!
+//! Line-based placement is not supported, so a subgrid is always auto-placed.
+//! CSS Grid §9: the span of an auto-placed subgrid in a subgridded axis is
+//! the number of lines described by its `<line-name-list>`, or a single track if it has none.
+//! The line names are not used for placement,
+//! so neither the own names of the subgrid nor the inherited names of the parent are tracked.
+//!
+//! The parent grid hands its spanned tracks to the subgrid (see `adopt_tracks`):
+//! the track sizing functions while the subgrid is measured for the parent's track sizing,
+//! and the resolved track sizes when the subgrid is laid out in its grid area.
+//! The subgrid reports its resolved track sizes back (see `measure_subgrid`),
+//! so it contributes to each of the spanned parent tracks separately.
+
+use alloc::vec::Vec;
+use float_pigment_css::{length_num::LengthNum, typing::Display};
+
+use crate::{
+    types::MinMax, ComputeRequest, ComputeResult, DefLength, LayoutGridAuto, LayoutGridTemplate,
+    LayoutStyle, LayoutTrackListItem, LayoutTrackSize, LayoutTreeNode, LayoutTreeVisitor,
+    LayoutUnit, OptionNum,
+};
+
+/// The tracks handed to a subgrid by its parent grid, in the subgridded axes.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AdoptedTracks<L: LengthNum, C: PartialEq + Clone> {
+    /// The spanned row tracks, if the rows are subgridded.
+    pub(crate) rows: Option<Vec<LayoutTrackListItem<L, C>>>,
+    /// The spanned column tracks, if the columns are subgridded.
+    pub(crate) columns: Option<Vec<LayoutTrackListItem<L, C>>>,
+}
+
+impl<L: LengthNum, C: PartialEq + Clone> Default for AdoptedTracks<L, C> {
+    fn default() -> Self {
+        Self {
+            rows: None,
+            columns: None,
+        }
+    }
+}
+
+/// The sizes of the adopted tracks of a subgrid, recorded for the track sizing of its parent grid.
+///
+/// The margin, border, and padding at the edges of the subgrid are added to the first and the last tracks,
+/// so they are the contributions to the spanned parent tracks.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SubgridTrackSizes<L: LengthNum> {
+    /// The row sizes, if the rows are subgridded.
+    pub(crate) rows: Option<Vec<L>>,
+    /// The column sizes, if the columns are subgridded.
+    pub(crate) columns: Option<Vec<L>>,
+}
+
+/// The contributions of a subgrid to each of the spanned parent tracks in one axis.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SubgridContributions<L: LengthNum> {
+    /// The min-content contributions.
+    pub(crate) min_content: Vec<L>,
+    /// The max-content contributions.
+    pub(crate) max_content: Vec<L>,
+}
+
+impl<L: LengthNum> SubgridContributions<L> {
+    /// Combine the track sizes of the min-content and the max-content measurements.
+    ///
+    /// It is `None` if any of them is not available, e.g. the measurement is skipped.
+    pub(crate) fn new(
+        min_content: Option<Vec<L>>,
+        max_content: Option<Vec<L>>,
+        span: usize,
+    ) -> Option<Self> {
+        let min_content = min_content.filter(|x| x.len() == span)?;
+        let max_content = max_content.filter(|x| x.len() == span)?;
+        Some(Self {
+            min_content,
+            max_content,
+        })
+    }
+}
+
+/// The axes in which a grid container is a subgrid.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct SubgridAxes {
+    /// `grid-template-rows: subgrid`
+    pub(crate) rows: bool,
+    /// `grid-template-columns: subgrid`
+    pub(crate) columns: bool,
+}
+
+impl SubgridAxes {
+    /// Find out the subgridded axes of a grid container.
+    ///
+    /// CSS Grid §9: if the grid container is not a grid item (its parent is not a grid container),
+    /// `subgrid` behaves as `none`.
+    pub(crate) fn of<T: LayoutTreeNode>(node: &T) -> Self {
+        let Some(parent) = node.tree_visitor().layout_parent() else {
+            return Self::default();
+        };
+        if !matches!(
+            parent.style().display(),
+            Display::Grid | Display::InlineGrid
+        ) {
+            return Self::default();
+        }
+        let style = node.style();
+        Self {
+            rows: matches!(style.grid_template_rows(), LayoutGridTemplate::Subgrid(_)),
+            columns: matches!(
+                style.grid_template_columns(),
+                LayoutGridTemplate::Subgrid(_)
+            ),
+        }
+    }
+
+    /// Clamp a grid area into the explicit grid in the subgridded axes.
+    ///
+    /// CSS Grid §9: the subgrid has no implicit grid in its subgridded axes,
+    /// so items that would be placed out of it are clamped into the last tracks.
+    pub(crate) fn clamp(
+        &self,
+        (row, column): (usize, usize),
+        (row_span, column_span): (usize, usize),
+        explicit_row_count: usize,
+        explicit_column_count: usize,
+    ) -> ((usize, usize), (usize, usize)) {
+        let clamp_axis = |subgridded: bool, start: usize, span: usize, count: usize| {
+            if subgridded {
+                let span = span.min(count.max(1));
+                (start.min(count.saturating_sub(span)), span)
+            } else {
+                (start, span)
+            }
+        };
+        let (row, row_span) = clamp_axis(self.rows, row, row_span, explicit_row_count);
+        let (column, column_span) =
+            clamp_axis(self.columns, column, column_span, explicit_column_count);
+        ((row, column), (row_span, column_span))
+    }
+}
+
+/// The span of a grid item in the (row, column) axes.
+///
+/// CSS Grid §9: an auto-placed subgrid spans the number of lines in its `<line-name-list>` minus one,
+/// and at least one track. Other grid items always occupy a single cell.
+pub(crate) fn item_span<T: LayoutTreeNode>(child: &T) -> (usize, usize) {
+    let style = child.style();
+    if !matches!(style.display(), Display::Grid | Display::InlineGrid) {
+        return (1, 1);
+    }
+    let span = |template: LayoutGridTemplate<T::Length, T::LengthCustom>| match template {
+        LayoutGridTemplate::Subgrid(line_names) => line_names.len().saturating_sub(1).max(1),
+        _ => 1,
+    };
+    (
+        span(style.grid_template_rows()),
+        span(style.grid_template_columns()),
+    )
+}
+
+/// Hand the spanned parent tracks to a subgrid before it is laid out.
+///
+/// The cache of the subgrid is cleared if the tracks change,
+/// since its results depend on the tracks but they are not part of the compute requests.
+pub(crate) fn adopt_tracks<T: LayoutTreeNode>(
+    unit: &mut LayoutUnit<T>,
+    tracks: AdoptedTracks<T::Length, T::LengthCustom>,
+) {
+    if unit.subgrid_tracks != tracks {
+        unit.subgrid_tracks = tracks;
+        unit.cache.clear();
+    }
+}
+
+/// Measure a subgrid for the track sizing of its parent grid, and take the sizes of its adopted tracks.
+///
+/// The cache of the subgrid is bypassed, since the track sizes are not cached.
+pub(crate) fn measure_subgrid<T: LayoutTreeNode>(
+    unit: &mut LayoutUnit<T>,
+    env: &mut T::Env,
+    node: &T,
+    request: ComputeRequest<T::Length>,
+) -> (
+    ComputeResult<T::Length>,
+    Option<SubgridTrackSizes<T::Length>>,
+) {
+    unit.cache.clear();
+    unit.subgrid_track_sizes = None;
+    let ret = unit.compute_internal(env, node, request);
+    (ret, unit.subgrid_track_sizes.take())
+}
+
+/// Add the margin, border, and padding at the edges of a subgrid to the sizes of its first and last tracks.
+pub(crate) fn outset_track_sizes<L: LengthNum>(sizes: &[L], start_edge: L, end_edge: L) -> Vec<L> {
+    let last = sizes.len().saturating_sub(1);
+    sizes
+        .iter()
+        .enumerate()
+        .map(|(i, size)| {
+            let mut size = *size;
+            if i == 0 {
+                size += start_edge;
+            }
+            if i == last {
+                size += end_edge;
+            }
+            size
+        })
+        .collect()
+}
+
+/// The track sizing functions of the parent tracks in `range`.
+///
+/// The implicit tracks use `grid-auto-rows` / `grid-auto-columns`.
+pub(crate) fn spanned_track_functions<L: LengthNum, C: PartialEq + Clone>(
+    explicit_track_list: &[&LayoutTrackListItem<L, C>],
+    grid_auto_tracks: &LayoutGridAuto<L, C>,
+    range: core::ops::Range<usize>,
+) -> Vec<LayoutTrackListItem<L, C>> {
+    range
+        .map(|i| match explicit_track_list.get(i) {
+            Some(item) => (*item).clone(),
+            None => {
+                LayoutTrackListItem::TrackSize(grid_auto_tracks.get(i - explicit_track_list.len()))
+            }
+        })
+        .collect()
+}
+
+/// The resolved sizes of the parent tracks, as fixed tracks.
+pub(crate) fn resolved_tracks<L: LengthNum, C: PartialEq + Clone>(
+    sizes: &[L],
+) -> Vec<LayoutTrackListItem<L, C>> {
+    sizes
+        .iter()
+        .map(|size| {
+            LayoutTrackListItem::TrackSize(LayoutTrackSize::Length(DefLength::Points(*size)))
+        })
+        .collect()
+}
+
+/// Take the margin, border, and padding of the subgrid from its first and last adopted tracks.
+///
+/// CSS Grid §9: the edges of the subgrid are taken from the tracks at its edges,
+/// which only matters if these tracks are fixed.
+pub(crate) fn inset_adopted_tracks<L: LengthNum, C: PartialEq + Clone>(
+    tracks: &mut [LayoutTrackListItem<L, C>],
+    start_edge: L,
+    end_edge: L,
+) {
+    let last = tracks.len().saturating_sub(1);
+    for (i, track) in tracks.iter_mut().enumerate() {
+        if let LayoutTrackListItem::TrackSize(LayoutTrackSize::Length(DefLength::Points(size))) =
+            track
+        {
+            if i == 0 {
+                *size -= start_edge;
+            }
+            if i == last {
+                *size -= end_edge;
+            }
+            *size = (*size).max(L::zero());
+        }
+    }
+}
+
+/// The track adopted in a subgridded axis if the parent grid does not hand its tracks over,
+/// e.g. the parent is a masonry container.
+///
+/// The parent grid stretches the subgrid into its grid area, so the adopted track
+/// takes the whole available grid space if it is definite.
+/// Otherwise the track is sized by the items of the subgrid.
+pub(crate) fn adopted_track<L: LengthNum, C: PartialEq + Clone>(
+    available_grid_space: OptionNum<L>,
+) -> LayoutTrackListItem<L, C> {
+    let size = match available_grid_space.val() {
+        Some(x) => DefLength::Points(x),
+        None => DefLength::Auto,
+    };
+    LayoutTrackListItem::TrackSize(LayoutTrackSize::Length(size))
+}
+
+/// Resolve the gap of a subgridded axis.
+///
+/// CSS Grid §9: the subgrid inherits the gaps of its parent grid,
+/// unless its own gap is specified (not `normal`).
+pub(crate) fn subgrid_gap<T: LayoutTreeNode>(
+    node: &T,
+    gap: impl Fn(&T::Style) -> DefLength<T::Length, T::LengthCustom>,
+) -> OptionNum<T::Length> {
+    let own_gap = gap(node.style());
+    if own_gap != DefLength::Undefined {
+        return own_gap.resolve(OptionNum::none(), node);
+    }
+    match node.tree_visitor().layout_parent() {
+        Some(parent) => gap(parent.style()).resolve(OptionNum::none(), parent),
+        None => OptionNum::none(),
+    }
+}
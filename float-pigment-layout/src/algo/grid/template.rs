@@ -1,24 +1,212 @@
 //! Grid Template Initialization
 //!
 //! CSS Grid §7.1: https://www.w3.org/TR/css-grid-1/#explicit-grids
+//! CSS Grid §7.2.3: https://www.w3.org/TR/css-grid-1/#repeat-notation
 //!
 //! Utilities for parsing and initializing grid template track lists.
+//!
+//! Line names are not supported, so the named lines (including the ones declared
+//! inside `repeat()`) are dropped, and grid items cannot be placed against them.
 
 use alloc::vec::Vec;
+use core::ops::Range;
+
+use float_pigment_css::{length_num::LengthNum, num_traits::Zero, typing::MAX_REPEAT_COUNT};
+
+use crate::{
+    types::MinMax, DefLength, LayoutGridTemplate, LayoutRepeatCount, LayoutTrackListItem,
+    LayoutTrackSize, LayoutTreeNode, OptionNum,
+};
+
+/// The upper bound of repetitions, guarding against degenerate inputs
+/// such as `repeat(100000000, 1px)` or a huge container with tiny tracks.
+const MAX_REPETITIONS: usize = MAX_REPEAT_COUNT as usize;
 
-use crate::{LayoutGridTemplate, LayoutTrackListItem, LayoutTreeNode};
+/// The explicit track list of one axis, with `repeat()` expanded.
+pub(crate) struct ExplicitTrackList<'a, L: LengthNum, C: PartialEq + Clone> {
+    /// The track sizes of the explicit grid, in order.
+    pub(crate) tracks: Vec<&'a LayoutTrackListItem<L, C>>,
+    /// The tracks generated by `repeat(auto-fit, ...)`.
+    ///
+    /// CSS Grid §7.2.3.2: these tracks are collapsed if they are empty after placement.
+    pub(crate) auto_fit: Option<Range<usize>>,
+}
+
+impl<'a, L: LengthNum, C: PartialEq + Clone> ExplicitTrackList<'a, L, C> {
+    /// The explicit track list of a subgridded axis, which adopts the spanned parent tracks.
+    pub(crate) fn adopted(tracks: &'a [LayoutTrackListItem<L, C>]) -> Self {
+        Self {
+            tracks: tracks.iter().collect(),
+            auto_fit: None,
+        }
+    }
+
+    /// Find out which tracks are collapsed.
+    ///
+    /// CSS Grid §7.2.3.2: an empty auto-fit track is treated as having a fixed
+    /// track sizing function of `0px`, and the gutters on either side of it collapse.
+    pub(crate) fn collapsed_tracks(
+        &self,
+        track_count: usize,
+        is_occupied: impl Fn(usize) -> bool,
+    ) -> Vec<bool> {
+        let mut ret = alloc::vec![false; track_count];
+        if let Some(range) = self.auto_fit.clone() {
+            for i in range {
+                if i < track_count && !is_occupied(i) {
+                    ret[i] = true;
+                }
+            }
+        }
+        ret
+    }
+}
 
 /// Initialize a track list from grid-template-rows/columns.
 ///
-/// Filters the track list to extract only track size items.
+/// Filters the track list to extract only track size items, and expands
+/// the `repeat()` notation (§7.2.3).
+///
+/// For `auto-fill` and `auto-fit`, the number of repetitions is the largest
+/// positive integer that does not cause the grid to overflow the available
+/// grid space (gaps included). If the available grid space is indefinite,
+/// the tracks are repeated once.
 pub(crate) fn initialize_track_list<'a, T: LayoutTreeNode>(
     grid_template: &'a LayoutGridTemplate<T::Length, T::LengthCustom>,
-) -> Vec<&'a LayoutTrackListItem<T::Length, T::LengthCustom>> {
-    match grid_template {
-        LayoutGridTemplate::TrackList(track_list) => track_list
-            .iter()
-            .filter(|item| matches!(item, LayoutTrackListItem::TrackSize(_)))
-            .collect(),
-        _ => Vec::with_capacity(0),
+    node: &T,
+    available_grid_space: OptionNum<T::Length>,
+    gap: T::Length,
+) -> ExplicitTrackList<'a, T::Length, T::LengthCustom> {
+    let track_list = match grid_template {
+        LayoutGridTemplate::TrackList(track_list) => track_list,
+        _ => {
+            return ExplicitTrackList {
+                tracks: Vec::with_capacity(0),
+                auto_fit: None,
+            }
+        }
+    };
+
+    let auto_repetitions =
+        resolve_auto_repetitions::<T>(track_list, node, available_grid_space, gap);
+
+    let mut tracks = Vec::with_capacity(track_list.len());
+    let mut auto_fit = None;
+    for item in track_list.iter() {
+        match item {
+            LayoutTrackListItem::TrackSize(_) => tracks.push(item),
+            LayoutTrackListItem::Repeat(count, list) => {
+                let repetitions = match count {
+                    LayoutRepeatCount::Count(x) => (*x as usize).min(MAX_REPETITIONS),
+                    LayoutRepeatCount::AutoFill | LayoutRepeatCount::AutoFit => auto_repetitions,
+                };
+                let start = tracks.len();
+                for _ in 0..repetitions {
+                    tracks.extend(
+                        list.iter()
+                            .filter(|item| matches!(item, LayoutTrackListItem::TrackSize(_))),
+                    );
+                }
+                if *count == LayoutRepeatCount::AutoFit {
+                    auto_fit = Some(start..tracks.len());
+                }
+            }
+            LayoutTrackListItem::LineNames(_) => {}
+        }
+    }
+    ExplicitTrackList { tracks, auto_fit }
+}
+
+/// Resolve the fixed size of a track for the auto-repetition calculation.
+///
+/// CSS Grid §7.2.3.2: each track is treated as its max track sizing function
+/// if that is definite or as its min track sizing function otherwise,
+/// and the max is floored by the min if both are definite.
+fn fixed_track_size<T: LayoutTreeNode>(
+    track_size: &LayoutTrackSize<T::Length, T::LengthCustom>,
+    node: &T,
+    available_grid_space: OptionNum<T::Length>,
+) -> T::Length {
+    let definite_size = |track_size: &LayoutTrackSize<T::Length, T::LengthCustom>| match track_size
+    {
+        LayoutTrackSize::Length(length @ (DefLength::Points(_) | DefLength::Percent(_))) => {
+            length.resolve(available_grid_space, node).val()
+        }
+        _ => None,
+    };
+    match track_size {
+        LayoutTrackSize::MinMax(min, max) => match (definite_size(min), definite_size(max)) {
+            (Some(min), Some(max)) => max.max(min),
+            (None, Some(x)) | (Some(x), None) => x,
+            (None, None) => T::Length::zero(),
+        },
+        _ => definite_size(track_size).unwrap_or(T::Length::zero()),
+    }
+}
+
+/// Resolve the number of repetitions of the `auto-fill` / `auto-fit` track list.
+///
+/// CSS Grid §7.2.3.2: Repeat-to-fill
+/// <https://www.w3.org/TR/css-grid-1/#auto-repeat>
+fn resolve_auto_repetitions<T: LayoutTreeNode>(
+    track_list: &[LayoutTrackListItem<T::Length, T::LengthCustom>],
+    node: &T,
+    available_grid_space: OptionNum<T::Length>,
+    gap: T::Length,
+) -> usize {
+    let Some(available) = available_grid_space.val() else {
+        return 1;
+    };
+
+    let mut repeat_size = T::Length::zero();
+    let mut repeat_count = 0;
+    let mut other_size = T::Length::zero();
+    let mut other_count = 0;
+    for item in track_list.iter() {
+        match item {
+            LayoutTrackListItem::TrackSize(track_size) => {
+                other_size += fixed_track_size(track_size, node, available_grid_space);
+                other_count += 1;
+            }
+            LayoutTrackListItem::Repeat(
+                LayoutRepeatCount::AutoFill | LayoutRepeatCount::AutoFit,
+                list,
+            ) => {
+                for item in list.iter() {
+                    if let LayoutTrackListItem::TrackSize(track_size) = item {
+                        repeat_size += fixed_track_size(track_size, node, available_grid_space);
+                        repeat_count += 1;
+                    }
+                }
+            }
+            LayoutTrackListItem::Repeat(LayoutRepeatCount::Count(x), list) => {
+                let x = (*x as usize).min(MAX_REPETITIONS);
+                for item in list.iter() {
+                    if let LayoutTrackListItem::TrackSize(track_size) = item {
+                        other_size += fixed_track_size(track_size, node, available_grid_space)
+                            .mul_i32(x as i32);
+                        other_count += x as i32;
+                    }
+                }
+            }
+            LayoutTrackListItem::LineNames(_) => {}
+        }
+    }
+    if repeat_count == 0 {
+        return 1;
+    }
+
+    // n repetitions take `n * (repeat_size + repeat_count * gap)` plus
+    // `other_size + (other_count - 1) * gap` for the remaining tracks.
+    let step = repeat_size + gap.mul_i32(repeat_count);
+    if step <= T::Length::zero() {
+        return 1;
+    }
+    let free_space = available - other_size - gap.mul_i32(other_count - 1);
+    let repetitions = free_space.to_f32() / step.to_f32() + 1e-3;
+    if repetitions < 1. {
+        1
+    } else {
+        (repetitions as usize).min(MAX_REPETITIONS)
     }
 }
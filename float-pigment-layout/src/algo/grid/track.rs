@@ -118,6 +118,7 @@ impl<T: LayoutTreeNode> GridTrack<T> {
 /// This enum represents the max track sizing function after §11.5 processing.
 /// It determines how the track participates in §11.6 Maximize and §11.8 Stretch:
 /// - `Fixed`: does not grow in §11.6 (finite growth_limit), excluded from §11.8
+///   (a `minmax()` track with a fixed max still grows up to its growth_limit)
 /// - `Auto`: grows in §11.6 (infinite growth_limit, never freezes), participates in §11.8
 /// - `Intrinsic`: grows in §11.6 (finite growth_limit, freezes at limit), excluded from §11.8
 /// - `Flex`: excluded from §11.6 (fr tracks already sized in §11.7), excluded from §11.8
@@ -131,7 +132,7 @@ pub(crate) enum TrackSizingFunction<T: LayoutTreeNode> {
     /// Auto sizing: `auto`
     Auto,
 
-    /// Intrinsic sizing: `min-content` or `max-content`
+    /// Intrinsic sizing: `min-content`, `max-content` or `fit-content()`
     Intrinsic,
 }
 
@@ -190,9 +191,9 @@ impl<T: LayoutTreeNode> GridTracks<T> {
                     auto_count += 1;
                     TrackSizingFunction::Auto
                 }
-                IntrinsicTrackType::MinContent | IntrinsicTrackType::MaxContent => {
-                    TrackSizingFunction::Intrinsic
-                }
+                IntrinsicTrackType::MinContent
+                | IntrinsicTrackType::MaxContent
+                | IntrinsicTrackType::FitContent => TrackSizingFunction::Intrinsic,
                 IntrinsicTrackType::Fixed => TrackSizingFunction::Fixed(DefLength::Points(
                     item.base_size.unwrap_or(T::Length::zero()),
                 )),
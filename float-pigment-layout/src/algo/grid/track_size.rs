@@ -80,13 +80,14 @@ pub(crate) fn apply_track_size<'a, T: LayoutTreeNode>(
     }
 
     // Apply track sizes to items
+    // (the track size of an item spanning multiple tracks is left indefinite)
     for item in grid_matrix.items_mut() {
-        let track_idx = match flow {
-            GridFlow::Row => item.row(),
-            GridFlow::Column => item.column(),
+        let (track_idx, span) = match flow {
+            GridFlow::Row => (item.row(), item.row_span()),
+            GridFlow::Column => (item.column(), item.column_span()),
         };
 
-        if track_idx < track_list.len() {
+        if span == 1 && track_idx < track_list.len() {
             match track_list[track_idx] {
                 LayoutTrackListItem::TrackSize(LayoutTrackSize::Length(length)) => match flow {
                     GridFlow::Row => {
@@ -100,7 +101,7 @@ pub(crate) fn apply_track_size<'a, T: LayoutTreeNode>(
                     GridFlow::Row => item.update_track_block_size(TrackSize::Fr(*fr_value)),
                     GridFlow::Column => item.update_track_inline_size(TrackSize::Fr(*fr_value)),
                 },
-                // MinContent/MaxContent tracks: initial base size = 0.
+                // MinContent/MaxContent/MinMax/FitContent tracks: initial base size = 0.
                 // Their actual sizes are resolved by content-based sizing
                 // in compute_track_sizes (§11.5), not here.
                 _ => {}
@@ -1,5 +1,8 @@
 //! Track Sizing Algorithm Implementation
 //!
+//! CSS Grid §11.4 (Initialize Track Sizes):
+//! https://www.w3.org/TR/css-grid-1/#algo-init
+//!
 //! CSS Grid §11.5 (Resolve Intrinsic Track Sizes):
 //! https://www.w3.org/TR/css-grid-1/#algo-content
 //!
@@ -7,14 +10,14 @@
 //! https://www.w3.org/TR/css-grid-1/#algo-flex-tracks
 
 use alloc::vec::Vec;
-use float_pigment_css::length_num::LengthNum;
+use float_pigment_css::{length_num::LengthNum, num_traits::Zero};
 
 use crate::{
     algo::grid::track::GridTracks, types::MinMax, DefLength, LayoutGridAuto, LayoutTrackListItem,
     LayoutTrackSize, LayoutTreeNode, OptionNum, OptionSize, Size,
 };
 
-use super::matrix::GridLayoutMatrix;
+use super::{matrix::GridLayoutMatrix, subgrid::SubgridContributions};
 
 /// Resolve fr track sizes using the iterative algorithm from CSS Grid §11.7.
 ///
@@ -46,6 +49,8 @@ fn resolve_fr_track_sizes<L: LengthNum + Copy>(
 
     match available_space.val() {
         Some(available) => {
+            // §11.6 comes first for tracks with finite growth limits
+            maximize_tracks_before_flex(tracks, available);
             // Definite free space: distribute remaining space proportionally
             resolve_fr_definite(tracks, available);
         }
@@ -56,10 +61,73 @@ fn resolve_fr_track_sizes<L: LengthNum + Copy>(
     }
 }
 
+/// §11.6: Maximize the inflexible tracks with finite growth limits before
+/// flexible tracks are expanded.
+///
+/// CSS Grid §11.6: <https://www.w3.org/TR/css-grid-1/#algo-grow-tracks>
+///
+/// §11.6 runs before §11.7, so a `minmax(100px, 200px) 1fr` grid grows the first
+/// track to its growth limit before the fr track takes the leftover space.
+/// Tracks with infinite growth limits (auto) are left to `GridTracks::maximize`,
+/// which also handles grids without fr tracks.
+fn maximize_tracks_before_flex<L: LengthNum + Copy>(tracks: &mut [TrackInfo<L>], available: L) {
+    if !tracks
+        .iter()
+        .any(|t| t.track_type == IntrinsicTrackType::Fr)
+    {
+        return;
+    }
+    let used_space = tracks.iter().fold(L::zero(), |acc, t| {
+        if t.track_type == IntrinsicTrackType::Fr {
+            acc + t.flex_base_size()
+        } else {
+            acc + t.base_size.unwrap_or(L::zero())
+        }
+    });
+    if available <= used_space {
+        return;
+    }
+    let mut remaining = available - used_space;
+    let mut growable: Vec<usize> = (0..tracks.len())
+        .filter(|&i| {
+            let t = &tracks[i];
+            t.track_type != IntrinsicTrackType::Fr
+                && matches!((t.base_size, t.growth_limit), (Some(b), Some(g)) if g > b)
+        })
+        .collect();
+
+    // Distribute equally, freezing tracks as they reach their growth limits
+    while !growable.is_empty() && remaining > L::zero() {
+        let share = remaining.div_f32(growable.len() as f32);
+        let mut space_used = L::zero();
+        let mut any_frozen = false;
+        growable.retain(|&i| {
+            let track = &mut tracks[i];
+            let base_size = track.base_size.unwrap_or(L::zero());
+            let growth_limit = track.growth_limit.unwrap_or(base_size);
+            if base_size + share >= growth_limit {
+                space_used += growth_limit - base_size;
+                track.base_size = Some(growth_limit);
+                any_frozen = true;
+                false
+            } else {
+                space_used += share;
+                track.base_size = Some(base_size + share);
+                true
+            }
+        });
+        remaining -= space_used;
+        if !any_frozen {
+            break;
+        }
+    }
+}
+
 /// §11.7: Resolve fr tracks with definite free space.
 ///
 /// Iteratively distributes remaining space among fr tracks, freezing
-/// tracks at min-content when their hypothetical size is too small.
+/// tracks at their base size (min-content for `auto` min sizing functions)
+/// when their hypothetical size is too small.
 fn resolve_fr_definite<L: LengthNum + Copy>(tracks: &mut [TrackInfo<L>], available: L) {
     let total_non_fr_size: L = tracks
         .iter()
@@ -93,9 +161,10 @@ fn resolve_fr_definite<L: LengthNum + Copy>(tracks: &mut [TrackInfo<L>], availab
         let mut any_frozen = false;
         flexible_indices.retain(|&i| {
             let hypothetical_size = hypothetical_fr_size.mul_f32(tracks[i].fr_value);
-            if hypothetical_size < tracks[i].min_content {
-                tracks[i].base_size = Some(tracks[i].min_content);
-                remaining_space -= tracks[i].min_content;
+            let flex_base_size = tracks[i].flex_base_size();
+            if hypothetical_size < flex_base_size {
+                tracks[i].base_size = Some(flex_base_size);
+                remaining_space -= flex_base_size;
                 active_flex -= tracks[i].fr_value;
                 any_frozen = true;
                 false
@@ -124,6 +193,7 @@ fn resolve_fr_definite<L: LengthNum + Copy>(tracks: &mut [TrackInfo<L>], availab
 /// 2. The unified 1fr size = max(all hypothetical_1fr values)
 /// 3. Each fr track's base_size = unified_1fr × fr_value
 /// 4. Clamp: if base_size < min_content, use min_content
+///    (or the base size from the min sizing function of a `minmax()` track)
 fn resolve_fr_indefinite<L: LengthNum + Copy>(tracks: &mut [TrackInfo<L>]) {
     // Step 1-2: Find the largest hypothetical 1fr size across all fr tracks
     let mut hypothetical_1fr = L::zero();
@@ -140,7 +210,7 @@ fn resolve_fr_indefinite<L: LengthNum + Copy>(tracks: &mut [TrackInfo<L>]) {
     for track in tracks.iter_mut() {
         if track.track_type == IntrinsicTrackType::Fr {
             let fr_size = hypothetical_1fr.mul_f32(track.fr_value);
-            track.base_size = Some(fr_size.max(track.min_content));
+            track.base_size = Some(fr_size.max(track.flex_base_size()));
         }
     }
 }
@@ -154,8 +224,12 @@ fn resolve_fr_indefinite<L: LengthNum + Copy>(tracks: &mut [TrackInfo<L>]) {
 /// - Fixed: size is predetermined, no intrinsic sizing needed
 /// - Auto: min = min-content, max = max-content (§11.5)
 /// - MinContent: min = min-content, max = min-content (§11.5)
-/// - MaxContent: min = min-content, max = max-content (§11.5)
+/// - MaxContent: min = max-content, max = max-content (§11.5)
+/// - FitContent: max = max-content clamped by the limit (§11.5)
 /// - Fr: handled separately in §11.7
+///
+/// A track has one type for its min sizing function and one for its max
+/// sizing function. They only differ for `minmax()`, `fit-content()` and `fr`.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum IntrinsicTrackType {
     /// A fixed-size track (length or percentage). No intrinsic sizing needed.
@@ -168,6 +242,15 @@ pub(crate) enum IntrinsicTrackType {
     MaxContent,
     /// An `fr` track: handled separately in §11.7.
     Fr,
+    /// A `fit-content()` max sizing function: max = max-content, clamped by the limit.
+    FitContent,
+}
+
+/// The min and max track sizing function types of a track.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct TrackSizingTypes {
+    pub(crate) min: IntrinsicTrackType,
+    pub(crate) max: IntrinsicTrackType,
 }
 
 pub(crate) struct TrackInfo<L: LengthNum> {
@@ -179,8 +262,14 @@ pub(crate) struct TrackInfo<L: LengthNum> {
     pub(crate) fr_value: f32,
     pub(crate) min_content: L,
     pub(crate) max_content: L,
-    /// The type of track for intrinsic sizing purposes (§11.5).
+    /// The type of the max track sizing function for intrinsic sizing purposes (§11.5).
     pub(crate) track_type: IntrinsicTrackType,
+    /// The type of the min track sizing function (§11.5).
+    pub(crate) min_track_type: IntrinsicTrackType,
+    /// The resolved size of a fixed min track sizing function.
+    pub(crate) min_size: Option<L>,
+    /// The resolved size of a fixed max track sizing function, or the `fit-content()` limit.
+    pub(crate) max_size: Option<L>,
 }
 
 impl<L: LengthNum + Copy> TrackInfo<L> {
@@ -192,11 +281,26 @@ impl<L: LengthNum + Copy> TrackInfo<L> {
             min_content: L::zero(),
             max_content: L::zero(),
             track_type: IntrinsicTrackType::Auto,
+            min_track_type: IntrinsicTrackType::Auto,
+            min_size: None,
+            max_size: None,
+        }
+    }
+
+    /// The base size of an fr track before it is flexed (§11.7.1).
+    ///
+    /// It comes from the min track sizing function: `auto` and `min-content`
+    /// use the min-content contribution.
+    fn flex_base_size(&self) -> L {
+        match self.min_track_type {
+            IntrinsicTrackType::Fixed => self.min_size.unwrap_or(L::zero()),
+            IntrinsicTrackType::MaxContent => self.max_content,
+            _ => self.min_content,
         }
     }
 }
 
-/// Determine IntrinsicTrackType from a LayoutTrackSize.
+/// Determine IntrinsicTrackType of the max track sizing function from a LayoutTrackSize.
 ///
 /// CSS Grid §7.2: Track Sizing Functions
 /// <https://www.w3.org/TR/css-grid-1/#track-sizing>
@@ -211,30 +315,88 @@ pub(crate) fn classify_track_type<L: LengthNum, C: PartialEq + Clone>(
         LayoutTrackSize::Fr(_) => IntrinsicTrackType::Fr,
         LayoutTrackSize::MinContent => IntrinsicTrackType::MinContent,
         LayoutTrackSize::MaxContent => IntrinsicTrackType::MaxContent,
+        LayoutTrackSize::MinMax(_, max) => classify_track_type(max),
+        LayoutTrackSize::FitContent(_) => IntrinsicTrackType::FitContent,
+    }
+}
+
+/// Determine IntrinsicTrackType of the min track sizing function from a LayoutTrackSize.
+///
+/// CSS Grid §7.2.4: `fr` and `fit-content()` have an `auto` min sizing function.
+/// A flexible min sizing function is invalid, and is treated as `auto` as well.
+pub(crate) fn classify_min_track_type<L: LengthNum, C: PartialEq + Clone>(
+    track_size: &LayoutTrackSize<L, C>,
+) -> IntrinsicTrackType {
+    match track_size {
+        LayoutTrackSize::MinMax(min, _) => classify_min_track_type(min),
+        _ => match classify_track_type(track_size) {
+            IntrinsicTrackType::Fr | IntrinsicTrackType::FitContent => IntrinsicTrackType::Auto,
+            x => x,
+        },
     }
 }
 
-impl IntrinsicTrackType {
+/// Resolve the fixed min and max track sizing functions of a track.
+///
+/// The `fit-content()` limit is returned as the max size.
+fn resolve_track_min_max_sizes<T: LayoutTreeNode>(
+    track_size: &LayoutTrackSize<T::Length, T::LengthCustom>,
+    available_space: OptionNum<T::Length>,
+    node: &T,
+) -> (Option<T::Length>, Option<T::Length>) {
+    let resolve = |track_size: &LayoutTrackSize<T::Length, T::LengthCustom>| match track_size {
+        LayoutTrackSize::Length(def_len @ (DefLength::Points(_) | DefLength::Percent(_)))
+        | LayoutTrackSize::FitContent(def_len) => {
+            Some(def_len.resolve(available_space, node).or_zero())
+        }
+        _ => None,
+    };
+    match track_size {
+        LayoutTrackSize::MinMax(min, max) => (resolve(min), resolve(max)),
+        LayoutTrackSize::FitContent(_) => (None, resolve(track_size)),
+        _ => {
+            let size = resolve(track_size);
+            (size, size)
+        }
+    }
+}
+
+impl TrackSizingTypes {
+    fn from_track_size<L: LengthNum, C: PartialEq + Clone>(
+        track_size: &LayoutTrackSize<L, C>,
+    ) -> Self {
+        Self {
+            min: classify_min_track_type(track_size),
+            max: classify_track_type(track_size),
+        }
+    }
+
     /// Whether this track needs a min-content layout pass.
     ///
-    /// All non-fixed tracks need min-content: auto, fr, min-content, max-content.
-    /// Fixed tracks (length/percentage) do not.
+    /// All non-fixed tracks need min-content: auto, fr, min-content, max-content, fit-content.
+    /// Tracks with fixed min and max sizing functions (length/percentage) do not.
     #[inline]
     pub(crate) fn needs_min_content(self) -> bool {
-        self != IntrinsicTrackType::Fixed
+        self.min != IntrinsicTrackType::Fixed || self.max != IntrinsicTrackType::Fixed
     }
 
     /// Whether this track needs a max-content layout pass.
     ///
+    /// §11.5 Step 2: Tracks with a max-content min sizing function
+    /// need max-content contributions.
     /// §11.5 Step 4: Tracks with intrinsic max sizing functions
-    /// (auto, max-content) need max-content contributions.
+    /// (auto, max-content, fit-content) need max-content contributions.
     /// §11.7.1: Fr tracks also need max-content for indefinite free space.
     #[inline]
     pub(crate) fn needs_max_content(self) -> bool {
-        matches!(
-            self,
-            IntrinsicTrackType::Auto | IntrinsicTrackType::MaxContent | IntrinsicTrackType::Fr
-        )
+        self.min == IntrinsicTrackType::MaxContent
+            || matches!(
+                self.max,
+                IntrinsicTrackType::Auto
+                    | IntrinsicTrackType::MaxContent
+                    | IntrinsicTrackType::FitContent
+                    | IntrinsicTrackType::Fr
+            )
     }
 }
 
@@ -246,45 +408,79 @@ pub(crate) fn classify_track_at_index<L: LengthNum, C: PartialEq + Clone>(
     index: usize,
     explicit_track_list: &[&LayoutTrackListItem<L, C>],
     grid_auto_tracks: &LayoutGridAuto<L, C>,
-) -> IntrinsicTrackType {
+) -> TrackSizingTypes {
     if let Some(track_item) = explicit_track_list.get(index) {
         match track_item {
-            LayoutTrackListItem::TrackSize(track_size) => classify_track_type(track_size),
-            _ => IntrinsicTrackType::Fixed,
+            LayoutTrackListItem::TrackSize(track_size) => {
+                TrackSizingTypes::from_track_size(track_size)
+            }
+            _ => TrackSizingTypes {
+                min: IntrinsicTrackType::Fixed,
+                max: IntrinsicTrackType::Fixed,
+            },
         }
     } else {
         let implicit_index = index - explicit_track_list.len();
-        classify_track_type(&grid_auto_tracks.get(implicit_index))
+        TrackSizingTypes::from_track_size(&grid_auto_tracks.get(implicit_index))
     }
 }
 
 /// Initialize track info for one axis.
 ///
 /// CSS Grid §7.6: Implicit tracks use grid-auto-rows/columns.
-/// For each track position, determines its type (Fixed/Auto/MinContent/MaxContent/Fr)
+/// For each track position, determines the types of its min and max sizing functions
+/// (Fixed/Auto/MinContent/MaxContent/FitContent/Fr)
 /// from either the explicit track list or implicit auto tracks.
-fn init_track_infos<L: LengthNum + Copy, C: PartialEq + Clone>(
+///
+/// CSS Grid §11.4: a fixed min sizing function initializes the base size, and
+/// a fixed max sizing function initializes the growth limit.
+///
+/// CSS Grid §7.2.3.2: collapsed tracks are treated as a fixed `0px` track.
+fn init_track_infos<T: LayoutTreeNode>(
     track_count: usize,
-    explicit_track_list: &[&LayoutTrackListItem<L, C>],
-    grid_auto_tracks: &LayoutGridAuto<L, C>,
-) -> Vec<TrackInfo<L>> {
+    explicit_track_list: &[&LayoutTrackListItem<T::Length, T::LengthCustom>],
+    collapsed: &[bool],
+    grid_auto_tracks: &LayoutGridAuto<T::Length, T::LengthCustom>,
+    available_space: OptionNum<T::Length>,
+    node: &T,
+) -> Vec<TrackInfo<T::Length>> {
     let explicit_count = explicit_track_list.len();
     (0..track_count)
         .map(|i| {
             let mut info = TrackInfo::new();
-            if let Some(LayoutTrackListItem::TrackSize(track_size)) = explicit_track_list.get(i) {
-                info.track_type = classify_track_type(track_size);
-                if let LayoutTrackSize::Fr(fr_value) = track_size {
-                    info.fr_value = *fr_value;
-                }
-            } else if i >= explicit_count {
+            if collapsed.get(i).copied().unwrap_or(false) {
+                info.track_type = IntrinsicTrackType::Fixed;
+                info.min_track_type = IntrinsicTrackType::Fixed;
+                info.base_size = Some(T::Length::zero());
+                info.growth_limit = Some(T::Length::zero());
+                return info;
+            }
+            let track_size = match explicit_track_list.get(i) {
+                Some(LayoutTrackListItem::TrackSize(track_size)) => track_size.clone(),
+                Some(_) => return info,
                 // Implicit track (§7.6)
-                let implicit_index = i - explicit_count;
-                let implicit_track_size = grid_auto_tracks.get(implicit_index);
-                info.track_type = classify_track_type(&implicit_track_size);
-                if let LayoutTrackSize::Fr(fr_value) = implicit_track_size {
-                    info.fr_value = fr_value;
+                None => grid_auto_tracks.get(i - explicit_count),
+            };
+            info.track_type = classify_track_type(&track_size);
+            info.min_track_type = classify_min_track_type(&track_size);
+            match &track_size {
+                LayoutTrackSize::Fr(fr_value) => info.fr_value = *fr_value,
+                LayoutTrackSize::MinMax(_, max) => {
+                    if let LayoutTrackSize::Fr(fr_value) = **max {
+                        info.fr_value = fr_value;
+                    }
                 }
+                _ => {}
+            }
+            let (min_size, max_size) =
+                resolve_track_min_max_sizes(&track_size, available_space, node);
+            info.min_size = min_size;
+            info.max_size = max_size;
+            if info.min_track_type == IntrinsicTrackType::Fixed {
+                info.base_size = min_size;
+            }
+            if info.track_type == IntrinsicTrackType::Fixed {
+                info.growth_limit = max_size;
             }
             info
         })
@@ -297,18 +493,20 @@ fn init_track_infos<L: LengthNum + Copy, C: PartialEq + Clone>(
 /// <https://www.w3.org/TR/css-grid-1/#algo-content>
 ///
 /// - §11.5 Step 2: For tracks with intrinsic min sizing function,
-///   increase base_size to the item's min-content contribution.
+///   increase base_size to the item's min-content contribution
+///   (max-content contribution for a `max-content` min sizing function).
 /// - §11.5 Step 4: For tracks with intrinsic max sizing function,
-///   increase growth_limit to the item's max-content contribution.
+///   increase growth_limit to the item's max-content contribution
+///   (min-content contribution for a `min-content` max sizing function,
+///   clamped by the limit for `fit-content()`).
 ///
-/// For fixed tracks: use the explicit track size (§11.4).
-/// For fr tracks: only min_content is updated (used as freeze threshold §11.7).
+/// For fixed sizing functions: the sizes are set in §11.4 and kept unchanged.
+/// For fr tracks: only min_content/max_content are updated (used in §11.7).
 fn update_track_intrinsic_sizes<L: LengthNum + Copy>(
     track: &mut TrackInfo<L>,
     outer_min_content: L,
     effective_min_content: L,
     outer_max_content: L,
-    fixed_track_size: OptionNum<L>,
 ) {
     // Always update min_content for all tracks (used as fr freeze threshold §11.7)
     track.min_content = track.min_content.max(outer_min_content);
@@ -319,84 +517,182 @@ fn update_track_intrinsic_sizes<L: LengthNum + Copy>(
         return;
     }
 
-    match track.track_type {
-        IntrinsicTrackType::Fixed => {
-            // Fixed track: use the specified size (§11.4)
-            if fixed_track_size.is_some() {
-                let size = fixed_track_size.val().unwrap();
-                track.base_size = Some(track.base_size.map(|s| s.max(size)).unwrap_or(size));
-                // Fixed track: growth_limit = base_size (§11.4)
-                track.growth_limit = track.base_size;
-            }
-        }
+    // §11.5 Step 2: intrinsic min sizing function -> increase base_size
+    let min_contribution = match track.min_track_type {
+        IntrinsicTrackType::Fixed => None,
+        IntrinsicTrackType::MaxContent => Some(outer_max_content),
+        _ => Some(effective_min_content),
+    };
+    if let Some(contribution) = min_contribution {
+        track.base_size = Some(
+            track
+                .base_size
+                .map_or(contribution, |s| s.max(contribution)),
+        );
+    }
+
+    // §11.5 Step 4: intrinsic max sizing function -> increase growth_limit
+    let max_contribution = match track.track_type {
         IntrinsicTrackType::Auto => {
-            // §11.5 Step 2: auto min -> increase base_size to min-content contribution.
-            track.base_size = Some(
-                track
-                    .base_size
-                    .map_or(effective_min_content, |s| s.max(effective_min_content)),
-            );
             // §11.4: auto max -> growth_limit = infinity (None).
             // §11.5 Step 4: increase(infinity, max-content) = infinity.
             // This allows §11.6 Maximize to distribute free space.
             track.growth_limit = None;
+            None
         }
-        IntrinsicTrackType::MinContent => {
-            // §11.5 Step 2: base_size = min-content contribution
-            track.base_size = Some(
-                track
-                    .base_size
-                    .map(|s| s.max(effective_min_content))
-                    .unwrap_or(effective_min_content),
-            );
-            // §11.5 Step 4: min-content max -> growth_limit = min-content
-            track.growth_limit = Some(
-                track
-                    .growth_limit
-                    .map(|s| s.max(effective_min_content))
-                    .unwrap_or(effective_min_content),
-            );
+        IntrinsicTrackType::MinContent => Some(effective_min_content),
+        IntrinsicTrackType::MaxContent => Some(outer_max_content),
+        IntrinsicTrackType::FitContent => Some(match track.max_size {
+            Some(limit) => outer_max_content.min(limit),
+            None => outer_max_content,
+        }),
+        IntrinsicTrackType::Fixed | IntrinsicTrackType::Fr => None,
+    };
+    if let Some(contribution) = max_contribution {
+        track.growth_limit = Some(
+            track
+                .growth_limit
+                .map_or(contribution, |s| s.max(contribution)),
+        );
+    }
+}
+
+/// Update the spanned tracks with the per-track contributions of a subgrid.
+///
+/// CSS Grid §9: the items of a subgrid participate in the track sizing of its parent grid,
+/// so each spanned track gets the contributions of the subgrid track it is adopted as.
+fn apply_subgrid_contributions<L: LengthNum + Copy>(
+    tracks: &mut [TrackInfo<L>],
+    contributions: &SubgridContributions<L>,
+) {
+    for ((track, min_content), max_content) in tracks
+        .iter_mut()
+        .zip(contributions.min_content.iter())
+        .zip(contributions.max_content.iter())
+    {
+        update_track_intrinsic_sizes(
+            track,
+            *min_content,
+            (*min_content).min(*max_content),
+            *max_content,
+        );
+    }
+}
+
+/// Distribute the contributions of an item spanning multiple tracks.
+///
+/// CSS Grid §11.5 Step 3: the space needed beyond the current sizes of the spanned tracks
+/// (the gaps between them included) is distributed equally
+/// to the base sizes of the tracks with an intrinsic min sizing function,
+/// and to the growth limits of the tracks with a finite intrinsic max sizing function.
+/// An `auto` max sizing function has an infinite growth limit, which absorbs any extra space.
+///
+/// CSS Grid §11.7.1: if the item crosses flexible tracks, the contributions are
+/// distributed to the flexible tracks only, and used when finding the size of an fr.
+///
+/// This is simplified: the spanning items are not processed in the order of their spans,
+/// and the base sizes are not limited by the growth limits during the distribution.
+fn distribute_span_contributions<L: LengthNum + Copy>(
+    tracks: &mut [TrackInfo<L>],
+    gap: L,
+    min_contribution: L,
+    max_contribution: L,
+) {
+    let zero = L::zero();
+    let gaps = gap.mul_i32(tracks.len() as i32 - 1);
+    let extra = |tracks: &[TrackInfo<L>], contribution: L, size: &dyn Fn(&TrackInfo<L>) -> L| {
+        let current = tracks.iter().fold(zero, |acc, track| acc + size(track));
+        contribution - gaps - current
+    };
+
+    let fr_count = tracks
+        .iter()
+        .filter(|track| track.track_type == IntrinsicTrackType::Fr)
+        .count();
+    if fr_count > 0 {
+        let non_fr_size = |track: &TrackInfo<L>| {
+            if track.track_type == IntrinsicTrackType::Fr {
+                zero
+            } else {
+                track.base_size.unwrap_or(zero)
+            }
+        };
+        let min_share = extra(tracks, min_contribution, &non_fr_size).div_i32(fr_count as i32);
+        let max_share = extra(tracks, max_contribution, &non_fr_size).div_i32(fr_count as i32);
+        for track in tracks
+            .iter_mut()
+            .filter(|track| track.track_type == IntrinsicTrackType::Fr)
+        {
+            track.min_content = track.min_content.max(min_share);
+            track.max_content = track.max_content.max(max_share);
         }
-        IntrinsicTrackType::MaxContent => {
-            // §11.5 Step 2: base_size = min-content contribution
-            track.base_size = Some(
-                track
-                    .base_size
-                    .map(|s| s.max(effective_min_content))
-                    .unwrap_or(effective_min_content),
-            );
-            // §11.5 Step 4: max-content max -> growth_limit = max-content
-            track.growth_limit = Some(
-                track
-                    .growth_limit
-                    .map(|s| s.max(outer_max_content))
-                    .unwrap_or(outer_max_content),
-            );
+        return;
+    }
+
+    let is_intrinsic_min = |track: &TrackInfo<L>| track.min_track_type != IntrinsicTrackType::Fixed;
+    let count = tracks
+        .iter()
+        .filter(|track| is_intrinsic_min(track))
+        .count();
+    if count > 0 {
+        let space = extra(tracks, min_contribution, &|track| {
+            track.base_size.unwrap_or(zero)
+        });
+        if space > zero {
+            let share = space.div_i32(count as i32);
+            for track in tracks.iter_mut().filter(|track| is_intrinsic_min(track)) {
+                track.base_size = Some(track.base_size.unwrap_or(zero) + share);
+            }
+        }
+    }
+
+    if tracks
+        .iter()
+        .any(|track| track.track_type == IntrinsicTrackType::Auto)
+    {
+        return;
+    }
+    let is_intrinsic_max = |track: &TrackInfo<L>| {
+        matches!(
+            track.track_type,
+            IntrinsicTrackType::MinContent
+                | IntrinsicTrackType::MaxContent
+                | IntrinsicTrackType::FitContent
+        )
+    };
+    let count = tracks
+        .iter()
+        .filter(|track| is_intrinsic_max(track))
+        .count();
+    if count > 0 {
+        let space = extra(tracks, max_contribution, &|track| {
+            track.growth_limit.or(track.base_size).unwrap_or(zero)
+        });
+        if space > zero {
+            let share = space.div_i32(count as i32);
+            for track in tracks.iter_mut().filter(|track| is_intrinsic_max(track)) {
+                let mut limit = track.growth_limit.or(track.base_size).unwrap_or(zero) + share;
+                if track.track_type == IntrinsicTrackType::FitContent {
+                    if let Some(max_size) = track.max_size {
+                        limit = limit.min(max_size);
+                    }
+                }
+                track.growth_limit = Some(limit);
+            }
         }
-        // Fr is handled by the early return above
-        IntrinsicTrackType::Fr => {}
     }
 }
 
-/// Resolve the fixed track size for an implicit or explicit track.
+/// Make sure that the growth limit of each track is not less than its base size.
 ///
-/// For explicit tracks, uses the item's pre-resolved track size.
-/// For implicit tracks (§7.6), resolves from grid-auto-rows/columns.
-fn resolve_fixed_track_size<T: LayoutTreeNode>(
-    track_index: usize,
-    explicit_count: usize,
-    item_track_size: OptionNum<T::Length>,
-    grid_auto_tracks: &LayoutGridAuto<T::Length, T::LengthCustom>,
-    available_space: OptionNum<T::Length>,
-    node: &T,
-) -> OptionNum<T::Length> {
-    if track_index < explicit_count {
-        item_track_size
-    } else {
-        let implicit_index = track_index - explicit_count;
-        match grid_auto_tracks.get(implicit_index) {
-            LayoutTrackSize::Length(def_len) => def_len.resolve(available_space, node),
-            _ => OptionNum::none(),
+/// CSS Grid §11.4: "If the growth limit is less than the base size,
+/// increase the growth limit to match the base size."
+fn clamp_growth_limits<L: LengthNum + Copy>(tracks: &mut [TrackInfo<L>]) {
+    for track in tracks.iter_mut() {
+        if let (Some(base_size), Some(growth_limit)) = (track.base_size, track.growth_limit) {
+            if growth_limit < base_size {
+                track.growth_limit = Some(base_size);
+            }
         }
     }
 }
@@ -409,44 +705,56 @@ fn resolve_fixed_track_size<T: LayoutTreeNode>(
 /// - CSS Grid §11.7 (Expand Flexible Tracks):
 ///   <https://www.w3.org/TR/css-grid-1/#algo-flex-tracks>
 ///
+/// The min and max track sizing functions are handled separately, so that
+/// `minmax()` and `fit-content()` tracks work as well.
+///
 /// Phase 1: Resolve intrinsic track sizes (§11.5)
 ///   Step 2 - For tracks with intrinsic min sizing function (auto, min-content,
 ///   max-content): set base_size = item's min-content contribution
+///   (max-content contribution for max-content)
 ///   Step 4 - For tracks with intrinsic max sizing function:
 ///   set growth_limit = item's max-content contribution
-///   (auto/max-content -> max-content; min-content -> min-content)
+///   (auto/max-content -> max-content; min-content -> min-content;
+///   fit-content -> max-content clamped by the limit)
 ///
-///   For fixed tracks: use the specified size for both base_size and growth_limit
+///   For fixed sizing functions: use the specified size for base_size or growth_limit
 ///   For fr tracks: collect min-content as freeze threshold
 ///
 /// Phase 2: Iterative fr algorithm (§11.7)
 /// 1. Calculate hypothetical_fr_size = remaining_space / total_flex
-/// 2. If any fr track's size < its min-content, freeze it at min-content
+/// 2. If any fr track's size < its base size, freeze it at the base size
 /// 3. Repeat until stable
 ///
 /// Returns `(column_tracks, row_tracks)` ready for §11.6/§11.8 processing.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn compute_track_sizes<T: LayoutTreeNode>(
+    node: &T,
     grid_layout_matrix: &GridLayoutMatrix<T>,
     column_track_list: &[&LayoutTrackListItem<T::Length, T::LengthCustom>],
     row_track_list: &[&LayoutTrackListItem<T::Length, T::LengthCustom>],
+    collapsed_columns: &[bool],
+    collapsed_rows: &[bool],
     available_grid_space: OptionSize<T::Length>,
     grid_auto_columns: &LayoutGridAuto<T::Length, T::LengthCustom>,
     grid_auto_rows: &LayoutGridAuto<T::Length, T::LengthCustom>,
+    (column_gap, row_gap): (T::Length, T::Length),
 ) -> (GridTracks<T>, GridTracks<T>) {
-    let explicit_column_count = column_track_list.len();
-    let explicit_row_count = row_track_list.len();
-
-    // Initialize track info for both axes (§7.6)
+    // Initialize track info for both axes (§7.6, §11.4)
     let mut columns = init_track_infos(
         grid_layout_matrix.column_count(),
         column_track_list,
+        collapsed_columns,
         grid_auto_columns,
+        available_grid_space.width,
+        node,
     );
     let mut rows = init_track_infos(
         grid_layout_matrix.row_count(),
         row_track_list,
+        collapsed_rows,
         grid_auto_rows,
+        available_grid_space.height,
+        node,
     );
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -461,11 +769,14 @@ pub(crate) fn compute_track_sizes<T: LayoutTreeNode>(
     //   increase growth_limit to the item's max-content contribution.
     //   - auto / max-content -> growth_limit = max-content contribution
     //   - min-content -> growth_limit = min-content contribution
+    //   - fit-content(limit) -> growth_limit = min(max-content contribution, limit)
     //
-    // For fixed tracks: use the explicit track size (§11.4)
+    // For fixed sizing functions: keep the explicit track size (§11.4)
     // For fr tracks: collect min-content for freeze threshold (§11.7)
     // ═══════════════════════════════════════════════════════════════════════
 
+    let mut spanning_columns = Vec::new();
+    let mut spanning_rows = Vec::new();
     for item in grid_layout_matrix.items() {
         let row = item.row();
         let column = item.column();
@@ -488,7 +799,9 @@ pub(crate) fn compute_track_sizes<T: LayoutTreeNode>(
         } else {
             min_content_size.height
         };
-        let outer_min_content_height = min_content_height + item.margin.vertical();
+        // §11.5 Step 1: baseline-aligned items are shimmed in the block axis
+        let outer_min_content_height =
+            min_content_height + item.margin.vertical() + item.baseline_shim;
 
         // §11.5 Step 4: max-content contribution for growth_limit
         //
@@ -512,50 +825,75 @@ pub(crate) fn compute_track_sizes<T: LayoutTreeNode>(
         } else {
             item.computed_size().height
         };
-        let outer_max_content_height = max_content_height + item.margin.vertical();
+        let outer_max_content_height =
+            max_content_height + item.margin.vertical() + item.baseline_shim;
 
         // Intrinsic sizing invariants from CSS Sizing:
         // min-content contribution must not exceed max-content contribution.
         let effective_min_content_width = outer_min_content_width.min(outer_max_content_width);
         let effective_min_content_height = outer_min_content_height.min(outer_max_content_height);
 
-        // Resolve fixed track sizes for implicit/explicit tracks
-        let col_fixed_size = resolve_fixed_track_size::<T>(
-            column,
-            explicit_column_count,
-            item.track_inline_size(),
-            grid_auto_columns,
-            available_grid_space.width,
-            item.node,
-        );
-        let row_fixed_size = resolve_fixed_track_size::<T>(
-            row,
-            explicit_row_count,
-            item.track_block_size(),
-            grid_auto_rows,
-            available_grid_space.height,
-            item.node,
-        );
-
         // Update column track
-        update_track_intrinsic_sizes(
-            &mut columns[column],
-            outer_min_content_width,
-            effective_min_content_width,
-            outer_max_content_width,
-            col_fixed_size,
-        );
+        // (the items spanning multiple tracks are handled after the others, §11.5 Step 3)
+        if item.column_span() == 1 {
+            update_track_intrinsic_sizes(
+                &mut columns[column],
+                outer_min_content_width,
+                effective_min_content_width,
+                outer_max_content_width,
+            );
+        } else if let Some(contributions) = &item.subgrid_column_contributions {
+            apply_subgrid_contributions(
+                &mut columns[column..(column + item.column_span())],
+                contributions,
+            );
+        } else {
+            spanning_columns.push((
+                column..(column + item.column_span()),
+                effective_min_content_width,
+                outer_max_content_width,
+            ));
+        }
 
         // Update row track
-        update_track_intrinsic_sizes(
-            &mut rows[row],
-            outer_min_content_height,
-            effective_min_content_height,
-            outer_max_content_height,
-            row_fixed_size,
+        if item.row_span() == 1 {
+            update_track_intrinsic_sizes(
+                &mut rows[row],
+                outer_min_content_height,
+                effective_min_content_height,
+                outer_max_content_height,
+            );
+        } else if let Some(contributions) = &item.subgrid_row_contributions {
+            apply_subgrid_contributions(&mut rows[row..(row + item.row_span())], contributions);
+        } else {
+            spanning_rows.push((
+                row..(row + item.row_span()),
+                effective_min_content_height,
+                outer_max_content_height,
+            ));
+        }
+    }
+
+    for (range, min_contribution, max_contribution) in spanning_columns {
+        distribute_span_contributions(
+            &mut columns[range],
+            column_gap,
+            min_contribution,
+            max_contribution,
+        );
+    }
+    for (range, min_contribution, max_contribution) in spanning_rows {
+        distribute_span_contributions(
+            &mut rows[range],
+            row_gap,
+            min_contribution,
+            max_contribution,
         );
     }
 
+    clamp_growth_limits(&mut columns);
+    clamp_growth_limits(&mut rows);
+
     // ═══════════════════════════════════════════════════════════════════════
     // Phase 2: Calculate fr track sizes with iterative algorithm (§11.7)
     // CSS Grid §11.7: Expand Flexible Tracks
@@ -579,6 +917,7 @@ pub(crate) fn compute_track_sizes<T: LayoutTreeNode>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::boxed::Box;
 
     /// Helper: create a non-fr TrackInfo with a fixed base_size.
     fn fixed_track(base: f32) -> TrackInfo<f32> {
@@ -589,6 +928,9 @@ mod tests {
             min_content: 0.0,
             max_content: 0.0,
             track_type: IntrinsicTrackType::Fixed,
+            min_track_type: IntrinsicTrackType::Fixed,
+            min_size: Some(base),
+            max_size: Some(base),
         }
     }
 
@@ -601,6 +943,9 @@ mod tests {
             min_content,
             max_content: 0.0,
             track_type: IntrinsicTrackType::Fr,
+            min_track_type: IntrinsicTrackType::Auto,
+            min_size: None,
+            max_size: None,
         }
     }
 
@@ -613,6 +958,9 @@ mod tests {
             min_content,
             max_content,
             track_type: IntrinsicTrackType::Fr,
+            min_track_type: IntrinsicTrackType::Auto,
+            min_size: None,
+            max_size: None,
         }
     }
 
@@ -860,4 +1208,116 @@ mod tests {
             IntrinsicTrackType::MaxContent
         );
     }
+
+    #[test]
+    fn classify_track_type_minmax() {
+        // §7.2.4: minmax(min, max) has separated min and max sizing functions
+        let ts: LayoutTrackSize<f32, i32> = LayoutTrackSize::MinMax(
+            Box::new(LayoutTrackSize::Length(DefLength::Points(100.0))),
+            Box::new(LayoutTrackSize::MaxContent),
+        );
+        assert_eq!(
+            classify_min_track_type::<f32, i32>(&ts),
+            IntrinsicTrackType::Fixed
+        );
+        assert_eq!(
+            classify_track_type::<f32, i32>(&ts),
+            IntrinsicTrackType::MaxContent
+        );
+    }
+
+    #[test]
+    fn classify_track_type_fit_content() {
+        // §7.2.4: fit-content(limit) behaves like minmax(auto, max-content)
+        let ts: LayoutTrackSize<f32, i32> = LayoutTrackSize::FitContent(DefLength::Points(100.0));
+        assert_eq!(
+            classify_min_track_type::<f32, i32>(&ts),
+            IntrinsicTrackType::Auto
+        );
+        assert_eq!(
+            classify_track_type::<f32, i32>(&ts),
+            IntrinsicTrackType::FitContent
+        );
+    }
+
+    #[test]
+    fn classify_min_track_type_fr() {
+        // §7.2.4: `1fr` is `minmax(auto, 1fr)`
+        let ts: LayoutTrackSize<f32, i32> = LayoutTrackSize::Fr(1.0);
+        assert_eq!(
+            classify_min_track_type::<f32, i32>(&ts),
+            IntrinsicTrackType::Auto
+        );
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // §11.5: Separated min and max sizing functions
+    // <https://www.w3.org/TR/css-grid-1/#algo-content>
+    // ═══════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn minmax_fixed_min_keeps_base_size() {
+        // Layout: grid-template-columns: minmax(100px, max-content)
+        // item: min-content = 50px, max-content = 150px
+        // base_size stays at the fixed min, growth_limit = max-content
+        let mut track = fixed_track(100.0);
+        track.growth_limit = None;
+        track.track_type = IntrinsicTrackType::MaxContent;
+        track.max_size = None;
+        update_track_intrinsic_sizes(&mut track, 50.0, 50.0, 150.0);
+        assert_eq!(track.base_size, Some(100.0));
+        assert_eq!(track.growth_limit, Some(150.0));
+    }
+
+    #[test]
+    fn fit_content_clamps_growth_limit() {
+        // Layout: grid-template-columns: fit-content(100px)
+        // item: min-content = 50px, max-content = 150px
+        // growth_limit = min(max-content, 100px) = 100px
+        let mut track = TrackInfo::new();
+        track.track_type = IntrinsicTrackType::FitContent;
+        track.max_size = Some(100.0);
+        update_track_intrinsic_sizes(&mut track, 50.0, 50.0, 150.0);
+        assert_eq!(track.base_size, Some(50.0));
+        assert_eq!(track.growth_limit, Some(100.0));
+    }
+
+    #[test]
+    fn growth_limit_is_floored_by_base_size() {
+        // §11.4: Layout: grid-template-columns: minmax(min-content, 20px)
+        // item: min-content = 50px -> growth_limit = base_size = 50px
+        let mut tracks = vec![fixed_track(20.0)];
+        tracks[0].min_track_type = IntrinsicTrackType::MinContent;
+        tracks[0].base_size = None;
+        update_track_intrinsic_sizes(&mut tracks[0], 50.0, 50.0, 80.0);
+        clamp_growth_limits(&mut tracks);
+        assert_eq!(tracks[0].base_size, Some(50.0));
+        assert_eq!(tracks[0].growth_limit, Some(50.0));
+    }
+
+    #[test]
+    fn fr_freeze_at_fixed_min() {
+        // §11.7: Layout: grid-template-columns: minmax(80px, 1fr) 1fr
+        // Available: 100px
+        // Round 1: hypothetical = 100/2 = 50 < 80 -> freeze track[0] at 80
+        // Round 2: remaining = 100 - 80 = 20 -> track[1] = 20
+        let mut tracks = vec![fr_track(1.0, 0.0), fr_track(1.0, 0.0)];
+        tracks[0].min_track_type = IntrinsicTrackType::Fixed;
+        tracks[0].min_size = Some(80.0);
+        resolve_fr_track_sizes(&mut tracks, OptionNum::some(100.0));
+        assert_eq!(tracks[0].base_size, Some(80.0));
+        assert_eq!(tracks[1].base_size, Some(20.0));
+    }
+
+    #[test]
+    fn minmax_maximized_before_fr() {
+        // §11.6 before §11.7: Layout: grid-template-columns: minmax(100px, 200px) 1fr
+        // Available: 500px -> minmax track grows to 200px, fr track takes 300px
+        let mut tracks = vec![fixed_track(100.0), fr_track(1.0, 0.0)];
+        tracks[0].growth_limit = Some(200.0);
+        tracks[0].max_size = Some(200.0);
+        resolve_fr_track_sizes(&mut tracks, OptionNum::some(500.0));
+        assert_eq!(tracks[0].base_size, Some(200.0));
+        assert_eq!(tracks[1].base_size, Some(300.0));
+    }
 }
@@ -0,0 +1,276 @@
+//! List items and their marker boxes.
+//!
+//! CSS Lists and Counters Module Level 3
+//! <https://www.w3.org/TR/css-lists-3/>
+//!
+//! The marker box of a list item is generated and measured by the host (`LayoutTreeNode::measure_marker`),
+//! and positioned by the flow layout of the list item:
+//! * an `outside` marker is placed before the inline-start edge of the content box, and it does not affect the layout;
+//! * an `inside` marker is placed at the start of the content box, and the inline content flows around it like a start float.
+//!
+//! Some simplifications are made:
+//!
+//! * the `list-item` counter is scoped to the parent of the list items,
+//!   and it can only be changed by the host-supplied `list_item_value`, `list_start` and `list_reversed`;
+//! * an `inside` marker does not shift the inline content of the nested (non-BFC) blocks;
+//! * a list item is never laid out as a multi-column container.
+
+use crate::*;
+
+/// Whether the node is a `display: list-item` node.
+#[inline]
+pub(crate) fn is_list_item<T: LayoutTreeNode>(style: &T::Style) -> bool {
+    style.display() == Display::ListItem
+}
+
+/// CSS Lists §4.6: the values of the `list-item` counter of the list items among the layout children.
+///
+/// Each list item increments the counter of its parent by one (or decrements it in a reversed list),
+/// unless the item has an explicit value.
+fn for_each_list_item_ordinal<'a, T: LayoutTreeNode>(parent: &'a T, mut f: impl FnMut(&'a T, i32)) {
+    let parent_style = parent.style();
+    let reversed = parent_style.list_reversed();
+    let step = if reversed { -1 } else { 1 };
+    let start = parent_style.list_start().unwrap_or_else(|| {
+        if reversed {
+            let mut count = 0i32;
+            parent.tree_visitor().for_each_layout_child(|child, _| {
+                if is_list_item::<T>(child.style()) {
+                    count = count.saturating_add(1);
+                }
+            });
+            count
+        } else {
+            1
+        }
+    });
+    let mut value = start.saturating_sub(step);
+    parent.tree_visitor().for_each_layout_child(|child, _| {
+        if !is_list_item::<T>(child.style()) {
+            return;
+        }
+        value = child
+            .style()
+            .list_item_value()
+            .unwrap_or_else(|| value.saturating_add(step));
+        f(child, value);
+    });
+}
+
+/// Store the ordinals of the list items among the layout children in their layout units.
+///
+/// It is done once in each layout pass of the parent, so that the siblings are not scanned again for each list item.
+pub(crate) fn prepare_children_list_ordinals<T: LayoutTreeNode>(node: &T) {
+    for_each_list_item_ordinal(node, |child, value| {
+        child.layout_node().unit().list_item_ordinal = Some(value);
+    });
+}
+
+/// The ordinal of a list item which is laid out without the layout pass of its parent, e.g. the tree root.
+fn list_item_ordinal<T: LayoutTreeNode>(node: &T) -> i32 {
+    let Some(parent) = node.tree_visitor().layout_parent() else {
+        return node.style().list_item_value().unwrap_or(1);
+    };
+    let mut ret = None;
+    for_each_list_item_ordinal(parent, |child, value| {
+        if ret.is_none() && core::ptr::eq(child, node) {
+            ret = Some(value);
+        }
+    });
+    ret.unwrap_or(1)
+}
+
+/// Measure the marker box of the node, if it generates one.
+///
+/// CSS Lists §3: no marker box is generated if both `list-style-type` and `list-style-image` are `none`.
+/// The `ordinal` is the one prepared by the layout pass of the parent, if any.
+pub(crate) fn measure_marker<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &T,
+    ordinal: Option<i32>,
+) -> Option<MeasureResult<T::Length>> {
+    let style = node.style();
+    if !is_list_item::<T>(style) {
+        return None;
+    }
+    if style.list_style_type() == ListStyleType::None
+        && style.list_style_image() == ListStyleImage::None
+    {
+        return None;
+    }
+    let ordinal = ordinal.unwrap_or_else(|| list_item_ordinal(node));
+    node.measure_marker(env, ordinal)
+}
+
+/// The rect of a marker box, relative to the border box of the list item.
+///
+/// The `main_offset` is the distance between the start edge of the content box and the marker box, in the block axis.
+pub(crate) fn marker_rect<L: LengthNum>(
+    axis_info: AxisInfo,
+    side: FloatSide,
+    position: ListStylePosition,
+    border_box_size: Size<L>,
+    padding_border: Edge<L>,
+    marker_size: Size<L>,
+    main_offset: L,
+) -> Rect<L> {
+    let dir = axis_info.dir;
+    let main_size = marker_size.main_size(dir);
+    let cross_size = marker_size.cross_size(dir);
+    let main = padding_border.main_axis_start(dir, axis_info.main_dir_rev) + main_offset;
+    let main = match axis_info.main_dir_rev {
+        AxisReverse::NotReversed => main,
+        AxisReverse::Reversed => border_box_size.main_size(dir) - main - main_size,
+    };
+    let content_start = padding_border.cross_axis_start(dir, AxisReverse::NotReversed);
+    let content_end = border_box_size.cross_size(dir)
+        - padding_border.cross_axis_end(dir, AxisReverse::NotReversed);
+    let cross = match (side, position) {
+        (FloatSide::Start, ListStylePosition::Outside) => content_start - cross_size,
+        (FloatSide::Start, ListStylePosition::Inside) => content_start,
+        (FloatSide::End, ListStylePosition::Outside) => content_end,
+        (FloatSide::End, ListStylePosition::Inside) => content_end - cross_size,
+    };
+    Rect::new(
+        Vector::new_with_dir(dir, main, cross).to_point(),
+        Size::new_with_dir(dir, main_size, cross_size),
+    )
+}
+
+/// The distance between the start edge of the content box and the marker box, in the block axis.
+///
+/// CSS Lists §3.5: the marker is aligned to the first baseline of the list item.
+/// It is placed at the start of the content box if there is no baseline.
+/// An `inside` marker never goes above the content box.
+pub(crate) fn marker_main_offset<L: LengthNum>(
+    axis_info: AxisInfo,
+    position: ListStylePosition,
+    padding_border: Edge<L>,
+    first_baseline_ascent: Option<Vector<L>>,
+    marker: &MeasureResult<L>,
+) -> L {
+    if axis_info.main_dir_rev == AxisReverse::Reversed {
+        return L::zero();
+    }
+    let dir = axis_info.dir;
+    let Some(baseline) = first_baseline_ascent else {
+        return L::zero();
+    };
+    let offset = baseline.main_axis(dir)
+        - padding_border.main_axis_start(dir, axis_info.main_dir_rev)
+        - marker.first_baseline_ascent.main_axis(dir);
+    match position {
+        ListStylePosition::Outside => offset,
+        ListStylePosition::Inside => offset.max(L::zero()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn horizontal() -> AxisInfo {
+        AxisInfo::from_writing_mode(WritingMode::HorizontalTb)
+    }
+
+    #[test]
+    fn outside_marker_rect() {
+        let padding_border = Edge {
+            left: 30.,
+            right: 10.,
+            top: 5.,
+            bottom: 5.,
+        };
+        let rect = marker_rect(
+            horizontal(),
+            FloatSide::Start,
+            ListStylePosition::Outside,
+            Size::new(200., 50.),
+            padding_border,
+            Size::new(20., 16.),
+            2.,
+        );
+        assert_eq!(rect, Rect::new(Point::new(10., 7.), Size::new(20., 16.)));
+        let rect = marker_rect(
+            horizontal(),
+            FloatSide::End,
+            ListStylePosition::Outside,
+            Size::new(200., 50.),
+            padding_border,
+            Size::new(20., 16.),
+            0.,
+        );
+        assert_eq!(rect, Rect::new(Point::new(190., 5.), Size::new(20., 16.)));
+    }
+
+    #[test]
+    fn inside_marker_rect() {
+        let padding_border = Edge {
+            left: 30.,
+            right: 10.,
+            top: 5.,
+            bottom: 5.,
+        };
+        let rect = marker_rect(
+            horizontal(),
+            FloatSide::End,
+            ListStylePosition::Inside,
+            Size::new(200., 50.),
+            padding_border,
+            Size::new(20., 16.),
+            0.,
+        );
+        assert_eq!(rect, Rect::new(Point::new(170., 5.), Size::new(20., 16.)));
+    }
+
+    #[test]
+    fn marker_baseline_alignment() {
+        let padding_border = Edge {
+            left: 0.,
+            right: 0.,
+            top: 4.,
+            bottom: 0.,
+        };
+        let marker = MeasureResult {
+            size: Size::new(10., 10.),
+            first_baseline_ascent: Vector::new(0., 10.),
+            last_baseline_ascent: Vector::new(0., 10.),
+        };
+        let baseline = Some(Vector::new(0., 24.));
+        assert_eq!(
+            marker_main_offset(
+                horizontal(),
+                ListStylePosition::Outside,
+                padding_border,
+                baseline,
+                &marker
+            ),
+            10.
+        );
+        let marker = MeasureResult {
+            size: Size::new(40., 40.),
+            first_baseline_ascent: Vector::new(0., 40.),
+            last_baseline_ascent: Vector::new(0., 40.),
+        };
+        assert_eq!(
+            marker_main_offset(
+                horizontal(),
+                ListStylePosition::Outside,
+                padding_border,
+                baseline,
+                &marker
+            ),
+            -20.
+        );
+        assert_eq!(
+            marker_main_offset(
+                horizontal(),
+                ListStylePosition::Inside,
+                padding_border,
+                baseline,
+                &marker
+            ),
+            0.
+        );
+    }
+}
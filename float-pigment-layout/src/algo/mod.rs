@@ -23,6 +23,11 @@
 // SOFTWARE.
 
 pub(crate) mod flex_box;
+pub(crate) mod float;
 pub(crate) mod flow;
+pub(crate) mod list;
+pub(crate) mod multicol;
+pub(crate) mod table;
+pub(crate) mod virtualized;
 
 pub(crate) mod grid;
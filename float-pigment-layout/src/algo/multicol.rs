@@ -0,0 +1,464 @@
+//! CSS Multi-column Layout
+//!
+//! Reference: <https://www.w3.org/TR/css-multicol-1/>
+//!
+//! The in-flow children of a multi-column container are laid out as unbreakable block-level boxes,
+//! and distributed across the column boxes in tree order.
+//! The column height is balanced unless `column-fill: auto` is used with a definite container height.
+//! A child with `column-span: all` spans all columns, and splits the content into several column sets.
+
+use crate::*;
+use float_pigment_css::num_traits::Zero;
+
+/// Whether the node is a multi-column container.
+///
+/// CSS Multi-column §2: a block container is a multi-column container
+/// if `column-count` or `column-width` is not `auto`.
+#[inline]
+pub(crate) fn is_multicol_container<T: LayoutTreeNode>(style: &T::Style) -> bool {
+    matches!(
+        style.display(),
+        Display::Block | Display::InlineBlock | Display::FlowRoot
+    ) && (style.column_count().is_some()
+        || !matches!(style.column_width(), DefLength::Auto | DefLength::Undefined))
+}
+
+/// CSS Multi-column §3.4: the used column count and column width.
+///
+/// `available` is the content box inline size of the container.
+pub(crate) fn used_column_count_and_width<L: LengthNum>(
+    column_count: Option<u32>,
+    column_width: OptionNum<L>,
+    available: L,
+    gap: L,
+) -> (u32, L) {
+    let count = match column_width.val() {
+        Some(width) if width + gap > L::zero() => {
+            let fit = (((available + gap) / (width + gap)).to_f32() as u32).max(1);
+            match column_count {
+                Some(count) => count.clamp(1, fit),
+                None => fit,
+            }
+        }
+        _ => column_count.unwrap_or(1).max(1),
+    };
+    let width = ((available - gap.mul_i32(count as i32 - 1)).div_i32(count as i32)).max(L::zero());
+    (count, width)
+}
+
+/// A child box to be placed in a column, in the block axis.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ColumnFragment<L: LengthNum> {
+    pub(crate) margin_start: L,
+    pub(crate) size: L,
+    pub(crate) margin_end: L,
+}
+
+#[derive(Debug)]
+pub(crate) struct ColumnDistribution<L: LengthNum> {
+    /// The column index and the block offset of each fragment.
+    pub(crate) placements: Vec<(usize, L)>,
+    /// The content height of each column.
+    pub(crate) heights: Vec<L>,
+    /// The minimum extra height that avoids one of the column breaks.
+    pub(crate) min_shortfall: Option<L>,
+}
+
+/// Fill the fragments into columns, starting a new column when the border box of a fragment exceeds `limit`.
+///
+/// Adjacent margins in a column collapse, and margins are truncated at column breaks
+/// (CSS Fragmentation §5.2).
+/// A fragment taller than `limit` still occupies a whole column.
+pub(crate) fn distribute_fragments<L: LengthNum>(
+    fragments: &[ColumnFragment<L>],
+    limit: L,
+) -> ColumnDistribution<L> {
+    let mut placements = Vec::with_capacity(fragments.len());
+    let mut heights = vec![];
+    let mut min_shortfall: Option<L> = None;
+    // the border box end and the margin end of the last fragment in the current column
+    let mut cur: Option<(L, L)> = None;
+    for f in fragments {
+        let offset = match cur {
+            None => f.margin_start,
+            Some((end, margin_end)) => {
+                let offset = end + margin_end.max(f.margin_start);
+                let shortfall = offset + f.size - limit;
+                if shortfall > L::zero() {
+                    min_shortfall = Some(match min_shortfall {
+                        Some(x) => x.min(shortfall),
+                        None => shortfall,
+                    });
+                    heights.push(end);
+                    L::zero()
+                } else {
+                    offset
+                }
+            }
+        };
+        placements.push((heights.len(), offset));
+        cur = Some((offset + f.size, f.margin_end));
+    }
+    if let Some((end, margin_end)) = cur {
+        heights.push(end + margin_end);
+    }
+    ColumnDistribution {
+        placements,
+        heights,
+        min_shortfall,
+    }
+}
+
+/// CSS Multi-column §7.1: find the minimum column height that fits the fragments into `count` columns.
+///
+/// Starts from an even split of the content, and grows by the minimum shortfall
+/// until no more than `count` columns are needed.
+pub(crate) fn balanced_column_height<L: LengthNum>(
+    fragments: &[ColumnFragment<L>],
+    count: u32,
+) -> L {
+    let total = distribute_fragments(fragments, L::max_value())
+        .heights
+        .first()
+        .copied()
+        .unwrap_or_else(L::zero);
+    let mut limit = fragments
+        .iter()
+        .fold(total.div_i32(count.max(1) as i32), |x, f| x.max(f.size));
+    loop {
+        let d = distribute_fragments(fragments, limit);
+        match d.min_shortfall {
+            Some(shortfall) if d.heights.len() > count as usize => limit += shortfall,
+            _ => break limit,
+        }
+    }
+}
+
+struct ColumnChild<'a, T: LayoutTreeNode> {
+    node: &'a T,
+    spanner: bool,
+    fragment: ColumnFragment<T::Length>,
+    margin_inline_start: T::Length,
+}
+
+/// Lay out an in-flow block-level child with the given available inline size.
+///
+/// The child is stretched to the available inline size unless it has a specified width.
+pub(crate) fn compute_block_child<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    child_node: &T,
+    inner_size: OptionSize<T::Length>,
+    available_width: OptionNum<T::Length>,
+    request: &ComputeRequest<T::Length>,
+) -> (ComputeResult<T::Length>, Edge<T::Length>) {
+    let mut child = child_node.layout_node().unit();
+    let (child_margin, child_border, child_padding_border) =
+        child.margin_border_padding(child_node, inner_size);
+    let min_max_limit =
+        child.normalized_min_max_limit(child_node, inner_size, child_border, child_padding_border);
+    let mut css_size =
+        child.css_border_box_size(child_node, inner_size, child_border, child_padding_border);
+    let stretched_width = available_width - child_margin.horizontal();
+    css_size.width = css_size.width.or(stretched_width);
+    let res = child.compute_internal(
+        env,
+        child_node,
+        ComputeRequest {
+            size: min_max_limit.normalized_size(css_size),
+            parent_inner_size: Normalized(inner_size),
+            max_content: min_max_limit
+                .normalized_size(OptionSize::new(stretched_width, OptionNum::none())),
+            kind: request.kind.shift_to_all_size(),
+            parent_is_block: true,
+            sizing_mode: request.sizing_mode,
+            line_clamp: None,
+        },
+    );
+    (res, child_margin.or_zero())
+}
+
+pub(crate) trait Multicol<T: LayoutTreeNode> {
+    fn compute(
+        &mut self,
+        env: &mut T::Env,
+        node: &T,
+        request: ComputeRequest<T::Length>,
+        margin: EdgeOption<T::Length>,
+        border: Edge<T::Length>,
+        padding_border: Edge<T::Length>,
+    ) -> ComputeResult<T::Length>;
+}
+
+impl<T: LayoutTreeNode> Multicol<T> for LayoutUnit<T> {
+    fn compute(
+        &mut self,
+        env: &mut T::Env,
+        node: &T,
+        request: ComputeRequest<T::Length>,
+        margin: EdgeOption<T::Length>,
+        border: Edge<T::Length>,
+        padding_border: Edge<T::Length>,
+    ) -> ComputeResult<T::Length> {
+        let style = node.style();
+        // a multi-column container establishes a BFC, so its margins never collapse with the children
+        let collapsed_margin =
+            CollapsedBlockMargin::from_margin(margin.top.or_zero(), margin.bottom.or_zero());
+        if let Some(x) = self.is_requested_size_fixed(&request, Some(collapsed_margin)) {
+            return x;
+        }
+
+        let node_size = request.size.0;
+        let definite_inner_width = node_size.width - padding_border.horizontal();
+        let available_inner_width = request.max_content.width - padding_border.horizontal();
+        let inner_height = node_size.height - padding_border.vertical();
+        let percentage_base = definite_inner_width.or(available_inner_width);
+        let gap = style.column_gap().resolve(percentage_base, node).or_zero();
+        let column_width = style.column_width().resolve(percentage_base, node);
+
+        let mut children: Vec<&T> = vec![];
+        node.tree_visitor().for_each_layout_child(|child_node, _| {
+            let child_style = child_node.style();
+            if is_display_none::<T>(child_style) || is_out_of_flow::<T>(child_style) {
+                return;
+            }
+            children.push(child_node);
+        });
+
+        // shrink-to-fit: use the max-content width of the columns, limited by the available space
+        let inner_width = definite_inner_width.unwrap_or_else(|| {
+            let count = style.column_count().unwrap_or(1).max(1);
+            let width = column_width.unwrap_or_else(|| {
+                children
+                    .iter()
+                    .fold(T::Length::zero(), |width, child_node| {
+                        let (res, child_margin) = compute_block_child(
+                            env,
+                            *child_node,
+                            OptionSize::new(OptionNum::none(), inner_height),
+                            OptionNum::none(),
+                            &ComputeRequest {
+                                kind: ComputeRequestKind::AllSize,
+                                ..request.clone()
+                            },
+                        );
+                        width.max(res.size.width + child_margin.horizontal())
+                    })
+            });
+            let max_content_width = width.mul_i32(count as i32) + gap.mul_i32(count as i32 - 1);
+            match available_inner_width.val() {
+                Some(available) => max_content_width.min(available.max(T::Length::zero())),
+                None => max_content_width,
+            }
+        });
+
+        // CSS Multi-column §3.4: determine the column count and width
+        let (column_count, column_width) =
+            used_column_count_and_width(style.column_count(), column_width, inner_width, gap);
+
+        // lay out the children in the column boxes or across all columns
+        let column_children: Vec<_> = children
+            .into_iter()
+            .map(|child_node| {
+                let spanner = child_node.style().column_span() == ColumnSpan::All;
+                let width = if spanner { inner_width } else { column_width };
+                let (res, child_margin) = compute_block_child(
+                    env,
+                    child_node,
+                    OptionSize::new(OptionNum::some(width), inner_height),
+                    OptionNum::some(width),
+                    &request,
+                );
+                ColumnChild {
+                    node: child_node,
+                    spanner,
+                    fragment: ColumnFragment {
+                        margin_start: res.collapsed_margin.start.solve(),
+                        size: res.size.height,
+                        margin_end: res.collapsed_margin.end.solve(),
+                    },
+                    margin_inline_start: child_margin.left,
+                }
+            })
+            .collect();
+
+        let is_position = request.kind == ComputeRequestKind::Position;
+        let rtl = style.direction() == Direction::RTL;
+        let column_x = |index: usize| {
+            let x = (column_width + gap).mul_i32(index as i32);
+            let x = if rtl {
+                inner_width - x - column_width
+            } else {
+                x
+            };
+            padding_border.left + x
+        };
+        let mut column_rects = vec![];
+        let mut block_offset = T::Length::zero();
+        let mut index = 0;
+        while index < column_children.len() {
+            let child = &column_children[index];
+            if child.spanner {
+                if is_position {
+                    child.node.layout_node().unit().result.origin = Point::new(
+                        padding_border.left + child.margin_inline_start,
+                        padding_border.top + block_offset + child.fragment.margin_start,
+                    );
+                }
+                block_offset +=
+                    child.fragment.margin_start + child.fragment.size + child.fragment.margin_end;
+                index += 1;
+                continue;
+            }
+
+            // a column set ends at a spanner
+            let start = index;
+            while index < column_children.len() && !column_children[index].spanner {
+                index += 1;
+            }
+            let column_set = &column_children[start..index];
+            let fragments: Vec<_> = column_set.iter().map(|x| x.fragment).collect();
+            let balanced = || balanced_column_height(&fragments, column_count);
+            // CSS Multi-column §7.1: only the last column set can be constrained by the container height
+            let remaining = if index == column_children.len() {
+                inner_height.map(|h| (h - block_offset).max(T::Length::zero()))
+            } else {
+                OptionNum::none()
+            };
+            let (limit, fill_auto) = match remaining.val() {
+                Some(remaining) => match style.column_fill() {
+                    ColumnFill::Auto => (remaining, true),
+                    _ => (balanced().min(remaining), false),
+                },
+                None => (balanced(), false),
+            };
+            let distribution = distribute_fragments(&fragments, limit);
+            let column_height = if fill_auto {
+                limit
+            } else {
+                distribution
+                    .heights
+                    .iter()
+                    .fold(T::Length::zero(), |x, y| x.max(*y))
+            };
+
+            if is_position {
+                for (child, (column, offset)) in column_set.iter().zip(distribution.placements) {
+                    child.node.layout_node().unit().result.origin = Point::new(
+                        column_x(column) + child.margin_inline_start,
+                        padding_border.top + block_offset + offset,
+                    );
+                }
+                for column in 0..distribution.heights.len() {
+                    column_rects.push(Rect::new(
+                        Point::new(column_x(column), padding_border.top + block_offset),
+                        Size::new(column_width, column_height),
+                    ));
+                }
+            }
+            block_offset += column_height;
+        }
+
+        let size = Size::new(
+            node_size
+                .width
+                .unwrap_or(inner_width + padding_border.horizontal()),
+            node_size
+                .height
+                .unwrap_or(block_offset + padding_border.vertical()),
+        );
+        let size = self.min_max_size_limit(
+            node,
+            *request.parent_inner_size,
+            size,
+            border,
+            padding_border,
+        );
+        let baseline_ascent = Vector::new(T::Length::zero(), size.height);
+        let ret = ComputeResult {
+            size,
+            first_baseline_ascent: baseline_ascent,
+            last_baseline_ascent: baseline_ascent,
+            collapsed_margin,
+            line_count: 0,
+        };
+
+        if is_position {
+            compute_special_position_children(
+                env,
+                node,
+                &ret,
+                border,
+                padding_border,
+                AxisInfo::from_writing_mode(style.writing_mode()),
+                false,
+            );
+            self.result = Rect::new(Point::zero(), ret.size.0);
+            self.result_column_rects = column_rects;
+            self.cache.write_position(node, &request, ret);
+        } else {
+            self.cache.write_all_size(node, &request, ret);
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fragment(margin_start: f32, size: f32, margin_end: f32) -> ColumnFragment<f32> {
+        ColumnFragment {
+            margin_start,
+            size,
+            margin_end,
+        }
+    }
+
+    #[test]
+    fn column_count_and_width() {
+        assert_eq!(
+            used_column_count_and_width(Some(3), OptionNum::none(), 320., 10.),
+            (3, 100.)
+        );
+        assert_eq!(
+            used_column_count_and_width(None, OptionNum::some(100.), 340., 20.),
+            (3, 100.)
+        );
+        assert_eq!(
+            used_column_count_and_width(Some(2), OptionNum::some(100.), 350., 20.),
+            (2, 165.)
+        );
+        assert_eq!(
+            used_column_count_and_width(None, OptionNum::some(400.), 350., 20.),
+            (1, 350.)
+        );
+    }
+
+    #[test]
+    fn distribute_with_margin_collapsing() {
+        let fragments = [
+            fragment(10., 50., 10.),
+            fragment(20., 50., 0.),
+            fragment(5., 30., 5.),
+        ];
+        let d = distribute_fragments(&fragments, 130.);
+        assert_eq!(d.placements, vec![(0, 10.), (0, 80.), (1, 0.)]);
+        assert_eq!(d.heights, vec![130., 35.]);
+        assert_eq!(d.min_shortfall, Some(35.));
+    }
+
+    #[test]
+    fn balance_columns() {
+        let fragments = [
+            fragment(0., 40., 0.),
+            fragment(0., 40., 0.),
+            fragment(0., 60., 0.),
+            fragment(0., 20., 0.),
+        ];
+        assert_eq!(balanced_column_height(&fragments, 2), 80.);
+        // the greedy fill cannot use 3 columns of 60
+        assert_eq!(balanced_column_height(&fragments, 3), 80.);
+        assert_eq!(balanced_column_height(&fragments, 1), 160.);
+    }
+}
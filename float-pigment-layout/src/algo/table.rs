@@ -0,0 +1,1268 @@
+//! CSS Table Layout
+//!
+//! Reference: <https://www.w3.org/TR/CSS2/tables.html> and <https://www.w3.org/TR/css-tables-3/>
+//!
+//! Both the automatic and the fixed table layout algorithms are supported,
+//! as well as the separated and the collapsing border models.
+//! Missing table boxes are generated as anonymous boxes (CSS 2.1 §17.2.1),
+//! so a table can also be built from misparented rows and cells,
+//! e.g. consecutive rows and cells in a block container are wrapped in a single anonymous table.
+//!
+//! Some simplifications are made:
+//!
+//! * the content of a cell is always aligned to the top of the cell (`vertical-align` is not supported);
+//! * a table-cell which is neither inside a table nor inside a block container (e.g. a flex item)
+//!   is laid out as a shrink-to-fit block;
+//! * the captions are placed at the top, inside the content box of the table;
+//! * the percentage widths of cells and columns are treated as `auto`;
+//! * the table is always laid out in the horizontal writing mode.
+
+use crate::*;
+use core::ops::Range;
+use float_pigment_css::num_traits::Zero;
+
+use super::multicol::compute_block_child;
+
+/// Whether the node is a table row or a row group which is not inside a table.
+///
+/// CSS 2.1 §17.2.1: an anonymous table box is generated around it.
+/// Inside a block container, the anonymous table is laid out by `compute_anonymous_table`;
+/// otherwise (e.g. a flex item), the node itself is laid out as the table.
+#[inline]
+pub(crate) fn is_anonymous_table_root<T: LayoutTreeNode>(node: &T) -> bool {
+    let parent_display = node
+        .tree_visitor()
+        .layout_parent()
+        .map(|parent| parent.style().display());
+    match node.style().display() {
+        Display::TableRow => !matches!(
+            parent_display,
+            Some(Display::Table | Display::TableRowGroup | Display::TableHeaderGroup)
+        ),
+        Display::TableRowGroup | Display::TableHeaderGroup => {
+            parent_display != Some(Display::Table)
+        }
+        _ => false,
+    }
+}
+
+/// Whether the node is a misparented table part, which is wrapped in an anonymous table
+/// together with the adjacent ones if it is inside a block container (CSS 2.1 §17.2.1).
+#[inline]
+pub(crate) fn is_misparented_table_part<T: LayoutTreeNode>(style: &T::Style) -> bool {
+    matches!(
+        style.display(),
+        Display::TableRow | Display::TableRowGroup | Display::TableHeaderGroup | Display::TableCell
+    )
+}
+
+/// Whether the node is a table (or an anonymous table) inside a block container.
+///
+/// A table is shrink-to-fit, so it is not stretched by the block container.
+/// This is also applied to a misparented table-cell.
+#[inline]
+pub(crate) fn is_table_wrapper_box<T: LayoutTreeNode>(style: &T::Style) -> bool {
+    matches!(
+        style.display(),
+        Display::Table
+            | Display::TableRow
+            | Display::TableRowGroup
+            | Display::TableHeaderGroup
+            | Display::TableCell
+    )
+}
+
+#[inline]
+fn sum<L: LengthNum>(values: &[L]) -> L {
+    values.iter().fold(L::zero(), |x, y| x + *y)
+}
+
+/// Add `amount` to `values` in proportion to `weights`, or equally if all weights are zero.
+pub(crate) fn spread<L: LengthNum>(values: &mut [L], weights: &[L], amount: L) {
+    if values.is_empty() {
+        return;
+    }
+    let total = sum(weights);
+    if total > L::zero() {
+        for (value, weight) in values.iter_mut().zip(weights) {
+            *value += amount * *weight / total;
+        }
+    } else {
+        let each = amount.div_i32(values.len() as i32);
+        for value in values.iter_mut() {
+            *value += each;
+        }
+    }
+}
+
+/// CSS 2.1 §17.5.2.2: distribute the available width to the columns in the automatic table layout.
+///
+/// The columns get their min-content widths if the width is not enough,
+/// then grow towards their max-content widths evenly.
+/// The remaining width is given to the columns without a specified width in proportion to their max-content widths.
+pub(crate) fn distribute_column_widths<L: LengthNum>(
+    min: &[L],
+    max: &[L],
+    specified: &[bool],
+    target: L,
+) -> Vec<L> {
+    let min_sum = sum(min);
+    let max_sum = sum(max);
+    if target <= min_sum {
+        return min.to_vec();
+    }
+    if target <= max_sum {
+        let ratio = (target - min_sum) / (max_sum - min_sum);
+        return min
+            .iter()
+            .zip(max)
+            .map(|(min, max)| *min + (*max - *min) * ratio)
+            .collect();
+    }
+    let mut widths = max.to_vec();
+    let weights: Vec<L> = if specified.iter().all(|x| *x) {
+        max.to_vec()
+    } else if max
+        .iter()
+        .zip(specified)
+        .any(|(max, specified)| !*specified && *max > L::zero())
+    {
+        max.iter()
+            .zip(specified)
+            .map(|(max, specified)| if *specified { L::zero() } else { *max })
+            .collect()
+    } else {
+        specified
+            .iter()
+            .map(|specified| if *specified { L::zero() } else { L::one() })
+            .collect()
+    };
+    spread(&mut widths, &weights, target - max_sum);
+    widths
+}
+
+/// CSS 2.1 §17.5.2.1: the column widths in the fixed table layout.
+///
+/// The columns without a specified width share the remaining width equally.
+/// If all columns have a specified width, the remaining width is spread in proportion to them.
+pub(crate) fn fixed_column_widths<L: LengthNum>(specified: &[Option<L>], available: L) -> Vec<L> {
+    let specified_sum = specified.iter().flatten().fold(L::zero(), |x, y| x + *y);
+    let auto_count = specified.iter().filter(|x| x.is_none()).count();
+    let remaining = available - specified_sum;
+    if auto_count > 0 {
+        let each = remaining.max(L::zero()).div_i32(auto_count as i32);
+        return specified.iter().map(|x| x.unwrap_or(each)).collect();
+    }
+    let mut widths: Vec<L> = specified.iter().flatten().copied().collect();
+    if remaining > L::zero() {
+        let weights = widths.clone();
+        spread(&mut widths, &weights, remaining);
+    }
+    widths
+}
+
+/// CSS 2.1 §17.6.2: the spacing between the grid lines in the collapsing border model.
+///
+/// Adjacent borders overlap, so the spacing is negative.
+/// `edges` contains the start line, the span, the start border and the end border of each cell.
+/// The outer borders of the cells also overlap with the table border.
+pub(crate) fn collapsed_gaps<L: LengthNum>(
+    count: usize,
+    edges: impl Iterator<Item = (usize, usize, L, L)>,
+    outer_start: L,
+    outer_end: L,
+) -> Vec<L> {
+    if count == 0 {
+        return vec![];
+    }
+    let mut start_borders = vec![L::zero(); count + 1];
+    let mut end_borders = vec![L::zero(); count + 1];
+    for (start, span, start_border, end_border) in edges {
+        start_borders[start] = start_borders[start].max(start_border);
+        end_borders[start + span] = end_borders[start + span].max(end_border);
+    }
+    (0..=count)
+        .map(|line| {
+            let overlap = if line == 0 {
+                outer_start.min(start_borders[line])
+            } else if line == count {
+                outer_end.min(end_borders[line])
+            } else {
+                start_borders[line].min(end_borders[line])
+            };
+            -overlap
+        })
+        .collect()
+}
+
+enum CellBox<'a, T: LayoutTreeNode> {
+    Cell(&'a T),
+    /// CSS 2.1 §17.2.1: consecutive non-cell children of a row are wrapped in an anonymous cell.
+    Anonymous(Vec<&'a T>),
+}
+
+struct TableCell<'a, T: LayoutTreeNode> {
+    content: CellBox<'a, T>,
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+    border: Edge<T::Length>,
+}
+
+struct TableRow<'a, T: LayoutTreeNode> {
+    /// `None` for an anonymous row.
+    node: Option<&'a T>,
+    group: Option<usize>,
+}
+
+struct TableRowGroup<'a, T: LayoutTreeNode> {
+    node: &'a T,
+    rows: Range<usize>,
+}
+
+struct TableColumn<'a, T: LayoutTreeNode> {
+    node: &'a T,
+    start: usize,
+    span: usize,
+}
+
+/// The table grid, built from the box tree.
+struct TableStructure<'a, T: LayoutTreeNode> {
+    captions: Vec<&'a T>,
+    columns: Vec<TableColumn<'a, T>>,
+    groups: Vec<TableRowGroup<'a, T>>,
+    rows: Vec<TableRow<'a, T>>,
+    cells: Vec<TableCell<'a, T>>,
+    column_count: usize,
+}
+
+#[inline]
+fn in_flow_children<T: LayoutTreeNode>(node: &T) -> Vec<&T> {
+    node.tree_visitor()
+        .layout_children()
+        .into_iter()
+        .filter(|child_node| {
+            let style = child_node.style();
+            !is_display_none::<T>(style) && !is_out_of_flow::<T>(style)
+        })
+        .collect()
+}
+
+fn cell_boxes<'a, T: LayoutTreeNode>(nodes: Vec<&'a T>) -> Vec<CellBox<'a, T>> {
+    let mut ret = vec![];
+    let mut anonymous = vec![];
+    for node in nodes {
+        if node.style().display() == Display::TableCell {
+            if !anonymous.is_empty() {
+                ret.push(CellBox::Anonymous(core::mem::take(&mut anonymous)));
+            }
+            ret.push(CellBox::Cell(node));
+        } else {
+            anonymous.push(node);
+        }
+    }
+    if !anonymous.is_empty() {
+        ret.push(CellBox::Anonymous(anonymous));
+    }
+    ret
+}
+
+impl<'a, T: LayoutTreeNode> TableStructure<'a, T> {
+    fn empty() -> Self {
+        Self {
+            captions: vec![],
+            columns: vec![],
+            groups: vec![],
+            rows: vec![],
+            cells: vec![],
+            column_count: 0,
+        }
+    }
+
+    fn new(node: &'a T) -> Self {
+        let mut s = Self::empty();
+        let mut row_boxes = vec![];
+        if is_anonymous_table_root(node) {
+            match node.style().display() {
+                Display::TableRow => {
+                    s.rows.push(TableRow {
+                        node: None,
+                        group: None,
+                    });
+                    row_boxes.push(cell_boxes(in_flow_children(node)));
+                }
+                _ => s.push_rows(in_flow_children(node), None, &mut row_boxes),
+            }
+        } else {
+            let mut header_groups = vec![];
+            let mut body = vec![];
+            for child_node in in_flow_children(node) {
+                match child_node.style().display() {
+                    Display::TableCaption => s.captions.push(child_node),
+                    Display::TableColumn => {
+                        let span = child_node.style().colspan().max(1) as usize;
+                        let start = s
+                            .columns
+                            .last()
+                            .map(|x| x.start.saturating_add(x.span))
+                            .unwrap_or(0);
+                        s.columns.push(TableColumn {
+                            node: child_node,
+                            start,
+                            span,
+                        });
+                    }
+                    Display::TableHeaderGroup => header_groups.push(child_node),
+                    _ => body.push(child_node),
+                }
+            }
+            s.push_body(header_groups, body, &mut row_boxes);
+        }
+        s.assign_slots(row_boxes);
+        s
+    }
+
+    /// The anonymous table which wraps a sequence of misparented table parts (CSS 2.1 §17.2.1).
+    fn new_anonymous(nodes: &[&'a T]) -> Self {
+        let mut s = Self::empty();
+        let mut row_boxes = vec![];
+        let (header_groups, body) = nodes
+            .iter()
+            .copied()
+            .partition(|node| node.style().display() == Display::TableHeaderGroup);
+        s.push_body(header_groups, body, &mut row_boxes);
+        s.assign_slots(row_boxes);
+        s
+    }
+
+    /// Push the row groups and the rows, while consecutive rows and cells outside row groups
+    /// are treated as anonymous row groups.
+    ///
+    /// CSS 2.1 §17.2: the header groups are placed before the other rows.
+    fn push_body(
+        &mut self,
+        header_groups: Vec<&'a T>,
+        body: Vec<&'a T>,
+        row_boxes: &mut Vec<Vec<CellBox<'a, T>>>,
+    ) {
+        for group_node in header_groups {
+            self.push_group(group_node, row_boxes);
+        }
+        let mut loose = vec![];
+        for child_node in body {
+            match child_node.style().display() {
+                Display::TableRowGroup => {
+                    self.push_rows(core::mem::take(&mut loose), None, row_boxes);
+                    self.push_group(child_node, row_boxes);
+                }
+                _ => loose.push(child_node),
+            }
+        }
+        self.push_rows(loose, None, row_boxes);
+    }
+
+    fn push_group(&mut self, group_node: &'a T, row_boxes: &mut Vec<Vec<CellBox<'a, T>>>) {
+        let group = self.groups.len();
+        let start = self.rows.len();
+        self.groups.push(TableRowGroup {
+            node: group_node,
+            rows: start..start,
+        });
+        self.push_rows(in_flow_children(group_node), Some(group), row_boxes);
+        self.groups[group].rows.end = self.rows.len();
+    }
+
+    /// Push the rows, while consecutive non-row nodes are wrapped in an anonymous row.
+    fn push_rows(
+        &mut self,
+        nodes: Vec<&'a T>,
+        group: Option<usize>,
+        row_boxes: &mut Vec<Vec<CellBox<'a, T>>>,
+    ) {
+        let mut loose = vec![];
+        for node in nodes {
+            if node.style().display() == Display::TableRow {
+                if !loose.is_empty() {
+                    self.rows.push(TableRow { node: None, group });
+                    row_boxes.push(cell_boxes(core::mem::take(&mut loose)));
+                }
+                self.rows.push(TableRow {
+                    node: Some(node),
+                    group,
+                });
+                row_boxes.push(cell_boxes(in_flow_children(node)));
+            } else {
+                loose.push(node);
+            }
+        }
+        if !loose.is_empty() {
+            self.rows.push(TableRow { node: None, group });
+            row_boxes.push(cell_boxes(loose));
+        }
+    }
+
+    /// CSS Tables 3 §3.3: assign the cells to the slots of the table grid.
+    ///
+    /// A row span is clipped to the end of the row group,
+    /// while consecutive rows outside row groups are treated as a row group.
+    /// The spans come from the host, so the slot arithmetic saturates instead of overflowing.
+    fn assign_slots(&mut self, row_boxes: Vec<Vec<CellBox<'a, T>>>) {
+        let row_count = self.rows.len();
+        let mut segment_end = vec![row_count; row_count];
+        for row in (0..row_count.saturating_sub(1)).rev() {
+            segment_end[row] = if self.rows[row].group == self.rows[row + 1].group {
+                segment_end[row + 1]
+            } else {
+                row + 1
+            };
+        }
+        let mut occupied: Vec<Vec<bool>> = vec![vec![]; row_count];
+        let mut column_count = self
+            .columns
+            .last()
+            .map(|x| x.start.saturating_add(x.span))
+            .unwrap_or(0);
+        for (row, boxes) in row_boxes.into_iter().enumerate() {
+            let mut column = 0;
+            for content in boxes {
+                while occupied[row].get(column).copied().unwrap_or(false) {
+                    column = column.saturating_add(1);
+                }
+                let (column_span, row_span) = match &content {
+                    CellBox::Cell(node) => {
+                        let style = node.style();
+                        (style.colspan().max(1) as usize, style.rowspan() as usize)
+                    }
+                    CellBox::Anonymous(_) => (1, 1),
+                };
+                let max_row_span = segment_end[row] - row;
+                let row_span = if row_span == 0 {
+                    max_row_span
+                } else {
+                    row_span.min(max_row_span)
+                };
+                let column_end = column.saturating_add(column_span);
+                let column_span = column_end - column;
+                for slots in occupied[row..row + row_span].iter_mut() {
+                    if slots.len() < column_end {
+                        slots.resize(column_end, false);
+                    }
+                    for slot in slots[column..column_end].iter_mut() {
+                        *slot = true;
+                    }
+                }
+                self.cells.push(TableCell {
+                    content,
+                    row,
+                    column,
+                    row_span,
+                    column_span,
+                    border: Edge {
+                        left: T::Length::zero(),
+                        right: T::Length::zero(),
+                        top: T::Length::zero(),
+                        bottom: T::Length::zero(),
+                    },
+                });
+                column = column_end;
+                column_count = column_count.max(column);
+            }
+        }
+        self.column_count = column_count;
+    }
+}
+
+/// The sum of the track sizes and the gaps between them in `range`.
+#[inline]
+fn span_size<L: LengthNum>(sizes: &[L], gaps: &[L], range: Range<usize>) -> L {
+    sum(&sizes[range.clone()]) + sum(&gaps[(range.start + 1)..range.end])
+}
+
+/// The specified width of a cell or a column, or `None` if it is `auto` or a percentage.
+#[inline]
+fn specified_width<T: LayoutTreeNode>(node: &T) -> OptionNum<T::Length> {
+    let unit = node.layout_node().unit();
+    let (_, border, padding_border) =
+        unit.margin_border_padding(node, OptionSize::new(OptionNum::none(), OptionNum::none()));
+    unit.css_border_box_size(
+        node,
+        OptionSize::new(OptionNum::none(), OptionNum::none()),
+        border,
+        padding_border,
+    )
+    .width
+}
+
+/// The min-content or max-content border box width of a node.
+#[inline]
+fn intrinsic_width<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &T,
+    sizing_mode: SizingMode,
+) -> T::Length {
+    let unconstrained = OptionSize::new(OptionNum::none(), OptionNum::none());
+    node.layout_node()
+        .unit()
+        .compute_internal(
+            env,
+            node,
+            ComputeRequest {
+                size: Normalized(unconstrained),
+                parent_inner_size: Normalized(unconstrained),
+                max_content: Normalized(unconstrained),
+                kind: ComputeRequestKind::AllSize,
+                parent_is_block: false,
+                sizing_mode,
+                line_clamp: None,
+            },
+        )
+        .size
+        .width
+}
+
+/// The min-content or max-content border box width of a block-level box inside a table,
+/// which is its specified width if provided.
+#[inline]
+fn block_intrinsic_width<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &T,
+    sizing_mode: SizingMode,
+) -> T::Length {
+    specified_width(node)
+        .val()
+        .unwrap_or_else(|| intrinsic_width(env, node, sizing_mode))
+}
+
+/// The min-content width and the max-content width of a cell, and whether it has a specified width.
+fn cell_intrinsic_widths<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    cell: &TableCell<T>,
+) -> (T::Length, T::Length, bool) {
+    match &cell.content {
+        CellBox::Cell(node) => {
+            let min = intrinsic_width(env, *node, SizingMode::MinContent);
+            match specified_width(*node).val() {
+                Some(width) => {
+                    let width = width.max(min);
+                    (width, width, true)
+                }
+                None => {
+                    let max = intrinsic_width(env, *node, SizingMode::MaxContent);
+                    (min, max.max(min), false)
+                }
+            }
+        }
+        CellBox::Anonymous(nodes) => {
+            let mut min = T::Length::zero();
+            let mut max = T::Length::zero();
+            for node in nodes {
+                let (margin, _, _) = node.layout_node().unit().margin_border_padding(
+                    *node,
+                    OptionSize::new(OptionNum::none(), OptionNum::none()),
+                );
+                let margin = margin.or_zero().horizontal();
+                min = min.max(block_intrinsic_width(env, *node, SizingMode::MinContent) + margin);
+                max = max.max(block_intrinsic_width(env, *node, SizingMode::MaxContent) + margin);
+            }
+            (min, max.max(min), false)
+        }
+    }
+}
+
+/// Lay out a cell with the given border box size, and returns its border box height.
+///
+/// If `origin` is provided, the cell is positioned at it,
+/// which is relative to the layout parent of the cell.
+#[allow(clippy::too_many_arguments)]
+fn compute_cell<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    cell: &TableCell<T>,
+    width: T::Length,
+    height: OptionNum<T::Length>,
+    parent_inner_size: OptionSize<T::Length>,
+    request: &ComputeRequest<T::Length>,
+    origin: Option<Point<T::Length>>,
+) -> T::Length {
+    let kind = if origin.is_some() {
+        ComputeRequestKind::Position
+    } else {
+        ComputeRequestKind::AllSize
+    };
+    match &cell.content {
+        CellBox::Cell(node) => {
+            let mut unit = node.layout_node().unit();
+            let (_, border, padding_border) = unit.margin_border_padding(*node, parent_inner_size);
+            let css_height = unit
+                .css_border_box_size(*node, parent_inner_size, border, padding_border)
+                .height;
+            let res = unit.compute_internal(
+                env,
+                *node,
+                ComputeRequest {
+                    size: Normalized(OptionSize::new(OptionNum::some(width), height)),
+                    parent_inner_size: Normalized(parent_inner_size),
+                    max_content: Normalized(OptionSize::new(
+                        OptionNum::some(width),
+                        OptionNum::none(),
+                    )),
+                    kind,
+                    parent_is_block: false,
+                    sizing_mode: request.sizing_mode,
+                    line_clamp: None,
+                },
+            );
+            if let Some(origin) = origin {
+                unit.result.origin = origin;
+            }
+            res.size.height.max(css_height.or_zero())
+        }
+        CellBox::Anonymous(nodes) => {
+            let mut offset = T::Length::zero();
+            for node in nodes {
+                let (res, margin) = compute_block_child(
+                    env,
+                    *node,
+                    OptionSize::new(OptionNum::some(width), OptionNum::none()),
+                    OptionNum::some(width),
+                    &ComputeRequest {
+                        kind,
+                        ..request.clone()
+                    },
+                );
+                if let Some(origin) = origin {
+                    node.layout_node().unit().result.origin =
+                        origin + Vector::new(margin.left, offset + margin.top);
+                }
+                offset += margin.vertical() + res.size.height;
+            }
+            offset
+        }
+    }
+}
+
+/// Save the results of a row, a row group, or a column, which are not laid out by `compute_internal`.
+fn save_track_result<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &T,
+    rect: Rect<T::Length>,
+    parent_inner_size: OptionSize<T::Length>,
+    layout_algorithm: LayoutAlgorithm,
+) {
+    let mut unit = node.layout_node().unit();
+    unit.result = rect;
+    unit.cache.touch(node);
+    unit.save_all_results(node, env, parent_inner_size, layout_algorithm);
+    let (_, border, padding_border) = unit.margin_border_padding(node, parent_inner_size);
+    drop(unit);
+    compute_special_position_children(
+        env,
+        node,
+        &ComputeResult {
+            size: Normalized(rect.size),
+            first_baseline_ascent: rect.size.to_vector(),
+            last_baseline_ascent: rect.size.to_vector(),
+            collapsed_margin: CollapsedBlockMargin::zero(),
+            line_count: 0,
+        },
+        border,
+        padding_border,
+        AxisInfo::from_writing_mode(node.style().writing_mode()),
+        false,
+    );
+}
+
+/// The table box properties used by the table layout.
+///
+/// An anonymous table (CSS 2.1 §17.2.1) has the initial values of the properties.
+#[derive(Clone, Copy)]
+struct TableBox<L: LengthNum> {
+    border: Edge<L>,
+    padding_border: Edge<L>,
+    /// The `border-spacing`, or `None` in the collapsing border model.
+    border_spacing: Option<Size<L>>,
+    fixed_layout: bool,
+    min_max_limit: MinMaxLimit<L>,
+}
+
+/// Lay out the table grid, and returns the border box size of the table.
+///
+/// The table parts are positioned in a position request,
+/// and `offset` is the position of the table in the layout parent of its direct children,
+/// which is zero unless it is an anonymous table.
+fn layout_table<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    table: &mut TableStructure<T>,
+    table_box: &TableBox<T::Length>,
+    request: &ComputeRequest<T::Length>,
+    offset: Vector<T::Length>,
+) -> Normalized<Size<T::Length>> {
+    let node_size = request.size.0;
+    let TableBox {
+        border,
+        padding_border,
+        ..
+    } = *table_box;
+    let column_count = table.column_count;
+    let row_count = table.rows.len();
+    let percentage_base = OptionSize::new(
+        node_size.width - padding_border.horizontal(),
+        node_size.height - padding_border.vertical(),
+    );
+    for cell in table.cells.iter_mut() {
+        if let CellBox::Cell(cell_node) = cell.content {
+            // the cells are not the layout children of the table, so they are prepared here
+            let mut unit = cell_node.layout_node().unit();
+            unit.prepare_intrinsic_sizes(env, cell_node);
+            cell.border = unit.margin_border_padding(cell_node, percentage_base).1;
+        }
+    }
+
+    // CSS 2.1 §17.6: the spacing between the cells, and the edges of the table grid
+    let (grid_edge, column_gaps, row_gaps) = match table_box.border_spacing {
+        None => {
+            let column_gaps = collapsed_gaps(
+                column_count,
+                table
+                    .cells
+                    .iter()
+                    .map(|x| (x.column, x.column_span, x.border.left, x.border.right)),
+                border.left,
+                border.right,
+            );
+            let row_gaps = collapsed_gaps(
+                row_count,
+                table
+                    .cells
+                    .iter()
+                    .map(|x| (x.row, x.row_span, x.border.top, x.border.bottom)),
+                border.top,
+                border.bottom,
+            );
+            (border, column_gaps, row_gaps)
+        }
+        Some(border_spacing) => {
+            let spacing = |spacing: T::Length, count: usize| {
+                if count == 0 {
+                    vec![]
+                } else {
+                    vec![spacing; count + 1]
+                }
+            };
+            (
+                padding_border,
+                spacing(border_spacing.width, column_count),
+                spacing(border_spacing.height, row_count),
+            )
+        }
+    };
+    let extra_width = grid_edge.horizontal() + sum(&column_gaps);
+
+    // CSS 2.1 §17.5.2: determine the column widths
+    let fixed_layout = table_box.fixed_layout
+        && node_size.width.is_some()
+        && request.sizing_mode == SizingMode::Normal;
+    let (table_width, column_widths) = if fixed_layout {
+        let table_width = node_size.width.or_zero();
+        let mut specified = vec![None; column_count];
+        for column in table.columns.iter() {
+            if let Some(width) = specified_width(column.node).val() {
+                let width = width.div_i32(column.span as i32);
+                for x in specified[column.start..column.start + column.span].iter_mut() {
+                    *x = Some(width);
+                }
+            }
+        }
+        for cell in table.cells.iter().filter(|x| x.row == 0) {
+            let CellBox::Cell(cell_node) = cell.content else {
+                continue;
+            };
+            let range = cell.column..cell.column + cell.column_span;
+            if specified[range.clone()].iter().any(|x| x.is_some()) {
+                continue;
+            }
+            if let Some(width) = specified_width(cell_node).val() {
+                let inner_gaps = sum(&column_gaps[(range.start + 1)..range.end]);
+                let width = (width - inner_gaps).div_i32(cell.column_span as i32);
+                for x in specified[range].iter_mut() {
+                    *x = Some(width);
+                }
+            }
+        }
+        let column_widths = fixed_column_widths(&specified, table_width - extra_width);
+        (
+            table_width.max(sum(&column_widths) + extra_width),
+            column_widths,
+        )
+    } else {
+        let mut column_min = vec![T::Length::zero(); column_count];
+        let mut column_max = vec![T::Length::zero(); column_count];
+        let mut column_specified = vec![false; column_count];
+        let mut spanning_cells = vec![];
+        for cell in table.cells.iter() {
+            let (min, max, specified) = cell_intrinsic_widths(env, cell);
+            if cell.column_span == 1 {
+                let c = cell.column;
+                column_min[c] = column_min[c].max(min);
+                column_max[c] = column_max[c].max(max);
+                column_specified[c] |= specified;
+            } else {
+                spanning_cells.push((cell.column..cell.column + cell.column_span, min, max));
+            }
+        }
+        for column in table.columns.iter() {
+            if let Some(width) = specified_width(column.node).val() {
+                let width = width.div_i32(column.span as i32);
+                for c in column.start..column.start + column.span {
+                    column_min[c] = column_min[c].max(width);
+                    column_max[c] = column_min[c];
+                    column_specified[c] = true;
+                }
+            }
+        }
+        // CSS 2.1 §17.5.2.2: the cells spanning several columns widen the spanned columns
+        spanning_cells.sort_by_key(|(range, _, _)| range.len());
+        for (range, min, max) in spanning_cells {
+            let weights = column_max[range.clone()].to_vec();
+            let min_sum = span_size(&column_min, &column_gaps, range.clone());
+            if min > min_sum {
+                spread(&mut column_min[range.clone()], &weights, min - min_sum);
+            }
+            let max_sum = span_size(&column_max, &column_gaps, range.clone());
+            if max > max_sum {
+                spread(&mut column_max[range], &weights, max - max_sum);
+            }
+        }
+        for (max, min) in column_max.iter_mut().zip(column_min.iter()) {
+            *max = max.max(*min);
+        }
+
+        let caption_min = table.captions.iter().fold(T::Length::zero(), |x, caption| {
+            let (margin, _, _) = caption.layout_node().unit().margin_border_padding(
+                *caption,
+                OptionSize::new(OptionNum::none(), OptionNum::none()),
+            );
+            x.max(
+                block_intrinsic_width(env, *caption, SizingMode::MinContent)
+                    + margin.or_zero().horizontal(),
+            )
+        });
+        let min_table = (sum(&column_min) + extra_width)
+            .max(caption_min + grid_edge.horizontal())
+            .max(padding_border.horizontal());
+        let max_table = (sum(&column_max) + extra_width).max(min_table);
+        let table_width = match node_size.width.val() {
+            Some(width) => width.max(min_table),
+            None => {
+                let width = match request.sizing_mode {
+                    SizingMode::MinContent => min_table,
+                    SizingMode::MaxContent => max_table,
+                    SizingMode::Normal => match request.max_content.width.val() {
+                        Some(available) => max_table.min(available),
+                        None => max_table,
+                    },
+                };
+                table_box.min_max_limit.width(width).max(min_table)
+            }
+        };
+        let column_widths = distribute_column_widths(
+            &column_min,
+            &column_max,
+            &column_specified,
+            table_width - extra_width,
+        );
+        (table_width, column_widths)
+    };
+
+    let is_position = request.kind == ComputeRequestKind::Position;
+    let inner_size = OptionSize::new(
+        OptionNum::some(table_width - padding_border.horizontal()),
+        node_size.height - padding_border.vertical(),
+    );
+
+    // the captions are stacked at the top
+    let caption_width = table_width - grid_edge.horizontal();
+    let mut captions_height = T::Length::zero();
+    for caption in table.captions.iter() {
+        let (res, caption_margin) = compute_block_child(
+            env,
+            *caption,
+            OptionSize::new(OptionNum::some(caption_width), OptionNum::none()),
+            OptionNum::some(caption_width),
+            request,
+        );
+        if is_position {
+            caption.layout_node().unit().result.origin = Point::new(
+                grid_edge.left + caption_margin.left,
+                grid_edge.top + captions_height + caption_margin.top,
+            ) + offset;
+        }
+        captions_height += caption_margin.vertical() + res.size.height;
+    }
+
+    // CSS 2.1 §17.5.3: determine the row heights
+    let cell_width = |cell: &TableCell<T>| {
+        span_size(
+            &column_widths,
+            &column_gaps,
+            cell.column..cell.column + cell.column_span,
+        )
+    };
+    let mut row_heights: Vec<T::Length> = table
+        .rows
+        .iter()
+        .map(|row| match row.node {
+            Some(row_node) => {
+                let unit = row_node.layout_node().unit();
+                let (_, border, padding_border) = unit.margin_border_padding(row_node, inner_size);
+                unit.css_border_box_size(row_node, inner_size, border, padding_border)
+                    .height
+                    .or_zero()
+            }
+            None => T::Length::zero(),
+        })
+        .collect();
+    let mut spanning_cells = vec![];
+    for cell in table.cells.iter() {
+        let height = compute_cell(
+            env,
+            cell,
+            cell_width(cell),
+            OptionNum::none(),
+            inner_size,
+            request,
+            None,
+        );
+        if cell.row_span == 1 {
+            row_heights[cell.row] = row_heights[cell.row].max(height);
+        } else {
+            spanning_cells.push((cell.row..cell.row + cell.row_span, height));
+        }
+    }
+    spanning_cells.sort_by_key(|(range, _)| range.len());
+    for (range, height) in spanning_cells {
+        let rows_height = span_size(&row_heights, &row_gaps, range.clone());
+        if height > rows_height {
+            let weights = vec![T::Length::zero(); range.len()];
+            spread(&mut row_heights[range], &weights, height - rows_height);
+        }
+    }
+    let content_height =
+        grid_edge.vertical() + captions_height + sum(&row_heights) + sum(&row_gaps);
+    if let Some(height) = node_size.height.val() {
+        if height > content_height {
+            let weights = row_heights.clone();
+            spread(&mut row_heights, &weights, height - content_height);
+        }
+    }
+
+    let size = Size::new(
+        table_width,
+        node_size
+            .height
+            .unwrap_or(content_height)
+            .max(content_height),
+    );
+    let size = Size::new(
+        table_box.min_max_limit.width(size.width),
+        table_box.min_max_limit.height(size.height),
+    );
+    if is_position {
+        let mut column_x = Vec::with_capacity(column_count);
+        let mut x = grid_edge.left;
+        for (width, gap) in column_widths.iter().zip(column_gaps.iter()) {
+            x += *gap;
+            column_x.push(x);
+            x += *width;
+        }
+        let mut row_y = Vec::with_capacity(row_count);
+        let mut y = grid_edge.top + captions_height;
+        for (height, gap) in row_heights.iter().zip(row_gaps.iter()) {
+            y += *gap;
+            row_y.push(y);
+            y += *height;
+        }
+        let grid_left = column_x.first().copied().unwrap_or(grid_edge.left);
+        let grid_width = match column_count {
+            0 => T::Length::zero(),
+            n => column_x[n - 1] + column_widths[n - 1] - grid_left,
+        };
+        let grid_top = row_y
+            .first()
+            .copied()
+            .unwrap_or(grid_edge.top + captions_height);
+        let grid_bottom = match row_count {
+            0 => grid_top,
+            n => row_y[n - 1] + row_heights[n - 1],
+        };
+        let rows_rect = |rows: Range<usize>| {
+            let top = row_y.get(rows.start).copied().unwrap_or(grid_bottom);
+            let bottom = if rows.is_empty() {
+                top
+            } else {
+                row_y[rows.end - 1] + row_heights[rows.end - 1]
+            };
+            Rect::new(
+                Point::new(grid_left, top),
+                Size::new(grid_width, bottom - top),
+            )
+        };
+        // the origins are relative to the layout parent, i.e. the row, the row group, or the table
+        let parent_origin = |row: usize| {
+            let row_info = &table.rows[row];
+            match (row_info.node, row_info.group) {
+                (Some(_), _) => rows_rect(row..row + 1).origin.to_vector(),
+                (None, Some(group)) => rows_rect(table.groups[group].rows.clone())
+                    .origin
+                    .to_vector(),
+                (None, None) => Vector::zero() - offset,
+            }
+        };
+
+        for cell in table.cells.iter() {
+            let rows = cell.row..cell.row + cell.row_span;
+            let height = span_size(&row_heights, &row_gaps, rows);
+            let origin =
+                Point::new(column_x[cell.column], row_y[cell.row]) - parent_origin(cell.row);
+            compute_cell(
+                env,
+                cell,
+                cell_width(cell),
+                OptionNum::some(height),
+                inner_size,
+                request,
+                Some(origin),
+            );
+        }
+        for (index, row) in table.rows.iter().enumerate() {
+            let Some(row_node) = row.node else {
+                continue;
+            };
+            let mut rect = rows_rect(index..index + 1);
+            if let Some(group) = row.group {
+                rect.origin -= rows_rect(table.groups[group].rows.clone())
+                    .origin
+                    .to_vector();
+            } else {
+                rect.origin += offset;
+            }
+            save_track_result(env, row_node, rect, inner_size, LayoutAlgorithm::TableRow);
+        }
+        for group in table.groups.iter() {
+            save_track_result(
+                env,
+                group.node,
+                rows_rect(group.rows.clone()).translate(offset),
+                inner_size,
+                LayoutAlgorithm::TableRow,
+            );
+        }
+        for column in table.columns.iter() {
+            let rect = if column.start < column_count {
+                let range = column.start..(column.start + column.span).min(column_count);
+                Rect::new(
+                    Point::new(column_x[column.start], grid_top),
+                    Size::new(
+                        span_size(&column_widths, &column_gaps, range),
+                        grid_bottom - grid_top,
+                    ),
+                )
+            } else {
+                Rect::new(Point::new(grid_left, grid_top), Size::zero())
+            }
+            .translate(offset);
+            save_track_result(
+                env,
+                column.node,
+                rect,
+                inner_size,
+                LayoutAlgorithm::TableColumn,
+            );
+        }
+    }
+
+    Normalized(size)
+}
+
+/// CSS 2.1 §17.2.1: lay out a sequence of consecutive misparented table parts in a block container
+/// as a single anonymous table, and returns its border box size.
+///
+/// If `origin` is provided, the table parts are positioned,
+/// and `origin` is the position of the anonymous table in the block container.
+pub(crate) fn compute_anonymous_table<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    nodes: &[&T],
+    parent_inner_size: OptionSize<T::Length>,
+    available_width: OptionNum<T::Length>,
+    sizing_mode: SizingMode,
+    origin: Option<Point<T::Length>>,
+) -> Size<T::Length> {
+    let table_box = TableBox {
+        border: Edge::zero(),
+        padding_border: Edge::zero(),
+        border_spacing: Some(Size::zero()),
+        fixed_layout: false,
+        min_max_limit: MinMaxLimit {
+            min_width: T::Length::zero(),
+            max_width: OptionNum::none(),
+            min_height: T::Length::zero(),
+            max_height: OptionNum::none(),
+        },
+    };
+    let request = ComputeRequest {
+        size: Normalized(OptionSize::new(OptionNum::none(), OptionNum::none())),
+        parent_inner_size: Normalized(parent_inner_size),
+        max_content: Normalized(OptionSize::new(available_width, OptionNum::none())),
+        kind: if origin.is_some() {
+            ComputeRequestKind::Position
+        } else {
+            ComputeRequestKind::AllSize
+        },
+        parent_is_block: true,
+        sizing_mode,
+        line_clamp: None,
+    };
+    let mut table = TableStructure::new_anonymous(nodes);
+    let offset = origin.unwrap_or(Point::zero()).to_vector();
+    layout_table(env, &mut table, &table_box, &request, offset).0
+}
+
+pub(crate) trait Table<T: LayoutTreeNode> {
+    fn compute(
+        &mut self,
+        env: &mut T::Env,
+        node: &T,
+        request: ComputeRequest<T::Length>,
+        margin: EdgeOption<T::Length>,
+        border: Edge<T::Length>,
+        padding_border: Edge<T::Length>,
+    ) -> ComputeResult<T::Length>;
+}
+
+impl<T: LayoutTreeNode> Table<T> for LayoutUnit<T> {
+    fn compute(
+        &mut self,
+        env: &mut T::Env,
+        node: &T,
+        request: ComputeRequest<T::Length>,
+        margin: EdgeOption<T::Length>,
+        border: Edge<T::Length>,
+        padding_border: Edge<T::Length>,
+    ) -> ComputeResult<T::Length> {
+        let style = node.style();
+        // a table establishes a BFC, so its margins never collapse with the content
+        let collapsed_margin =
+            CollapsedBlockMargin::from_margin(margin.top.or_zero(), margin.bottom.or_zero());
+        if let Some(x) = self.is_requested_size_fixed(&request, Some(collapsed_margin)) {
+            return x;
+        }
+
+        let table_box = TableBox {
+            border,
+            padding_border,
+            border_spacing: (style.border_collapse() != BorderCollapse::Collapse).then(|| {
+                Size::new(
+                    style
+                        .border_spacing_horizontal()
+                        .resolve(OptionNum::none(), node)
+                        .or_zero(),
+                    style
+                        .border_spacing_vertical()
+                        .resolve(OptionNum::none(), node)
+                        .or_zero(),
+                )
+            }),
+            fixed_layout: style.table_layout() == TableLayout::Fixed,
+            min_max_limit: self.normalized_min_max_limit(
+                node,
+                *request.parent_inner_size,
+                border,
+                padding_border,
+            ),
+        };
+        let mut table = TableStructure::new(node);
+        let size = layout_table(env, &mut table, &table_box, &request, Vector::zero());
+        let baseline_ascent = Vector::new(T::Length::zero(), size.height);
+        let ret = ComputeResult {
+            size,
+            first_baseline_ascent: baseline_ascent,
+            last_baseline_ascent: baseline_ascent,
+            collapsed_margin,
+            line_count: 0,
+        };
+
+        if request.kind == ComputeRequestKind::Position {
+            compute_special_position_children(
+                env,
+                node,
+                &ret,
+                border,
+                padding_border,
+                AxisInfo::from_writing_mode(style.writing_mode()),
+                false,
+            );
+            self.result = Rect::new(Point::zero(), ret.size.0);
+            self.cache.write_position(node, &request, ret);
+        } else {
+            self.cache.write_all_size(node, &request, ret);
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distribute_widths() {
+        let min = [10., 20., 30.];
+        let max = [20., 40., 60.];
+        let specified = [false, false, false];
+        assert_eq!(
+            distribute_column_widths(&min, &max, &specified, 50.),
+            vec![10., 20., 30.]
+        );
+        assert_eq!(
+            distribute_column_widths(&min, &max, &specified, 90.),
+            vec![15., 30., 45.]
+        );
+        assert_eq!(
+            distribute_column_widths(&min, &max, &specified, 240.),
+            vec![40., 80., 120.]
+        );
+        // the extra width goes to the columns without a specified width
+        assert_eq!(
+            distribute_column_widths(&min, &max, &[true, false, false], 220.),
+            vec![20., 80., 120.]
+        );
+        assert_eq!(
+            distribute_column_widths(&[0., 50.], &[0., 50.], &[false, true], 100.),
+            vec![50., 50.]
+        );
+    }
+
+    #[test]
+    fn fixed_widths() {
+        assert_eq!(
+            fixed_column_widths(&[Some(100.), None, None], 300.),
+            vec![100., 100., 100.]
+        );
+        assert_eq!(
+            fixed_column_widths(&[Some(200.), None], 100.),
+            vec![200., 0.]
+        );
+        assert_eq!(
+            fixed_column_widths(&[Some(50.), Some(150.)], 400.),
+            vec![100., 300.]
+        );
+    }
+
+    #[test]
+    fn collapsed_border_gaps() {
+        let gaps = collapsed_gaps(2, [(0, 1, 2., 2.), (1, 1, 4., 1.)].into_iter(), 3., 0.);
+        assert_eq!(gaps, vec![-2., -2., -0.]);
+        assert!(collapsed_gaps::<f32>(0, core::iter::empty(), 1., 1.).is_empty());
+    }
+}
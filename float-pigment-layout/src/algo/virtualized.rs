@@ -0,0 +1,266 @@
+//! Virtualized list layout.
+//!
+//! A block container or a `flex-direction: column` flex container can be virtualized by the host
+//! (see `LayoutNode::set_virtualization`), which is useful for very long lists.
+//! The in-flow children are stacked in the block axis, but only the children intersecting the viewport range
+//! are laid out. The others are placeholders with the estimated block size,
+//! which is corrected by the real size once the child has been laid out.
+//!
+//! Compared to the normal layout, the list is simplified:
+//! the margins of the children never collapse with each other or with the container,
+//! the flexible lengths and `justify-content` are not applied to the flex items,
+//! and the inline size of the container is not shrink-to-fit.
+//! Only the `horizontal-tb` writing mode is supported.
+
+use crate::*;
+use float_pigment_css::num_traits::Zero;
+
+/// The virtualization settings of a list container, which are provided by the host.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutVirtualization<L: LengthNum> {
+    /// The start of the viewport range in the block axis, relative to the content box of the container.
+    pub viewport_start: L,
+    /// The end of the viewport range in the block axis, relative to the content box of the container.
+    pub viewport_end: L,
+    /// The estimated block size of the children (margins included) which have never been laid out.
+    pub estimated_child_size: L,
+}
+
+/// The results of a virtualized list container.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutVirtualizedResult<L: LengthNum> {
+    /// The total block size of the children, in which the placeholders are estimated.
+    pub total_extent: L,
+    /// The layout child indices of the children which have real layout results.
+    ///
+    /// The children in the range which are not in-flow (e.g. `position: absolute`) are not virtualized.
+    pub laid_out: core::ops::Range<usize>,
+}
+
+/// Whether the node is laid out as a virtualized list.
+pub(crate) fn is_virtualized_container<T: LayoutTreeNode>(
+    style: &T::Style,
+    virtualization: Option<&LayoutVirtualization<T::Length>>,
+) -> bool {
+    if virtualization.is_none() || style.writing_mode() != WritingMode::HorizontalTb {
+        return false;
+    }
+    match style.display() {
+        Display::Block | Display::FlowRoot => !algo::multicol::is_multicol_container::<T>(style),
+        Display::Flex | Display::InlineFlex => style.flex_direction() == FlexDirection::Column,
+        _ => false,
+    }
+}
+
+/// Lay out a child of the list with the given inline size.
+///
+/// A flex item is not stretched if it is aligned otherwise.
+fn compute_list_child<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    child_node: &T,
+    inner_size: OptionSize<T::Length>,
+    stretch: bool,
+    is_flex: bool,
+    request: &ComputeRequest<T::Length>,
+) -> (ComputeResult<T::Length>, Edge<T::Length>) {
+    let mut child = child_node.layout_node().unit();
+    let (child_margin, child_border, child_padding_border) =
+        child.margin_border_padding(child_node, inner_size);
+    let min_max_limit =
+        child.normalized_min_max_limit(child_node, inner_size, child_border, child_padding_border);
+    let mut css_size =
+        child.css_border_box_size(child_node, inner_size, child_border, child_padding_border);
+    let available_width = inner_size.width - child_margin.horizontal();
+    if stretch {
+        css_size.width = css_size.width.or(available_width);
+    }
+    let res = child.compute_internal(
+        env,
+        child_node,
+        ComputeRequest {
+            size: min_max_limit.normalized_size(css_size),
+            parent_inner_size: Normalized(inner_size),
+            max_content: min_max_limit
+                .normalized_size(OptionSize::new(available_width, OptionNum::none())),
+            kind: request.kind.shift_to_all_size(),
+            parent_is_block: !is_flex,
+            sizing_mode: request.sizing_mode,
+            line_clamp: None,
+        },
+    );
+    (res, child_margin.or_zero())
+}
+
+pub(crate) trait Virtualized<T: LayoutTreeNode> {
+    fn compute(
+        &mut self,
+        env: &mut T::Env,
+        node: &T,
+        request: ComputeRequest<T::Length>,
+        margin: EdgeOption<T::Length>,
+        border: Edge<T::Length>,
+        padding_border: Edge<T::Length>,
+    ) -> ComputeResult<T::Length>;
+}
+
+impl<T: LayoutTreeNode> Virtualized<T> for LayoutUnit<T> {
+    fn compute(
+        &mut self,
+        env: &mut T::Env,
+        node: &T,
+        request: ComputeRequest<T::Length>,
+        margin: EdgeOption<T::Length>,
+        border: Edge<T::Length>,
+        padding_border: Edge<T::Length>,
+    ) -> ComputeResult<T::Length> {
+        let style = node.style();
+        // the margins of the children never collapse through the container
+        let collapsed_margin =
+            CollapsedBlockMargin::from_margin(margin.top.or_zero(), margin.bottom.or_zero());
+        if let Some(x) = self.is_requested_size_fixed(&request, Some(collapsed_margin)) {
+            return x;
+        }
+        let Some(virtualization) = self.virtualization else {
+            unreachable!();
+        };
+
+        let node_size = request.size.0;
+        let inner_width = (node_size.width - padding_border.horizontal())
+            .or(request.max_content.width - padding_border.horizontal())
+            .or_zero()
+            .max(T::Length::zero());
+        let inner_height = node_size.height - padding_border.vertical();
+        let inner_size = OptionSize::new(OptionNum::some(inner_width), inner_height);
+        let is_flex = matches!(style.display(), Display::Flex | Display::InlineFlex);
+        let gap = if is_flex {
+            algo::flex_box::resolve_row_gap(style, node, &Normalized(inner_size))
+        } else {
+            T::Length::zero()
+        };
+
+        let is_position = request.kind == ComputeRequestKind::Position;
+        let mut block_offset = T::Length::zero();
+        let mut has_prev = false;
+        let mut laid_out: Option<core::ops::Range<usize>> = None;
+        node.tree_visitor()
+            .for_each_layout_child(|child_node, index| {
+                let child_style = child_node.style();
+                if is_display_none::<T>(child_style) {
+                    child_node
+                        .layout_node()
+                        .unit()
+                        .clear_display_none_result(child_node);
+                    return;
+                }
+                if is_out_of_flow::<T>(child_style) {
+                    return;
+                }
+                if has_prev {
+                    block_offset += gap;
+                }
+                has_prev = true;
+                let estimated = child_node
+                    .layout_node()
+                    .unit()
+                    .virtualized_extent
+                    .unwrap_or(virtualization.estimated_child_size);
+                let visible = block_offset < virtualization.viewport_end
+                    && block_offset + estimated > virtualization.viewport_start;
+                if !visible {
+                    if is_position {
+                        let mut child = child_node.layout_node().unit();
+                        child.set_virtualized_placeholder(Rect::new(
+                            Point::new(padding_border.left, padding_border.top + block_offset),
+                            Size::new(inner_width, estimated),
+                        ));
+                    }
+                    block_offset += estimated;
+                    return;
+                }
+
+                let align_self = if is_flex {
+                    algo::flex_box::align_self::<T>(child_style, style)
+                } else {
+                    AlignSelf::Stretch
+                };
+                let stretch = matches!(align_self, AlignSelf::Stretch | AlignSelf::Normal);
+                let (res, child_margin) =
+                    compute_list_child(env, child_node, inner_size, stretch, is_flex, &request);
+                let (margin_start, margin_end) = if is_flex {
+                    (child_margin.top, child_margin.bottom)
+                } else {
+                    (
+                        res.collapsed_margin.start.solve(),
+                        res.collapsed_margin.end.solve(),
+                    )
+                };
+                let extent = margin_start + res.size.height + margin_end;
+                let mut child = child_node.layout_node().unit();
+                child.virtualized_extent = Some(extent);
+                if is_position {
+                    let free_space = inner_width - res.size.width - child_margin.horizontal();
+                    let cross_offset = match align_self {
+                        AlignSelf::Center => free_space.div_i32(2),
+                        AlignSelf::FlexEnd | AlignSelf::End => free_space,
+                        _ => T::Length::zero(),
+                    };
+                    child.result.origin = Point::new(
+                        padding_border.left + child_margin.left + cross_offset,
+                        padding_border.top + block_offset + margin_start,
+                    );
+                }
+                drop(child);
+                laid_out = Some(match laid_out.take() {
+                    Some(range) => range.start..(index + 1),
+                    None => index..(index + 1),
+                });
+                block_offset += extent;
+            });
+
+        let size = Size::new(
+            node_size
+                .width
+                .unwrap_or(inner_width + padding_border.horizontal()),
+            node_size
+                .height
+                .unwrap_or(block_offset + padding_border.vertical()),
+        );
+        let size = self.min_max_size_limit(
+            node,
+            *request.parent_inner_size,
+            size,
+            border,
+            padding_border,
+        );
+        let baseline_ascent = Vector::new(T::Length::zero(), size.height);
+        let ret = ComputeResult {
+            size,
+            first_baseline_ascent: baseline_ascent,
+            last_baseline_ascent: baseline_ascent,
+            collapsed_margin,
+            line_count: 0,
+        };
+
+        if is_position {
+            compute_special_position_children(
+                env,
+                node,
+                &ret,
+                border,
+                padding_border,
+                AxisInfo::from_writing_mode(style.writing_mode()),
+                is_flex,
+            );
+            self.result = Rect::new(Point::zero(), ret.size.0);
+            self.result_virtualized = Some(LayoutVirtualizedResult {
+                total_extent: block_offset,
+                laid_out: laid_out.unwrap_or(0..0),
+            });
+            self.cache.write_position(node, &request, ret);
+        } else {
+            self.cache.write_all_size(node, &request, ret);
+        }
+
+        ret
+    }
+}
@@ -44,16 +44,32 @@ impl<L: LengthNum> LayoutComputeCache<L> {
 
     pub(crate) fn calc_parent_size_affected(node: &impl LayoutTreeNode) -> bool {
         let style = node.style();
-        if let DefLength::Percent(_) = style.min_width() {
+        if let DefLength::Percent(_)
+        | DefLength::FitContent
+        | DefLength::FitContentPercent(_)
+        | DefLength::Stretch = style.min_width()
+        {
             return true;
         };
-        if let DefLength::Percent(_) = style.min_height() {
+        if let DefLength::Percent(_)
+        | DefLength::FitContent
+        | DefLength::FitContentPercent(_)
+        | DefLength::Stretch = style.min_height()
+        {
             return true;
         };
-        if let DefLength::Percent(_) = style.max_width() {
+        if let DefLength::Percent(_)
+        | DefLength::FitContent
+        | DefLength::FitContentPercent(_)
+        | DefLength::Stretch = style.max_width()
+        {
             return true;
         };
-        if let DefLength::Percent(_) = style.max_height() {
+        if let DefLength::Percent(_)
+        | DefLength::FitContent
+        | DefLength::FitContentPercent(_)
+        | DefLength::Stretch = style.max_height()
+        {
             return true;
         };
         if let DefLength::Percent(_) = style.margin_left() {
@@ -164,6 +180,41 @@ impl<L: LengthNum> LayoutComputeCache<L> {
         (size, max_content, p)
     }
 
+    /// Generate the key of a request as it is bucketed in the layout trace.
+    ///
+    /// Unlike `gen_key`, it does not touch the cache.
+    pub(crate) fn trace_key(
+        &self,
+        node: &impl LayoutTreeNode,
+        req: &ComputeRequest<L>,
+    ) -> LayoutCacheKey<L::Hashable> {
+        let parent_size_affected = if self.touched {
+            self.parent_size_affected
+        } else {
+            Self::calc_parent_size_affected(node)
+        };
+        let p = if parent_size_affected {
+            *req.parent_inner_size
+        } else {
+            OptionSize::new(OptionNum::none(), OptionNum::none())
+        };
+        let sizing_mode = if req.kind == ComputeRequestKind::Position {
+            None
+        } else {
+            Some(req.sizing_mode)
+        };
+        LayoutCacheKey {
+            kind: req.kind.into(),
+            size: Size::new(req.size.width.to_hashable(), req.size.height.to_hashable()),
+            max_content: Size::new(
+                req.max_content.width.to_hashable(),
+                req.max_content.height.to_hashable(),
+            ),
+            parent_inner_size: Size::new(p.width.to_hashable(), p.height.to_hashable()),
+            sizing_mode,
+        }
+    }
+
     pub(crate) fn write_all_size_inner(
         &mut self,
         node: &impl LayoutTreeNode<Length = L>,
@@ -183,7 +234,7 @@ impl<L: LengthNum> LayoutComputeCache<L> {
         req: &ComputeRequest<L>,
         result: ComputeResult<L>,
     ) {
-        if req.parent_is_block {
+        if req.parent_is_block || req.line_clamp.is_some() {
             // do not store cache if parent is common block
             return;
         }
@@ -223,6 +274,12 @@ impl<L: LengthNum> LayoutComputeCache<L> {
         req: &ComputeRequest<L>,
         result: ComputeResult<L>,
     ) {
+        if req.line_clamp.is_some() {
+            // the result depends on the lines remaining in the clamped ancestor
+            self.touch(node);
+            self.clear_position_cache();
+            return;
+        }
         if !req.parent_is_block {
             // do not store cache if parent is common block
             self.write_all_size(node, req, result);
@@ -238,7 +295,7 @@ impl<L: LengthNum> LayoutComputeCache<L> {
         node: &impl LayoutTreeNode<Length = L>,
         req: &ComputeRequest<L>,
     ) -> Option<ComputeResult<L>> {
-        if !self.touched {
+        if !self.touched || req.line_clamp.is_some() {
             return None;
         }
         if req.kind != ComputeRequestKind::Position {
@@ -0,0 +1,497 @@
+//! Fragmentation (pagination).
+//!
+//! CSS Fragmentation Module Level 3
+//! <https://www.w3.org/TR/css-break-3/>
+//!
+//! The tree is laid out continuously at first.
+//! Then the in-flow content of each block container is pushed across the fragmentainer boundaries
+//! by inserting pagination struts before it, and the block containers with `auto` height grow to hold the struts.
+//! At last, the border box of each node is sliced into the fragmentainers it lies in.
+//!
+//! The layout results are in a continuous coordinate of the root,
+//! in which the fragmentainer `i` covers the vertical range `i * block_size .. (i + 1) * block_size`.
+//!
+//! Some simplifications are made:
+//!
+//! * the block axis is always the vertical axis;
+//! * only block containers in flow layout and single-line column flex containers are broken inside,
+//!   while scroll containers, other flex containers, grid, table, and multi-column containers are monolithic;
+//! * the lines inside a measured inline node are broken only if they are reported by `LayoutTreeNode::measured_lines`;
+//! * margins adjoining a break are kept;
+//! * `left`, `right`, `recto`, and `verso` breaks are treated as `page` breaks,
+//!   and a forced break is applied inside the box rather than propagated to its ancestors;
+//! * floats and out-of-flow boxes are moved together with the in-flow content before them, but never broken inside.
+
+use crate::algo::float::is_float;
+use crate::*;
+use core::cmp::Ordering;
+use float_pigment_css::num_traits::Zero;
+
+/// A part of the border box of a node in a fragmentainer (e.g. a page).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutFragment<L: LengthNum> {
+    /// The index of the fragmentainer.
+    pub index: usize,
+    /// The part of the border rect in the fragmentainer, relative to the fragmentainer origin.
+    pub rect: Rect<L>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Fragmentainer<L: LengthNum> {
+    block_size: L,
+}
+
+impl<L: LengthNum> Fragmentainer<L> {
+    /// The index of the fragmentainer containing `offset`; a boundary belongs to the later one.
+    fn index_of(&self, offset: L) -> usize {
+        if offset <= L::zero() {
+            return 0;
+        }
+        let mut index = (offset.to_f32() / self.block_size.to_f32()) as usize;
+        while index > 0 && self.start_of(index) > offset {
+            index -= 1;
+        }
+        while self.start_of(index + 1) <= offset {
+            index += 1;
+        }
+        index
+    }
+
+    fn start_of(&self, index: usize) -> L {
+        self.block_size.mul_i32(index as i32)
+    }
+
+    fn is_at_start(&self, offset: L) -> bool {
+        self.start_of(self.index_of(offset)) == offset
+    }
+
+    /// Whether the range `start..end` crosses a fragmentainer boundary.
+    fn is_broken(&self, start: L, end: L) -> bool {
+        end > self.start_of(self.index_of(start) + 1)
+    }
+
+    /// The strut needed to move `offset` to the next fragmentainer, or zero if it is already at a boundary.
+    fn strut_before(&self, offset: L) -> L {
+        if self.is_at_start(offset) {
+            L::zero()
+        } else {
+            self.start_of(self.index_of(offset) + 1) - offset
+        }
+    }
+}
+
+/// The struts inserted in a block container, recorded at their offsets in the unfragmented container.
+struct Struts<L: LengthNum> {
+    list: Vec<(L, L)>,
+    total: L,
+}
+
+impl<L: LengthNum> Struts<L> {
+    fn new() -> Self {
+        Self {
+            list: Vec::new(),
+            total: L::zero(),
+        }
+    }
+
+    fn push(&mut self, offset: L, amount: L) {
+        if amount > L::zero() {
+            self.list.push((offset, amount));
+            self.total += amount;
+        }
+    }
+
+    /// The total amount of the struts inserted before the unfragmented `offset`.
+    fn at(&self, offset: L) -> L {
+        let mut ret = L::zero();
+        for (pos, amount) in self.list.iter() {
+            if *pos <= offset {
+                ret += *amount;
+            }
+        }
+        ret
+    }
+}
+
+/// The in-flow content of a block container, with vertical ranges in the unfragmented container.
+enum FlowItem<'a, T: LayoutTreeNode> {
+    Block(&'a T, T::Length, T::Length),
+    Atom(T::Length, T::Length),
+}
+
+fn collect_flow_items<'a, T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &'a T,
+    offset: T::Length,
+    items: &mut Vec<FlowItem<'a, T>>,
+) {
+    // the items of a flex container are blockified
+    let is_flex = node.layout_node().unit().layout_algorithm == LayoutAlgorithm::Flex;
+    node.tree_visitor().for_each_layout_child(|child, _| {
+        let style = child.style();
+        if is_display_none::<T>(style) || is_out_of_flow::<T>(style) || is_float::<T>(style) {
+            return;
+        }
+        let rect = child.layout_node().unit().result;
+        let top = offset + rect.origin.y;
+        let bottom = top + rect.size.height;
+        match style.display() {
+            _ if is_flex => items.push(FlowItem::Block(child, top, bottom)),
+            Display::Inline if !child.should_measure(env) => {
+                collect_flow_items(env, child, top, items)
+            }
+            Display::Inline => {
+                let lines = child.measured_lines(env);
+                if lines.is_empty() {
+                    items.push(FlowItem::Atom(top, bottom));
+                }
+                for (line_top, line_bottom) in lines {
+                    items.push(FlowItem::Atom(top + line_top, top + line_bottom));
+                }
+            }
+            Display::InlineBlock | Display::InlineFlex | Display::InlineGrid => {
+                items.push(FlowItem::Atom(top, bottom))
+            }
+            _ => items.push(FlowItem::Block(child, top, bottom)),
+        }
+    });
+}
+
+fn is_forced_break(value: Break) -> bool {
+    matches!(
+        value,
+        Break::Page | Break::Left | Break::Right | Break::Recto | Break::Verso
+    )
+}
+
+fn is_avoid_break(value: Break) -> bool {
+    matches!(value, Break::Avoid | Break::AvoidPage)
+}
+
+fn is_avoid_break_inside(value: BreakInside) -> bool {
+    matches!(value, BreakInside::Avoid | BreakInside::AvoidPage)
+}
+
+/// Whether the in-flow content of the node is stacked in the block axis, so it can be broken between.
+fn is_breakable_container<T: LayoutTreeNode>(node: &T) -> bool {
+    let style = node.style();
+    match node.layout_node().unit().layout_algorithm {
+        LayoutAlgorithm::Block => true,
+        // the items of a single-line column flex container are stacked like the blocks
+        LayoutAlgorithm::Flex => {
+            style.flex_direction() == FlexDirection::Column && style.flex_wrap() == FlexWrap::NoWrap
+        }
+        _ => false,
+    }
+}
+
+fn is_monolithic<T: LayoutTreeNode>(node: &T) -> bool {
+    node.style().overflow_y() != Overflow::Visible || !is_breakable_container(node)
+}
+
+/// Break the lines of an inline formatting context, honoring `orphans` and `widows`.
+#[allow(clippy::too_many_arguments)]
+fn fragment_lines<L: LengthNum>(
+    atoms: &[(L, L)],
+    block_start: L,
+    struts: &mut Struts<L>,
+    fc: Fragmentainer<L>,
+    orphans: usize,
+    widows: usize,
+    forced_break_before: bool,
+) {
+    // atoms with overlapping vertical ranges are in the same line
+    let mut atoms = atoms.to_vec();
+    atoms.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    let mut lines: Vec<(L, L)> = Vec::with_capacity(atoms.len());
+    for (top, bottom) in atoms {
+        match lines.last_mut() {
+            Some(last) if top < last.1 => last.1 = last.1.lower_bound(bottom),
+            _ => lines.push((top, bottom)),
+        }
+    }
+    let Some(first) = lines.first() else {
+        return;
+    };
+    if forced_break_before {
+        struts.push(
+            first.0,
+            fc.strut_before(block_start + first.0 + struts.total),
+        );
+    }
+    let line_count = lines.len();
+    let mut page_first_line = 0;
+    let mut page_index = fc.index_of(block_start + lines[0].0 + struts.total);
+    let mut i = 0;
+    while i < line_count {
+        let (top, bottom) = lines[i];
+        let start = block_start + top + struts.total;
+        let end = block_start + bottom + struts.total;
+        if bottom - top <= fc.block_size && !fc.is_at_start(start) && fc.is_broken(start, end) {
+            // choose a line to break before
+            let satisfies_orphans =
+                |b: usize| b > page_first_line && b - page_first_line >= orphans;
+            let b = if satisfies_orphans(i.min(line_count.saturating_sub(widows))) {
+                i.min(line_count - widows)
+            } else if satisfies_orphans(i) {
+                i
+            } else if page_first_line == 0
+                && !fc.is_at_start(block_start + lines[0].0 + struts.total)
+            {
+                0
+            } else {
+                i
+            };
+            let start = block_start + lines[b].0 + struts.total;
+            struts.push(lines[b].0, fc.strut_before(start));
+            page_first_line = b;
+            page_index = fc.index_of(block_start + lines[b].0 + struts.total);
+            i = b + 1;
+            continue;
+        }
+        let index = fc.index_of(start);
+        if index != page_index {
+            page_index = index;
+            page_first_line = i;
+        }
+        i += 1;
+    }
+}
+
+/// Fragment the in-flow content of a block container, whose border box starts at `block_start`.
+///
+/// Returns the growth of the border box.
+fn fragment_block_container<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &T,
+    block_start: T::Length,
+    fc: Fragmentainer<T::Length>,
+) -> T::Length {
+    let style = node.style();
+    let orphans = (style.orphans() as usize).max(1);
+    let widows = (style.widows() as usize).max(1);
+    let mut items = Vec::new();
+    collect_flow_items(env, node, T::Length::zero(), &mut items);
+    let mut struts = Struts::new();
+    // the previous block-level sibling: its unfragmented top, its fragmented top, and its `break-after`
+    let mut prev_block: Option<(T::Length, T::Length, Break)> = None;
+    let mut forced_break_pending = false;
+    let mut i = 0;
+    while i < items.len() {
+        match items[i] {
+            FlowItem::Block(child, top, bottom) => {
+                let child_style = child.style();
+                let height = bottom - top;
+                let mut start = block_start + top + struts.total;
+                let monolithic = is_monolithic(child);
+                if forced_break_pending || is_forced_break(child_style.break_before()) {
+                    let strut = fc.strut_before(start);
+                    struts.push(top, strut);
+                    start += strut;
+                } else if (monolithic || is_avoid_break_inside(child_style.break_inside()))
+                    && height <= fc.block_size
+                    && fc.is_broken(start, start + height)
+                {
+                    // move the previous sibling together if the break between them should be avoided
+                    let keep_with_prev =
+                        prev_block.and_then(|(prev_top, prev_start, prev_after)| {
+                            let avoid = is_avoid_break(prev_after)
+                                || is_avoid_break(child_style.break_before());
+                            let together = avoid
+                                && !fc.is_at_start(prev_start)
+                                && fc.index_of(prev_start) == fc.index_of(start)
+                                && start + height - prev_start <= fc.block_size;
+                            together.then_some((prev_top, prev_start))
+                        });
+                    let (strut_top, strut_start) = keep_with_prev.unwrap_or((top, start));
+                    let strut = fc.strut_before(strut_start);
+                    struts.push(strut_top, strut);
+                    start += strut;
+                }
+                if !monolithic {
+                    let growth = fragment_block_container(env, child, start, fc);
+                    struts.push(bottom, growth);
+                }
+                forced_break_pending = is_forced_break(child_style.break_after());
+                prev_block = Some((top, start, child_style.break_after()));
+                i += 1;
+            }
+            FlowItem::Atom(..) => {
+                let mut atoms = Vec::new();
+                while let Some(FlowItem::Atom(top, bottom)) = items.get(i) {
+                    atoms.push((*top, *bottom));
+                    i += 1;
+                }
+                fragment_lines(
+                    &atoms,
+                    block_start,
+                    &mut struts,
+                    fc,
+                    orphans,
+                    widows,
+                    forced_break_pending,
+                );
+                forced_break_pending = false;
+                prev_block = None;
+            }
+        }
+    }
+    if struts.list.is_empty() {
+        return T::Length::zero();
+    }
+    shift_children(env, node, T::Length::zero(), T::Length::zero(), &struts);
+    match style.height() {
+        DefLength::Undefined | DefLength::Auto => {
+            grow(&mut node.layout_node().unit(), struts.total);
+            struts.total
+        }
+        _ => T::Length::zero(),
+    }
+}
+
+/// Move the children by the struts before them, and returns the largest strut amount inside the node.
+fn shift_children<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &T,
+    offset: T::Length,
+    parent_shift: T::Length,
+    struts: &Struts<T::Length>,
+) -> T::Length {
+    let mut last = parent_shift;
+    node.tree_visitor().for_each_layout_child(|child, _| {
+        if is_display_none::<T>(child.style()) {
+            return;
+        }
+        let (top, is_inline_box) = {
+            let unit = child.layout_node().unit();
+            (
+                offset + unit.result.origin.y,
+                unit.layout_algorithm == LayoutAlgorithm::Inline,
+            )
+        };
+        let shift = struts.at(top);
+        let child_last = if is_inline_box {
+            // an inline box is broken with the lines inside it
+            shift_children(env, child, top, shift, struts)
+        } else if child.style().display() == Display::Inline && child.should_measure(env) {
+            // a measured inline node is broken with the lines inside it
+            let line_shifts: Vec<_> = child
+                .measured_lines(env)
+                .into_iter()
+                .map(|(line_top, _)| struts.at(top + line_top) - shift)
+                .collect();
+            let child_last = shift + line_shifts.last().copied().unwrap_or(T::Length::zero());
+            if line_shifts.iter().any(|x| *x > T::Length::zero()) {
+                child.layout_node().unit().result_line_shifts = line_shifts;
+            }
+            child_last
+        } else {
+            shift
+        };
+        let mut unit = child.layout_node().unit();
+        unit.result.origin.y += shift - parent_shift;
+        grow(&mut unit, child_last - shift);
+        last = last.lower_bound(child_last);
+    });
+    last
+}
+
+fn grow<T: LayoutTreeNode>(unit: &mut LayoutUnit<T>, amount: T::Length) {
+    if amount <= T::Length::zero() {
+        return;
+    }
+    unit.result.size.height += amount;
+    unit.result_padding_rect.size.height += amount;
+    unit.result_content_rect.size.height += amount;
+}
+
+/// Slice the border rect (in the continuous coordinate) into fragments.
+fn slice_rect<L: LengthNum>(rect: Rect<L>, fc: Fragmentainer<L>) -> Vec<LayoutFragment<L>> {
+    let start = rect.min_y();
+    let end = rect.max_y();
+    let first = fc.index_of(start);
+    let mut last = fc.index_of(end);
+    if last > first && fc.is_at_start(end) {
+        last -= 1;
+    }
+    (first..=last)
+        .map(|index| {
+            let page_start = fc.start_of(index);
+            let top = start.lower_bound(page_start);
+            let bottom = end.upper_bound(fc.start_of(index + 1)).lower_bound(top);
+            LayoutFragment {
+                index,
+                rect: Rect::new(
+                    Point::new(rect.min_x(), top - page_start),
+                    Size::new(rect.width(), bottom - top),
+                ),
+            }
+        })
+        .collect()
+}
+
+fn update_fragments<T: LayoutTreeNode>(
+    node: &T,
+    parent_origin: Vector<T::Length>,
+    fc: Fragmentainer<T::Length>,
+) {
+    let rect = {
+        let mut unit = node.layout_node().unit();
+        let rect = unit.result.translate(parent_origin);
+        unit.result_fragments = slice_rect(rect, fc);
+        rect
+    };
+    node.tree_visitor().for_each_layout_child(|child, _| {
+        if is_display_none::<T>(child.style()) {
+            return;
+        }
+        update_fragments(child, rect.origin.to_vector(), fc);
+    });
+}
+
+/// Clear the fragments and the position caches of the descendants.
+///
+/// The fragmented results should not be reused by the next unfragmented layout.
+fn reset_descendants<T: LayoutTreeNode>(node: &T) {
+    node.tree_visitor().for_each_child(|child, _| {
+        {
+            let mut unit = child.layout_node().unit();
+            unit.result_fragments.clear();
+            unit.result_line_shifts.clear();
+            unit.cache.clear_position_cache();
+        }
+        reset_descendants(child);
+    });
+}
+
+/// Fragment the tree after the continuous layout.
+pub(crate) fn fragment_tree<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &T,
+    fragmentainer_block_size: T::Length,
+) {
+    reset_descendants(node);
+    node.layout_node().unit().cache.clear_position_cache();
+    if fragmentainer_block_size <= T::Length::zero() {
+        return;
+    }
+    let fc = Fragmentainer {
+        block_size: fragmentainer_block_size,
+    };
+    let block_start = node.layout_node().unit().result.origin.y;
+    if is_breakable_container(node) {
+        fragment_block_container(env, node, block_start, fc);
+    }
+    update_fragments(node, Vector::zero(), fc);
+}
+
+impl<T: LayoutTreeNode> LayoutUnit<T> {
+    /// Clear the fragments of the tree, if it has been fragmented.
+    pub(crate) fn clear_fragments(&mut self, node: &T) {
+        if self.result_fragments.is_empty() {
+            return;
+        }
+        self.result_fragments.clear();
+        reset_descendants(node);
+    }
+}
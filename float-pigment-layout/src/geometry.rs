@@ -0,0 +1,215 @@
+//! Transform-aware geometry.
+//!
+//! CSS Transforms 1 & 2
+//! <https://www.w3.org/TR/css-transforms-1/>
+//! <https://www.w3.org/TR/css-transforms-2/>
+//!
+//! Transforms do not affect layout, so the layout results are not transformed.
+//! The functions here compose the transforms of the ancestor chain,
+//! and give the transformed border box of a node in the coordinate of the tree root
+//! (i.e. the coordinate of the root layout results).
+//! `transform-style` is always `flat`, so the transforms are flattened into the plane of each ancestor.
+//! The scroll offsets are not included.
+
+use crate::*;
+
+/// A 3D transform matrix in the row-vector convention of `euclid`.
+pub type LayoutTransform = euclid::Transform3D<f32, euclid::UnknownUnit, euclid::UnknownUnit>;
+
+/// A `transform` function.
+///
+/// The angles are in radians.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutTransformItem<L: LengthNum, T: PartialEq + Clone = i32> {
+    /// `matrix(a, b, c, d, e, f)`
+    Matrix([f32; 6]),
+    /// `matrix3d(...)`, in column-major order
+    Matrix3D([f32; 16]),
+    /// `translate3d(x, y, z)`, the percentages are resolved against the border box
+    Translate(DefLength<L, T>, DefLength<L, T>, L),
+    /// `scale3d(x, y, z)`
+    Scale(f32, f32, f32),
+    /// `rotate3d(x, y, z, angle)`
+    Rotate(f32, f32, f32, f32),
+    /// `skew(x, y)`
+    Skew(f32, f32),
+    /// `perspective(d)`
+    Perspective(L),
+}
+
+/// The corners of a transformed border box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutQuad {
+    /// The top-left, top-right, bottom-right, and bottom-left corners (before transformed).
+    pub points: [Point<f32>; 4],
+}
+
+impl LayoutQuad {
+    /// The axis-aligned bounding box.
+    pub fn bounds(&self) -> Rect<f32> {
+        Rect::from_points(self.points)
+    }
+}
+
+fn resolve_length<T: LayoutTreeNode>(
+    length: &DefLength<T::Length, T::LengthCustom>,
+    base: T::Length,
+    node: &T,
+) -> f32 {
+    length
+        .resolve(OptionNum::some(base), node)
+        .or_zero()
+        .to_f32()
+}
+
+fn transform_item_matrix<T: LayoutTreeNode>(
+    item: &LayoutTransformItem<T::Length, T::LengthCustom>,
+    size: Size<T::Length>,
+    node: &T,
+) -> LayoutTransform {
+    match item {
+        LayoutTransformItem::Matrix([a, b, c, d, e, f]) => {
+            LayoutTransform::new_2d(*a, *b, *c, *d, *e, *f)
+        }
+        LayoutTransformItem::Matrix3D(m) => LayoutTransform::new(
+            m[0], m[1], m[2], m[3], m[4], m[5], m[6], m[7], m[8], m[9], m[10], m[11], m[12], m[13],
+            m[14], m[15],
+        ),
+        LayoutTransformItem::Translate(x, y, z) => LayoutTransform::translation(
+            resolve_length(x, size.width, node),
+            resolve_length(y, size.height, node),
+            z.to_f32(),
+        ),
+        LayoutTransformItem::Scale(x, y, z) => LayoutTransform::scale(*x, *y, *z),
+        LayoutTransformItem::Rotate(x, y, z, angle) => {
+            // a zero vector means no rotation
+            if *x == 0. && *y == 0. && *z == 0. {
+                LayoutTransform::identity()
+            } else {
+                let axis =
+                    euclid::Vector3D::<f32, euclid::UnknownUnit>::new(*x, *y, *z).normalize();
+                LayoutTransform::rotation(axis.x, axis.y, axis.z, euclid::Angle::radians(*angle))
+            }
+        }
+        LayoutTransformItem::Skew(x, y) => {
+            LayoutTransform::skew(euclid::Angle::radians(*x), euclid::Angle::radians(*y))
+        }
+        LayoutTransformItem::Perspective(d) => {
+            // a non-positive distance is clamped to 1px
+            LayoutTransform::perspective(d.to_f32().max(1.))
+        }
+    }
+}
+
+/// Get the `transform` of the node with `transform-origin` applied, in the coordinate of its border box.
+///
+/// Should be called after the layout results are updated.
+pub fn local_transform<T: LayoutTreeNode>(node: &T) -> LayoutTransform {
+    let style = node.style();
+    let items = style.transform();
+    if items.is_empty() {
+        return LayoutTransform::identity();
+    }
+    let size = node.layout_node().unit().result.size;
+    let (origin_x, origin_y, origin_z) = style.transform_origin();
+    let origin = euclid::Vector3D::new(
+        resolve_length(&origin_x, size.width, node),
+        resolve_length(&origin_y, size.height, node),
+        origin_z.to_f32(),
+    );
+    // the last function is applied to the points first
+    items
+        .iter()
+        .rev()
+        .fold(
+            LayoutTransform::translation(-origin.x, -origin.y, -origin.z),
+            |matrix, item| matrix.then(&transform_item_matrix(item, size, node)),
+        )
+        .then_translate(origin)
+}
+
+/// Flatten a transform into the plane, i.e. drop the z components.
+fn flatten(m: LayoutTransform) -> LayoutTransform {
+    LayoutTransform::new(
+        m.m11, m.m12, 0., m.m14, m.m21, m.m22, 0., m.m24, 0., 0., 1., 0., m.m41, m.m42, 0., m.m44,
+    )
+}
+
+/// Get the transform from the border box coordinate of the node to the coordinate of the tree root.
+///
+/// The transforms of the node and all its ancestors are included.
+/// Should be called after the layout results are updated.
+pub fn absolute_transform<T: LayoutTreeNode>(node: &T) -> LayoutTransform {
+    let mut ret = LayoutTransform::identity();
+    let mut cur = Some(node);
+    while let Some(node) = cur {
+        let origin = node.layout_node().unit().result.origin;
+        let local = local_transform(node).then_translate(euclid::Vector3D::new(
+            origin.x.to_f32(),
+            origin.y.to_f32(),
+            0.,
+        ));
+        ret = ret.then(&flatten(local));
+        cur = node.tree_visitor().layout_parent();
+    }
+    ret
+}
+
+/// Get the transformed border box of the node in the coordinate of the tree root.
+///
+/// Returns `None` if some corner is behind the viewer, i.e. it cannot be projected.
+/// Should be called after the layout results are updated.
+pub fn transformed_quad<T: LayoutTreeNode>(node: &T) -> Option<LayoutQuad> {
+    let size = node.layout_node().unit().result.size;
+    let (width, height) = (size.width.to_f32(), size.height.to_f32());
+    let matrix = absolute_transform(node);
+    let corners = [(0., 0.), (width, 0.), (width, height), (0., height)];
+    let mut points = [Point::zero(); 4];
+    for (point, (x, y)) in points.iter_mut().zip(corners) {
+        *point = matrix.transform_point2d(Point::new(x, y))?;
+    }
+    Some(LayoutQuad { points })
+}
+
+/// Get the axis-aligned bounding box of the transformed border box of the node, in the coordinate of the tree root.
+///
+/// Returns `None` if some corner is behind the viewer.
+/// Should be called after the layout results are updated.
+pub fn transformed_bounds<T: LayoutTreeNode>(node: &T) -> Option<Rect<f32>> {
+    transformed_quad(node).map(|quad| quad.bounds())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: Point<f32>, b: Point<f32>) -> bool {
+        (a.x - b.x).abs() < 1e-4 && (a.y - b.y).abs() < 1e-4
+    }
+
+    #[test]
+    fn rotation_is_clockwise() {
+        let m = LayoutTransform::rotation(0., 0., 1., euclid::Angle::degrees(90.));
+        let p = m.transform_point2d(Point::new(1., 0.)).unwrap();
+        assert!(approx_eq(p, Point::new(0., 1.)));
+    }
+
+    #[test]
+    fn matrix_order() {
+        // matrix(1, 0, 0, 1, 10, 20) is a translation
+        let m = LayoutTransform::new_2d(1., 0., 0., 1., 10., 20.);
+        let p = m.transform_point2d(Point::new(1., 1.)).unwrap();
+        assert!(approx_eq(p, Point::new(11., 21.)));
+        // skew(45deg, 0) moves x by y
+        let m = LayoutTransform::skew(euclid::Angle::degrees(45.), euclid::Angle::radians(0.));
+        let p = m.transform_point2d(Point::new(0., 10.)).unwrap();
+        assert!(approx_eq(p, Point::new(10., 10.)));
+    }
+
+    #[test]
+    fn flatten_keeps_2d() {
+        let m = LayoutTransform::rotation(0., 1., 0., euclid::Angle::degrees(60.));
+        let p = flatten(m).transform_point2d(Point::new(10., 5.)).unwrap();
+        assert!(approx_eq(p, Point::new(5., 5.)));
+    }
+}
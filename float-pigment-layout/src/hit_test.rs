@@ -0,0 +1,148 @@
+//! Hit testing.
+//!
+//! Find the deepest node under a point with the layout results.
+//! The children are visited in the reversed painting order of CSS 2.1 Appendix E,
+//! simplified so that the children are only ordered among their siblings,
+//! i.e. every node is treated as a stacking context.
+//! The descendants of a box with non-`visible` overflow are clipped by its padding box,
+//! and are moved by its scroll offset, which is provided by the host.
+//! A `position: fixed` box does not move with the scroll offsets of its ancestors,
+//! and a `position: sticky` box is moved by its sticky offset for the scroll offset of its nearest scroll container.
+
+use crate::*;
+
+/// The painting order of a child among its siblings, CSS 2.1 Appendix E.
+///
+/// A child with a greater key is painted later, so it is on top.
+fn paint_order<T: LayoutTreeNode>(node: &T, parent: &T) -> (u8, i32) {
+    let style = node.style();
+    // z-index also applies to the flex items and the grid items (CSS Flexbox §4.3)
+    let z_index_applies = style.position() != Position::Static
+        || matches!(
+            parent.style().display(),
+            Display::Flex | Display::InlineFlex | Display::Grid | Display::InlineGrid
+        );
+    let z_index = if z_index_applies {
+        style.z_index()
+    } else {
+        None
+    };
+    match z_index {
+        Some(z) if z < 0 => (0, z),
+        Some(z) if z > 0 => (5, z),
+        _ if z_index_applies => (4, 0),
+        _ if style.float() != Float::None => (2, 0),
+        _ => match style.display() {
+            Display::Inline | Display::InlineBlock | Display::InlineFlex | Display::InlineGrid => {
+                (3, 0)
+            }
+            _ => (1, 0),
+        },
+    }
+}
+
+fn hit_node<'a, T: LayoutTreeNode>(
+    node: &'a T,
+    parent_origin: Vector<T::Length>,
+    scrolled: Vector<T::Length>,
+    container_scroll: Vector<T::Length>,
+    point: Point<T::Length>,
+    scroll_offset: &mut impl FnMut(&T) -> Vector<T::Length>,
+) -> Option<&'a T> {
+    let style = node.style();
+    if is_display_none::<T>(style) {
+        return None;
+    }
+    // `scrolled` is the sum of the scroll offsets applied to the `parent_origin`,
+    // which is reverted for a fixed box
+    let (parent_origin, mut scrolled) = match style.position() {
+        Position::Fixed => (parent_origin + scrolled, Vector::zero()),
+        Position::Sticky => match sticky_constraint(node) {
+            Some(c) => (parent_origin + c.sticky_offset(container_scroll), scrolled),
+            None => (parent_origin, scrolled),
+        },
+        _ => (parent_origin, scrolled),
+    };
+    let (border_rect, padding_rect) = {
+        let unit = node.layout_node().unit();
+        let border_rect = unit.result.translate(parent_origin);
+        (
+            border_rect,
+            unit.result_padding_rect
+                .translate(border_rect.origin.to_vector()),
+        )
+    };
+    let clip_x = style.overflow_x() != Overflow::Visible;
+    let clip_y = style.overflow_y() != Overflow::Visible;
+    let inside_x = point.x >= padding_rect.min_x() && point.x < padding_rect.max_x();
+    let inside_y = point.y >= padding_rect.min_y() && point.y < padding_rect.max_y();
+    if (!clip_x || inside_x) && (!clip_y || inside_y) {
+        let mut origin = border_rect.origin.to_vector();
+        let mut container_scroll = container_scroll;
+        if clip_x || clip_y {
+            let offset = scroll_offset(node);
+            origin -= offset;
+            scrolled += offset;
+            container_scroll = offset;
+        }
+        let mut children = node.tree_visitor().layout_children();
+        // the sort is stable, so the tree order is kept in each layer
+        children.sort_by_key(|child| paint_order(*child, node));
+        for child in children.into_iter().rev() {
+            if let Some(ret) = hit_node(
+                child,
+                origin,
+                scrolled,
+                container_scroll,
+                point,
+                scroll_offset,
+            ) {
+                return Some(ret);
+            }
+        }
+    }
+    if style.pointer_events() != PointerEvents::None && border_rect.contains(point) {
+        Some(node)
+    } else {
+        None
+    }
+}
+
+/// Find the nodes under the `point`.
+///
+/// The `point` is in the coordinate of the layout results of the `root`, i.e. relative to the parent of it.
+/// The `scroll_offset` is called for each node with non-`visible` overflow which may contain the `point`,
+/// and should return its current scroll position.
+/// A node with `pointer-events: none` is never the target, but its descendants can still be.
+/// A `position: sticky` node is moved by its sticky offset (see `StickyConstraint`) for the scroll position of its scroll container.
+///
+/// Returns the path from the `root` to the deepest node under the `point` (including both),
+/// or an empty `Vec` if nothing is hit.
+/// Should be called after the layout results are updated.
+pub fn hit_test<'a, T: LayoutTreeNode>(
+    root: &'a T,
+    point: Point<T::Length>,
+    mut scroll_offset: impl FnMut(&T) -> Vector<T::Length>,
+) -> Vec<&'a T> {
+    let Some(target) = hit_node(
+        root,
+        Vector::zero(),
+        Vector::zero(),
+        Vector::zero(),
+        point,
+        &mut scroll_offset,
+    ) else {
+        return Vec::new();
+    };
+    let mut ret = vec![target];
+    let mut cur = target;
+    while !core::ptr::eq(cur, root) {
+        let Some(parent) = cur.tree_visitor().parent() else {
+            break;
+        };
+        ret.push(parent);
+        cur = parent;
+    }
+    ret.reverse();
+    ret
+}
@@ -0,0 +1,186 @@
+//! Intrinsic sizing keywords.
+//!
+//! CSS Box Sizing Module Level 3 / 4
+//! <https://www.w3.org/TR/css-sizing-3/#sizing-values>
+//! <https://www.w3.org/TR/css-sizing-4/#sizing-values>
+//!
+//! The `min-content`, `max-content` and `fit-content` sizes of a node are computed by the existing
+//! min-content and max-content passes (`SizingMode::MinContent` and `SizingMode::MaxContent`),
+//! before the parent lays out its children, and they are stored in the `LayoutUnit` of the node.
+//!
+//! Some simplifications are made:
+//!
+//! * the content keywords only apply to the inline axis of the node, and they behave as `auto` in the block axis
+//!   (which is the content size for `width` and `height`, but no limit for `min-*` and `max-*`);
+//! * a custom length (e.g. `calc`) is not supported inside `fit-content(...)`.
+//!
+//! The intrinsic sizes are prepared for the layout children of each node (once until the node is marked dirty),
+//! for the cells of a table, and for the tree root.
+
+use crate::*;
+use float_pigment_css::num_traits::Zero;
+
+/// The min-content and max-content border box sizes of a node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct IntrinsicSizes<L: LengthNum> {
+    pub(crate) min_content: Size<L>,
+    pub(crate) max_content: Size<L>,
+}
+
+/// Whether any sizing property of the node uses an intrinsic sizing keyword.
+#[inline]
+pub(crate) fn has_intrinsic_sizing<T: LayoutTreeNode>(style: &T::Style) -> bool {
+    style.width().is_intrinsic()
+        || style.height().is_intrinsic()
+        || style.min_width().is_intrinsic()
+        || style.min_height().is_intrinsic()
+        || style.max_width().is_intrinsic()
+        || style.max_height().is_intrinsic()
+        || style.flex_basis().is_intrinsic()
+}
+
+impl<T: LayoutTreeNode> LayoutUnit<T> {
+    /// Compute the intrinsic sizes of the node if any of its sizing properties requires them.
+    pub(crate) fn prepare_intrinsic_sizes(&mut self, env: &mut T::Env, node: &T) {
+        if self.intrinsic_sizes.is_some() || !has_intrinsic_sizing::<T>(node.style()) {
+            return;
+        }
+        let min_content = self.content_size(env, node, SizingMode::MinContent);
+        let max_content = self.content_size(env, node, SizingMode::MaxContent);
+        self.intrinsic_sizes = Some(IntrinsicSizes {
+            min_content,
+            max_content,
+        });
+    }
+
+    /// Prepare the intrinsic sizes of the layout children of the node.
+    ///
+    /// Only the children which use an intrinsic sizing keyword are computed.
+    pub(crate) fn prepare_children_intrinsic_sizes(env: &mut T::Env, node: &T) {
+        node.tree_visitor().for_each_layout_child(|child, _| {
+            if has_intrinsic_sizing::<T>(child.style()) {
+                child
+                    .layout_node()
+                    .unit()
+                    .prepare_intrinsic_sizes(env, child);
+            }
+        });
+    }
+
+    fn content_size(
+        &mut self,
+        env: &mut T::Env,
+        node: &T,
+        sizing_mode: SizingMode,
+    ) -> Size<T::Length> {
+        let unconstrained = OptionSize::new(OptionNum::none(), OptionNum::none());
+        self.compute_internal(
+            env,
+            node,
+            ComputeRequest {
+                size: Normalized(unconstrained),
+                parent_inner_size: Normalized(unconstrained),
+                max_content: Normalized(unconstrained),
+                kind: ComputeRequestKind::AllSize,
+                parent_is_block: false,
+                sizing_mode,
+                line_clamp: None,
+            },
+        )
+        .size
+        .0
+    }
+
+    /// Replace the border box size in the axes which use an intrinsic sizing keyword.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn apply_intrinsic_sizing(
+        &self,
+        node: &T,
+        lengths: (
+            DefLength<T::Length, T::LengthCustom>,
+            DefLength<T::Length, T::LengthCustom>,
+        ),
+        size: OptionSize<T::Length>,
+        parent_inner_size: OptionSize<T::Length>,
+        border: Edge<T::Length>,
+        padding_border: Edge<T::Length>,
+    ) -> OptionSize<T::Length> {
+        let (width, height) = lengths;
+        if !width.is_intrinsic() && !height.is_intrinsic() {
+            return size;
+        }
+        let (margin, _, _) = self.margin_border_padding(node, parent_inner_size);
+        let (extra_width, extra_height) = match node.style().box_sizing() {
+            BoxSizing::BorderBox => (T::Length::zero(), T::Length::zero()),
+            BoxSizing::PaddingBox => (
+                padding_border.horizontal() - border.horizontal(),
+                padding_border.vertical() - border.vertical(),
+            ),
+            BoxSizing::ContentBox => (padding_border.horizontal(), padding_border.vertical()),
+        };
+        let width = if width.is_intrinsic() {
+            self.resolve_intrinsic_length(
+                node,
+                width,
+                AxisDirection::Horizontal,
+                parent_inner_size.width,
+                parent_inner_size.width - margin.left.or_zero() - margin.right.or_zero(),
+                extra_width,
+            )
+        } else {
+            size.width
+        };
+        let height = if height.is_intrinsic() {
+            self.resolve_intrinsic_length(
+                node,
+                height,
+                AxisDirection::Vertical,
+                parent_inner_size.height,
+                parent_inner_size.height - margin.top.or_zero() - margin.bottom.or_zero(),
+                extra_height,
+            )
+        } else {
+            size.height
+        };
+        OptionSize::new(width, height)
+    }
+
+    /// Resolve an intrinsic sizing keyword to a border box size in the specified axis.
+    ///
+    /// The `available` is the available space for the margin box of the node,
+    /// and the `box_sizing_extra` converts a fixed `fit-content(...)` limit to the border box.
+    /// Returns none for the other lengths, or if the keyword behaves as `auto`.
+    pub(crate) fn resolve_intrinsic_length(
+        &self,
+        node: &T,
+        length: DefLength<T::Length, T::LengthCustom>,
+        dir: AxisDirection,
+        parent: OptionNum<T::Length>,
+        available: OptionNum<T::Length>,
+        box_sizing_extra: T::Length,
+    ) -> OptionNum<T::Length> {
+        if length == DefLength::Stretch {
+            return available;
+        }
+        let inline_dir = match node.style().writing_mode() {
+            WritingMode::HorizontalTb => AxisDirection::Horizontal,
+            WritingMode::VerticalLr | WritingMode::VerticalRl => AxisDirection::Vertical,
+        };
+        let Some(sizes) = self.intrinsic_sizes.filter(|_| dir == inline_dir) else {
+            return OptionNum::none();
+        };
+        let (min, max) = match dir {
+            AxisDirection::Horizontal => (sizes.min_content.width, sizes.max_content.width),
+            AxisDirection::Vertical => (sizes.min_content.height, sizes.max_content.height),
+        };
+        let fit_content = |limit: OptionNum<T::Length>| limit.map(|x| max.min(min.max(x)));
+        match length {
+            DefLength::MinContent => OptionNum::some(min),
+            DefLength::MaxContent => OptionNum::some(max),
+            DefLength::FitContent => fit_content(available),
+            DefLength::FitContentPoints(x) => fit_content(OptionNum::some(x + box_sizing_extra)),
+            DefLength::FitContentPercent(x) => fit_content(parent * x + box_sizing_extra),
+            _ => OptionNum::none(),
+        }
+    }
+}
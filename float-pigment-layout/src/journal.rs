@@ -0,0 +1,84 @@
+//! Layout change journal.
+//!
+//! If enabled on the tree root (see `LayoutNode::set_journal_enabled`), each update records the nodes
+//! whose position, size, border, padding, or first baseline changed, with both the old and the new values.
+//! The nodes whose results are saved in a layout pass are flagged,
+//! so only the flagged nodes are compared after the pass, instead of the whole tree.
+
+use crate::*;
+
+/// The layout results of a node which are recorded in the journal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutJournalValues<L: LengthNum> {
+    /// The border box, relative to the parent (same as `LayoutNode::result`).
+    pub rect: Rect<L>,
+    /// The border widths.
+    pub border: Edge<L>,
+    /// The paddings.
+    pub padding: Edge<L>,
+    /// The first baseline ascent, relative to the border box.
+    pub first_baseline_ascent: Vector<L>,
+}
+
+/// A change of the layout results of a node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutJournalEntry<L: LengthNum> {
+    /// The address of the tree node.
+    pub node: usize,
+    /// The results before the update.
+    ///
+    /// It is `None` if the node has not been recorded before, e.g. it is newly inserted,
+    /// or it has not been laid out since the journal is enabled.
+    pub old: Option<LayoutJournalValues<L>>,
+    /// The results after the update.
+    pub new: LayoutJournalValues<L>,
+}
+
+/// Flag the ancestors of a node which is laid out alone (e.g. a relayout boundary),
+/// so that it can be found when collecting the journal.
+pub(crate) fn mark_journal_ancestors<T: LayoutTreeNode>(node: &T) {
+    let mut cur = node.tree_visitor();
+    while let Some(parent) = cur.parent() {
+        let mut unit = parent.layout_node().unit();
+        if unit.journal_descendant_touched {
+            break;
+        }
+        unit.journal_descendant_touched = true;
+        drop(unit);
+        cur = parent.tree_visitor();
+    }
+}
+
+/// Compare the results of the flagged nodes in the subtree, and record the changes.
+///
+/// If `force` is set, all nodes in the subtree are compared.
+pub(crate) fn collect_journal<T: LayoutTreeNode>(
+    node: &T,
+    force: bool,
+    journal: &mut Vec<LayoutJournalEntry<T::Length>>,
+) {
+    let mut unit = node.layout_node().unit();
+    let touched = core::mem::take(&mut unit.journal_touched) || force;
+    let descendant_touched = core::mem::take(&mut unit.journal_descendant_touched);
+    if touched {
+        let values = LayoutJournalValues {
+            rect: unit.result,
+            border: unit.computed_style.border,
+            padding: unit.computed_style.padding,
+            first_baseline_ascent: unit.result_first_baseline_ascent,
+        };
+        if unit.journal_committed != Some(values) {
+            journal.push(LayoutJournalEntry {
+                node: trace_node_key(node),
+                old: unit.journal_committed.replace(values),
+                new: values,
+            });
+        }
+    }
+    drop(unit);
+    if touched || descendant_touched {
+        node.tree_visitor().for_each_child(|child_node, _| {
+            collect_journal(child_node, force, journal);
+        });
+    }
+}
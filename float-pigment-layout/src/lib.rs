@@ -54,21 +54,54 @@ use core::{
 };
 
 use float_pigment_css::typing::{
-    AlignContent, AlignItems, AlignSelf, BoxSizing, Direction, Display, FlexDirection, FlexWrap,
-    GridAutoFlow, JustifyContent, JustifyItems, JustifySelf, Position, TextAlign, WritingMode,
+    AlignContent, AlignItems, AlignSelf, BorderCollapse, BoxSizing, Break, BreakInside, Clear,
+    ColumnFill, ColumnSpan, Direction, Display, FlexDirection, FlexWrap, Float, GridAutoFlow,
+    JustifyContent, JustifyItems, JustifySelf, ListStyleImage, ListStylePosition, ListStyleType,
+    MasonryAutoFlow, Overflow, PointerEvents, Position, TableLayout, TextAlign, WritingMode,
 };
 
 pub use unit::SizingMode;
 
 mod algo;
 mod cache;
+mod fragmentation;
+mod geometry;
+mod hit_test;
+mod intrinsic;
+mod journal;
+mod overflow;
+mod relayout;
+mod snapshot;
 mod special_positioned;
+mod sticky;
+mod trace;
 mod types;
 mod unit;
 
+pub use algo::virtualized::{LayoutVirtualization, LayoutVirtualizedResult};
 pub(crate) use cache::*;
+pub use fragmentation::LayoutFragment;
+pub(crate) use fragmentation::*;
+pub use geometry::{
+    absolute_transform, local_transform, transformed_bounds, transformed_quad, LayoutQuad,
+    LayoutTransform, LayoutTransformItem,
+};
+pub use hit_test::hit_test;
+pub(crate) use intrinsic::*;
+pub(crate) use journal::*;
+pub use journal::{LayoutJournalEntry, LayoutJournalValues};
+pub(crate) use overflow::*;
+pub(crate) use relayout::*;
+pub use snapshot::LayoutSnapshot;
 pub use special_positioned::is_independent_positioning;
 pub(crate) use special_positioned::*;
+pub use sticky::StickyConstraint;
+pub(crate) use sticky::*;
+pub(crate) use trace::{trace_measure, trace_node_key};
+pub use trace::{
+    LayoutAlgorithmTraceStats, LayoutCacheKey, LayoutCacheStats, LayoutMeasureKind,
+    LayoutNodeTraceStats, LayoutRequestKind, LayoutTrace, LayoutTraceEvent, LayoutTraceSummary,
+};
 pub use types::*;
 pub(crate) use unit::*;
 
@@ -186,6 +219,38 @@ pub trait LayoutTreeNode: Sized {
         _computed_style: &ComputedStyle<Self::Length>,
     ) {
     }
+
+    /// Get the layout trace collector referred by the environment.
+    ///
+    /// If it returns `Some`, the compute calls, cache hits and misses, measure function invocations,
+    /// and the time of each layout algorithm are recorded in it.
+    /// Returns `None` by default, so that nothing is recorded.
+    fn layout_trace(_env: &mut Self::Env) -> Option<&mut LayoutTrace<Self::Length>> {
+        None
+    }
+
+    /// Measure the marker box of a `display: list-item` node.
+    ///
+    /// The `ordinal` is the value of the `list-item` counter of this node, which can be used to generate the marker text.
+    /// The first baseline of the marker is aligned with the first baseline of the list item.
+    /// Returns `None` if the node does not generate a marker box, which is the default.
+    fn measure_marker(
+        &self,
+        _env: &mut Self::Env,
+        _ordinal: i32,
+    ) -> Option<MeasureResult<Self::Length>> {
+        None
+    }
+
+    /// Get the line boxes inside a measured inline node (e.g. a multi-line text),
+    /// as the `(top, bottom)` ranges relative to its border box, in the text order.
+    ///
+    /// It is used by fragmentation to break the lines across the fragmentainers honoring `orphans` and `widows`,
+    /// and the shift of each line is reported by `LayoutNode::line_shifts`.
+    /// Returns an empty list by default, so the node is treated as a single line.
+    fn measured_lines(&self, _env: &mut Self::Env) -> Vec<(Self::Length, Self::Length)> {
+        Vec::new()
+    }
 }
 
 /// A helper type for tree traversal.
@@ -215,6 +280,57 @@ pub trait LayoutTreeVisitor<T: LayoutTreeNode> {
     fn children_iter<'a, 'b: 'a>(&'b self) -> impl Iterator<Item = &'a T>
     where
         T: 'a;
+
+    /// Get the child nodes which participate in the layout of the current node.
+    ///
+    /// CSS Display §2.5: a `display: contents` child does not generate a box,
+    /// so its children are visited in its place (recursively).
+    /// The index is the position among the visited nodes, not the index in `child_at`.
+    fn for_each_layout_child<'a, 'b: 'a, F>(&'b self, mut f: F)
+    where
+        F: FnMut(&'a T, usize),
+        T: 'a,
+    {
+        let mut index = 0;
+        self.for_each_child(|child, _| {
+            splice_display_contents(child, &mut |node| {
+                f(node, index);
+                index += 1;
+            });
+        });
+    }
+
+    /// Get the child nodes which participate in the layout of the current node as a `Vec`.
+    ///
+    /// See `LayoutTreeVisitor::for_each_layout_child` for details.
+    fn layout_children<'a, 'b: 'a>(&'b self) -> Vec<&'a T>
+    where
+        T: 'a,
+    {
+        let mut ret = Vec::with_capacity(self.children_len());
+        self.for_each_layout_child(|child, _| ret.push(child));
+        ret
+    }
+
+    /// Get the parent node which the current node participates in the layout of.
+    ///
+    /// The `display: contents` ancestors are skipped.
+    fn layout_parent(&self) -> Option<&T> {
+        let mut parent = self.parent()?;
+        while parent.style().display() == Display::Contents {
+            parent = parent.tree_visitor().parent()?;
+        }
+        Some(parent)
+    }
+}
+
+fn splice_display_contents<'a, T: LayoutTreeNode>(node: &'a T, f: &mut dyn FnMut(&'a T)) {
+    if node.style().display() == Display::Contents {
+        node.tree_visitor()
+            .for_each_child(|child, _| splice_display_contents(child, f));
+    } else {
+        f(node);
+    }
 }
 
 /// The styles of a tree node.
@@ -294,6 +410,175 @@ pub trait LayoutStyle<L: LengthNum, T: PartialEq + Clone = i32> {
     fn justify_self(&self) -> JustifySelf {
         JustifySelf::Auto
     }
+    /// CSS Grid 3 §3.2: masonry-auto-flow
+    /// <https://www.w3.org/TR/css-grid-3/#masonry-auto-flow>
+    fn masonry_auto_flow(&self) -> MasonryAutoFlow {
+        MasonryAutoFlow::Pack
+    }
+    /// CSS 2.1 §9.5.1: float
+    /// <https://www.w3.org/TR/CSS2/visuren.html#float-position>
+    fn float(&self) -> Float {
+        Float::None
+    }
+    /// CSS 2.1 §9.5.2: clear
+    /// <https://www.w3.org/TR/CSS2/visuren.html#flow-control>
+    fn clear(&self) -> Clear {
+        Clear::None
+    }
+    /// CSS Overflow §3: overflow-x
+    /// <https://www.w3.org/TR/css-overflow-3/#overflow-properties>
+    fn overflow_x(&self) -> Overflow {
+        Overflow::Visible
+    }
+    /// CSS Overflow §3: overflow-y
+    /// <https://www.w3.org/TR/css-overflow-3/#overflow-properties>
+    fn overflow_y(&self) -> Overflow {
+        Overflow::Visible
+    }
+    /// CSS Overflow 4 §4.1: line-clamp (`-webkit-line-clamp`), `None` for `none`
+    /// <https://www.w3.org/TR/css-overflow-4/#line-clamp>
+    fn line_clamp(&self) -> Option<u32> {
+        None
+    }
+    /// CSS Fragmentation §3.1: break-before
+    /// <https://www.w3.org/TR/css-break-3/#break-between>
+    fn break_before(&self) -> Break {
+        Break::Auto
+    }
+    /// CSS Fragmentation §3.1: break-after
+    /// <https://www.w3.org/TR/css-break-3/#break-between>
+    fn break_after(&self) -> Break {
+        Break::Auto
+    }
+    /// CSS Fragmentation §3.2: break-inside
+    /// <https://www.w3.org/TR/css-break-3/#break-within>
+    fn break_inside(&self) -> BreakInside {
+        BreakInside::Auto
+    }
+    /// CSS Fragmentation §3.3: orphans
+    /// <https://www.w3.org/TR/css-break-3/#widows-orphans>
+    fn orphans(&self) -> u32 {
+        2
+    }
+    /// CSS Fragmentation §3.3: widows
+    /// <https://www.w3.org/TR/css-break-3/#widows-orphans>
+    fn widows(&self) -> u32 {
+        2
+    }
+    /// CSS Multi-column §3.2: column-count, `None` for `auto`
+    /// <https://www.w3.org/TR/css-multicol-1/#cc>
+    fn column_count(&self) -> Option<u32> {
+        None
+    }
+    /// CSS Multi-column §3.1: column-width
+    /// <https://www.w3.org/TR/css-multicol-1/#cw>
+    fn column_width(&self) -> DefLength<L, T> {
+        DefLength::Auto
+    }
+    /// CSS Multi-column §6.1: column-span
+    /// <https://www.w3.org/TR/css-multicol-1/#column-span>
+    fn column_span(&self) -> ColumnSpan {
+        ColumnSpan::None
+    }
+    /// CSS Multi-column §7.1: column-fill
+    /// <https://www.w3.org/TR/css-multicol-1/#cf>
+    fn column_fill(&self) -> ColumnFill {
+        ColumnFill::Balance
+    }
+    /// CSS 2.1 §17.5.2: table-layout
+    /// <https://www.w3.org/TR/CSS2/tables.html#width-layout>
+    fn table_layout(&self) -> TableLayout {
+        TableLayout::Auto
+    }
+    /// CSS 2.1 §17.6: border-collapse
+    /// <https://www.w3.org/TR/CSS2/tables.html#borders>
+    fn border_collapse(&self) -> BorderCollapse {
+        BorderCollapse::Separate
+    }
+    /// CSS 2.1 §17.6.1: the horizontal part of border-spacing
+    /// <https://www.w3.org/TR/CSS2/tables.html#separated-borders>
+    fn border_spacing_horizontal(&self) -> DefLength<L, T> {
+        DefLength::Points(L::zero())
+    }
+    /// CSS 2.1 §17.6.1: the vertical part of border-spacing
+    /// <https://www.w3.org/TR/CSS2/tables.html#separated-borders>
+    fn border_spacing_vertical(&self) -> DefLength<L, T> {
+        DefLength::Points(L::zero())
+    }
+    /// The number of columns spanned by a table cell (or a table column), e.g. the HTML `colspan` attribute.
+    ///
+    /// It is not a CSS property, so it should be supplied by the host.
+    fn colspan(&self) -> u32 {
+        1
+    }
+    /// The number of rows spanned by a table cell, e.g. the HTML `rowspan` attribute.
+    ///
+    /// `0` means spanning to the end of the row group.
+    /// It is not a CSS property, so it should be supplied by the host.
+    fn rowspan(&self) -> u32 {
+        1
+    }
+    /// CSS Lists §3.1: list-style-type
+    /// <https://www.w3.org/TR/css-lists-3/#text-markers>
+    fn list_style_type(&self) -> ListStyleType {
+        ListStyleType::Disc
+    }
+    /// CSS Lists §3.2: list-style-image
+    /// <https://www.w3.org/TR/css-lists-3/#image-markers>
+    fn list_style_image(&self) -> ListStyleImage {
+        ListStyleImage::None
+    }
+    /// CSS Lists §3.5: list-style-position
+    /// <https://www.w3.org/TR/css-lists-3/#list-style-position-property>
+    fn list_style_position(&self) -> ListStylePosition {
+        ListStylePosition::Outside
+    }
+    /// The explicit value of the `list-item` counter on a list item, e.g. the HTML `value` attribute of `<li>`.
+    ///
+    /// The following list items count from this value.
+    /// It is not a CSS property, so it should be supplied by the host.
+    fn list_item_value(&self) -> Option<i32> {
+        None
+    }
+    /// The initial value of the `list-item` counter of the list items in this node, e.g. the HTML `start` attribute of `<ol>`.
+    ///
+    /// `None` means `1`, or the number of the list items if `list_reversed` is set.
+    /// It is not a CSS property, so it should be supplied by the host.
+    fn list_start(&self) -> Option<i32> {
+        None
+    }
+    /// Whether the list items in this node count down, e.g. the HTML `reversed` attribute of `<ol>`.
+    ///
+    /// It is not a CSS property, so it should be supplied by the host.
+    fn list_reversed(&self) -> bool {
+        false
+    }
+    /// CSS 2.1 §9.9.1: z-index, `None` for `auto`
+    /// <https://www.w3.org/TR/CSS2/visuren.html#z-index>
+    ///
+    /// It does not affect layout, and is only used in hit testing.
+    fn z_index(&self) -> Option<i32> {
+        None
+    }
+    /// CSS UI 4: pointer-events
+    /// <https://www.w3.org/TR/css-ui-4/#pointer-events-control>
+    ///
+    /// It does not affect layout, and is only used in hit testing.
+    fn pointer_events(&self) -> PointerEvents {
+        PointerEvents::Auto
+    }
+    /// CSS Transforms 1 §7: transform
+    /// <https://www.w3.org/TR/css-transforms-1/#transform-property>
+    ///
+    /// It does not affect layout, and is only used in the transform-aware geometry queries.
+    fn transform(&self) -> Vec<LayoutTransformItem<L, T>> {
+        Vec::new()
+    }
+    /// CSS Transforms 1 §8: transform-origin, in x, y, and z
+    /// <https://www.w3.org/TR/css-transforms-1/#transform-origin-property>
+    fn transform_origin(&self) -> (DefLength<L, T>, DefLength<L, T>, L) {
+        (DefLength::Percent(0.5), DefLength::Percent(0.5), L::zero())
+    }
 }
 
 /// The layout information of a tree node.
@@ -323,6 +608,9 @@ impl<T: LayoutTreeNode> LayoutNode<T> {
     }
 
     /// Informs the node styles been changed.
+    ///
+    /// The ancestors are marked dirty as well, until a relayout boundary (see `LayoutNode::is_relayout_boundary`),
+    /// which will be laid out alone in the next update.
     #[inline]
     pub fn mark_dirty(&self, node: &T::TreeVisitor) -> bool {
         let ret = self.unit.borrow_mut().mark_dirty(node);
@@ -330,6 +618,16 @@ impl<T: LayoutTreeNode> LayoutNode<T> {
         ret
     }
 
+    /// Whether the node is a relayout boundary in the last layout.
+    ///
+    /// The size of a relayout boundary cannot depend on its descendants,
+    /// e.g. a block with fixed width and height that establishes a block formatting context,
+    /// so the dirty propagation of its descendants stops at it.
+    #[inline]
+    pub fn is_relayout_boundary(&self) -> bool {
+        self.unit.borrow().relayout_boundary
+    }
+
     /// Get the size and position results (border rect).
     #[inline]
     pub fn result(&self) -> Rect<T::Length> {
@@ -348,6 +646,126 @@ impl<T: LayoutTreeNode> LayoutNode<T> {
         self.unit.borrow().result_content_rect()
     }
 
+    /// Get the scrollable overflow rect, relative to the border rect.
+    ///
+    /// CSS Overflow §2.2: it is the union of the padding rect and the border rects of all descendants,
+    /// while the descendants of a box with non-`visible` overflow are clipped by its padding rect.
+    /// The size of it is the `scrollWidth` and `scrollHeight`
+    /// (but the part before the padding rect origin cannot be scrolled to).
+    ///
+    /// Should be called after the layout results are updated.
+    #[inline]
+    pub fn scrollable_overflow(&self, node: &T) -> Rect<T::Length> {
+        let padding_rect = self.result_padding_rect();
+        match descendants_overflow(node, Vector::zero()) {
+            Some(overflow) => padding_rect.union(&overflow),
+            None => padding_rect,
+        }
+    }
+
+    /// Get the sticky constraint of a `position: sticky` node.
+    ///
+    /// Returns `None` if the node is not sticky or has no parent.
+    /// Should be called after the layout results are updated.
+    #[inline]
+    pub fn sticky_constraint(&self, node: &T) -> Option<StickyConstraint<T::Length>> {
+        sticky_constraint(node)
+    }
+
+    /// Get the sticky offset of a `position: sticky` node, when the nearest scroll container is scrolled by `scroll_offset`.
+    ///
+    /// The offset is not included in the layout results, and should be added to the layout position when rendering.
+    /// Returns zero if the node is not sticky.
+    #[inline]
+    pub fn sticky_offset(&self, node: &T, scroll_offset: Vector<T::Length>) -> Vector<T::Length> {
+        match sticky_constraint(node) {
+            Some(c) => c.sticky_offset(scroll_offset),
+            None => Vector::zero(),
+        }
+    }
+
+    /// Get the column boxes of a multi-column container, relative to its border box.
+    ///
+    /// The list is empty if the node is not a multi-column container.
+    /// The host can paint column rules between adjacent rects.
+    #[inline]
+    pub fn column_rects(&self) -> Vec<Rect<T::Length>> {
+        self.unit.borrow().result_column_rects.clone()
+    }
+
+    /// Get the marker box of a `display: list-item` node, relative to its border box.
+    ///
+    /// It is `None` if the node does not generate a marker box.
+    #[inline]
+    pub fn marker_rect(&self) -> Option<Rect<T::Length>> {
+        self.unit.borrow().result_marker_rect
+    }
+
+    /// Get the fragments of the border box, one for each fragmentainer (e.g. page) the node lies in.
+    ///
+    /// The list is empty unless the tree is laid out with `LayoutNode::update_with_fragmentation`.
+    #[inline]
+    pub fn fragments(&self) -> Vec<LayoutFragment<T::Length>> {
+        self.unit.borrow().result_fragments.clone()
+    }
+
+    /// Get how far each line reported by `LayoutTreeNode::measured_lines` is pushed down by fragmentation,
+    /// relative to the border box of the node.
+    ///
+    /// The list is empty unless some lines inside the node are moved to the next fragmentainer.
+    #[inline]
+    pub fn line_shifts(&self) -> Vec<T::Length> {
+        self.unit.borrow().result_line_shifts.clone()
+    }
+
+    /// Set the virtualization settings of a list container, or `None` to disable it.
+    ///
+    /// A block container or a `flex-direction: column` flex container can be virtualized,
+    /// so that only the in-flow children intersecting the viewport range are laid out.
+    /// Returns whether the settings changed; if so, the node should be marked dirty (see `LayoutNode::mark_dirty`).
+    #[inline]
+    pub fn set_virtualization(
+        &self,
+        virtualization: Option<LayoutVirtualization<T::Length>>,
+    ) -> bool {
+        let mut unit = self.unit.borrow_mut();
+        if unit.virtualization == virtualization {
+            return false;
+        }
+        unit.virtualization = virtualization;
+        true
+    }
+
+    /// Get the virtualization settings, see `LayoutNode::set_virtualization`.
+    #[inline]
+    pub fn virtualization(&self) -> Option<LayoutVirtualization<T::Length>> {
+        self.unit.borrow().virtualization
+    }
+
+    /// Get the results of a virtualized list container, i.e. the total estimated extent and the children laid out.
+    ///
+    /// It is `None` if the node is not laid out as a virtualized list.
+    #[inline]
+    pub fn virtualized_result(&self) -> Option<LayoutVirtualizedResult<T::Length>> {
+        self.unit.borrow().result_virtualized.clone()
+    }
+
+    /// Whether the node is a child of a virtualized list which is not laid out.
+    ///
+    /// If so, the result is an estimated border box, and the results of the descendants are outdated.
+    #[inline]
+    pub fn is_virtualized_placeholder(&self) -> bool {
+        self.unit.borrow().virtualized_placeholder
+    }
+
+    /// Get the first baseline ascent, relative to the border box.
+    ///
+    /// If the node has no baseline, it is synthesized from the border box.
+    #[inline]
+    pub fn first_baseline_ascent(&self) -> Vector<T::Length> {
+        self.unit.borrow().result_first_baseline_ascent
+    }
+
     /// Get the computed styles, such as margins, borders, and paddings.
     #[inline]
     pub fn computed_style(&self) -> ComputedStyle<T::Length> {
@@ -366,7 +784,9 @@ impl<T: LayoutTreeNode> LayoutNode<T> {
     /// The `env` will be received in measure functions.
     #[inline]
     pub fn update(&self, env: &mut T::Env, node: &T, available_size: OptionSize<T::Length>) {
-        self.unit.borrow_mut().compute(env, node, available_size)
+        relayout_dirty_boundaries(env, node, false, self.is_journal_enabled());
+        self.unit.borrow_mut().compute(env, node, available_size);
+        self.record_journal(node, false);
     }
 
     /// Check all nodes that has been `mark_dirty`, and update the layout results of the whole tree with container size given.
@@ -383,12 +803,88 @@ impl<T: LayoutTreeNode> LayoutNode<T> {
         available_size: OptionSize<T::Length>,
         containing_size: OptionSize<T::Length>,
     ) {
+        relayout_dirty_boundaries(env, node, false, self.is_journal_enabled());
         self.unit.borrow_mut().compute_with_containing_size(
             env,
             node,
             available_size,
             containing_size,
-        )
+        );
+        self.record_journal(node, false);
+    }
+
+    /// Update the layout results of the whole tree in fragmentation mode.
+    ///
+    /// It is similar to `LayoutNode::update_with_containing_size`,
+    /// but the in-flow content is broken into fragmentainers (e.g. pages) of `fragmentainer_block_size` in height.
+    /// The results are in a continuous coordinate, in which the fragmentainer `i` starts at `i * fragmentainer_block_size`,
+    /// and `LayoutNode::fragments` tells the part of each node in each fragmentainer.
+    ///
+    /// Should only be called on the tree root node.
+    pub fn update_with_fragmentation(
+        &self,
+        env: &mut T::Env,
+        node: &T,
+        available_size: OptionSize<T::Length>,
+        containing_size: OptionSize<T::Length>,
+        fragmentainer_block_size: T::Length,
+    ) {
+        // the boundaries cannot be laid out alone, since the fragments are moved after layout
+        relayout_dirty_boundaries(env, node, true, self.is_journal_enabled());
+        self.unit.borrow_mut().compute_with_containing_size(
+            env,
+            node,
+            available_size,
+            containing_size,
+        );
+        fragment_tree(env, node, fragmentainer_block_size);
+        // the fragments may move any node, so the whole tree is compared
+        self.record_journal(node, true);
+    }
+
+    /// Enable or disable the layout change journal.
+    ///
+    /// If enabled, each update records the nodes whose position, size, border, padding, or first baseline changed,
+    /// which can be read by `LayoutNode::journal` or `LayoutNode::take_journal`.
+    /// Should only be called on the tree root node.
+    pub fn set_journal_enabled(&self, enabled: bool) {
+        let mut unit = self.unit.borrow_mut();
+        match (enabled, unit.journal.is_some()) {
+            (true, false) => unit.journal = Some(Vec::new()),
+            (false, true) => unit.journal = None,
+            _ => {}
+        }
+    }
+
+    /// Get the layout changes in the last update.
+    ///
+    /// It is empty unless the journal is enabled by `LayoutNode::set_journal_enabled`.
+    pub fn journal(&self) -> Vec<LayoutJournalEntry<T::Length>> {
+        self.unit.borrow().journal.clone().unwrap_or_default()
+    }
+
+    /// Take the layout changes in the last update, leaving the journal empty.
+    pub fn take_journal(&self) -> Vec<LayoutJournalEntry<T::Length>> {
+        self.unit
+            .borrow_mut()
+            .journal
+            .as_mut()
+            .map(core::mem::take)
+            .unwrap_or_default()
+    }
+
+    #[inline]
+    fn is_journal_enabled(&self) -> bool {
+        self.unit.borrow().journal.is_some()
+    }
+
+    fn record_journal(&self, node: &T, force: bool) {
+        let Some(mut journal) = self.unit.borrow_mut().journal.take() else {
+            return;
+        };
+        journal.clear();
+        collect_journal(node, force, &mut journal);
+        self.unit.borrow_mut().journal = Some(journal);
     }
 
     #[inline]
@@ -430,6 +926,103 @@ pub trait InlineMeasure<T: LayoutTreeNode> {
         Size<T::Length>,
         Vec<(Point<T::Length>, MeasureResult<T::Length>)>,
     );
+
+    /// Measure a series of inline nodes which should flow around some exclusion areas.
+    ///
+    /// This is the same as `block_size`, except that the line boxes should be shortened
+    /// (or moved down) to avoid the `exclusions`, e.g. the floats placed beside these inline nodes.
+    /// The exclusion rects are relative to the origin of the inline series.
+    ///
+    /// The default implementation ignores the exclusions.
+    #[allow(clippy::type_complexity, clippy::too_many_arguments)]
+    fn block_size_with_exclusions(
+        env: &mut Self::Env,
+        block_node: &T,
+        inline_nodes: Vec<InlineUnitMetadata<T>>,
+        req_size: OptionSize<T::Length>,
+        max_content_with_max_size: OptionSize<T::Length>,
+        update_position: bool,
+        sizing_mode: SizingMode,
+        _exclusions: &[InlineExclusion<T::Length>],
+    ) -> (
+        Size<T::Length>,
+        Vec<(Point<T::Length>, MeasureResult<T::Length>)>,
+    ) {
+        Self::block_size(
+            env,
+            block_node,
+            inline_nodes,
+            req_size,
+            max_content_with_max_size,
+            update_position,
+            sizing_mode,
+        )
+    }
+
+    /// Measure a series of inline nodes inside a line-clamped block container.
+    ///
+    /// This is the same as `block_size_with_exclusions`, except that only the first `line_clamp.max_lines`
+    /// line boxes are counted in the returned size, and the number of these line boxes should be returned.
+    /// The nodes after them should still be positioned, though they are hidden by the clamp.
+    /// The last counted line box should carry the ellipsis if some line boxes are hidden,
+    /// or if `line_clamp.has_following_content` is set and all line boxes are counted.
+    ///
+    /// The default implementation ignores the clamp and treats the whole series as one line box.
+    #[allow(clippy::type_complexity, clippy::too_many_arguments)]
+    fn block_size_with_line_clamp(
+        env: &mut Self::Env,
+        block_node: &T,
+        inline_nodes: Vec<InlineUnitMetadata<T>>,
+        req_size: OptionSize<T::Length>,
+        max_content_with_max_size: OptionSize<T::Length>,
+        update_position: bool,
+        sizing_mode: SizingMode,
+        exclusions: &[InlineExclusion<T::Length>],
+        _line_clamp: InlineLineClamp,
+    ) -> (
+        Size<T::Length>,
+        Vec<(Point<T::Length>, MeasureResult<T::Length>)>,
+        u32,
+    ) {
+        let (size, positions) = Self::block_size_with_exclusions(
+            env,
+            block_node,
+            inline_nodes,
+            req_size,
+            max_content_with_max_size,
+            update_position,
+            sizing_mode,
+            exclusions,
+        );
+        (size, positions, 1)
+    }
+}
+
+/// The line clamp applied to a series of inline nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InlineLineClamp {
+    /// The max number of line boxes that can be shown, which is always positive.
+    pub max_lines: u32,
+    /// Whether there is some content after this series inside the clamped block container.
+    pub has_following_content: bool,
+}
+
+/// An area that inline content should flow around, e.g. the margin box of a float.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InlineExclusion<L: LengthNum> {
+    /// The rect of the area, relative to the origin of the inline series.
+    pub rect: Rect<L>,
+    /// The side of the line box that the area is attached to.
+    pub side: FloatSide,
+}
+
+/// The side of the line box that a float is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatSide {
+    /// The line-left side, i.e. the left side in horizontal writing modes.
+    Start,
+    /// The line-right side.
+    End,
 }
 
 /// Inline unit with some metadata.
@@ -532,4 +1125,19 @@ pub enum LayoutAlgorithm {
 
     /// Used when the node is an inline-grid node.
     InlineGrid,
+
+    /// Used when the node is a multi-column container, e.g. `column-count: 2`.
+    Multicol,
+
+    /// Used when the node is a virtualized list container, see `LayoutNode::set_virtualization`.
+    Virtualized,
+
+    /// Used when the node is a table, e.g. `display: table`.
+    Table,
+
+    /// Used when the node is a table row or a table row group inside a table.
+    TableRow,
+
+    /// Used when the node is a table column inside a table.
+    TableColumn,
 }
@@ -0,0 +1,54 @@
+use crate::*;
+
+/// Clip the range `start..end` by the range `clip_start..clip_end`.
+#[inline]
+fn clip_axis<L: LengthNum>(start: L, end: L, clip_start: L, clip_end: L) -> (L, L) {
+    let start = start.max(clip_start);
+    let end = end.min(clip_end).max(start);
+    (start, end)
+}
+
+/// The union of the border rects of all descendants, offset by `offset`.
+///
+/// The descendants of a box with non-`visible` overflow are clipped by the padding rect of that box,
+/// in the axes which are not `visible`.
+pub(crate) fn descendants_overflow<T: LayoutTreeNode>(
+    node: &T,
+    offset: Vector<T::Length>,
+) -> Option<Rect<T::Length>> {
+    let mut ret: Option<Rect<T::Length>> = None;
+    node.tree_visitor().for_each_layout_child(|child_node, _| {
+        let style = child_node.style();
+        if is_display_none::<T>(style) {
+            return;
+        }
+        let (border_rect, padding_rect) = {
+            let unit = child_node.layout_node().unit();
+            (
+                unit.result.translate(offset),
+                unit.result_padding_rect
+                    .translate(offset + unit.result.origin.to_vector()),
+            )
+        };
+        let mut rect = border_rect;
+        if let Some(inner) = descendants_overflow(child_node, border_rect.origin.to_vector()) {
+            let (mut x, mut y) = (
+                (inner.min_x(), inner.max_x()),
+                (inner.min_y(), inner.max_y()),
+            );
+            if style.overflow_x() != Overflow::Visible {
+                x = clip_axis(x.0, x.1, padding_rect.min_x(), padding_rect.max_x());
+            }
+            if style.overflow_y() != Overflow::Visible {
+                y = clip_axis(y.0, y.1, padding_rect.min_y(), padding_rect.max_y());
+            }
+            let inner = Rect::new(Point::new(x.0, y.0), Size::new(x.1 - x.0, y.1 - y.0));
+            rect = rect.union(&inner);
+        }
+        ret = Some(match ret {
+            Some(x) => x.union(&rect),
+            None => rect,
+        });
+    });
+    ret
+}
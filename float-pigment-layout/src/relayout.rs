@@ -0,0 +1,196 @@
+//! Relayout boundaries.
+//!
+//! A relayout boundary is a node whose border box size cannot depend on its descendants,
+//! e.g. a `overflow: hidden` block with fixed width and height.
+//! When a descendant is marked dirty, the propagation stops at the boundary,
+//! and the boundary subtree is laid out again alone in the next update with the request of the last layout.
+//! If the results of the boundary changed after all (e.g. its baseline), its ancestors are marked dirty then.
+
+use crate::*;
+
+/// Returns if the node is a relayout boundary, i.e. the dirty propagation of the descendants can stop at it.
+pub(crate) fn is_relayout_boundary<T: LayoutTreeNode>(node: &T) -> bool {
+    let Some(parent) = node.tree_visitor().parent() else {
+        return false;
+    };
+    let style = node.style();
+    let parent_style = parent.style();
+    if !matches!(
+        style.display(),
+        Display::Block | Display::FlowRoot | Display::ListItem | Display::Flex | Display::Grid
+    ) {
+        return false;
+    }
+    let parent_is_flex = matches!(parent_style.display(), Display::Flex | Display::InlineFlex);
+    let parent_is_grid = matches!(parent_style.display(), Display::Grid | Display::InlineGrid);
+    if !parent_is_flex
+        && !parent_is_grid
+        && !matches!(
+            parent_style.display(),
+            Display::Block
+                | Display::InlineBlock
+                | Display::FlowRoot
+                | Display::ListItem
+                | Display::TableCell
+                | Display::TableCaption
+        )
+    {
+        return false;
+    }
+    // the children of a multicol container are moved into the columns
+    if algo::multicol::is_multicol_container::<T>(parent_style) {
+        return false;
+    }
+    let is_fixed = |x: DefLength<T::Length, T::LengthCustom>| matches!(x, DefLength::Points(_));
+    let is_fixed_or_auto = |x: DefLength<T::Length, T::LengthCustom>| {
+        matches!(
+            x,
+            DefLength::Points(_) | DefLength::Auto | DefLength::Undefined
+        )
+    };
+    if !is_fixed(style.width()) || !is_fixed(style.height()) {
+        return false;
+    }
+    if !is_fixed_or_auto(style.min_width())
+        || !is_fixed_or_auto(style.min_height())
+        || !is_fixed_or_auto(style.max_width())
+        || !is_fixed_or_auto(style.max_height())
+    {
+        return false;
+    }
+    // the margins and floats inside should not interact with the outside
+    if !algo::flow::establishes_bfc(node) {
+        return false;
+    }
+    if parent_is_flex || parent_is_grid {
+        // the automatic minimum size of a flex or grid item is content-based,
+        // unless it is a scroll container or the minimum size is given
+        let scroll_container =
+            style.overflow_x() != Overflow::Visible && style.overflow_y() != Overflow::Visible;
+        let min_size_given = is_fixed(style.min_width()) && is_fixed(style.min_height());
+        if !scroll_container && !min_size_given {
+            return false;
+        }
+        if parent_is_flex && !is_fixed_or_auto(style.flex_basis()) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Whether some absolutely positioned descendants are laid out by a containing block outside the node.
+fn has_escaping_absolute<T: LayoutTreeNode>(node: &T) -> bool {
+    if node.style().position() != Position::Static {
+        return false;
+    }
+    let mut ret = false;
+    node.tree_visitor().for_each_layout_child(|child_node, _| {
+        if ret || is_display_none::<T>(child_node.style()) {
+            return;
+        }
+        ret = match child_node.style().position() {
+            Position::Absolute => true,
+            Position::Static => has_escaping_absolute(child_node),
+            _ => false,
+        };
+    });
+    ret
+}
+
+/// Whether the baselines of the node may be used by its ancestors, e.g. in a line box or in baseline alignment.
+///
+/// A block container takes the baselines of its children, so the ancestors are checked until a user is found.
+fn is_baseline_used<T: LayoutTreeNode>(node: &T) -> bool {
+    let mut cur = node;
+    while let Some(parent) = cur.tree_visitor().parent() {
+        let parent_style = parent.style();
+        match parent_style.display() {
+            Display::Block | Display::FlowRoot | Display::ListItem | Display::Contents => {}
+            Display::Flex | Display::InlineFlex => {
+                if matches!(
+                    algo::flex_box::align_self::<T>(cur.style(), parent_style),
+                    AlignSelf::Baseline | AlignSelf::LastBaseline
+                ) {
+                    return true;
+                }
+            }
+            _ => return true,
+        }
+        cur = parent;
+    }
+    false
+}
+
+/// Mark the ancestors of a pending relayout boundary, so that it can be found in the next update.
+pub(crate) fn mark_dirty_boundary_ancestors<T: LayoutTreeNode>(node: &T) {
+    let mut cur = node.tree_visitor();
+    while let Some(parent) = cur.parent() {
+        let mut unit = parent.layout_node().unit();
+        if unit.has_dirty_boundary {
+            break;
+        }
+        unit.has_dirty_boundary = true;
+        drop(unit);
+        cur = parent.tree_visitor();
+    }
+}
+
+/// Mark all ancestors dirty, regardless of the relayout boundaries.
+fn mark_ancestors_dirty<T: LayoutTreeNode>(node: &T) {
+    let mut cur = node.tree_visitor();
+    while let Some(parent) = cur.parent() {
+        parent.tree_visitor().dirty_marked();
+        parent.layout_node().unit().mark_self_dirty();
+        cur = parent.tree_visitor();
+    }
+}
+
+/// Lay out the pending relayout boundaries in the subtree, the inner ones first.
+///
+/// If `force` is set, the boundaries are not laid out alone, but their ancestors are marked dirty instead.
+/// If `journal` is set, the ancestors of the boundaries laid out are flagged for the journal collection.
+pub(crate) fn relayout_dirty_boundaries<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &T,
+    force: bool,
+    journal: bool,
+) {
+    if !core::mem::take(&mut node.layout_node().unit().has_dirty_boundary) {
+        return;
+    }
+    node.tree_visitor().for_each_child(|child_node, _| {
+        relayout_dirty_boundaries(env, child_node, force, journal);
+        if core::mem::take(&mut child_node.layout_node().unit().pending_relayout) {
+            relayout_boundary(env, child_node, force, journal);
+        }
+    });
+}
+
+fn relayout_boundary<T: LayoutTreeNode>(env: &mut T::Env, node: &T, force: bool, journal: bool) {
+    let last_position = if force || has_escaping_absolute(node) {
+        None
+    } else {
+        node.layout_node().unit().last_position.clone()
+    };
+    let Some((request, prev)) = last_position else {
+        mark_ancestors_dirty(node);
+        return;
+    };
+    let mut unit = node.layout_node().unit();
+    let origin = unit.result.origin;
+    unit.prepare_intrinsic_sizes(env, node);
+    let ret = unit.compute_internal(env, node, request);
+    unit.result.origin = origin;
+    drop(unit);
+    if journal {
+        mark_journal_ancestors(node);
+    }
+    let changed = ret.size != prev.size
+        || ret.collapsed_margin != prev.collapsed_margin
+        || ret.line_count != prev.line_count;
+    let baseline_changed = ret.first_baseline_ascent != prev.first_baseline_ascent
+        || ret.last_baseline_ascent != prev.last_baseline_ascent;
+    if changed || (baseline_changed && is_baseline_used(node)) {
+        mark_ancestors_dirty(node);
+    }
+}
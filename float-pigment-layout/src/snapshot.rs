@@ -0,0 +1,266 @@
+//! Layout snapshots for visual regression testing.
+//!
+//! A snapshot collects the boxes of a laid out tree in the coordinate of the root.
+//! It can be written as JSON, which is stable and diffable,
+//! or as SVG, which draws the border, padding, and content boxes and the baselines with node labels.
+
+use crate::*;
+use alloc::string::String;
+use core::fmt::Write;
+
+/// The boxes of a node and its descendants.
+///
+/// All rects are relative to the origin of the parent of the snapshot root,
+/// so the root rects equal the root layout results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutSnapshot<L: LengthNum> {
+    /// The label of the node.
+    pub label: String,
+    /// The border box.
+    pub border_rect: Rect<L>,
+    /// The padding box.
+    pub padding_rect: Rect<L>,
+    /// The content box.
+    pub content_rect: Rect<L>,
+    /// The vertical offset of the first baseline.
+    pub baseline: L,
+    /// The snapshots of the children which generate boxes.
+    pub children: Vec<LayoutSnapshot<L>>,
+}
+
+impl<L: LengthNum> LayoutSnapshot<L> {
+    /// Collect the snapshot of a laid out tree.
+    ///
+    /// The `label` is called for each node which generates boxes.
+    /// Should be called after the layout results are updated.
+    pub fn new<T: LayoutTreeNode<Length = L>>(
+        node: &T,
+        label: &mut impl FnMut(&T) -> String,
+    ) -> Self {
+        Self::collect(node, Vector::zero(), label)
+    }
+
+    fn collect<T: LayoutTreeNode<Length = L>>(
+        node: &T,
+        parent_origin: Vector<L>,
+        label: &mut impl FnMut(&T) -> String,
+    ) -> Self {
+        let (border_rect, padding_rect, content_rect, baseline) = {
+            let unit = node.layout_node().unit();
+            let border_rect = unit.result.translate(parent_origin);
+            let origin = border_rect.origin.to_vector();
+            (
+                border_rect,
+                unit.result_padding_rect.translate(origin),
+                unit.result_content_rect.translate(origin),
+                border_rect.origin.y + unit.result_first_baseline_ascent.y,
+            )
+        };
+        let mut children = Vec::new();
+        node.tree_visitor().for_each_layout_child(|child, _| {
+            if is_display_none::<T>(child.style()) {
+                return;
+            }
+            children.push(Self::collect(child, border_rect.origin.to_vector(), label));
+        });
+        Self {
+            label: label(node),
+            border_rect,
+            padding_rect,
+            content_rect,
+            baseline,
+            children,
+        }
+    }
+
+    /// The union of the border boxes in the snapshot.
+    pub fn bounds(&self) -> Rect<L> {
+        self.children
+            .iter()
+            .fold(self.border_rect, |bounds, child| {
+                bounds.union(&child.bounds())
+            })
+    }
+
+    /// Write the snapshot as pretty-printed JSON.
+    ///
+    /// Numbers are rounded to two decimal places, so the output is stable across length types.
+    pub fn to_json(&self) -> String {
+        let mut ret = String::new();
+        self.write_json(&mut ret, 0);
+        ret.push('\n');
+        ret
+    }
+
+    fn write_json(&self, out: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth + 1);
+        out.push_str("{\n");
+        out.push_str(&indent);
+        out.push_str("\"label\": ");
+        write_json_str(out, &self.label);
+        out.push_str(",\n");
+        for (name, rect) in [
+            ("border", self.border_rect),
+            ("padding", self.padding_rect),
+            ("content", self.content_rect),
+        ] {
+            let _ = writeln!(
+                out,
+                "{}\"{}\": [{}, {}, {}, {}],",
+                indent,
+                name,
+                fmt_num(rect.min_x()),
+                fmt_num(rect.min_y()),
+                fmt_num(rect.width()),
+                fmt_num(rect.height()),
+            );
+        }
+        let _ = write!(
+            out,
+            "{}\"baseline\": {},\n{}\"children\": [",
+            indent,
+            fmt_num(self.baseline),
+            indent
+        );
+        for (index, child) in self.children.iter().enumerate() {
+            out.push_str(if index == 0 { "\n" } else { ",\n" });
+            out.push_str(&indent);
+            out.push_str("  ");
+            child.write_json(out, depth + 2);
+        }
+        if !self.children.is_empty() {
+            out.push('\n');
+            out.push_str(&indent);
+        }
+        out.push_str("]\n");
+        out.push_str(&"  ".repeat(depth));
+        out.push('}');
+    }
+
+    /// Write the snapshot as SVG.
+    ///
+    /// The border boxes are red, the padding boxes are green, the content boxes are blue,
+    /// and the baselines are magenta.
+    pub fn to_svg(&self) -> String {
+        let bounds = self.bounds();
+        let mut ret = String::new();
+        let _ = writeln!(
+            ret,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" width=\"{}\" height=\"{}\" font-family=\"monospace\" font-size=\"8\" fill=\"none\">",
+            fmt_num(bounds.min_x()),
+            fmt_num(bounds.min_y()),
+            fmt_num(bounds.width()),
+            fmt_num(bounds.height()),
+            fmt_num(bounds.width()),
+            fmt_num(bounds.height()),
+        );
+        self.write_svg(&mut ret);
+        ret.push_str("</svg>\n");
+        ret
+    }
+
+    fn write_svg(&self, out: &mut String) {
+        out.push_str("<g>\n");
+        for (rect, color) in [
+            (self.border_rect, "#e53935"),
+            (self.padding_rect, "#43a047"),
+            (self.content_rect, "#1e88e5"),
+        ] {
+            let _ = writeln!(
+                out,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" stroke=\"{}\" stroke-width=\"0.5\"/>",
+                fmt_num(rect.min_x()),
+                fmt_num(rect.min_y()),
+                fmt_num(rect.width()),
+                fmt_num(rect.height()),
+                color,
+            );
+        }
+        let _ = writeln!(
+            out,
+            "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#d81b60\" stroke-width=\"0.5\" stroke-dasharray=\"2 1\"/>",
+            fmt_num(self.border_rect.min_x()),
+            fmt_num(self.baseline),
+            fmt_num(self.border_rect.max_x()),
+            fmt_num(self.baseline),
+        );
+        let _ = write!(
+            out,
+            "<text x=\"{}\" y=\"{}\" fill=\"#424242\">",
+            fmt_num(self.border_rect.min_x() + L::from_i32(1)),
+            fmt_num(self.border_rect.min_y() + L::from_i32(8)),
+        );
+        write_xml_text(out, &self.label);
+        out.push_str("</text>\n");
+        for child in self.children.iter() {
+            child.write_svg(out);
+        }
+        out.push_str("</g>\n");
+    }
+}
+
+/// Format a number with at most two decimal places, without trailing zeros.
+fn fmt_num<L: LengthNum>(value: L) -> String {
+    let mut ret = format!("{:.2}", value.to_f32());
+    if ret.contains('.') {
+        let len = ret.trim_end_matches('0').trim_end_matches('.').len();
+        ret.truncate(len);
+    }
+    if ret == "-0" {
+        ret = String::from("0");
+    }
+    ret
+}
+
+fn write_json_str(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_xml_text(out: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '&' => out.push_str("&amp;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_numbers() {
+        assert_eq!(fmt_num(10f32), "10");
+        assert_eq!(fmt_num(10.5f32), "10.5");
+        assert_eq!(fmt_num(1. / 3f32), "0.33");
+        assert_eq!(fmt_num(-0.001f32), "0");
+    }
+
+    #[test]
+    fn escape_labels() {
+        let mut json = String::new();
+        write_json_str(&mut json, "a\"b\\\n");
+        assert_eq!(json, "\"a\\\"b\\\\\\n\"");
+        let mut xml = String::new();
+        write_xml_text(&mut xml, "<a & b>");
+        assert_eq!(xml, "&lt;a &amp; b&gt;");
+    }
+}
@@ -12,8 +12,8 @@ pub fn is_independent_positioning<T: LayoutTreeNode>(node: &T) -> bool {
 #[inline(always)]
 pub(crate) fn is_out_of_flow<T: LayoutTreeNode>(style: &T::Style) -> bool {
     match style.position() {
-        Position::Absolute | Position::Fixed | Position::Sticky => true,
-        Position::Static | Position::Relative => false,
+        Position::Absolute | Position::Fixed => true,
+        Position::Static | Position::Relative | Position::Sticky => false,
     }
 }
 
@@ -38,32 +38,113 @@ pub(crate) fn compute_special_position_children<T: LayoutTreeNode>(
         OptionNum::some(node_size.height - border.vertical()),
     ));
 
-    // TODO relative to non-static
-    let mut f = move |child_node: &T| {
+    let is_containing_block = establishes_absolute_containing_block(node);
+    let parent_info = || ParentInfo {
+        compute_result: node_result,
+        style,
+        inner_size: &node_inner_size,
+        inner_option_size: &node_inner_option_size,
+        border: &border,
+        padding_border: &padding_border,
+    };
+    LayoutUnit::clear_display_contents_children_result(node);
+    node.tree_visitor().for_each_layout_child(|child_node, _| {
         let child_style = child_node.style();
         if child_style.display() == Display::None {
             let mut child = child_node.layout_node().unit();
             child.clear_display_none_result(child_node);
             return;
         }
+        let position = child_style.position();
+        // laid out by the containing block instead
+        if position == Position::Absolute && !is_containing_block {
+            return;
+        }
         compute_special_position(
             env,
             child_node,
-            ParentInfo {
-                compute_result: node_result,
-                style,
-                inner_size: &node_inner_size,
-                inner_option_size: &node_inner_option_size,
-                border: &border,
-                padding_border: &padding_border,
-            },
+            parent_info(),
             axis_info,
             accept_flex_props,
-            child_style.position(),
+            position,
+            None,
         );
-    };
-    node.tree_visitor()
-        .for_each_child(|child_node, _| f(child_node));
+    });
+
+    // the absolutely positioned descendants inside the non-positioned children;
+    // their static positions are given by the block layout of their parents,
+    // or the content box start of their parents otherwise
+    if is_containing_block {
+        let axis_info = AxisInfo {
+            dir: AxisDirection::Vertical,
+            main_dir_rev: AxisReverse::NotReversed,
+            cross_dir_rev: AxisReverse::NotReversed,
+        };
+        for_each_nested_absolute(node, Vector::zero(), &mut |child_node, parent, offset| {
+            let static_position = child_node.layout_node().unit().static_position;
+            let static_offset = offset
+                + static_position.unwrap_or_else(|| {
+                    parent
+                        .layout_node()
+                        .unit()
+                        .result_content_rect
+                        .origin
+                        .to_vector()
+                });
+            compute_special_position(
+                env,
+                child_node,
+                parent_info(),
+                axis_info,
+                false,
+                Position::Absolute,
+                Some(static_offset),
+            );
+            child_node.layout_node().unit().result.origin -= offset;
+        });
+    }
+}
+
+/// Forget the static positions of the children, which are set again by the block layout of the node.
+pub(crate) fn clear_children_static_positions<T: LayoutTreeNode>(node: &T) {
+    node.tree_visitor().for_each_layout_child(|child_node, _| {
+        child_node.layout_node().unit().static_position = None;
+    });
+}
+
+/// CSS Positioned Layout §2.1: whether the node is the containing block of its absolutely positioned descendants.
+///
+/// It is the nearest positioned ancestor, or the tree root if there is no such ancestor.
+#[inline]
+fn establishes_absolute_containing_block<T: LayoutTreeNode>(node: &T) -> bool {
+    node.style().position() != Position::Static || node.tree_visitor().parent().is_none()
+}
+
+/// Visit the absolutely positioned descendants whose containing block is `node`,
+/// excluding the children of `node`.
+///
+/// The callback receives the descendant, its parent, and the offset of the parent border box in the `node` border box.
+fn for_each_nested_absolute<T: LayoutTreeNode>(
+    node: &T,
+    offset: Vector<T::Length>,
+    f: &mut impl FnMut(&T, &T, Vector<T::Length>),
+) {
+    node.tree_visitor().for_each_layout_child(|child_node, _| {
+        let style = child_node.style();
+        if is_display_none::<T>(style) || style.position() != Position::Static {
+            return;
+        }
+        let child_offset = offset + child_node.layout_node().unit().result.origin.to_vector();
+        child_node
+            .tree_visitor()
+            .for_each_layout_child(|grandchild, _| {
+                let style = grandchild.style();
+                if !is_display_none::<T>(style) && style.position() == Position::Absolute {
+                    f(grandchild, child_node, child_offset);
+                }
+            });
+        for_each_nested_absolute(child_node, child_offset, f);
+    });
 }
 
 pub(crate) struct ParentInfo<'a, T: LayoutTreeNode> {
@@ -83,12 +164,15 @@ pub(crate) fn compute_special_position<T: LayoutTreeNode>(
     axis_info: AxisInfo,
     accept_flex_props: bool,
     position: Position,
+    static_offset: Option<Vector<T::Length>>,
 ) {
-    if position == Position::Static {
+    // the sticky offset depends on scrolling, so it is applied by the host (see `StickyConstraint`)
+    if position == Position::Static || position == Position::Sticky {
         return;
     }
     let mut layout_unit = node.layout_node().unit();
     let style = node.style();
+    let static_offset = static_offset.or(layout_unit.static_position);
 
     let container_size = if position == Position::Fixed {
         Size::new(env.screen_width(), env.screen_height())
@@ -177,6 +261,7 @@ pub(crate) fn compute_special_position<T: LayoutTreeNode>(
                     kind: ComputeRequestKind::AllSize,
                     parent_is_block: false,
                     sizing_mode: SizingMode::Normal,
+                    line_clamp: None,
                 },
             )
             .size;
@@ -193,6 +278,7 @@ pub(crate) fn compute_special_position<T: LayoutTreeNode>(
                 kind: ComputeRequestKind::Position,
                 parent_is_block: false,
                 sizing_mode: SizingMode::Normal,
+                line_clamp: None,
             },
         )
     } else {
@@ -206,6 +292,7 @@ pub(crate) fn compute_special_position<T: LayoutTreeNode>(
                 kind: ComputeRequestKind::Position,
                 parent_is_block: false,
                 sizing_mode: SizingMode::Normal,
+                line_clamp: None,
             },
         )
     };
@@ -334,6 +421,8 @@ pub(crate) fn compute_special_position<T: LayoutTreeNode>(
                         + free_content_main_size.div_i32(2)
                 }
             }
+        } else if let Some(x) = static_offset {
+            x.main_axis(axis_info.dir)
         } else {
             parent
                 .padding_border
@@ -401,7 +490,10 @@ pub(crate) fn compute_special_position<T: LayoutTreeNode>(
                 | AlignSelf::Normal => parent
                     .padding_border
                     .cross_axis_start(axis_info.dir, axis_info.main_dir_rev),
-                AlignSelf::FlexEnd | AlignSelf::End | AlignSelf::SelfEnd => {
+                AlignSelf::FlexEnd
+                | AlignSelf::End
+                | AlignSelf::SelfEnd
+                | AlignSelf::LastBaseline => {
                     parent
                         .border
                         .cross_axis_start(axis_info.dir, axis_info.main_dir_rev)
@@ -435,6 +527,8 @@ pub(crate) fn compute_special_position<T: LayoutTreeNode>(
                     .padding_border
                     .cross_axis_start(axis_info.dir, axis_info.main_dir_rev),
             }
+        } else if let Some(x) = static_offset {
+            x.cross_axis(axis_info.dir)
         } else {
             parent
                 .padding_border
@@ -0,0 +1,214 @@
+//! Sticky positioning.
+//!
+//! CSS Positioned Layout §3.4 Sticky positioning
+//! <https://www.w3.org/TR/css-position-3/#stickypos-insets>
+//!
+//! A sticky box is laid out in flow. The sticky offset depends on the scroll position of
+//! the nearest scroll container, so it is not applied by the layout pass.
+//! Instead, the constraint is collected once after layout,
+//! and the host computes the offset for each scroll position with `StickyConstraint::sticky_offset`.
+
+use crate::*;
+
+/// The geometry needed to compute the sticky offset of a `position: sticky` box.
+///
+/// All rects are relative to the border box of the nearest scroll container,
+/// when the scroll container is not scrolled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StickyConstraint<L: LengthNum> {
+    /// The border rect of the sticky box, without the sticky offset.
+    pub border_rect: Rect<L>,
+    /// The content rect of the containing block, which the margin box of the sticky box should not leave.
+    pub containing_rect: Rect<L>,
+    /// The margins of the sticky box.
+    pub margin: Edge<L>,
+    /// The padding rect of the scroll container, i.e. the scrollport.
+    pub scrollport_rect: Rect<L>,
+    /// The `left`, `right`, `top`, and `bottom` insets resolved against the scrollport; `auto` is `None`.
+    pub inset: EdgeOption<L>,
+}
+
+/// Compute the offset in one axis.
+///
+/// If both the start and the end insets are specified, the start one wins.
+#[inline]
+fn sticky_axis_offset<L: LengthNum>(
+    start: L,
+    end: L,
+    view_start: Option<L>,
+    view_end: Option<L>,
+    containing_start: L,
+    containing_end: L,
+) -> L {
+    let mut offset = L::zero();
+    if let Some(view_end) = view_end {
+        if end > view_end {
+            offset = (view_end - end)
+                .max(containing_start - start)
+                .min(L::zero());
+        }
+    }
+    if let Some(view_start) = view_start {
+        if start + offset < view_start {
+            offset = (view_start - start)
+                .min(containing_end - end)
+                .max(L::zero());
+        }
+    }
+    offset
+}
+
+impl<L: LengthNum> StickyConstraint<L> {
+    /// Get the sticky offset when the scroll container is scrolled by `scroll_offset`.
+    ///
+    /// The result should be added to the layout position of the sticky box.
+    pub fn sticky_offset(&self, scroll_offset: Vector<L>) -> Vector<L> {
+        let view = self.scrollport_rect.translate(scroll_offset);
+        let b = &self.border_rect;
+        let c = &self.containing_rect;
+        let x = sticky_axis_offset(
+            b.min_x(),
+            b.max_x(),
+            self.inset.left.val().map(|x| view.min_x() + x),
+            self.inset.right.val().map(|x| view.max_x() - x),
+            c.min_x() + self.margin.left,
+            c.max_x() - self.margin.right,
+        );
+        let y = sticky_axis_offset(
+            b.min_y(),
+            b.max_y(),
+            self.inset.top.val().map(|x| view.min_y() + x),
+            self.inset.bottom.val().map(|x| view.max_y() - x),
+            c.min_y() + self.margin.top,
+            c.max_y() - self.margin.bottom,
+        );
+        Vector::new(x, y)
+    }
+}
+
+/// Whether the node is a scroll container.
+#[inline]
+fn is_scroll_container<T: LayoutTreeNode>(style: &T::Style) -> bool {
+    style.overflow_x() != Overflow::Visible || style.overflow_y() != Overflow::Visible
+}
+
+/// Collect the sticky constraint of a `position: sticky` node.
+///
+/// The scroll container is the nearest ancestor with non-`visible` overflow,
+/// or the tree root if there is no such ancestor.
+pub(crate) fn sticky_constraint<T: LayoutTreeNode>(
+    node: &T,
+) -> Option<StickyConstraint<T::Length>> {
+    let style = node.style();
+    if style.position() != Position::Sticky || is_display_none::<T>(style) {
+        return None;
+    }
+    let parent = node.tree_visitor().layout_parent()?;
+
+    // the offset of the parent border box in the scroll container border box
+    let mut parent_offset = Vector::zero();
+    let mut cur = parent;
+    let scroll_container = loop {
+        if is_scroll_container::<T>(cur.style()) {
+            break cur;
+        }
+        match cur.tree_visitor().layout_parent() {
+            Some(p) => {
+                parent_offset += cur.layout_node().unit().result.origin.to_vector();
+                cur = p;
+            }
+            None => break cur,
+        }
+    };
+
+    let (border_rect, margin) = {
+        let unit = node.layout_node().unit();
+        (
+            unit.result.translate(parent_offset),
+            unit.computed_style().margin,
+        )
+    };
+    let mut containing_rect = parent
+        .layout_node()
+        .unit()
+        .result_content_rect()
+        .translate(parent_offset);
+    if core::ptr::eq(parent, scroll_container) {
+        // the content of a scroll container can be scrolled to, so it extends to the scrollable overflow
+        if let Some(overflow) = descendants_overflow(parent, Vector::zero()) {
+            containing_rect = containing_rect.union(&overflow);
+        }
+    }
+    let scrollport_rect = scroll_container.layout_node().unit().result_padding_rect;
+    let size = scrollport_rect.size;
+    let inset = EdgeOption {
+        left: style.left().resolve_num(size.width, node),
+        right: style.right().resolve_num(size.width, node),
+        top: style.top().resolve_num(size.height, node),
+        bottom: style.bottom().resolve_num(size.height, node),
+    };
+    Some(StickyConstraint {
+        border_rect,
+        containing_rect,
+        margin,
+        scrollport_rect,
+        inset,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constraint(inset: EdgeOption<f32>) -> StickyConstraint<f32> {
+        StickyConstraint {
+            border_rect: Rect::new(Point::new(0., 100.), Size::new(100., 20.)),
+            containing_rect: Rect::new(Point::new(0., 100.), Size::new(100., 200.)),
+            margin: Edge {
+                left: 0.,
+                right: 0.,
+                top: 0.,
+                bottom: 10.,
+            },
+            scrollport_rect: Rect::new(Point::new(0., 0.), Size::new(100., 100.)),
+            inset,
+        }
+    }
+
+    #[test]
+    fn stick_to_top() {
+        let c = constraint(EdgeOption {
+            left: OptionNum::none(),
+            right: OptionNum::none(),
+            top: OptionNum::some(5.),
+            bottom: OptionNum::none(),
+        });
+        assert_eq!(c.sticky_offset(Vector::new(0., 0.)), Vector::new(0., 0.));
+        assert_eq!(c.sticky_offset(Vector::new(0., 95.)), Vector::new(0., 0.));
+        assert_eq!(c.sticky_offset(Vector::new(0., 150.)), Vector::new(0., 55.));
+        // stops at the end of the containing block
+        assert_eq!(
+            c.sticky_offset(Vector::new(0., 400.)),
+            Vector::new(0., 170.)
+        );
+    }
+
+    #[test]
+    fn stick_to_bottom() {
+        let c = constraint(EdgeOption {
+            left: OptionNum::none(),
+            right: OptionNum::none(),
+            top: OptionNum::none(),
+            bottom: OptionNum::some(0.),
+        });
+        assert_eq!(c.sticky_offset(Vector::new(0., 0.)), Vector::new(0., 0.));
+        let mut c = c;
+        c.border_rect.origin.y = 250.;
+        assert_eq!(c.sticky_offset(Vector::new(0., 0.)), Vector::new(0., -150.));
+        assert_eq!(
+            c.sticky_offset(Vector::new(0., 100.)),
+            Vector::new(0., -70.)
+        );
+        assert_eq!(c.sticky_offset(Vector::new(0., 200.)), Vector::new(0., 0.));
+    }
+}
@@ -0,0 +1,320 @@
+//! Layout instrumentation.
+//!
+//! A `LayoutTrace` collects statistics of layout passes: the compute calls of each node,
+//! the cache hits and misses, the measure function invocations, and the time spent in each layout algorithm.
+//! It is owned by the host outside the layout environment and provided through `LayoutTreeNode::layout_trace`,
+//! so that the environment itself can stay a plain value.
+//! Nothing is collected if it returns `None` (the default).
+
+use crate::*;
+use hashbrown::HashMap;
+
+/// The kind of a compute request, which is a part of the cache key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayoutRequestKind {
+    /// Compute the size in the row (horizontal) direction.
+    RowSize,
+    /// Compute the size in the column (vertical) direction.
+    ColSize,
+    /// Compute the size in both directions.
+    AllSize,
+    /// Compute the size and the positions of the children.
+    Position,
+}
+
+impl From<ComputeRequestKind> for LayoutRequestKind {
+    fn from(kind: ComputeRequestKind) -> Self {
+        match kind {
+            ComputeRequestKind::RowSize => Self::RowSize,
+            ComputeRequestKind::ColSize => Self::ColSize,
+            ComputeRequestKind::AllSize => Self::AllSize,
+            ComputeRequestKind::Position => Self::Position,
+        }
+    }
+}
+
+/// The cache key of a compute request, by which the cache hits and misses are bucketed.
+///
+/// The lengths are in the hashable form of the length type (see `LengthNum::to_hashable`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayoutCacheKey<H> {
+    /// The request kind.
+    pub kind: LayoutRequestKind,
+    /// The requested size.
+    pub size: OptionSize<H>,
+    /// The max-content size.
+    pub max_content: OptionSize<H>,
+    /// The parent inner size, which is undetermined if the node does not depend on it.
+    pub parent_inner_size: OptionSize<H>,
+    /// The sizing mode, which is not a part of the key of the position requests.
+    pub sizing_mode: Option<SizingMode>,
+}
+
+/// The kind of a measure function invocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayoutMeasureKind {
+    /// `LayoutTreeNode::measure_block_size`.
+    Block,
+    /// `LayoutTreeNode::measure_inline_unit`.
+    InlineUnit,
+    /// `InlineMeasure::block_size*` on an inline formatting context, recorded on the block container.
+    InlineContent,
+}
+
+/// The cache hits and misses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LayoutCacheStats {
+    /// The number of requests answered by the cache.
+    pub hits: u64,
+    /// The number of requests actually computed.
+    pub misses: u64,
+}
+
+/// The statistics of a node.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayoutNodeTraceStats {
+    /// The number of compute calls.
+    pub compute_calls: u64,
+    /// The cache hits and misses.
+    pub cache: LayoutCacheStats,
+    /// The measure function invocations of each kind.
+    pub measure_calls: HashMap<LayoutMeasureKind, u64>,
+}
+
+/// The statistics of a layout algorithm.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LayoutAlgorithmTraceStats {
+    /// The number of passes, i.e. the compute calls which are not answered by the cache.
+    pub passes: u64,
+    /// The time spent in this algorithm, excluding the time spent in the child nodes.
+    ///
+    /// It is in the unit of the clock, and is always zero if no clock is given.
+    pub self_time: u64,
+}
+
+/// The summary of the traced layout passes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutTraceSummary<H: Eq + core::hash::Hash> {
+    /// The total number of compute calls.
+    pub compute_calls: u64,
+    /// The cache hits and misses by the cache key.
+    pub cache: HashMap<LayoutCacheKey<H>, LayoutCacheStats>,
+    /// The statistics of each node, keyed by the address of the tree node.
+    pub nodes: HashMap<usize, LayoutNodeTraceStats>,
+    /// The statistics of each layout algorithm.
+    pub algorithms: HashMap<LayoutAlgorithm, LayoutAlgorithmTraceStats>,
+}
+
+impl<H: Eq + core::hash::Hash> Default for LayoutTraceSummary<H> {
+    fn default() -> Self {
+        Self {
+            compute_calls: 0,
+            cache: HashMap::new(),
+            nodes: HashMap::new(),
+            algorithms: HashMap::new(),
+        }
+    }
+}
+
+impl<H: Eq + core::hash::Hash> LayoutTraceSummary<H> {
+    /// The total cache hits and misses.
+    pub fn total_cache(&self) -> LayoutCacheStats {
+        sum_cache_stats(self.cache.values())
+    }
+
+    /// The cache hits and misses of all the keys of the request kind.
+    pub fn cache_by_kind(&self, kind: LayoutRequestKind) -> LayoutCacheStats {
+        sum_cache_stats(
+            self.cache
+                .iter()
+                .filter(|(k, _)| k.kind == kind)
+                .map(|(_, v)| v),
+        )
+    }
+
+    /// The total measure function invocations.
+    pub fn total_measure_calls(&self) -> u64 {
+        self.nodes
+            .values()
+            .flat_map(|x| x.measure_calls.values())
+            .sum()
+    }
+}
+
+fn sum_cache_stats<'a>(stats: impl Iterator<Item = &'a LayoutCacheStats>) -> LayoutCacheStats {
+    stats.fold(LayoutCacheStats::default(), |acc, x| LayoutCacheStats {
+        hits: acc.hits + x.hits,
+        misses: acc.misses + x.misses,
+    })
+}
+
+/// A trace event, recorded if `LayoutTrace::record_events` is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutTraceEvent {
+    /// A compute call finished.
+    Compute {
+        /// The address of the tree node.
+        node: usize,
+        /// The nesting depth of compute calls.
+        depth: usize,
+        /// The request kind.
+        kind: LayoutRequestKind,
+        /// Whether the request is answered by the cache.
+        cache_hit: bool,
+        /// The layout algorithm of the node.
+        algorithm: LayoutAlgorithm,
+        /// The time spent, including the child nodes.
+        time: u64,
+    },
+    /// A measure function is invoked.
+    Measure {
+        /// The address of the tree node.
+        node: usize,
+        /// The measure kind.
+        kind: LayoutMeasureKind,
+    },
+}
+
+#[derive(Debug)]
+struct ComputeFrame {
+    start: u64,
+    child_time: u64,
+}
+
+/// A collector of layout statistics.
+#[derive(Debug)]
+pub struct LayoutTrace<L: LengthNum> {
+    clock: Option<fn() -> u64>,
+    events: Option<Vec<LayoutTraceEvent>>,
+    stack: Vec<ComputeFrame>,
+    summary: LayoutTraceSummary<L::Hashable>,
+}
+
+impl<L: LengthNum> LayoutTrace<L> {
+    /// Create a collector.
+    ///
+    /// The `clock` returns a monotonic timestamp (e.g. in nanoseconds) and is used to measure the time of each algorithm.
+    /// Timing is disabled if it is `None`.
+    pub fn new(clock: Option<fn() -> u64>) -> Self {
+        Self {
+            clock,
+            events: None,
+            stack: Vec::new(),
+            summary: LayoutTraceSummary::default(),
+        }
+    }
+
+    /// Enable or disable the trace event recording.
+    pub fn record_events(&mut self, enabled: bool) {
+        match (enabled, self.events.is_some()) {
+            (true, false) => self.events = Some(Vec::new()),
+            (false, true) => self.events = None,
+            _ => {}
+        }
+    }
+
+    /// Get the recorded trace events.
+    pub fn events(&self) -> &[LayoutTraceEvent] {
+        self.events.as_deref().unwrap_or_default()
+    }
+
+    /// Get the summary collected so far.
+    pub fn summary(&self) -> &LayoutTraceSummary<L::Hashable> {
+        &self.summary
+    }
+
+    /// Take the summary, and clear the recorded events.
+    pub fn take_summary(&mut self) -> LayoutTraceSummary<L::Hashable> {
+        if let Some(events) = self.events.as_mut() {
+            events.clear();
+        }
+        core::mem::take(&mut self.summary)
+    }
+
+    fn now(&self) -> u64 {
+        self.clock.map(|f| f()).unwrap_or(0)
+    }
+
+    pub(crate) fn begin_compute(&mut self) {
+        let start = self.now();
+        self.stack.push(ComputeFrame {
+            start,
+            child_time: 0,
+        });
+    }
+
+    pub(crate) fn end_compute(
+        &mut self,
+        node: usize,
+        key: LayoutCacheKey<L::Hashable>,
+        cache_hit: bool,
+        algorithm: LayoutAlgorithm,
+    ) {
+        let now = self.now();
+        let Some(frame) = self.stack.pop() else {
+            return;
+        };
+        let time = now.saturating_sub(frame.start);
+        if let Some(parent) = self.stack.last_mut() {
+            parent.child_time += time;
+        }
+        let kind = key.kind;
+        let summary = &mut self.summary;
+        summary.compute_calls += 1;
+        let cache = summary.cache.entry(key).or_default();
+        let node_stats = summary.nodes.entry(node).or_default();
+        node_stats.compute_calls += 1;
+        if cache_hit {
+            cache.hits += 1;
+            node_stats.cache.hits += 1;
+        } else {
+            cache.misses += 1;
+            node_stats.cache.misses += 1;
+            let algorithm_stats = summary.algorithms.entry(algorithm).or_default();
+            algorithm_stats.passes += 1;
+            algorithm_stats.self_time += time.saturating_sub(frame.child_time);
+        }
+        if let Some(events) = self.events.as_mut() {
+            events.push(LayoutTraceEvent::Compute {
+                node,
+                depth: self.stack.len(),
+                kind,
+                cache_hit,
+                algorithm,
+                time,
+            });
+        }
+    }
+
+    pub(crate) fn measure(&mut self, node: usize, kind: LayoutMeasureKind) {
+        *self
+            .summary
+            .nodes
+            .entry(node)
+            .or_default()
+            .measure_calls
+            .entry(kind)
+            .or_default() += 1;
+        if let Some(events) = self.events.as_mut() {
+            events.push(LayoutTraceEvent::Measure { node, kind });
+        }
+    }
+}
+
+/// The key of a tree node in the trace.
+#[inline]
+pub(crate) fn trace_node_key<T>(node: &T) -> usize {
+    node as *const T as usize
+}
+
+/// Record a measure function invocation if the layout is traced.
+#[inline]
+pub(crate) fn trace_measure<T: LayoutTreeNode>(
+    env: &mut T::Env,
+    node: &T,
+    kind: LayoutMeasureKind,
+) {
+    if let Some(trace) = T::layout_trace(env) {
+        trace.measure(trace_node_key(node), kind);
+    }
+}
@@ -1,6 +1,6 @@
 use core::{fmt::Display, ops::Deref};
 
-use alloc::{string::String, vec::Vec};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use euclid::UnknownUnit;
 use float_pigment_css::typing::{Direction, WritingMode};
 
@@ -193,6 +193,27 @@ pub enum DefLength<L: LengthNum, T: PartialEq + Clone = i32> {
     ///
     /// Will be resolved by `LayoutTreeNode::resolve_custom_length`.
     Custom(T),
+
+    /// The min-content size.
+    ///
+    /// The content keywords only apply to the inline axis of the node.
+    /// In the block axis, they behave as `auto`.
+    MinContent,
+
+    /// The max-content size.
+    MaxContent,
+
+    /// The fit-content size, limited by the available space.
+    FitContent,
+
+    /// The fit-content size, limited by a fixed value.
+    FitContentPoints(L),
+
+    /// The fit-content size, limited by a ratio of the containing block.
+    FitContentPercent(f32),
+
+    /// Fill the available space.
+    Stretch,
 }
 
 impl<L: LengthNum, T: PartialEq + Display + Clone> Display for DefLength<L, T> {
@@ -203,11 +224,33 @@ impl<L: LengthNum, T: PartialEq + Display + Clone> Display for DefLength<L, T> {
             Self::Points(x) => write!(f, "Points({})", L::to_f32(*x)),
             Self::Percent(x) => write!(f, "Percent({})", *x),
             Self::Custom(x) => write!(f, "Custom({})", *x),
+            Self::MinContent => write!(f, "MinContent"),
+            Self::MaxContent => write!(f, "MaxContent"),
+            Self::FitContent => write!(f, "FitContent"),
+            Self::FitContentPoints(x) => write!(f, "FitContentPoints({})", L::to_f32(*x)),
+            Self::FitContentPercent(x) => write!(f, "FitContentPercent({})", *x),
+            Self::Stretch => write!(f, "Stretch"),
         }
     }
 }
 
 impl<L: LengthNum, T: PartialEq + Clone> DefLength<L, T> {
+    /// Whether the length is an intrinsic sizing keyword.
+    ///
+    /// These lengths are resolved against the intrinsic sizes of the node (see the `intrinsic` module),
+    /// and they are treated as `auto` by the `resolve*` methods.
+    pub fn is_intrinsic(&self) -> bool {
+        matches!(
+            self,
+            Self::MinContent
+                | Self::MaxContent
+                | Self::FitContent
+                | Self::FitContentPoints(_)
+                | Self::FitContentPercent(_)
+                | Self::Stretch
+        )
+    }
+
     pub(crate) fn resolve<N: LayoutTreeNode<Length = L, LengthCustom = T>>(
         &self,
         parent: OptionNum<L>,
@@ -221,6 +264,7 @@ impl<L: LengthNum, T: PartialEq + Clone> DefLength<L, T> {
             Self::Custom(x) => {
                 OptionNum::some(node.resolve_custom_length(x, parent.unwrap_or(L::zero())))
             }
+            _ => OptionNum::none(),
         }
     }
 
@@ -237,6 +281,7 @@ impl<L: LengthNum, T: PartialEq + Clone> DefLength<L, T> {
             Self::Custom(x) => {
                 OptionNum::some(node.resolve_custom_length(x, parent.unwrap_or(L::zero())))
             }
+            _ => OptionNum::none(),
         }
     }
 
@@ -251,6 +296,7 @@ impl<L: LengthNum, T: PartialEq + Clone> DefLength<L, T> {
             Self::Points(x) => OptionNum::some(*x),
             Self::Percent(x) => OptionNum::some(parent.mul_f32(*x)),
             Self::Custom(x) => OptionNum::some(node.resolve_custom_length(x, parent)),
+            _ => OptionNum::none(),
         }
     }
 }
@@ -1062,6 +1108,10 @@ pub enum LayoutGridTemplate<L: LengthNum, T: PartialEq + Clone = i32> {
     #[default]
     None,
     TrackList(Vec<LayoutTrackListItem<L, T>>),
+    Subgrid(Vec<Vec<String>>),
+    /// CSS Grid 3: the masonry axis of a masonry layout.
+    /// <https://www.w3.org/TR/css-grid-3/#masonry-layout>
+    Masonry,
 }
 
 #[allow(missing_docs)]
@@ -1069,23 +1119,37 @@ pub enum LayoutGridTemplate<L: LengthNum, T: PartialEq + Clone = i32> {
 pub enum LayoutTrackListItem<L: LengthNum, T: PartialEq + Clone = i32> {
     LineNames(Vec<String>),
     TrackSize(LayoutTrackSize<L, T>),
+    /// CSS Grid §7.2.3: the `repeat()` notation.
+    /// <https://www.w3.org/TR/css-grid-1/#repeat-notation>
+    Repeat(LayoutRepeatCount, Vec<LayoutTrackListItem<L, T>>),
 }
 
 impl<L: LengthNum, T: PartialEq + Clone> LayoutTrackListItem<L, T> {
     /// Returns `true` if this item is a track size with an intrinsic sizing
     /// `auto`, `min-content`, `max-content` are intrinsic.
+    ///
+    /// A `repeat()` item is intrinsic if any of its repeated tracks is intrinsic.
     pub fn is_intrinsic(&self) -> bool {
-        matches!(
-            self,
-            LayoutTrackListItem::TrackSize(
-                LayoutTrackSize::Length(DefLength::Auto)
-                    | LayoutTrackSize::MinContent
-                    | LayoutTrackSize::MaxContent
-            )
-        )
+        match self {
+            LayoutTrackListItem::Repeat(_, list) => list.iter().any(|x| x.is_intrinsic()),
+            LayoutTrackListItem::TrackSize(track_size) => track_size.is_intrinsic(),
+            LayoutTrackListItem::LineNames(_) => false,
+        }
     }
 }
 
+/// CSS Grid §7.2.3: the repetition count of the `repeat()` notation.
+/// <https://www.w3.org/TR/css-grid-1/#repeat-syntax>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutRepeatCount {
+    /// Repeat the tracks a fixed number of times.
+    Count(u32),
+    /// Repeat as many times as fit in the grid container.
+    AutoFill,
+    /// Like `AutoFill`, but empty repeated tracks are collapsed.
+    AutoFit,
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum LayoutTrackSize<L: LengthNum, T: PartialEq + Clone = i32> {
@@ -1093,6 +1157,28 @@ pub enum LayoutTrackSize<L: LengthNum, T: PartialEq + Clone = i32> {
     MaxContent,
     Fr(f32),
     Length(DefLength<L, T>),
+    /// CSS Grid §7.2.4: `minmax(min, max)`, with separated min and max track sizing functions.
+    /// <https://www.w3.org/TR/css-grid-1/#valdef-grid-template-columns-minmax>
+    MinMax(Box<LayoutTrackSize<L, T>>, Box<LayoutTrackSize<L, T>>),
+    /// CSS Grid §7.2.4: `fit-content(limit)`, i.e. `minmax(auto, max-content)` clamped by `limit`.
+    /// <https://www.w3.org/TR/css-grid-1/#valdef-grid-template-columns-fit-content>
+    FitContent(DefLength<L, T>),
+}
+
+impl<L: LengthNum, T: PartialEq + Clone> LayoutTrackSize<L, T> {
+    /// Returns `true` if the min or the max track sizing function is intrinsic.
+    ///
+    /// `auto`, `min-content`, `max-content` and `fit-content()` are intrinsic.
+    pub fn is_intrinsic(&self) -> bool {
+        match self {
+            LayoutTrackSize::Length(DefLength::Auto)
+            | LayoutTrackSize::MinContent
+            | LayoutTrackSize::MaxContent
+            | LayoutTrackSize::FitContent(_) => true,
+            LayoutTrackSize::MinMax(min, max) => min.is_intrinsic() || max.is_intrinsic(),
+            LayoutTrackSize::Length(_) | LayoutTrackSize::Fr(_) => false,
+        }
+    }
 }
 
 /// CSS Grid §7.6: Implicit Track Sizing
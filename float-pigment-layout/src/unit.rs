@@ -7,8 +7,34 @@ pub(crate) struct LayoutUnit<T: LayoutTreeNode> {
     pub(crate) result: Rect<T::Length>,
     pub(crate) result_padding_rect: Rect<T::Length>,
     pub(crate) result_content_rect: Rect<T::Length>,
+    pub(crate) result_column_rects: Vec<Rect<T::Length>>,
+    pub(crate) result_marker_rect: Option<Rect<T::Length>>,
+    pub(crate) result_fragments: Vec<LayoutFragment<T::Length>>,
+    pub(crate) result_line_shifts: Vec<T::Length>,
+    pub(crate) result_first_baseline_ascent: Vector<T::Length>,
+    pub(crate) intrinsic_sizes: Option<IntrinsicSizes<T::Length>>,
+    pub(crate) children_prepared: bool,
     pub(crate) computed_style: ComputedStyle<T::Length>,
     pub(crate) layout_algorithm: LayoutAlgorithm,
+    pub(crate) relayout_boundary: bool,
+    pub(crate) pending_relayout: bool,
+    pub(crate) has_dirty_boundary: bool,
+    pub(crate) last_position: Option<(ComputeRequest<T::Length>, ComputeResult<T::Length>)>,
+    pub(crate) virtualization: Option<LayoutVirtualization<T::Length>>,
+    pub(crate) result_virtualized: Option<LayoutVirtualizedResult<T::Length>>,
+    pub(crate) virtualized_extent: Option<T::Length>,
+    pub(crate) virtualized_placeholder: bool,
+    pub(crate) journal: Option<Vec<LayoutJournalEntry<T::Length>>>,
+    pub(crate) journal_committed: Option<LayoutJournalValues<T::Length>>,
+    pub(crate) journal_touched: bool,
+    pub(crate) journal_descendant_touched: bool,
+    pub(crate) subgrid_tracks: algo::grid::subgrid::AdoptedTracks<T::Length, T::LengthCustom>,
+    pub(crate) subgrid_track_sizes: Option<algo::grid::subgrid::SubgridTrackSizes<T::Length>>,
+    pub(crate) float_context: Option<algo::float::FloatContext<T::Length>>,
+    pub(crate) list_item_ordinal: Option<i32>,
+    /// The static position of an absolutely positioned box relative to the border box of its parent,
+    /// set by the block layout of the parent.
+    pub(crate) static_position: Option<Vector<T::Length>>,
 }
 
 impl<T: LayoutTreeNode> LayoutUnit<T> {
@@ -18,8 +44,32 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
             result: Rect::zero(),
             result_padding_rect: Rect::zero(),
             result_content_rect: Rect::zero(),
+            result_column_rects: Vec::new(),
+            result_marker_rect: None,
+            result_fragments: Vec::new(),
+            result_line_shifts: Vec::new(),
+            result_first_baseline_ascent: Vector::zero(),
+            intrinsic_sizes: None,
+            children_prepared: false,
             computed_style: ComputedStyle::default(),
             layout_algorithm: LayoutAlgorithm::None,
+            relayout_boundary: false,
+            pending_relayout: false,
+            has_dirty_boundary: false,
+            last_position: None,
+            virtualization: None,
+            result_virtualized: None,
+            virtualized_extent: None,
+            virtualized_placeholder: false,
+            journal: None,
+            journal_committed: None,
+            journal_touched: false,
+            journal_descendant_touched: false,
+            subgrid_tracks: algo::grid::subgrid::AdoptedTracks::default(),
+            subgrid_track_sizes: None,
+            float_context: None,
+            list_item_ordinal: None,
+            static_position: None,
         }
     }
 
@@ -30,18 +80,46 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
         let mut cur = node_tree_visitor;
         while let Some(parent) = cur.parent() {
             parent.tree_visitor().dirty_marked();
-            if !parent.layout_node().unit().mark_self_dirty() {
+            // a `display: contents` node is never laid out, so its cache is always clean;
+            // the propagation should continue to its layout parent
+            let mut parent_unit = parent.layout_node().unit();
+            if !parent_unit.mark_self_dirty() && parent.style().display() != Display::Contents {
                 break;
             }
+            // the size of a relayout boundary does not depend on the descendants,
+            // so it can be laid out alone
+            if parent_unit.relayout_boundary {
+                parent_unit.pending_relayout = true;
+                drop(parent_unit);
+                mark_dirty_boundary_ancestors(parent);
+                break;
+            }
+            drop(parent_unit);
             cur = parent.tree_visitor();
         }
         true
     }
 
-    fn mark_self_dirty(&mut self) -> bool {
+    pub(crate) fn mark_self_dirty(&mut self) -> bool {
+        self.intrinsic_sizes = None;
+        self.children_prepared = false;
+        self.list_item_ordinal = None;
         self.cache.clear()
     }
 
+    /// Use an estimated result for a child of a virtualized list which is not laid out.
+    pub(crate) fn set_virtualized_placeholder(&mut self, rect: Rect<T::Length>) {
+        // the position cache cannot be used any more, since the results are overwritten
+        self.cache.clear_position_cache();
+        self.result = rect;
+        self.result_padding_rect = Rect::new(Point::zero(), rect.size);
+        self.result_content_rect = Rect::new(Point::zero(), rect.size);
+        self.relayout_boundary = false;
+        self.last_position = None;
+        self.virtualized_placeholder = true;
+        self.journal_touched = true;
+    }
+
     #[inline]
     pub(crate) fn result(&self) -> Rect<T::Length> {
         self.result
@@ -65,6 +143,8 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
         available_size: OptionSize<T::Length>,
         containing_size: OptionSize<T::Length>,
     ) {
+        self.prepare_intrinsic_sizes(env, node);
+        self.clear_fragments(node);
         let (margin, border, padding_border) = self.margin_border_padding(node, containing_size);
         let min_max_limit =
             self.normalized_min_max_limit(node, containing_size, border, padding_border);
@@ -91,6 +171,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
                 || node.style().display() == Display::InlineBlock
                 || node.style().display() == Display::Inline,
             sizing_mode: SizingMode::Normal,
+            line_clamp: None,
         };
         let result = self.compute_internal(env, node, req);
         self.result = Rect::new(
@@ -107,6 +188,8 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
 
     #[inline]
     pub(crate) fn compute(&mut self, env: &mut T::Env, node: &T, size: OptionSize<T::Length>) {
+        self.prepare_intrinsic_sizes(env, node);
+        self.clear_fragments(node);
         let size = Normalized(size);
         let req = ComputeRequest {
             size,
@@ -115,6 +198,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
             kind: ComputeRequestKind::Position,
             parent_is_block: false,
             sizing_mode: SizingMode::Normal,
+            line_clamp: None,
         };
         let result = self.compute_internal(env, node, req);
         self.result = Rect::new(Point::zero(), result.size.0);
@@ -132,7 +216,15 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
         self.result = Rect::zero();
         self.result_padding_rect = Rect::zero();
         self.result_content_rect = Rect::zero();
+        self.result_column_rects.clear();
+        self.result_marker_rect = None;
+        self.result_first_baseline_ascent = Vector::zero();
         self.layout_algorithm = LayoutAlgorithm::None;
+        self.relayout_boundary = false;
+        self.last_position = None;
+        self.result_virtualized = None;
+        self.virtualized_placeholder = false;
+        self.journal_touched = true;
         node.tree_visitor().for_each_child(|child_node, _| {
             child_node
                 .layout_node()
@@ -141,6 +233,28 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
         });
     }
 
+    /// Reset the results of the `display: contents` children (recursively),
+    /// since they do not generate boxes.
+    pub(crate) fn clear_display_contents_children_result(node: &T) {
+        node.tree_visitor().for_each_child(|child_node, _| {
+            if child_node.style().display() != Display::Contents {
+                return;
+            }
+            let mut child = child_node.layout_node().unit();
+            child.cache.clear_position_cache();
+            child.result = Rect::zero();
+            child.result_padding_rect = Rect::zero();
+            child.result_content_rect = Rect::zero();
+            child.result_column_rects.clear();
+            child.result_marker_rect = None;
+            child.result_first_baseline_ascent = Vector::zero();
+            child.layout_algorithm = LayoutAlgorithm::None;
+            child.journal_touched = true;
+            drop(child);
+            Self::clear_display_contents_children_result(child_node);
+        });
+    }
+
     pub(crate) fn compute_internal(
         &mut self,
         env: &mut T::Env,
@@ -158,15 +272,66 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
             // The BFC behavior is handled by establishes_bfc() + bfc_established
             // short-circuit; the layout path is identical to Block.
             Display::FlowRoot => LayoutAlgorithm::Block,
+            // CSS Display 3 §2.5: a `display: contents` node is only laid out directly
+            // if it is the root, which is treated as a block container.
+            Display::Contents => LayoutAlgorithm::Block,
+            // CSS Display 3 §2.6: a list item is a block container with a marker box,
+            // which is positioned by the flow layout.
+            Display::ListItem => LayoutAlgorithm::Block,
+            Display::Table => LayoutAlgorithm::Table,
+            // CSS 2.1 §17.2.1: a misparented row or row group is wrapped in an anonymous table
+            Display::TableRow | Display::TableRowGroup | Display::TableHeaderGroup => {
+                if algo::table::is_anonymous_table_root(node) {
+                    LayoutAlgorithm::Table
+                } else {
+                    LayoutAlgorithm::Block
+                }
+            }
+            Display::TableCell | Display::TableCaption | Display::TableColumn => {
+                LayoutAlgorithm::Block
+            }
         };
+        if layout_algorithm == LayoutAlgorithm::Block
+            && algo::multicol::is_multicol_container::<T>(style)
+        {
+            layout_algorithm = LayoutAlgorithm::Multicol;
+        }
+        if matches!(
+            layout_algorithm,
+            LayoutAlgorithm::Block | LayoutAlgorithm::Flex
+        ) && algo::virtualized::is_virtualized_container::<T>(
+            style,
+            self.virtualization.as_ref(),
+        ) {
+            layout_algorithm = LayoutAlgorithm::Virtualized;
+        }
 
-        let ret = if let Some(r) = self.cache.read(node, &request) {
+        if let Some(trace) = T::layout_trace(env) {
+            trace.begin_compute();
+        }
+        let mut cache_hit = false;
+        // the result depends on the floats handed over by the parent (see `FloatContext::nested`)
+        let cached = if self.float_context.is_some() {
+            None
+        } else {
+            self.cache.read(node, &request)
+        };
+        let ret = if let Some(r) = cached {
+            cache_hit = true;
             // if cached, use the cache value
             // info!("!!! {:p} cache req {:?}", self, request);
             r
         } else {
             // do request
             // info!("!!! {:p} req {:?}", self, request);
+            // the layout children are prepared once until this node is marked dirty,
+            // which also happens when any of the children is marked dirty
+            if !self.children_prepared {
+                self.children_prepared = true;
+                Self::prepare_children_intrinsic_sizes(env, node);
+                algo::list::prepare_children_list_ordinals(node);
+                clear_children_static_positions(node);
+            }
             let (margin, border, padding_border) =
                 self.margin_border_padding(node, *request.parent_inner_size);
             if let Some(ret) = self.compute_measure_block_if_exists(
@@ -188,6 +353,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
                             first_baseline_ascent: Vector::zero(),
                             last_baseline_ascent: Vector::zero(),
                             collapsed_margin: CollapsedBlockMargin::zero(),
+                            line_count: 0,
                         }
                     }
                     LayoutAlgorithm::Block => algo::flow::Flow::compute(
@@ -217,6 +383,33 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
                         border,
                         padding_border,
                     ),
+                    LayoutAlgorithm::Multicol => algo::multicol::Multicol::compute(
+                        self,
+                        env,
+                        node,
+                        request.clone(),
+                        margin,
+                        border,
+                        padding_border,
+                    ),
+                    LayoutAlgorithm::Virtualized => algo::virtualized::Virtualized::compute(
+                        self,
+                        env,
+                        node,
+                        request.clone(),
+                        margin,
+                        border,
+                        padding_border,
+                    ),
+                    LayoutAlgorithm::Table => algo::table::Table::compute(
+                        self,
+                        env,
+                        node,
+                        request.clone(),
+                        margin,
+                        border,
+                        padding_border,
+                    ),
                     _ => unreachable!(),
                 }
             }
@@ -224,6 +417,18 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
 
         if request.kind == ComputeRequestKind::Position {
             self.save_all_results(node, env, *request.parent_inner_size, layout_algorithm);
+            self.result_first_baseline_ascent = ret.first_baseline_ascent;
+            self.relayout_boundary = is_relayout_boundary(node);
+            self.last_position = Some((request.clone(), ret));
+            self.virtualized_placeholder = false;
+        }
+        if let Some(trace) = T::layout_trace(env) {
+            trace.end_compute(
+                trace_node_key(node),
+                self.cache.trace_key(node, &request),
+                cache_hit,
+                layout_algorithm,
+            );
         }
         // info!("!!! {:p} res {:?} {:?}", self, request, ret);
         ret
@@ -240,6 +445,16 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
         self.save_border_padding_result(border, padding_border);
         self.save_computed_style(margin, border, padding_border - border);
         self.layout_algorithm = layout_algorithm;
+        self.journal_touched = true;
+        if layout_algorithm != LayoutAlgorithm::Multicol {
+            self.result_column_rects.clear();
+        }
+        if layout_algorithm != LayoutAlgorithm::Virtualized {
+            self.result_virtualized = None;
+        }
+        if node.style().display() != Display::ListItem {
+            self.result_marker_rect = None;
+        }
         node.size_updated(env, self.result.size, &self.computed_style);
     }
 
@@ -323,6 +538,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
                             first_baseline_ascent: size.to_vector(),
                             last_baseline_ascent: size.to_vector(),
                             collapsed_margin,
+                            line_count: 0,
                         });
                     }
                 }
@@ -335,6 +551,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
                         first_baseline_ascent: size.to_vector(),
                         last_baseline_ascent: size.to_vector(),
                         collapsed_margin,
+                        line_count: 0,
                     });
                 }
             }
@@ -346,6 +563,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
                         first_baseline_ascent: size.to_vector(),
                         last_baseline_ascent: size.to_vector(),
                         collapsed_margin,
+                        line_count: 0,
                     });
                 }
             }
@@ -489,7 +707,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
             min_height,
             max_height,
         } = Self::min_max_size(node, parent_size);
-        match style.box_sizing() {
+        let mut ret = match style.box_sizing() {
             BoxSizing::BorderBox => {
                 let min_width = padding_border.horizontal().maybe_max(min_width);
                 let min_height = padding_border.vertical().maybe_max(min_height);
@@ -530,7 +748,31 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
                     max_height,
                 }
             }
-        }
+        };
+        let min = self.apply_intrinsic_sizing(
+            node,
+            (style.min_width(), style.min_height()),
+            OptionSize::new(
+                OptionNum::some(ret.min_width),
+                OptionNum::some(ret.min_height),
+            ),
+            parent_size,
+            border,
+            padding_border,
+        );
+        ret.min_width = padding_border.horizontal().maybe_max(min.width);
+        ret.min_height = padding_border.vertical().maybe_max(min.height);
+        let max = self.apply_intrinsic_sizing(
+            node,
+            (style.max_width(), style.max_height()),
+            OptionSize::new(ret.max_width, ret.max_height),
+            parent_size,
+            border,
+            padding_border,
+        );
+        ret.max_width = max.width;
+        ret.max_height = max.height;
+        ret
     }
 
     #[inline]
@@ -546,7 +788,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
             style.width().resolve(parent_inner_size.width, node),
             style.height().resolve(parent_inner_size.height, node),
         );
-        match style.box_sizing() {
+        let size = match style.box_sizing() {
             BoxSizing::BorderBox => size,
             BoxSizing::PaddingBox => OptionSize::new(
                 size.width + padding_border.horizontal() - border.horizontal(),
@@ -556,7 +798,15 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
                 size.width + padding_border.horizontal(),
                 size.height + padding_border.vertical(),
             ),
-        }
+        };
+        self.apply_intrinsic_sizing(
+            node,
+            (style.width(), style.height()),
+            size,
+            parent_inner_size,
+            border,
+            padding_border,
+        )
     }
 
     #[inline]
@@ -586,6 +836,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
                 border,
                 padding_border,
             );
+            trace_measure(env, node, LayoutMeasureKind::Block);
             let r = node.measure_block_size(
                 env,
                 req_size,
@@ -627,6 +878,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
                         .or_zero()
                         .main_axis_end(axis_info.dir, axis_info.main_dir_rev),
                 ),
+                line_count: 0,
             };
             if request.kind == ComputeRequestKind::Position {
                 self.result = Rect::new(Point::zero(), *size);
@@ -665,6 +917,7 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
             };
             let min_max_limit =
                 self.normalized_min_max_limit(node, parent_inner_size, border, padding_border);
+            trace_measure(env, node, LayoutMeasureKind::InlineUnit);
             let r = node.measure_inline_unit(
                 env,
                 req_size,
@@ -716,35 +969,54 @@ impl<T: LayoutTreeNode> LayoutUnit<T> {
         offset_main: T::Length,
         offset_cross: T::Length,
     ) -> Vector<T::Length> {
-        let (width, height, width_rev, height_rev) = match axis_info.dir {
-            AxisDirection::Horizontal => (
-                offset_main,
-                offset_cross,
-                axis_info.main_dir_rev,
-                axis_info.cross_dir_rev,
-            ),
-            AxisDirection::Vertical => (
-                offset_cross,
-                offset_main,
-                axis_info.cross_dir_rev,
-                axis_info.main_dir_rev,
-            ),
-        };
-        let width = match width_rev {
-            AxisReverse::NotReversed => width,
-            AxisReverse::Reversed => parent_size.width - width - self.result.size.width,
-        };
-        let height = match height_rev {
-            AxisReverse::NotReversed => height,
-            AxisReverse::Reversed => parent_size.height - height - self.result.size.height,
-        };
-        self.result.origin = Point::new(width, height);
+        self.result.origin = origin_in_parent(
+            axis_info,
+            parent_size,
+            self.result.size,
+            offset_main,
+            offset_cross,
+        );
 
         // info!("!!! {:p} pos {:?}", self, self.result);
         self.result.origin.to_vector()
     }
 }
 
+/// The origin of a box of `size` placed at the given offsets in its parent,
+/// without positioning the box (see `LayoutUnit::gen_origin`).
+#[inline]
+pub(crate) fn origin_in_parent<L: LengthNum>(
+    axis_info: AxisInfo,
+    parent_size: Size<L>,
+    size: Size<L>,
+    offset_main: L,
+    offset_cross: L,
+) -> Point<L> {
+    let (width, height, width_rev, height_rev) = match axis_info.dir {
+        AxisDirection::Horizontal => (
+            offset_main,
+            offset_cross,
+            axis_info.main_dir_rev,
+            axis_info.cross_dir_rev,
+        ),
+        AxisDirection::Vertical => (
+            offset_cross,
+            offset_main,
+            axis_info.cross_dir_rev,
+            axis_info.main_dir_rev,
+        ),
+    };
+    let width = match width_rev {
+        AxisReverse::NotReversed => width,
+        AxisReverse::Reversed => parent_size.width - width - size.width,
+    };
+    let height = match height_rev {
+        AxisReverse::NotReversed => height,
+        AxisReverse::Reversed => parent_size.height - height - size.height,
+    };
+    Point::new(width, height)
+}
+
 #[allow(missing_docs)]
 /// SizingMode is used to determine the sizing mode of the node.
 #[derive(Clone, PartialEq, Copy, Hash, Eq, Debug)]
@@ -762,13 +1034,14 @@ pub(crate) struct ComputeRequest<L: LengthNum> {
     pub(crate) kind: ComputeRequestKind,
     pub(crate) parent_is_block: bool,
     pub(crate) sizing_mode: SizingMode,
+    pub(crate) line_clamp: Option<InlineLineClamp>, // the line clamp inherited from the clamped ancestor block container, the cache is bypassed if set
 }
 
 impl<L: LengthNum> fmt::Debug for ComputeRequest<L> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "ComputeRequest<{:?},{:?}>({:?}x{:?}, parent {:?}x{:?}, max {:?}x{:?}, parent_is_block {:?})",
+            "ComputeRequest<{:?},{:?}>({:?}x{:?}, parent {:?}x{:?}, max {:?}x{:?}, parent_is_block {:?}, line_clamp {:?})",
             self.kind,
             self.sizing_mode,
             self.size.width,
@@ -778,6 +1051,7 @@ impl<L: LengthNum> fmt::Debug for ComputeRequest<L> {
             self.max_content.width,
             self.max_content.height,
             self.parent_is_block,
+            self.line_clamp,
         )
     }
 }
@@ -788,6 +1062,7 @@ pub(crate) struct ComputeResult<L: LengthNum> {
     pub(crate) first_baseline_ascent: Vector<L>, // only valid on position request
     pub(crate) last_baseline_ascent: Vector<L>, // only valid on position request
     pub(crate) collapsed_margin: CollapsedBlockMargin<L>, // only valid on corresponding size which the request includes and collapsed_margin set
+    pub(crate) line_count: u32, // the number of line boxes counted in the size, only valid if the request has a line clamp
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]